@@ -0,0 +1,269 @@
+use std::alloc::{Layout, alloc, dealloc, handle_alloc_error};
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+use std::slice;
+use std::str;
+
+mod slab;
+
+pub use slab::{Iter as SlabIter, Key, Slab};
+
+/*
+Arena de tipo "bump"
+
+Se pide un bloque grande al allocador y cada asignación solo "empuja" un
+puntero hacia adelante. No hay liberación individual: todo se libera junto
+cuando la arena se destruye.
+
+    chunk:  ┌──────┬──┬────────────┬───────────────────────────┐
+            │ u64  │u8│  [u32; 3]  │          libre            │
+            └──────┴──┴────────────┴───────────────────────────┘
+                                   ▲ next                      ▲ end
+
+Asignar un T:
+  1. redondear `next` hacia arriba al alineamiento de T;
+  2. si no cabe antes de `end`, pedir un chunk nuevo (el doble del anterior, o
+     lo que haga falta) y seguir ahí: los chunks viejos se conservan, así que
+     las referencias ya entregadas siguen siendo válidas;
+  3. escribir el valor y avanzar `next`.
+
+`next` y `end` son `Cell<usize>`: `alloc` recibe `&self`, para poder tener
+muchas referencias vivas a la vez mientras se siguen asignando valores.
+
+Destructores
+------------
+Los valores que necesitan `Drop` se anotan en una lista (puntero + función
+que los destruye) y se destruyen al soltar la arena, en orden inverso al de
+asignación. Para que eso sea seguro, un valor no puede contener referencias
+que mueran ANTES que la arena: de ahí el parámetro `'a` y el límite `T: 'a` en
+`alloc`. Es invariante a propósito, para que el compilador no lo pueda achicar.
+
+Consecuencia: un valor asignado con `alloc` no puede apuntar a otro valor de
+la misma arena (esa referencia viviría lo que el préstamo de la arena, no
+todo `'a`). Para estructuras enlazadas dentro de la arena está `alloc_copy`:
+los tipos `Copy` no tienen destructor, así que no hace falta ese límite.
+*/
+
+/// Tamaño del primer chunk si no se indica otro.
+pub const DEFAULT_CHUNK_SIZE: usize = 4096;
+
+/// Alineamiento mínimo de cada chunk.
+const CHUNK_ALIGN: usize = 16;
+
+struct Chunk {
+    ptr: NonNull<u8>,
+    layout: Layout,
+}
+
+/// Valor pendiente de destruir.
+struct DropEntry {
+    ptr: *mut u8,
+    drop_fn: unsafe fn(*mut u8),
+}
+
+/// Destruye el `T` que está en `ptr`.
+///
+/// # Safety
+/// `ptr` debe apuntar a un `T` vivo que no se vuelva a usar.
+unsafe fn drop_value<T>(ptr: *mut u8) {
+    unsafe { ptr::drop_in_place(ptr as *mut T) }
+}
+
+/// Arena de asignación por desplazamiento ("bump"), con chunks encadenados.
+///
+/// ```
+/// use arena::Arena;
+///
+/// let arena = Arena::new();
+/// let a = arena.alloc(1u64);
+/// let b = arena.alloc(String::from("hola"));
+/// let s = arena.alloc_str("mundo");
+/// *a += 1;
+/// assert_eq!((*a, b.as_str(), &*s), (2, "hola", "mundo"));
+/// ```
+pub struct Arena<'a> {
+    chunks: RefCell<Vec<Chunk>>,
+    next: Cell<usize>,
+    end: Cell<usize>,
+    drops: RefCell<Vec<DropEntry>>,
+    _invariant: PhantomData<Cell<&'a ()>>,
+}
+
+impl<'a> Arena<'a> {
+    /// Crea una arena vacía y reserva de una vez su primer chunk de
+    /// [`DEFAULT_CHUNK_SIZE`] bytes.
+    pub fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Crea una arena y reserva de una vez su primer chunk, de `size` bytes.
+    ///
+    /// # Panics
+    /// Si `size` es 0.
+    pub fn with_chunk_size(size: usize) -> Self {
+        assert!(size > 0, "el tamaño de chunk debe ser mayor que 0");
+        let arena = Self {
+            chunks: RefCell::new(Vec::new()),
+            next: Cell::new(0),
+            end: Cell::new(0),
+            drops: RefCell::new(Vec::new()),
+            _invariant: PhantomData,
+        };
+        arena.add_chunk(size);
+        arena
+    }
+
+    /// Cantidad de chunks reservados hasta ahora.
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+
+    /// Bytes reservados en total entre todos los chunks.
+    pub fn allocated_bytes(&self) -> usize {
+        self.chunks.borrow().iter().map(|c| c.layout.size()).sum()
+    }
+
+    /// Reserva un chunk nuevo de `size` bytes y pasa a asignar desde él.
+    fn add_chunk(&self, size: usize) {
+        let layout = Layout::from_size_align(size, CHUNK_ALIGN).expect("chunk demasiado grande");
+        // SAFETY: size > 0
+        let ptr = unsafe { alloc(layout) };
+        let Some(ptr) = NonNull::new(ptr) else {
+            handle_alloc_error(layout);
+        };
+
+        self.next.set(ptr.as_ptr() as usize);
+        self.end.set(ptr.as_ptr() as usize + size);
+        self.chunks.borrow_mut().push(Chunk { ptr, layout });
+    }
+
+    /// Reserva espacio sin inicializar para `layout`.
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        if layout.size() == 0 {
+            // Un ZST no ocupa memoria: cualquier dirección bien alineada sirve
+            return NonNull::<u8>::dangling().with_addr(layout.align().try_into().unwrap());
+        }
+
+        if let Some(ptr) = self.try_bump(layout) {
+            return ptr;
+        }
+
+        // No cabe: chunk nuevo del doble del último, o lo justo para este valor
+        let last = self.chunks.borrow().last().map_or(0, |c| c.layout.size());
+        let needed = layout.size() + layout.align();
+        self.add_chunk((last * 2).max(needed));
+        self.try_bump(layout)
+            .expect("el chunk nuevo tiene espacio suficiente")
+    }
+
+    fn try_bump(&self, layout: Layout) -> Option<NonNull<u8>> {
+        let start = self.next.get().checked_next_multiple_of(layout.align())?;
+        let new_next = start.checked_add(layout.size())?;
+        if new_next > self.end.get() {
+            return None;
+        }
+
+        self.next.set(new_next);
+        // Se reconstruye el puntero a partir del chunk actual para conservar
+        // su procedencia (provenance)
+        let base = self.chunks.borrow().last()?.ptr;
+        let offset = start - base.as_ptr() as usize;
+        // SAFETY: start está dentro del chunk actual
+        Some(unsafe { base.add(offset) })
+    }
+
+    /// Mueve `value` a la arena y retorna una referencia exclusiva a él.
+    ///
+    /// Si `T` tiene destructor, se ejecuta cuando la arena se destruye.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: 'a>(&self, value: T) -> &mut T {
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        // SAFETY: ptr está alineado, tiene espacio para un T y nadie más lo usa
+        unsafe { ptr.as_ptr().write(value) };
+
+        if mem::needs_drop::<T>() {
+            self.drops.borrow_mut().push(DropEntry {
+                ptr: ptr.as_ptr() as *mut u8,
+                drop_fn: drop_value::<T>,
+            });
+        }
+        // SAFETY: cada asignación es memoria distinta; la referencia no puede
+        // vivir más que el préstamo de la arena
+        unsafe { &mut *ptr.as_ptr() }
+    }
+
+    /// Como [`Arena::alloc`], para tipos `Copy`: no registra destructor y por
+    /// eso no exige `T: 'a`. Sirve para nodos que apuntan a otros nodos de la
+    /// misma arena.
+    ///
+    /// ```
+    /// use std::cell::Cell;
+    /// use arena::Arena;
+    ///
+    /// #[derive(Clone, Copy)]
+    /// struct Node<'n> {
+    ///     value: i32,
+    ///     next: Option<&'n Node<'n>>,
+    /// }
+    ///
+    /// let arena = Arena::new();
+    /// let tail = arena.alloc_copy(Node { value: 2, next: None });
+    /// let head = arena.alloc_copy(Node { value: 1, next: Some(tail) });
+    /// assert_eq!(head.next.unwrap().value, 2);
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_copy<T: Copy>(&self, value: T) -> &mut T {
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        // SAFETY: igual que en `alloc`
+        unsafe {
+            ptr.as_ptr().write(value);
+            &mut *ptr.as_ptr()
+        }
+    }
+
+    /// Copia `src` a la arena y retorna el slice nuevo.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        let layout = Layout::array::<T>(src.len()).expect("slice demasiado grande");
+        let ptr = self.alloc_layout(layout).cast::<T>();
+        // SAFETY: hay espacio alineado para src.len() elementos, sin solapar a src
+        unsafe {
+            ptr::copy_nonoverlapping(src.as_ptr(), ptr.as_ptr(), src.len());
+            slice::from_raw_parts_mut(ptr.as_ptr(), src.len())
+        }
+    }
+
+    /// Copia `s` a la arena y retorna el `str` nuevo.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_str(&self, s: &str) -> &mut str {
+        let bytes = self.alloc_slice_copy(s.as_bytes());
+        // SAFETY: son los bytes de un &str, UTF-8 válido
+        unsafe { str::from_utf8_unchecked_mut(bytes) }
+    }
+}
+
+impl Default for Arena<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Arena<'_> {
+    fn drop(&mut self) {
+        // Primero los destructores, del último asignado al primero...
+        for entry in self.drops.get_mut().drain(..).rev() {
+            // SAFETY: cada entrada apunta a un valor vivo que solo la arena destruye
+            unsafe { (entry.drop_fn)(entry.ptr) };
+        }
+        // ...y después la memoria
+        for chunk in self.chunks.get_mut().drain(..) {
+            // SAFETY: el chunk se reservó con este mismo layout
+            unsafe { dealloc(chunk.ptr.as_ptr(), chunk.layout) };
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::fmt;
+
+use vectors::MyVec;
+
+/*
+Slab generacional
+
+Las posiciones de un vector sirven de identificador estable solo si nunca se
+reutilizan. Si se reutilizan aparece el problema ABA:
+
+    let a = slab.insert("a");   // a = índice 3
+    slab.remove(a);
+    let b = slab.insert("b");   // reutiliza el índice 3
+    slab.get(a);                // ¡retornaría "b"!
+
+Solución: cada casilla lleva una GENERACIÓN que se incrementa al vaciarla, y
+la llave guarda la generación con la que se creó. Una llave vieja ya no
+coincide:
+
+    casillas:  ┌─────────────┬─────────────┬─────────────┬─────────────┐
+               │ gen 0  "x"  │ gen 1 libre │ gen 0  "y"  │ gen 2  "b"  │
+               └─────────────┴──────┬──────┴─────────────┴─────────────┘
+                                    │ siguiente libre: ninguno
+    free_head ──────────────────────┘
+
+    Key { index: 3, generation: 1 }  → generación distinta → None
+    Key { index: 3, generation: 2 }  → "b"
+
+Las casillas libres forman una lista enlazada dentro del mismo vector
+(`next_free`), así que insertar y quitar son O(1) y la memoria no crece si se
+inserta y quita en ciclo: siempre se reutiliza una casilla libre antes de
+crecer.
+
+La generación es un `u32`. Si una casilla llega a `u32::MAX`, volver a 0
+haría válidas otra vez las llaves de hace 2^32 usos, así que esa casilla se
+RETIRA: queda vacía para siempre y no vuelve a la lista de libres. Se pierde
+una casilla cada 2^32 reutilizaciones, a cambio de que el ABA nunca vuelva.
+*/
+
+/// Llave estable de un [`Slab`]: índice de la casilla y generación.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Key {
+    index: usize,
+    generation: u32,
+}
+
+impl Key {
+    /// Índice de la casilla a la que apunta la llave.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Generación con la que se creó la llave.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+enum Entry<T> {
+    Occupied {
+        generation: u32,
+        value: T,
+    },
+    Vacant {
+        generation: u32,
+        next_free: Option<usize>,
+    },
+}
+
+/// Contenedor con llaves generacionales: las llaves de elementos quitados
+/// nunca apuntan a elementos nuevos.
+///
+/// ```
+/// use arena::Slab;
+///
+/// let mut slab = Slab::new();
+/// let a = slab.insert("a");
+/// assert_eq!(slab.remove(a), Some("a"));
+///
+/// let b = slab.insert("b");
+/// assert_eq!(a.index(), b.index()); // se reutilizó la casilla...
+/// assert_eq!(slab.get(a), None);    // ...pero la llave vieja no sirve
+/// assert_eq!(slab.get(b), Some(&"b"));
+/// ```
+pub struct Slab<T> {
+    entries: MyVec<Entry<T>>,
+    free_head: Option<usize>,
+    len: usize,
+}
+
+impl<T> Slab<T> {
+    /// Crea un slab vacío.
+    pub fn new() -> Self {
+        Self {
+            entries: MyVec::new(),
+            free_head: None,
+            len: 0,
+        }
+    }
+
+    /// Retorna la cantidad de elementos vivos.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si no hay elementos vivos.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna la cantidad de casillas (vivas, libres o retiradas).
+    pub fn capacity(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Guarda `value` y retorna su llave.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado: reutiliza una casilla libre si la hay.
+    pub fn insert(&mut self, value: T) -> Key {
+        self.len += 1;
+
+        let Some(index) = self.free_head else {
+            let index = self.entries.len();
+            self.entries.push_back(Entry::Occupied {
+                generation: 0,
+                value,
+            });
+            return Key {
+                index,
+                generation: 0,
+            };
+        };
+
+        let entry = &mut self.entries.as_mut_slice()[index];
+        let Entry::Vacant {
+            generation,
+            next_free,
+        } = *entry
+        else {
+            unreachable!("la lista de libres solo enlaza casillas vacías");
+        };
+        self.free_head = next_free;
+        *entry = Entry::Occupied { generation, value };
+        Key { index, generation }
+    }
+
+    /// Retorna el elemento de `key`, o `None` si ya fue quitado.
+    pub fn get(&self, key: Key) -> Option<&T> {
+        match self.entries.get(key.index)? {
+            Entry::Occupied { generation, value } if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Retorna una referencia mutable al elemento de `key`.
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        match self.entries.get_mut(key.index)? {
+            Entry::Occupied { generation, value } if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Retorna `true` si `key` apunta a un elemento vivo.
+    pub fn contains(&self, key: Key) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Quita el elemento de `key` y lo retorna. La casilla pasa a la lista de
+    /// libres con la generación incrementada, salvo que ya esté en `u32::MAX`:
+    /// entonces se retira y no se vuelve a usar.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        if !self.contains(key) {
+            return None;
+        }
+
+        let next_generation = key.generation.checked_add(1);
+        let vacant = match next_generation {
+            Some(generation) => Entry::Vacant {
+                generation,
+                next_free: self.free_head,
+            },
+            None => Entry::Vacant {
+                generation: u32::MAX,
+                next_free: None,
+            },
+        };
+        let Entry::Occupied { value, .. } =
+            std::mem::replace(&mut self.entries.as_mut_slice()[key.index], vacant)
+        else {
+            unreachable!("contains verificó que la casilla está ocupada");
+        };
+        if next_generation.is_some() {
+            self.free_head = Some(key.index);
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Retorna un iterador sobre `(Key, &T)` de los elementos vivos, en orden
+    /// de casilla.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            entries: self.entries.as_slice().iter().enumerate(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<T> Default for Slab<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Slab<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Iterador sobre los elementos vivos de un [`Slab`], creado por [`Slab::iter`].
+pub struct Iter<'a, T> {
+    entries: std::iter::Enumerate<std::slice::Iter<'a, Entry<T>>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = (Key, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Las casillas vacías se saltan
+        for (index, entry) in self.entries.by_ref() {
+            if let Entry::Occupied { generation, value } = entry {
+                self.remaining -= 1;
+                return Some((
+                    Key {
+                        index,
+                        generation: *generation,
+                    },
+                    value,
+                ));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a Slab<T> {
+    type Item = (Key, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slot_at_max_generation_is_retired() {
+        let mut slab = Slab::new();
+        let first = slab.insert("a");
+        // Adelanta la generación de la casilla para no hacer 2^32 ciclos
+        let Entry::Occupied { generation, .. } = &mut slab.entries.as_mut_slice()[first.index]
+        else {
+            unreachable!();
+        };
+        *generation = u32::MAX - 1;
+        let old = Key {
+            index: first.index,
+            generation: u32::MAX - 1,
+        };
+
+        // De MAX - 1 a MAX la casilla todavía vuelve a la lista de libres
+        assert_eq!(slab.remove(old), Some("a"));
+        assert_eq!(slab.free_head, Some(first.index));
+        let last = slab.insert("b");
+        assert_eq!(last.index(), first.index);
+        assert_eq!(last.generation(), u32::MAX);
+
+        // En MAX ya no hay generación siguiente: la casilla se retira
+        assert_eq!(slab.remove(last), Some("b"));
+        assert_eq!(slab.free_head, None);
+        assert!(matches!(
+            slab.entries.as_slice()[first.index],
+            Entry::Vacant {
+                generation: u32::MAX,
+                next_free: None
+            }
+        ));
+
+        let fresh = slab.insert("c");
+        assert_ne!(fresh.index(), first.index);
+        assert_eq!(fresh.generation(), 0);
+        assert_eq!(slab.capacity(), 2);
+
+        assert_eq!(slab.get(old), None);
+        assert_eq!(slab.get(last), None);
+        assert_eq!(slab.remove(last), None);
+        assert_eq!(slab.get(fresh), Some(&"c"));
+        assert_eq!(slab.len(), 1);
+    }
+}
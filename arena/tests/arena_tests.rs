@@ -0,0 +1,98 @@
+use std::cell::Cell;
+use std::mem::align_of;
+
+use arena::Arena;
+
+#[test]
+fn test_many_mixed_values_read_back() {
+    let arena = Arena::new();
+    let mut bytes = Vec::new();
+    let mut words = Vec::new();
+    let mut strings = Vec::new();
+
+    for i in 0..2_000u32 {
+        bytes.push(&*arena.alloc(i as u8));
+        words.push(&*arena.alloc(u64::from(i) * 1_000_003));
+        strings.push(&*arena.alloc(format!("valor {i}")));
+    }
+
+    for i in 0..2_000u32 {
+        assert_eq!(*bytes[i as usize], i as u8);
+        assert_eq!(*words[i as usize], u64::from(i) * 1_000_003);
+        assert_eq!(strings[i as usize], &format!("valor {i}"));
+    }
+    assert!(arena.chunk_count() > 1);
+}
+
+#[test]
+fn test_alignment_after_odd_sized_values() {
+    let arena = Arena::new();
+    for _ in 0..100 {
+        let b = arena.alloc(1u8);
+        let w = arena.alloc(2u64);
+        let x = arena.alloc(3u128);
+        assert_eq!((w as *const u64 as usize) % align_of::<u64>(), 0);
+        assert_eq!((x as *const u128 as usize) % align_of::<u128>(), 0);
+        assert_eq!((*b, *w, *x), (1, 2, 3));
+    }
+}
+
+#[test]
+fn test_chunk_growth_keeps_old_references_valid() {
+    let arena = Arena::with_chunk_size(64);
+    assert_eq!(arena.chunk_count(), 1);
+
+    let first = arena.alloc([7u8; 48]);
+    // No cabe en lo que queda del primer chunk
+    let second = arena.alloc([9u8; 48]);
+    assert_eq!(arena.chunk_count(), 2);
+    assert_eq!(arena.allocated_bytes(), 64 + 128);
+
+    // Un valor más grande que el doble del último chunk pide justo lo necesario
+    let big = arena.alloc_slice_copy(&[1u32; 200]);
+    assert_eq!(arena.chunk_count(), 3);
+    assert_eq!(big.len(), 200);
+
+    first[0] = 1;
+    assert_eq!((first[0], first[47], second[0]), (1, 7, 9));
+}
+
+#[test]
+fn test_slices_strings_and_zero_sized() {
+    let arena = Arena::new();
+    let s = arena.alloc_str("¡hola arena!");
+    s.make_ascii_uppercase();
+    assert_eq!(s, "¡HOLA ARENA!");
+
+    let empty: &mut [u64] = arena.alloc_slice_copy(&[]);
+    assert!(empty.is_empty());
+    let unit = arena.alloc(());
+    assert_eq!(*unit, ());
+}
+
+struct Counted<'c>(&'c Cell<usize>, &'c Cell<Vec<u32>>, u32);
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+        let mut order = self.1.take();
+        order.push(self.2);
+        self.1.set(order);
+    }
+}
+
+#[test]
+fn test_destructors_run_once_at_arena_drop_in_reverse_order() {
+    let drops = Cell::new(0);
+    let order = Cell::new(Vec::new());
+    {
+        let arena = Arena::with_chunk_size(64);
+        for i in 0..50 {
+            arena.alloc(Counted(&drops, &order, i));
+            arena.alloc(i as u16);
+        }
+        assert_eq!(drops.get(), 0);
+    }
+    assert_eq!(drops.get(), 50);
+    assert_eq!(order.take(), (0..50).rev().collect::<Vec<_>>());
+}
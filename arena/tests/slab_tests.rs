@@ -0,0 +1,76 @@
+use arena::Slab;
+
+#[test]
+fn test_stale_key_after_remove_and_reinsert() {
+    let mut slab = Slab::new();
+    let a = slab.insert(10);
+    let _keep = slab.insert(20);
+
+    assert_eq!(slab.remove(a), Some(10));
+    let b = slab.insert(30);
+
+    assert_eq!(a.index(), b.index());
+    assert_ne!(a.generation(), b.generation());
+    assert_eq!(slab.get(a), None);
+    assert_eq!(slab.get_mut(a), None);
+    assert_eq!(slab.remove(a), None);
+    assert_eq!(slab.get(b), Some(&30));
+    assert_eq!(slab.len(), 2);
+}
+
+#[test]
+fn test_remove_twice_returns_none() {
+    let mut slab = Slab::new();
+    let k = slab.insert("x");
+    assert_eq!(slab.remove(k), Some("x"));
+    assert_eq!(slab.remove(k), None);
+    assert!(slab.is_empty());
+}
+
+#[test]
+fn test_iter_skips_holes() {
+    let mut slab = Slab::new();
+    let keys: Vec<_> = (0..10).map(|i| slab.insert(i)).collect();
+    for k in keys.iter().step_by(3) {
+        slab.remove(*k);
+    }
+
+    let iter = slab.iter();
+    assert_eq!(iter.len(), 6);
+    let live: Vec<_> = iter.map(|(k, v)| (k, *v)).collect();
+    assert_eq!(
+        live.iter().map(|(_, v)| *v).collect::<Vec<_>>(),
+        [1, 2, 4, 5, 7, 8]
+    );
+    for (k, v) in live {
+        assert_eq!(slab.get(k), Some(&v));
+    }
+}
+
+#[test]
+fn test_get_mut_and_debug() {
+    let mut slab = Slab::new();
+    let k = slab.insert(String::from("ho"));
+    slab.get_mut(k).unwrap().push_str("la");
+    assert_eq!(slab.get(k).map(String::as_str), Some("hola"));
+    assert_eq!(
+        format!("{slab:?}"),
+        r#"{Key { index: 0, generation: 0 }: "hola"}"#
+    );
+}
+
+#[test]
+fn test_insert_remove_cycles_keep_capacity_stable() {
+    let mut slab = Slab::new();
+    let mut live: Vec<_> = (0..16).map(|i| slab.insert(i)).collect();
+    let capacity = slab.capacity();
+
+    for i in 0..1_000_000 {
+        let k = live.swap_remove(i % live.len());
+        assert!(slab.remove(k).is_some());
+        live.push(slab.insert(i));
+    }
+
+    assert_eq!(slab.capacity(), capacity);
+    assert_eq!(slab.len(), 16);
+}
@@ -0,0 +1,191 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::poo::{Circle, Point, Rect, Square, Triangle, TriangleError};
+
+/*
+Builders
+
+`Circle::new(0.0, 0.0, 5.0)`: ¿cuál es el radio? Con un builder cada valor
+lleva su nombre:
+
+    Circle::builder().center(0.0, 0.0).radius(5.0).build()
+
+- Los métodos toman `self` y lo retornan, para encadenarlos.
+- Lo opcional tiene un valor por defecto (centro y esquina en el origen); lo
+  obligatorio es un `Option` y faltar es un error, no un panic.
+- `build(&self)` valida y construye SIN consumir el builder: se puede usar
+  como plantilla y construir varias figuras.
+*/
+
+/// Error al construir una figura con un builder.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+    /// Falta un campo obligatorio.
+    MissingField(&'static str),
+    /// Una medida negativa o NaN.
+    InvalidLength { field: &'static str, value: f64 },
+    /// Menos de 3 vértices para un triángulo.
+    TooFewVertices(usize),
+    /// Más de 3 vértices para un triángulo.
+    TooManyVertices(usize),
+    /// Los vértices del triángulo son colineales.
+    Collinear,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MissingField(field) => write!(f, "missing required field `{field}`"),
+            BuildError::InvalidLength { field, value } => {
+                write!(f, "`{field}` must be a non-negative number, got {value}")
+            }
+            BuildError::TooFewVertices(n) => {
+                write!(f, "a triangle needs 3 vertices, got only {n}")
+            }
+            BuildError::TooManyVertices(n) => {
+                write!(f, "a triangle needs 3 vertices, got {n}")
+            }
+            BuildError::Collinear => write!(f, "triangle vertices are collinear"),
+        }
+    }
+}
+
+impl Error for BuildError {}
+
+impl From<TriangleError> for BuildError {
+    fn from(e: TriangleError) -> Self {
+        match e {
+            TriangleError::Collinear => BuildError::Collinear,
+        }
+    }
+}
+
+/// Valida una medida obligatoria: presente, no negativa y no NaN.
+fn length(field: &'static str, value: Option<f64>) -> Result<f64, BuildError> {
+    match value {
+        None => Err(BuildError::MissingField(field)),
+        Some(v) if v >= 0.0 => Ok(v),
+        Some(value) => Err(BuildError::InvalidLength { field, value }),
+    }
+}
+
+/// Builder de [`Circle`]; el centro por defecto es el origen.
+#[derive(Clone, Debug, Default)]
+pub struct CircleBuilder {
+    center: Point,
+    radius: Option<f64>,
+}
+
+impl CircleBuilder {
+    pub fn center(mut self, x: f64, y: f64) -> Self {
+        self.center = Point::new(x, y);
+        self
+    }
+
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    pub fn build(&self) -> Result<Circle, BuildError> {
+        let radius = length("radius", self.radius)?;
+        Ok(Circle::new(self.center.x, self.center.y, radius))
+    }
+}
+
+/// Builder de [`Rect`]; la esquina por defecto es el origen.
+#[derive(Clone, Debug, Default)]
+pub struct RectBuilder {
+    top_left: Point,
+    width: Option<f64>,
+    height: Option<f64>,
+}
+
+impl RectBuilder {
+    pub fn top_left(mut self, x: f64, y: f64) -> Self {
+        self.top_left = Point::new(x, y);
+        self
+    }
+
+    pub fn size(mut self, width: f64, height: f64) -> Self {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    pub fn build(&self) -> Result<Rect, BuildError> {
+        let width = length("width", self.width)?;
+        let height = length("height", self.height)?;
+        Ok(Rect::new(self.top_left, width, height))
+    }
+}
+
+/// Builder de [`Square`]; la esquina por defecto es el origen.
+#[derive(Clone, Debug, Default)]
+pub struct SquareBuilder {
+    top_left: Point,
+    side: Option<f64>,
+}
+
+impl SquareBuilder {
+    pub fn top_left(mut self, x: f64, y: f64) -> Self {
+        self.top_left = Point::new(x, y);
+        self
+    }
+
+    pub fn side(mut self, side: f64) -> Self {
+        self.side = Some(side);
+        self
+    }
+
+    pub fn build(&self) -> Result<Square, BuildError> {
+        let side = length("side", self.side)?;
+        Ok(Square::new(self.top_left.x, self.top_left.y, side))
+    }
+}
+
+/// Builder de [`Triangle`]: `vertex` se llama exactamente tres veces.
+#[derive(Clone, Debug, Default)]
+pub struct TriangleBuilder {
+    vertices: Vec<Point>,
+}
+
+impl TriangleBuilder {
+    pub fn vertex(mut self, p: Point) -> Self {
+        self.vertices.push(p);
+        self
+    }
+
+    pub fn build(&self) -> Result<Triangle, BuildError> {
+        match *self.vertices.as_slice() {
+            [a, b, c] => Ok(Triangle::new(a, b, c)?),
+            ref v if v.len() < 3 => Err(BuildError::TooFewVertices(v.len())),
+            ref v => Err(BuildError::TooManyVertices(v.len())),
+        }
+    }
+}
+
+impl Circle {
+    pub fn builder() -> CircleBuilder {
+        CircleBuilder::default()
+    }
+}
+
+impl Rect {
+    pub fn builder() -> RectBuilder {
+        RectBuilder::default()
+    }
+}
+
+impl Square {
+    pub fn builder() -> SquareBuilder {
+        SquareBuilder::default()
+    }
+}
+
+impl Triangle {
+    pub fn builder() -> TriangleBuilder {
+        TriangleBuilder::default()
+    }
+}
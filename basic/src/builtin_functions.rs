@@ -1,3 +1,4 @@
+#[allow(dead_code)]
 fn vector_demo() {
     /*
     ----------------------------------------
@@ -26,9 +27,9 @@ fn vector_demo() {
     // inicializar
     println!("INICIALIZAR VECTORES");
 
-    let mut v2 = vec![0; 5]; // [0, 0, 0, 0, 0]
+    let v2 = vec![0; 5]; // [0, 0, 0, 0, 0]
     println!("{:?}", v2); // [0, 0, 0, 0, 0]
-    let mut v2: Vec<i32> = (1..6).collect();
+    let v2: Vec<i32> = (1..6).collect();
     println!("{:?}", v2); // [1, 2, 3, 4, 5]
     println!("---------------------------------------------------------------");
 
@@ -129,6 +130,7 @@ fn vector_demo() {
 }
 
 
+#[allow(dead_code)]
 fn string_demo() {
     /*
     ----------------------------------------
@@ -264,6 +266,7 @@ fn string_demo() {
 }
 
 
+#[allow(dead_code)]
 pub fn builtin_functions_tour() {
     // vector_demo();
     string_demo();
@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/*
+-----------------------------------------------------------
+   Árbol de expresiones: enums recursivos con Box
+-----------------------------------------------------------
+
+Un enum no puede contenerse a sí mismo directamente: su tamaño sería
+infinito. `Box<Expr>` tiene tamaño fijo (un puntero), así que cada operación
+guarda a sus operandos en el heap y el árbol puede crecer lo que haga falta.
+
+"3 + 4 * (2 - x)" queda como:
+
+            +
+          /   \
+         3     *
+             /   \
+            4     -
+                /   \
+               2     x
+
+Parser de descenso recursivo: una función por nivel de precedencia, de
+menor a mayor. Cada nivel llama al siguiente para leer sus operandos, así
+que lo que está más abajo "se agrupa primero":
+
+    expr   := term   (('+' | '-') term)*      izquierda a derecha
+    term   := unary  (('*' | '/') unary)*     izquierda a derecha
+    unary  := '-' unary | power
+    power  := atom ('^' unary)?               derecha a izquierda
+    atom   := número | variable | '(' expr ')'
+
+- Asociatividad por izquierda (`8 - 3 - 2` = `(8 - 3) - 2`): un bucle que
+  va colgando el árbol acumulado como operando izquierdo.
+- Asociatividad por derecha (`2 ^ 3 ^ 2` = `2 ^ (3 ^ 2)`): el operando
+  derecho se lee con una llamada recursiva que vuelve a consumir `^`.
+- `-2 ^ 2` es `-(2 ^ 2)`, como en matemática.
+
+Cada `(`, `-` o `^` anidado es una llamada recursiva más, así que
+"((((...))))" con decenas de miles de paréntesis desbordaría la pila. Y
+aunque el parser lee "1 + 1 + ... + 1" con un bucle, el árbol que arma tiene
+un nivel por cada `+`: soltarlo, evaluarlo o imprimirlo es recursivo y
+también la desbordaría. Por eso la profundidad cuenta tanto el anidamiento
+como los operadores encadenados, e igual que en `json`, pasar de
+[`MAX_DEPTH`] niveles es un error de parseo.
+
+`Display` vuelve a imprimir el árbol poniendo paréntesis solo donde hacen
+falta para que, al volver a parsearlo, salga el mismo árbol.
+*/
+
+/// Profundidad máxima del árbol que acepta el parser: paréntesis, `-`, `^`
+/// y operadores encadenados (`1 + 2 + 3` ya usa dos niveles).
+pub const MAX_DEPTH: usize = 128;
+
+/// Operador binario de una [`Expr`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+impl BinOp {
+    fn symbol(self) -> char {
+        match self {
+            BinOp::Add => '+',
+            BinOp::Sub => '-',
+            BinOp::Mul => '*',
+            BinOp::Div => '/',
+            BinOp::Pow => '^',
+        }
+    }
+
+    fn precedence(self) -> u8 {
+        match self {
+            BinOp::Add | BinOp::Sub => 1,
+            BinOp::Mul | BinOp::Div => 2,
+            BinOp::Pow => 4,
+        }
+    }
+}
+
+/// Precedencia de la negación unaria: entre `*` y `^`.
+const NEG_PRECEDENCE: u8 = 3;
+
+/// Expresión aritmética como árbol.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use basic::expr::Expr;
+///
+/// let expr: Expr = "3 + 4 * (2 - x)".parse().unwrap();
+/// let vars = HashMap::from([("x".to_string(), 5.0)]);
+/// assert_eq!(expr.eval(&vars), Ok(-9.0));
+/// assert_eq!(expr.to_string(), "3 + 4 * (2 - x)");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    Var(String),
+    Neg(Box<Expr>),
+    BinaryOp {
+        op: BinOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+}
+
+/// Error de [`Expr::eval`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvalError {
+    /// La variable no está en el mapa de valores.
+    UndefinedVariable(String),
+    /// El divisor de una `/` evaluó a cero.
+    DivisionByZero,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UndefinedVariable(name) => write!(f, "undefined variable `{name}`"),
+            EvalError::DivisionByZero => write!(f, "division by zero"),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+/// Qué salió mal al parsear.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Un carácter que no puede ir en esa posición.
+    UnexpectedChar(char),
+    /// La entrada terminó cuando faltaba un operando.
+    UnexpectedEnd,
+    /// Un `(` que nunca se cerró.
+    UnclosedParen,
+    /// Más de [`MAX_DEPTH`] niveles de anidamiento.
+    TooDeep,
+    /// La expresión terminó pero sobra texto (por ejemplo un `)` de más).
+    TrailingInput,
+}
+
+/// Error de parseo con el offset en bytes donde ocurrió.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub offset: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected `{c}`")?,
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            ParseErrorKind::UnclosedParen => write!(f, "unclosed `(`")?,
+            ParseErrorKind::TooDeep => write!(f, "nesting deeper than {MAX_DEPTH} levels")?,
+            ParseErrorKind::TrailingInput => write!(f, "unexpected trailing input")?,
+        }
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parser de descenso recursivo sobre los bytes de la entrada.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, kind: ParseErrorKind, offset: usize) -> ParseError {
+        ParseError { kind, offset }
+    }
+
+    /// Salta espacios y retorna el próximo carácter sin consumirlo.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.input[self.pos..];
+        let trimmed = rest.trim_start();
+        self.pos += rest.len() - trimmed.len();
+        trimmed.chars().next()
+    }
+
+    /// Consume `c` si es el próximo carácter.
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Baja un nivel, o falla si ya se está en `MAX_DEPTH`.
+    fn descend(&mut self) -> Result<(), ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::TooDeep, self.pos));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Llama a `parse` un nivel más adentro, respetando `MAX_DEPTH`.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Expr, ParseError>,
+    ) -> Result<Expr, ParseError> {
+        self.descend()?;
+        let expr = parse(self);
+        self.depth -= 1;
+        expr
+    }
+
+    fn expr(&mut self) -> Result<Expr, ParseError> {
+        let depth = self.depth;
+        let mut lhs = self.term()?;
+        loop {
+            let op = match self.peek() {
+                Some('+') => BinOp::Add,
+                Some('-') => BinOp::Sub,
+                _ => break,
+            };
+            // Cada operador deja al árbol acumulado un nivel más abajo
+            self.descend()?;
+            self.pos += 1;
+            lhs = Expr::binary(op, lhs, self.term()?);
+        }
+        self.depth = depth;
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Expr, ParseError> {
+        let depth = self.depth;
+        let mut lhs = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some('*') => BinOp::Mul,
+                Some('/') => BinOp::Div,
+                _ => break,
+            };
+            self.descend()?;
+            self.pos += 1;
+            lhs = Expr::binary(op, lhs, self.unary()?);
+        }
+        self.depth = depth;
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.eat('-') {
+            return Ok(Expr::Neg(Box::new(self.nested(Self::unary)?)));
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Expr, ParseError> {
+        let base = self.atom()?;
+        if self.eat('^') {
+            // Recursión a la derecha: 2 ^ 3 ^ 2 = 2 ^ (3 ^ 2)
+            return Ok(Expr::binary(BinOp::Pow, base, self.nested(Self::unary)?));
+        }
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<Expr, ParseError> {
+        let start = self.pos;
+        let Some(c) = self.peek() else {
+            return Err(self.error(ParseErrorKind::UnexpectedEnd, self.pos));
+        };
+
+        if c == '(' {
+            let open = self.pos;
+            self.pos += 1;
+            let inner = self.nested(Self::expr)?;
+            if !self.eat(')') {
+                return Err(self.error(ParseErrorKind::UnclosedParen, open));
+            }
+            return Ok(inner);
+        }
+
+        let rest = &self.input[self.pos..];
+        if c.is_ascii_digit() || c == '.' {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(rest.len());
+            return match rest[..len].parse() {
+                Ok(value) => {
+                    self.pos += len;
+                    Ok(Expr::Num(value))
+                }
+                Err(_) => Err(self.error(ParseErrorKind::UnexpectedChar(c), start)),
+            };
+        }
+        if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            self.pos += len;
+            return Ok(Expr::Var(rest[..len].to_string()));
+        }
+
+        Err(self.error(ParseErrorKind::UnexpectedChar(c), self.pos))
+    }
+}
+
+impl Expr {
+    fn binary(op: BinOp, lhs: Expr, rhs: Expr) -> Expr {
+        Expr::BinaryOp {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        }
+    }
+
+    /// Parsea `input` completo como una expresión.
+    pub fn parse(input: &str) -> Result<Expr, ParseError> {
+        let mut parser = Parser { input, pos: 0, depth: 0 };
+        let expr = parser.expr()?;
+        if parser.peek().is_some() {
+            return Err(parser.error(ParseErrorKind::TrailingInput, parser.pos));
+        }
+        Ok(expr)
+    }
+
+    /// Evalúa la expresión tomando las variables de `vars`.
+    ///
+    /// # Complejidad
+    /// **O(n)** en la cantidad de nodos.
+    pub fn eval(&self, vars: &HashMap<String, f64>) -> Result<f64, EvalError> {
+        match self {
+            Expr::Num(value) => Ok(*value),
+            Expr::Var(name) => vars
+                .get(name)
+                .copied()
+                .ok_or_else(|| EvalError::UndefinedVariable(name.clone())),
+            Expr::Neg(inner) => Ok(-inner.eval(vars)?),
+            Expr::BinaryOp { op, lhs, rhs } => {
+                let (a, b) = (lhs.eval(vars)?, rhs.eval(vars)?);
+                match op {
+                    BinOp::Add => Ok(a + b),
+                    BinOp::Sub => Ok(a - b),
+                    BinOp::Mul => Ok(a * b),
+                    BinOp::Div if b == 0.0 => Err(EvalError::DivisionByZero),
+                    BinOp::Div => Ok(a / b),
+                    BinOp::Pow => Ok(a.powf(b)),
+                }
+            }
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::BinaryOp { op, .. } => op.precedence(),
+            Expr::Neg(_) => NEG_PRECEDENCE,
+            // Un literal negativo se imprime con '-': se comporta como Neg
+            Expr::Num(value) if value.is_sign_negative() => NEG_PRECEDENCE,
+            Expr::Num(_) | Expr::Var(_) => u8::MAX,
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Expr, ParseError> {
+        Expr::parse(input)
+    }
+}
+
+/// Escribe `expr` entre paréntesis si `parens` es verdadero.
+fn write_operand(f: &mut fmt::Formatter<'_>, expr: &Expr, parens: bool) -> fmt::Result {
+    if parens {
+        write!(f, "({expr})")
+    } else {
+        write!(f, "{expr}")
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Num(value) => write!(f, "{value}"),
+            Expr::Var(name) => write!(f, "{name}"),
+            Expr::Neg(inner) => {
+                write!(f, "-")?;
+                write_operand(f, inner, inner.precedence() < NEG_PRECEDENCE)
+            }
+            Expr::BinaryOp { op, lhs, rhs } => {
+                let prec = op.precedence();
+                // ^ asocia por derecha y su operando derecho admite un '-' unario
+                let (lhs_parens, rhs_parens) = if *op == BinOp::Pow {
+                    (lhs.precedence() <= prec, rhs.precedence() < NEG_PRECEDENCE)
+                } else {
+                    (lhs.precedence() < prec, rhs.precedence() <= prec)
+                };
+                write_operand(f, lhs, lhs_parens)?;
+                write!(f, " {} ", op.symbol())?;
+                write_operand(f, rhs, rhs_parens)
+            }
+        }
+    }
+}
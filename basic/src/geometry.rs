@@ -0,0 +1,7 @@
+//! Algoritmos geométricos sobre las figuras de [`crate::poo`].
+
+pub mod closest;
+pub mod collision;
+pub mod hull;
+pub mod point;
+pub mod segment;
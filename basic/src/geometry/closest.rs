@@ -0,0 +1,110 @@
+use crate::poo::Point;
+
+/*
+Par de puntos más cercano: divide y vencerás
+
+Comparar todos contra todos es O(n²). La idea O(n log n):
+
+1. Ordenar por x y partir por la mitad con una recta vertical x = m.
+2. Resolver cada mitad recursivamente: el mejor de ambas da una distancia d.
+3. El par más cercano podría tener un punto a cada lado de la recta (el caso
+   que una recursión ingenua olvida). Ese par solo puede estar en la FRANJA
+   |x - m| < d:
+
+           izquierda   │   derecha
+                  ·    │ ·
+               ·   ┌───┼───┐
+                   │ · │  ·│  ← franja de ancho 2d
+              ·    │   │·  │
+                   └───┼───┘
+                       m
+
+4. Recorriendo la franja ordenada por y, cada punto solo se compara con los
+   siguientes mientras la diferencia en y sea < d: un empaquetado en
+   cuadrados de lado d/2 muestra que son a lo sumo 7, así que la franja es
+   O(n).
+
+Para no reordenar la franja por y en cada nivel (eso daría O(n log² n)), la
+recursión además deja su mitad ordenada por y, como un merge sort: el merge
+de las dos mitades es O(n).
+*/
+
+/// Par más cercano y su distancia: `(a, b, |a - b|)`.
+type Pair = (Point, Point, f64);
+
+fn closer(a: Pair, b: Pair) -> Pair {
+    if b.2 < a.2 { b } else { a }
+}
+
+/// Versión de referencia O(n²): prueba todos los pares. `None` con menos de
+/// 2 puntos.
+pub fn closest_pair_brute(points: &[Point]) -> Option<Pair> {
+    let mut best: Option<Pair> = None;
+    for (i, &a) in points.iter().enumerate() {
+        for &b in &points[i + 1..] {
+            let pair = (a, b, a.distance_to(&b));
+            best = Some(best.map_or(pair, |best| closer(best, pair)));
+        }
+    }
+    best
+}
+
+/// Par de puntos más cercano y su distancia; `None` con menos de 2 puntos.
+/// Si hay repetidos, la distancia es 0.
+///
+/// # Complejidad
+/// **O(n log n)** tiempo y O(n) memoria extra.
+pub fn closest_pair(points: &[Point]) -> Option<Pair> {
+    if points.len() < 2 {
+        return None;
+    }
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x));
+    let mut buffer = Vec::with_capacity(sorted.len());
+    Some(closest_in(&mut sorted, &mut buffer))
+}
+
+/// `points` llega ordenado por x (al menos 2) y sale ordenado por y.
+fn closest_in(points: &mut [Point], buffer: &mut Vec<Point>) -> Pair {
+    let by_y = |a: &Point, b: &Point| a.y.total_cmp(&b.y);
+    if points.len() <= 3 {
+        let best = closest_pair_brute(points).unwrap();
+        points.sort_by(by_y);
+        return best;
+    }
+
+    let mid = points.len() / 2;
+    // Se guarda antes de recursar: después las mitades quedan ordenadas por y
+    let mid_x = points[mid].x;
+    let (left, right) = points.split_at_mut(mid);
+    let mut best = closer(closest_in(left, buffer), closest_in(right, buffer));
+
+    // Merge de las dos mitades por y
+    buffer.clear();
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < points.len() {
+        if by_y(&points[i], &points[j]).is_le() {
+            buffer.push(points[i]);
+            i += 1;
+        } else {
+            buffer.push(points[j]);
+            j += 1;
+        }
+    }
+    buffer.extend_from_slice(&points[i..mid]);
+    buffer.extend_from_slice(&points[j..]);
+    points.copy_from_slice(buffer);
+
+    // Franja alrededor de la recta, ya ordenada por y
+    buffer.clear();
+    buffer.extend(points.iter().filter(|p| (p.x - mid_x).abs() < best.2));
+    for (k, &a) in buffer.iter().enumerate() {
+        for &b in &buffer[k + 1..] {
+            if b.y - a.y >= best.2 {
+                break;
+            }
+            best = closer(best, (a, b, a.distance_to(&b)));
+        }
+    }
+    best
+}
@@ -0,0 +1,102 @@
+use crate::poo::{Circle, Drawable, Point, Rect};
+
+/*
+Colisiones entre figuras
+
+Dos figuras chocan si comparten al menos un punto. Como en `contains_point`,
+el borde cuenta: dos círculos tangentes o dos rectángulos que se tocan por un
+lado SÍ chocan, y una figura dentro de otra también.
+
+Pruebas exactas:
+
+    círculo–círculo   |c₁ - c₂| ≤ r₁ + r₂
+
+    rect–rect         los intervalos se solapan en x Y en y
+
+    círculo–rect      se "aprieta" el centro dentro del rect (clamp) para
+                      obtener el punto del rect más cercano; chocan si ese
+                      punto está a distancia ≤ r
+
+                        ┌───────┐
+                        │       ● ← clamp(c)
+                        │       │   ╲
+                        └───────┘    c   (distancia ≤ r → chocan)
+
+Para cualquier otro par (triángulos, polígonos) se comparan las cajas
+(`bounding_box`). Es CONSERVADOR: si las cajas no se tocan, las figuras
+tampoco; pero cajas que se tocan no garantizan que las figuras lo hagan
+(falsos positivos, nunca falsos negativos).
+*/
+
+/// Resultado de [`collide`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Collision {
+    /// `true` si las figuras (o sus cajas, si `conservative`) se tocan.
+    pub hit: bool,
+    /// `true` si se usaron las cajas en vez de una prueba exacta: `hit` puede
+    /// ser un falso positivo.
+    pub conservative: bool,
+}
+
+/// Círculo contra círculo; tangentes cuenta como choque.
+pub fn circle_circle(a: &Circle, b: &Circle) -> bool {
+    let d = a.center() - b.center();
+    let reach = a.radius() + b.radius();
+    d.dot(&d) <= reach * reach
+}
+
+/// Rectángulo contra rectángulo; tocarse por un borde cuenta.
+pub fn rect_rect(a: &Rect, b: &Rect) -> bool {
+    a.intersects(b)
+}
+
+/// Círculo contra rectángulo, por el punto del rect más cercano al centro.
+pub fn circle_rect(c: &Circle, r: &Rect) -> bool {
+    let center = c.center();
+    let nearest = Point::new(
+        center.x.clamp(r.left(), r.right()),
+        center.y.clamp(r.top(), r.bottom()),
+    );
+    let d = center - nearest;
+    d.dot(&d) <= c.radius() * c.radius()
+}
+
+/// Prueba dos figuras cualesquiera: exacta si ambas son círculos o
+/// rectángulos, y por cajas (conservadora) en otro caso.
+pub fn collide(a: &dyn Drawable, b: &dyn Drawable) -> Collision {
+    let exact = |hit| Collision {
+        hit,
+        conservative: false,
+    };
+
+    match (a.as_circle(), b.as_circle(), a.as_rect(), b.as_rect()) {
+        (Some(ca), Some(cb), _, _) => exact(circle_circle(ca, cb)),
+        (Some(c), None, _, Some(r)) | (None, Some(c), Some(r), _) => exact(circle_rect(c, &r)),
+        (None, None, Some(ra), Some(rb)) => exact(rect_rect(&ra, &rb)),
+        _ => Collision {
+            hit: rect_rect(&a.bounding_box(), &b.bounding_box()),
+            conservative: true,
+        },
+    }
+}
+
+/// `true` si `a` y `b` chocan, según [`collide`] (puede ser conservador).
+pub fn intersects(a: &dyn Drawable, b: &dyn Drawable) -> bool {
+    collide(a, b).hit
+}
+
+/// Todos los pares `(i, j)` con `i < j` que chocan según [`intersects`].
+///
+/// # Complejidad
+/// **O(n²)** pruebas.
+pub fn any_collisions(shapes: &[Box<dyn Drawable>]) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    for i in 0..shapes.len() {
+        for j in i + 1..shapes.len() {
+            if intersects(shapes[i].as_ref(), shapes[j].as_ref()) {
+                pairs.push((i, j));
+            }
+        }
+    }
+    pairs
+}
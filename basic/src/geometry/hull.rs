@@ -0,0 +1,74 @@
+use crate::poo::Point;
+
+/*
+Envolvente convexa: cadena monótona de Andrew
+
+La envolvente es el menor polígono convexo que contiene a todos los puntos
+(una liga estirada alrededor de clavos).
+
+1. Ordenar por (x, y) y quitar repetidos.
+2. Cadena INFERIOR, de izquierda a derecha: cada punto nuevo se agrega al
+   final, pero antes se sacan los últimos mientras no formen un giro a la
+   izquierda (producto cruz ≤ 0).
+3. Cadena SUPERIOR: lo mismo recorriendo de derecha a izquierda.
+4. Unir las dos sin repetir los extremos.
+
+            superior
+         ●─────────●
+        ╱     ·     ╲         · interiores: alguna cadena los sacó
+       ●   ·     ·   ●
+        ╲___________╱
+            inferior
+
+Sacar también los giros nulos (colineales) deja solo los vértices "de
+verdad": si todos los puntos están en una recta, quedan sus dos extremos.
+
+Con `y` hacia arriba el resultado va en sentido ANTIHORARIO (en pantalla, con
+`y` hacia abajo, se ve horario).
+*/
+
+/// Giro de `a → b → c`: positivo a la izquierda, 0 si son colineales.
+fn turn(a: &Point, b: &Point, c: &Point) -> f64 {
+    (*b - *a).cross(&(*c - *a))
+}
+
+/// Agrega `p` a la cadena, sacando antes los puntos que ya no giran a la
+/// izquierda.
+fn push_turning_left(chain: &mut Vec<Point>, p: Point) {
+    while chain.len() >= 2 && turn(&chain[chain.len() - 2], &chain[chain.len() - 1], &p) <= 0.0 {
+        chain.pop();
+    }
+    chain.push(p);
+}
+
+/// Vértices de la envolvente convexa, en sentido antihorario (con `y` hacia
+/// arriba) empezando por el de menor (x, y), sin repetir el primero al final.
+///
+/// Con menos de 3 puntos distintos retorna esos puntos ordenados; si todos son
+/// colineales, los dos extremos.
+///
+/// # Complejidad
+/// **O(n log n)** por el ordenamiento; las cadenas son O(n).
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut lower = Vec::new();
+    for &p in &sorted {
+        push_turning_left(&mut lower, p);
+    }
+    let mut upper = Vec::new();
+    for &p in sorted.iter().rev() {
+        push_turning_left(&mut upper, p);
+    }
+
+    // El último de cada cadena es el primero de la otra
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
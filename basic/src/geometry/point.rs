@@ -0,0 +1,189 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::poo::Point;
+
+/*
+Puntos genéricos con const generics
+
+`PointN<T, N>` guarda sus N componentes en un arreglo `[T; N]`; `N` es un
+parámetro de tipo que es un VALOR (const generic), así que `PointN<f64, 2>`
+y `PointN<f64, 3>` son tipos distintos y sumar un punto 2D con uno 3D no
+compila:
+
+    PointN<f64, 3>  =  [ x | y | z ]      (24 bytes, sin heap)
+
+El `Point` de `poo` sigue siendo el punto de las figuras: sus campos públicos
+`x` e `y` se usan en todos lados y un arreglo no puede exponerlos con ese
+nombre. Para pasar de uno a otro hay `From` en ambos sentidos con
+`Point2f`, que es el mismo punto en versión genérica.
+
+Las operaciones piden lo mínimo a `T`: sumar y restar necesitan `Add`/`Sub`,
+`dot` necesita además un cero (`Default`) y `length` solo existe para `f64`
+(la raíz cuadrada de un entero no es entera). [`Scalar`] junta esas
+cotas para no repetirlas.
+*/
+
+/// Lo que necesita un número para ser componente de un [`PointN`].
+pub trait Scalar:
+    Copy + Default + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+}
+
+impl<T> Scalar for T where
+    T: Copy + Default + PartialEq + Add<Output = T> + Sub<Output = T> + Mul<Output = T>
+{
+}
+
+/// Punto (o vector) de `N` componentes de tipo `T`.
+///
+/// ```
+/// use basic::geometry::point::{Point2i, Point3f};
+///
+/// let a = Point3f::new([1.0, 2.0, 2.0]);
+/// assert_eq!(a.length(), 3.0);
+///
+/// let p = Point2i::new([3, -4]) * 2;
+/// assert_eq!(p[1], -8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PointN<T, const N: usize>(pub [T; N]);
+
+pub type Point2f = PointN<f64, 2>;
+pub type Point3f = PointN<f64, 3>;
+pub type Point2i = PointN<i64, 2>;
+pub type Point3i = PointN<i64, 3>;
+
+impl<T, const N: usize> PointN<T, N> {
+    pub fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+
+    /// Cantidad de componentes (la dimensión).
+    pub const fn dim(&self) -> usize {
+        N
+    }
+
+    pub fn components(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T: Scalar, const N: usize> PointN<T, N> {
+    /// El origen: todas las componentes en `T::default()` (el cero).
+    pub fn origin() -> Self {
+        Self([T::default(); N])
+    }
+
+    /// Producto punto: Σ aᵢ·bᵢ.
+    pub fn dot(&self, other: &Self) -> T {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .fold(T::default(), |acc, (&a, &b)| acc + a * b)
+    }
+}
+
+impl<const N: usize> PointN<f64, N> {
+    /// Largo del vector desde el origen.
+    pub fn length(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        (*self - *other).length()
+    }
+}
+
+impl<T: Copy> PointN<T, 2> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Copy> PointN<T, 3> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T, const N: usize> Index<usize> for PointN<T, N> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        &self.0[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for PointN<T, N> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0[i]
+    }
+}
+
+impl<T: Scalar, const N: usize> Add for PointN<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<T: Scalar, const N: usize> Sub for PointN<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<T: Scalar + Neg<Output = T>, const N: usize> Neg for PointN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|c| -c))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<T> for PointN<T, N> {
+    type Output = Self;
+
+    fn mul(self, k: T) -> Self {
+        Self(self.0.map(|c| c * k))
+    }
+}
+
+impl<T: Scalar, const N: usize> AddAssign for PointN<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Scalar, const N: usize> SubAssign for PointN<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl From<Point> for Point2f {
+    fn from(p: Point) -> Self {
+        PointN([p.x, p.y])
+    }
+}
+
+impl From<Point2f> for Point {
+    fn from(p: Point2f) -> Self {
+        Point::new(p[0], p[1])
+    }
+}
@@ -0,0 +1,158 @@
+use crate::poo::{EPSILON, Point, Polygon};
+
+/*
+Intersección de segmentos con pruebas de orientación
+
+La orientación de c respecto de la recta a → b es el signo de
+(b - a) × (c - a): izquierda (+), derecha (-) o colineal (0).
+
+Dos segmentos ab y cd se cruzan "limpiamente" si c y d quedan a lados
+opuestos de ab Y a y b quedan a lados opuestos de cd:
+
+        c                      c
+         ╲   b                  ╲
+      a ──╳──                    ╲    a ────── b
+           ╲                      d
+            d
+     se cruzan                  d del mismo lado de ab: no
+
+Si alguna orientación es 0, un extremo está sobre la recta del otro
+segmento, y se cortan solo si ese extremo cae DENTRO del otro segmento (un
+extremo que toca, o dos segmentos colineales que se superponen).
+
+Las orientaciones usan una tolerancia relativa al largo del segmento, igual
+que `Polygon::contains_point`, para que un punto "casi" sobre la recta por
+error de redondeo cuente como sobre ella.
+*/
+
+/// Segmento de recta entre `a` y `b` (incluidos).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment {
+    pub a: Point,
+    pub b: Point,
+}
+
+/// Orientación de `c` respecto de `a → b`: 1 izquierda, -1 derecha, 0
+/// colineal (con tolerancia).
+fn orientation(a: &Point, b: &Point, c: &Point) -> i8 {
+    let ab = *b - *a;
+    let turn = ab.cross(&(*c - *a));
+    if turn.abs() <= EPSILON * ab.dot(&ab).max(1.0) {
+        0
+    } else if turn > 0.0 {
+        1
+    } else {
+        -1
+    }
+}
+
+impl Segment {
+    pub fn new(a: Point, b: Point) -> Self {
+        Self { a, b }
+    }
+
+    pub fn length(&self) -> f64 {
+        self.a.distance_to(&self.b)
+    }
+
+    /// Distancia de `p` al punto más cercano del segmento: se proyecta `p`
+    /// sobre la recta y se limita la proyección a los extremos.
+    pub fn distance_to_point(&self, p: &Point) -> f64 {
+        let ab = self.b - self.a;
+        let len2 = ab.dot(&ab);
+        if len2 == 0.0 {
+            return p.distance_to(&self.a);
+        }
+        let t = ((*p - self.a).dot(&ab) / len2).clamp(0.0, 1.0);
+        p.distance_to(&(self.a + ab * t))
+    }
+
+    /// `true` si `p` está sobre el segmento, con tolerancia.
+    pub fn point_on_segment(&self, p: &Point) -> bool {
+        let (a, b) = (&self.a, &self.b);
+        orientation(a, b, p) == 0
+            && p.x >= a.x.min(b.x) - EPSILON
+            && p.x <= a.x.max(b.x) + EPSILON
+            && p.y >= a.y.min(b.y) - EPSILON
+            && p.y <= a.y.max(b.y) + EPSILON
+    }
+
+    /// `true` si comparten al menos un punto: cruzarse, tocarse en un extremo
+    /// o superponerse siendo colineales.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let (a, b, c, d) = (&self.a, &self.b, &other.a, &other.b);
+        let (o1, o2) = (orientation(a, b, c), orientation(a, b, d));
+        let (o3, o4) = (orientation(c, d, a), orientation(c, d, b));
+
+        if o1 * o2 < 0 && o3 * o4 < 0 {
+            return true;
+        }
+        (o1 == 0 && self.point_on_segment(c))
+            || (o2 == 0 && self.point_on_segment(d))
+            || (o3 == 0 && other.point_on_segment(a))
+            || (o4 == 0 && other.point_on_segment(b))
+    }
+
+    /// Un punto común a ambos segmentos, o `None` si no se tocan.
+    ///
+    /// Si se cruzan, es el punto de cruce. Si son colineales y se superponen
+    /// hay infinitos puntos comunes: se retorna el de menor (x, y) de la
+    /// superposición (uno de los cuatro extremos).
+    pub fn intersection_point(&self, other: &Segment) -> Option<Point> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let r = self.b - self.a;
+        let s = other.b - other.a;
+        let denom = r.cross(&s);
+        if denom.abs() > EPSILON * r.dot(&r).max(s.dot(&s)).max(1.0) {
+            // a + t·r = c + u·s, despejando t con productos cruz
+            let t = ((other.a - self.a).cross(&s) / denom).clamp(0.0, 1.0);
+            return Some(self.a + r * t);
+        }
+
+        // Paralelos que se tocan: colineales superpuestos
+        [
+            (self.a, other.point_on_segment(&self.a)),
+            (self.b, other.point_on_segment(&self.b)),
+            (other.a, self.point_on_segment(&other.a)),
+            (other.b, self.point_on_segment(&other.b)),
+        ]
+        .into_iter()
+        .filter(|&(_, on)| on)
+        .map(|(p, _)| p)
+        .min_by(|p, q| p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y)))
+    }
+}
+
+/// `true` si dos lados del polígono se cortan fuera de los vértices que
+/// comparten (p. ej. un "moño" ⋈), o si un lado vuelve sobre el anterior.
+///
+/// # Complejidad
+/// **O(n²)** pares de lados.
+pub fn polygon_self_intersects(polygon: &Polygon) -> bool {
+    let v = polygon.vertices();
+    let n = v.len();
+    let edge = |i: usize| Segment::new(v[i], v[(i + 1) % n]);
+
+    for i in 0..n {
+        // Lados vecinos: comparten un vértice, solo fallan si se doblan hacia atrás
+        let (e, next) = (edge(i), edge((i + 1) % n));
+        let (r, s) = (e.b - e.a, next.b - next.a);
+        if orientation(&e.a, &e.b, &next.b) == 0 && r.dot(&s) < 0.0 {
+            return true;
+        }
+
+        for j in i + 2..n {
+            // El último lado es vecino del primero
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+            if e.intersects(&edge(j)) {
+                return true;
+            }
+        }
+    }
+    false
+}
@@ -0,0 +1,480 @@
+use std::error::Error;
+use std::fmt::{self, Write};
+
+/*
+-----------------------------------------------------------
+   Valor tipo JSON: enum recursivo
+-----------------------------------------------------------
+
+Un documento JSON es un árbol: arreglos y objetos contienen otros valores.
+
+    {"nombre": "Ana", "tags": ["a", "b"], "edad": 30}
+
+    Object ─┬─ "nombre" → Str("Ana")
+            ├─ "tags"   → Array ─┬─ Str("a")
+            │                    └─ Str("b")
+            └─ "edad"   → Number(30)
+
+Aquí no hace falta `Box`: `Vec<Value>` ya guarda sus elementos en el heap,
+así que `Value` tiene tamaño fijo aunque se contenga a sí mismo. El objeto es
+un `Vec<(String, Value)>` y no un HashMap, para conservar el orden de las
+claves al volver a imprimirlo.
+
+El parser es de descenso recursivo: `value()` mira el primer carácter y
+delega en `array()`, `object()`, `string()`... que vuelven a llamar a
+`value()` para sus hijos. Cada nivel de anidamiento es un marco más en la
+pila de llamadas, así que "[[[[...]]]]" con cien mil corchetes la
+desbordaría. Por eso hay una profundidad máxima: pasarla es un error de
+parseo, no un crash.
+
+Los errores llevan línea y columna (desde 1; la columna cuenta caracteres).
+*/
+
+/// Anidamiento máximo de arreglos y objetos que acepta el parser.
+pub const MAX_DEPTH: usize = 128;
+
+/// Valor JSON.
+///
+/// ```
+/// use basic::json::Value;
+///
+/// let v = Value::parse(r#"{"a": [1, true, null], "b": "x\ny"}"#).unwrap();
+/// assert_eq!(v.get("a"), Some(&Value::Array(vec![
+///     Value::Number(1.0),
+///     Value::Bool(true),
+///     Value::Null,
+/// ])));
+/// assert_eq!(v.to_string(), r#"{"a":[1,true,null],"b":"x\ny"}"#);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+/// Qué salió mal al parsear.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// Un carácter que no puede ir en esa posición.
+    UnexpectedChar(char),
+    /// La entrada terminó antes de completar el valor.
+    UnexpectedEnd,
+    /// Un string sin comilla de cierre.
+    UnterminatedString,
+    /// Una secuencia `\` desconocida o un `\u` inválido.
+    InvalidEscape,
+    /// Un número mal formado.
+    InvalidNumber,
+    /// Más de [`MAX_DEPTH`] niveles de anidamiento.
+    TooDeep,
+    /// Sobra texto después del valor.
+    TrailingInput,
+}
+
+/// Error de parseo con su posición (línea y columna desde 1).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected `{c}`")?,
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            ParseErrorKind::UnterminatedString => write!(f, "unterminated string")?,
+            ParseErrorKind::InvalidEscape => write!(f, "invalid escape sequence")?,
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number")?,
+            ParseErrorKind::TooDeep => write!(f, "nesting deeper than {MAX_DEPTH} levels")?,
+            ParseErrorKind::TrailingInput => write!(f, "unexpected trailing input")?,
+        }
+        write!(f, " at line {}, column {}", self.line, self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    /// Error en el byte `offset`, traducido a línea y columna.
+    fn error_at(&self, kind: ParseErrorKind, offset: usize) -> ParseError {
+        let before = &self.input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+        ParseError { kind, line, column }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        self.error_at(kind, self.pos)
+    }
+
+    /// Error para lo que haya en la posición actual: un carácter inesperado o
+    /// el fin de la entrada.
+    fn unexpected(&self) -> ParseError {
+        match self.input[self.pos..].chars().next() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedChar(c)),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    /// Salta espacios y retorna el próximo byte sin consumirlo.
+    fn peek(&mut self) -> Option<u8> {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    /// Consume `byte` o falla.
+    fn expect(&mut self, byte: u8) -> Result<(), ParseError> {
+        if self.peek() != Some(byte) {
+            return Err(self.unexpected());
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Value, ParseError> {
+        match self.peek() {
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'"') => Ok(Value::Str(self.string()?)),
+            Some(b'[') => self.nested(Self::array),
+            Some(b'{') => self.nested(Self::object),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, ParseError> {
+        if !self.input[self.pos..].starts_with(word) {
+            return Err(self.unexpected());
+        }
+        self.pos += word.len();
+        Ok(value)
+    }
+
+    /// Llama a `parse` un nivel más adentro, respetando `MAX_DEPTH`.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Value, ParseError>,
+    ) -> Result<Value, ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(ParseErrorKind::TooDeep));
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn array(&mut self) -> Result<Value, ParseError> {
+        self.expect(b'[')?;
+        let mut items = Vec::new();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, ParseError> {
+        self.expect(b'{')?;
+        let mut entries = Vec::new();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(entries));
+        }
+        loop {
+            if self.peek() != Some(b'"') {
+                return Err(self.unexpected());
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            entries.push((key, self.value()?));
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(Value::Object(entries));
+                }
+                _ => return Err(self.unexpected()),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        let open = self.pos;
+        self.pos += 1; // la comilla de apertura
+        let mut out = String::new();
+
+        loop {
+            let Some(c) = self.input[self.pos..].chars().next() else {
+                return Err(self.error_at(ParseErrorKind::UnterminatedString, open));
+            };
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                '\\' => {
+                    self.pos += 1;
+                    out.push(self.escape()?);
+                }
+                // Un salto de línea sin escapar también deja el string abierto
+                '\n' => return Err(self.error_at(ParseErrorKind::UnterminatedString, open)),
+                c if c < ' ' => return Err(self.error(ParseErrorKind::UnexpectedChar(c))),
+                c => {
+                    self.pos += c.len_utf8();
+                    out.push(c);
+                }
+            }
+        }
+    }
+
+    /// Lee lo que sigue a una `\` (ya consumida).
+    fn escape(&mut self) -> Result<char, ParseError> {
+        let start = self.pos - 1;
+        let invalid = |p: &Self| Err(p.error_at(ParseErrorKind::InvalidEscape, start));
+        let Some(&byte) = self.input.as_bytes().get(self.pos) else {
+            return invalid(self);
+        };
+        self.pos += 1;
+
+        let c = match byte {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{8}',
+            b'f' => '\u{c}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let Some(high) = self.hex4() else {
+                    return invalid(self);
+                };
+                // Fuera del plano básico llega como par sustituto: 🦀
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    if !self.input[self.pos..].starts_with("\\u") {
+                        return invalid(self);
+                    }
+                    self.pos += 2;
+                    match self.hex4() {
+                        Some(low @ 0xDC00..0xE000) => {
+                            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                        }
+                        _ => return invalid(self),
+                    }
+                } else {
+                    high
+                };
+                match char::from_u32(code) {
+                    Some(c) => c,
+                    None => return invalid(self),
+                }
+            }
+            _ => return invalid(self),
+        };
+        Ok(c)
+    }
+
+    /// Lee 4 dígitos hexadecimales.
+    fn hex4(&mut self) -> Option<u32> {
+        let digits = self.input.get(self.pos..self.pos + 4)?;
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        self.pos += 4;
+        Some(value)
+    }
+
+    /// -?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?
+    fn number(&mut self) -> Result<Value, ParseError> {
+        let start = self.pos;
+        let bytes = self.input.as_bytes();
+        let digits = |from: usize| bytes[from..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let mut end = start;
+
+        if bytes[end] == b'-' {
+            end += 1;
+        }
+        match digits(end) {
+            0 => return Err(self.error_at(ParseErrorKind::InvalidNumber, start)),
+            // Sin ceros a la izquierda: "01" no es un número JSON
+            n if n > 1 && bytes[end] == b'0' => {
+                return Err(self.error_at(ParseErrorKind::InvalidNumber, start));
+            }
+            n => end += n,
+        }
+        if bytes.get(end) == Some(&b'.') {
+            match digits(end + 1) {
+                0 => return Err(self.error_at(ParseErrorKind::InvalidNumber, start)),
+                n => end += 1 + n,
+            }
+        }
+        if let Some(b'e' | b'E') = bytes.get(end) {
+            end += 1;
+            if let Some(b'+' | b'-') = bytes.get(end) {
+                end += 1;
+            }
+            match digits(end) {
+                0 => return Err(self.error_at(ParseErrorKind::InvalidNumber, start)),
+                n => end += n,
+            }
+        }
+
+        self.pos = end;
+        self.input[start..end]
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| self.error_at(ParseErrorKind::InvalidNumber, start))
+    }
+}
+
+impl Value {
+    /// Parsea `input` completo como un valor JSON.
+    pub fn parse(input: &str) -> Result<Value, ParseError> {
+        let mut parser = Parser { input, pos: 0, depth: 0 };
+        let value = parser.value()?;
+        if parser.peek().is_some() {
+            return Err(parser.error(ParseErrorKind::TrailingInput));
+        }
+        Ok(value)
+    }
+
+    /// Si es un objeto, retorna el valor de `key` (la primera aparición).
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// Imprime el valor con un elemento por línea, sangrando `indent`
+    /// espacios por nivel.
+    ///
+    /// ```
+    /// use basic::json::Value;
+    ///
+    /// let v = Value::parse(r#"{"a": [1, 2], "b": {}}"#).unwrap();
+    /// assert_eq!(v.to_string_pretty(2), "{\n  \"a\": [\n    1,\n    2\n  ],\n  \"b\": {}\n}");
+    /// ```
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, level: usize) {
+        let pad = |out: &mut String, level: usize| out.extend(std::iter::repeat_n(' ', indent * level));
+
+        match self {
+            Value::Array(items) if !items.is_empty() => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    pad(out, level + 1);
+                    item.write_pretty(out, indent, level + 1);
+                }
+                out.push('\n');
+                pad(out, level);
+                out.push(']');
+            }
+            Value::Object(entries) if !entries.is_empty() => {
+                out.push_str("{\n");
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    pad(out, level + 1);
+                    write_string(out, key).unwrap();
+                    out.push_str(": ");
+                    value.write_pretty(out, indent, level + 1);
+                }
+                out.push('\n');
+                pad(out, level);
+                out.push('}');
+            }
+            // Escalares y contenedores vacíos se imprimen igual que en compacto
+            other => write!(out, "{other}").unwrap(),
+        }
+    }
+}
+
+/// Escribe `s` entre comillas, escapando lo necesario.
+fn write_string(out: &mut impl Write, s: &str) -> fmt::Result {
+    out.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+/// Formato compacto, sin espacios.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(b) => write!(f, "{b}"),
+            // JSON no tiene NaN ni infinito
+            Value::Number(n) if !n.is_finite() => f.write_str("null"),
+            Value::Number(n) => write!(f, "{n}"),
+            Value::Str(s) => write_string(f, s),
+            Value::Array(items) => {
+                f.write_char('[')?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_char(']')
+            }
+            Value::Object(entries) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::poo::{Drawable, Point, Rect};
+
+/*
+-----------------------------------------------------------
+   KD-tree (k = 2)
+-----------------------------------------------------------
+
+Árbol binario donde cada nivel parte el plano por un eje distinto: en
+profundidad par se compara `x`, en impar `y`. Cada nodo es la MEDIANA de su
+porción de puntos, así que el árbol queda balanceado.
+
+             (7,2)          ← nivel 0: parte por x (x = 7)
+            /     \
+        (5,4)     (9,6)     ← nivel 1: parte por y
+        /   \      /
+    (2,3) (4,7) (8,1)
+
+A la izquierda de (7,2) quedan los puntos con x <= 7; debajo de (5,4), los
+de ese lado con y <= 4, etc.
+
+No hacen falta nodos con punteros: los puntos se reordenan en un arreglo de
+forma que la mediana de cada rango [lo, hi) queda en el medio, la mitad
+izquierda es su subárbol izquierdo y la derecha el derecho.
+
+Búsqueda del más cercano: se baja primero por el lado de la consulta. Al
+volver, el otro lado solo se revisa si el plano divisor está más cerca que el
+mejor encontrado; si no, ningún punto de ese lado puede ganar (poda).
+*/
+
+fn coord(p: &Point, axis: usize) -> f64 {
+    if axis == 0 { p.x } else { p.y }
+}
+
+fn distance(a: &Point, b: &Point) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+/// KD-tree de dos dimensiones sobre puntos [`Point`].
+///
+/// ```
+/// use basic::kd_tree::KdTree;
+/// use basic::poo::Point;
+///
+/// let tree = KdTree::new(&[Point::new(0.0, 0.0), Point::new(5.0, 5.0)]);
+/// let (p, d) = tree.nearest(&Point::new(4.0, 4.0)).unwrap();
+/// assert_eq!((p.x, p.y), (5.0, 5.0));
+/// assert!((d - 2.0_f64.sqrt()).abs() < 1e-9);
+/// ```
+pub struct KdTree {
+    /// Puntos ordenados como árbol implícito (mediana en el medio de cada rango).
+    points: Vec<Point>,
+}
+
+/// Candidato a vecino cercano, ordenado por distancia para el max-heap de
+/// `k_nearest`.
+struct Candidate {
+    dist: f64,
+    index: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.dist.total_cmp(&other.dist)
+    }
+}
+
+impl KdTree {
+    /// Construye el árbol partiendo cada rango por su mediana.
+    ///
+    /// # Complejidad
+    /// **O(n log n)**: en cada nivel, `select_nth_unstable` es O(n).
+    pub fn new(points: &[Point]) -> Self {
+        let mut points = points.to_vec();
+        Self::build(&mut points, 0);
+        Self { points }
+    }
+
+    fn build(points: &mut [Point], depth: usize) {
+        if points.len() <= 1 {
+            return;
+        }
+        let axis = depth % 2;
+        let mid = points.len() / 2;
+        points.select_nth_unstable_by(mid, |a, b| coord(a, axis).total_cmp(&coord(b, axis)));
+
+        let (left, right) = points.split_at_mut(mid);
+        Self::build(left, depth + 1);
+        Self::build(&mut right[1..], depth + 1);
+    }
+
+    /// Retorna la cantidad de puntos.
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Retorna `true` si el árbol no tiene puntos.
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Retorna el punto más cercano a `query` y su distancia euclidiana.
+    ///
+    /// # Complejidad
+    /// **O(log n)** en promedio para puntos bien repartidos.
+    pub fn nearest(&self, query: &Point) -> Option<(Point, f64)> {
+        let mut best: Option<(usize, f64)> = None;
+        self.nearest_in(query, 0, self.points.len(), 0, &mut best);
+        best.map(|(index, dist)| (self.points[index], dist))
+    }
+
+    fn nearest_in(&self, query: &Point, lo: usize, hi: usize, depth: usize, best: &mut Option<(usize, f64)>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let point = &self.points[mid];
+
+        let dist = distance(query, point);
+        if best.is_none_or(|(_, best_dist)| dist < best_dist) {
+            *best = Some((mid, dist));
+        }
+
+        let axis = depth % 2;
+        let diff = coord(query, axis) - coord(point, axis);
+        let (near, far) = if diff < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.nearest_in(query, near.0, near.1, depth + 1, best);
+        // Poda: el otro lado está al menos a |diff| de la consulta
+        if best.is_none_or(|(_, best_dist)| diff.abs() < best_dist) {
+            self.nearest_in(query, far.0, far.1, depth + 1, best);
+        }
+    }
+
+    /// Retorna los `k` puntos más cercanos a `query` con sus distancias,
+    /// del más cercano al más lejano (menos de `k` si no hay suficientes).
+    pub fn k_nearest(&self, query: &Point, k: usize) -> Vec<(Point, f64)> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        // Max-heap con los k mejores: la raíz es el peor de ellos
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.k_nearest_in(query, k, 0, self.points.len(), 0, &mut heap);
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|c| (self.points[c.index], c.dist))
+            .collect()
+    }
+
+    fn k_nearest_in(
+        &self,
+        query: &Point,
+        k: usize,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        heap: &mut BinaryHeap<Candidate>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let point = &self.points[mid];
+
+        heap.push(Candidate {
+            dist: distance(query, point),
+            index: mid,
+        });
+        if heap.len() > k {
+            heap.pop();
+        }
+
+        let axis = depth % 2;
+        let diff = coord(query, axis) - coord(point, axis);
+        let (near, far) = if diff < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.k_nearest_in(query, k, near.0, near.1, depth + 1, heap);
+        let worst = heap.peek().map_or(f64::INFINITY, |c| c.dist);
+        if heap.len() < k || diff.abs() < worst {
+            self.k_nearest_in(query, k, far.0, far.1, depth + 1, heap);
+        }
+    }
+
+    /// Retorna los puntos dentro de `rect`, bordes incluidos, en un orden
+    /// arbitrario.
+    pub fn range_query(&self, rect: &Rect) -> Vec<Point> {
+        let mut found = Vec::new();
+        self.range_in(rect, 0, self.points.len(), 0, &mut found);
+        found
+    }
+
+    fn range_in(&self, rect: &Rect, lo: usize, hi: usize, depth: usize, found: &mut Vec<Point>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let point = &self.points[mid];
+        if rect.contains_point(point) {
+            found.push(*point);
+        }
+
+        // Solo se baja por los lados que el rectángulo toca. Los empates con
+        // la mediana pueden caer a cualquier lado, por eso las comparaciones
+        // no son estrictas.
+        let axis = depth % 2;
+        let split = coord(point, axis);
+        if coord(&rect.top_left, axis) <= split {
+            self.range_in(rect, lo, mid, depth + 1, found);
+        }
+        if coord(&rect.bottom_right(), axis) >= split {
+            self.range_in(rect, mid + 1, hi, depth + 1, found);
+        }
+    }
+}
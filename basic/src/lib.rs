@@ -0,0 +1,12 @@
+pub mod builder;
+pub mod expr;
+pub mod geometry;
+pub mod json;
+pub mod kd_tree;
+pub mod my_box;
+pub mod poo;
+pub mod rc_tree;
+pub mod scene;
+pub mod shape;
+pub mod shape_json;
+pub mod svg;
@@ -0,0 +1,146 @@
+use std::alloc::{self, Layout};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+
+/*
+-----------------------------------------------------------
+   MyBox<T>: un Box hecho a mano
+-----------------------------------------------------------
+
+`Box<T>` parece magia, pero por dentro solo hace tres cosas:
+
+1. Pide memoria al allocator con el `Layout` de T (tamaño + alineación)
+   y escribe el valor ahí.
+2. Implementa `Deref`/`DerefMut` para que `*b` y `b.metodo()` lleguen
+   al valor en el heap.
+3. En `Drop`, corre el destructor de T y devuelve la memoria con el
+   MISMO `Layout` que se usó para pedirla.
+
+   STACK                HEAP
+   ┌───────────┐       ┌─────────┐
+   │ MyBox     │       │         │
+   │  ptr ─────┼──────►│  T      │
+   └───────────┘       └─────────┘
+
+Dos casos especiales:
+
+- Tipos de tamaño cero (ZST, p. ej. `struct Dog;`): no hay nada que
+  guardar, así que no se llama al allocator. Se usa `NonNull::dangling()`,
+  un puntero no nulo y bien alineado que nunca se desreferencia para leer
+  bytes reales.
+- `MyBox<dyn Trait>`: convertir `MyBox<Dog>` en `MyBox<dyn Animal>`
+  requiere el trait `CoerceUnsized`, que es inestable. El atajo es
+  `MyBox::from_box`, que toma la memoria de un `Box<dyn Trait>` (cuyo
+  puntero "gordo" ya lleva la vtable) y se vuelve su dueño.
+*/
+
+/// Puntero con dueño único a un valor en el heap.
+pub struct MyBox<T: ?Sized> {
+    ptr: NonNull<T>,
+    // Le indica al compilador que MyBox es dueño de un T (importa para drop check)
+    _owns: PhantomData<T>,
+}
+
+// SAFETY: MyBox es dueño exclusivo de su T, igual que Box
+unsafe impl<T: ?Sized + Send> Send for MyBox<T> {}
+unsafe impl<T: ?Sized + Sync> Sync for MyBox<T> {}
+
+impl<T> MyBox<T> {
+    /// Mueve `value` al heap.
+    ///
+    /// # Panics
+    /// Aborta el programa si el allocator no tiene memoria.
+    pub fn new(value: T) -> Self {
+        let layout = Layout::new::<T>();
+        let ptr = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            // SAFETY: el layout tiene tamaño distinto de cero
+            let raw = unsafe { alloc::alloc(layout) } as *mut T;
+            NonNull::new(raw).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+        };
+        // SAFETY: ptr es válido para escribir un T (o es dangling y T no ocupa bytes)
+        unsafe { ptr.as_ptr().write(value) };
+        MyBox {
+            ptr,
+            _owns: PhantomData,
+        }
+    }
+
+    /// Saca el valor del heap y libera la memoria, sin correr el destructor de T.
+    pub fn into_inner(this: Self) -> T {
+        // SAFETY: el valor está inicializado; se lee una sola vez porque
+        // `forget` evita que el Drop de MyBox lo vuelva a destruir
+        let value = unsafe { this.ptr.as_ptr().read() };
+        let layout = Layout::new::<T>();
+        if layout.size() != 0 {
+            // SAFETY: la memoria se pidió en `new` con este mismo layout
+            unsafe { alloc::dealloc(this.ptr.as_ptr() as *mut u8, layout) };
+        }
+        mem::forget(this);
+        value
+    }
+}
+
+impl<T: ?Sized> MyBox<T> {
+    /// Toma posesión de la memoria de un `Box`. Sirve para obtener `MyBox<dyn Trait>`.
+    pub fn from_box(boxed: Box<T>) -> Self {
+        // SAFETY: Box::into_raw nunca es nulo. Box usa el allocator global con
+        // `Layout::for_value`, el mismo que usa el Drop de MyBox
+        let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(boxed)) };
+        MyBox {
+            ptr,
+            _owns: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for MyBox<T> {
+    fn drop(&mut self) {
+        // El layout se calcula antes de destruir: para `dyn Trait` sale de la vtable
+        let layout = Layout::for_value(&**self);
+        // SAFETY: el valor está inicializado y nadie más lo destruye
+        unsafe { ptr::drop_in_place(self.ptr.as_ptr()) };
+        if layout.size() != 0 {
+            // SAFETY: la memoria se pidió con este layout (en `new` o por el Box original)
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout) };
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: ptr apunta a un T vivo mientras exista el MyBox
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: &mut self garantiza acceso exclusivo
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T> From<T> for MyBox<T> {
+    fn from(value: T) -> Self {
+        MyBox::new(value)
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MyBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for MyBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
@@ -3,9 +3,11 @@ use std::sync::{Arc, Mutex, RwLock};
 use std::cell::RefCell;
 use std::thread;
 
+use basic::my_box::MyBox;
 
 
-/**
+
+/*
 -----------------------------------------------------------
    -1. El HEAP y el STACK
 -----------------------------------------------------------
@@ -40,6 +42,7 @@ de ownership para evitar fugas de memoria
 Aqui no hay mucho que comentar, son los punteros de toda la vida
 
 */
+#[allow(dead_code)]
 fn pointers_demo() {
     let x = 42; // stack
     let y = &x; // referencia (puntero seguro)
@@ -108,6 +111,7 @@ Conclusión:
 - Si quieres múltiples dueños REALES, debes usar `Rc<T>` o `Arc<T>`.
 
 ***/
+#[allow(dead_code)]
 fn box_demo() {
     let bx = Box::new(99);
     let y = &*bx;
@@ -195,6 +199,7 @@ IMPORTANTE recordar que se guarda en el HEAP y esto tiene muchas ventajas:
 ***/
 
 
+#[allow(dead_code)]
 fn rc_demo() {
     println!("\n--- Rc<T> ---");
     let rc1 = Rc::new(String::from("hola"));
@@ -261,6 +266,7 @@ Ambos arc1 y arc2 apuntan al mismo valor y lo pueden imprimir.
 
 ***/
 
+#[allow(dead_code)]
 fn arc_demo() {
     println!("\n--- Arc<T> ---");
     let arc1 = Arc::new(123);
@@ -371,6 +377,7 @@ uno es el "hermano multithread" del otro.
 */
 
 
+#[allow(dead_code)]
 fn mutex_demo() {
     let shared = Arc::new(Mutex::new(0));
 
@@ -439,6 +446,7 @@ Advertencias:
 
 ***/
 
+#[allow(dead_code)]
 fn rwlock_demo() {
     // Compartido entre hilos
     let data = Arc::new(RwLock::new(0));
@@ -474,6 +482,7 @@ fn rwlock_demo() {
 //
 // 7. Raw pointers (punteros crudos, inseguros)
 //
+#[allow(dead_code)]
 fn raw_pointers_demo() {
     println!("\n--- Raw pointers (unsafe) ---");
     let mut x = 42;
@@ -504,6 +513,7 @@ impl Animal for Cat {
     fn sound(&self) { println!("Miau!"); }
 }
 
+#[allow(dead_code)]
 fn trait_objects_demo() {
     println!("\n--- Box<dyn Trait> ---");
     let animals: Vec<Box<dyn Animal>> = vec![Box::new(Dog), Box::new(Cat)];
@@ -512,6 +522,23 @@ fn trait_objects_demo() {
     }
 }
 
+// Lo mismo con nuestro propio Box (ver `basic::my_box`)
+#[allow(dead_code)]
+fn my_box_demo() {
+    println!("\n--- MyBox<dyn Trait> ---");
+    let animals: Vec<MyBox<dyn Animal>> = vec![
+        MyBox::from_box(Box::new(Dog)),
+        MyBox::from_box(Box::new(Cat)),
+    ];
+    for a in animals {
+        a.sound();
+    }
+
+    let mut n = MyBox::new(5);
+    *n += 1;
+    println!("MyBox contiene {}", n);
+}
+
 pub fn pointers_tour() {
     // pointers_demo();
     // box_demo()
@@ -520,4 +547,5 @@ pub fn pointers_tour() {
     refcell_demo();
     // raw_pointers_demo();
     // trait_objects_demo();
+    // my_box_demo();
 }
@@ -1,43 +1,312 @@
-// main.rs
+use std::cmp::Ordering;
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::geometry::segment::Segment;
+use crate::scene::Scene;
+use crate::shape_json::ToJson;
+use crate::svg::ToSvg;
+
+/// Tolerancia relativa para comparar medidas en punto flotante.
+pub(crate) const EPSILON: f64 = 1e-9;
 
 // ---------- Definición de Structs ----------
-struct Point {
-    x: f64,
-    y: f64,
+/// Punto (o vector) en el plano.
+///
+/// `PartialEq` compara exacto, como `f64`; para resultados de
+/// cálculos conviene [`Point::approx_eq`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
 }
 
-struct Circle {
+#[derive(Clone, Debug, PartialEq)]
+pub struct Circle {
     center: Point,
     radius: f64,
 }
 
-struct Square {
+#[derive(Clone, Debug, PartialEq)]
+pub struct Square {
     top_left: Point,
     side: f64,
 }
 
+/// Triángulo dado por sus tres vértices; nunca es degenerado.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Triangle {
+    a: Point,
+    b: Point,
+    c: Point,
+}
+
+/// Error al construir un [`Triangle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriangleError {
+    /// Los tres vértices están sobre una misma recta (área cero).
+    Collinear,
+}
+
+impl fmt::Display for TriangleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TriangleError::Collinear => write!(f, "triangle vertices are collinear"),
+        }
+    }
+}
+
+impl Error for TriangleError {}
+
+/// Polígono simple (sin lados que se crucen) dado por sus vértices en orden,
+/// en cualquier sentido de giro. El último vértice se une con el primero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+/// Error al construir un [`Polygon`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolygonError {
+    /// Se dieron menos de 3 vértices.
+    TooFewVertices(usize),
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolygonError::TooFewVertices(n) => {
+                write!(f, "polygon needs at least 3 vertices, got {n}")
+            }
+        }
+    }
+}
+
+impl Error for PolygonError {}
+
+/// Rectángulo alineado a los ejes.
+///
+/// Como en la pantalla (y en SVG), `y` crece hacia abajo: `top_left` es la
+/// esquina de menor `x` y menor `y`. El rectángulo es CERRADO: sus bordes
+/// cuentan como parte de él, tanto en `contains_point` como en `intersects`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub top_left: Point,
+    pub width: f64,
+    pub height: f64,
+}
+
 // ---------- Métodos con impl ----------
+impl Rect {
+    /// # Panics
+    /// Si `width` o `height` son negativos.
+    pub fn new(top_left: Point, width: f64, height: f64) -> Self {
+        assert!(width >= 0.0 && height >= 0.0, "el ancho y el alto no pueden ser negativos");
+        Self { top_left, width, height }
+    }
+
+    /// Rectángulo con esquinas opuestas `a` y `b`, en cualquier orden.
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        Self {
+            top_left: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            width: (a.x - b.x).abs(),
+            height: (a.y - b.y).abs(),
+        }
+    }
+
+    pub fn left(&self) -> f64 {
+        self.top_left.x
+    }
+
+    pub fn top(&self) -> f64 {
+        self.top_left.y
+    }
+
+    pub fn right(&self) -> f64 {
+        self.top_left.x + self.width
+    }
+
+    pub fn bottom(&self) -> f64 {
+        self.top_left.y + self.height
+    }
+
+    pub fn bottom_right(&self) -> Point {
+        Point::new(self.right(), self.bottom())
+    }
+
+    /// Retorna `true` si comparten al menos un punto; tocarse por un borde o
+    /// una esquina cuenta.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.left() <= other.right()
+            && other.left() <= self.right()
+            && self.top() <= other.bottom()
+            && other.top() <= self.bottom()
+    }
+
+    /// Zona común a ambos. Si solo se tocan por un borde, el resultado tiene
+    /// ancho o alto 0.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Rect::from_corners(
+            Point::new(self.left().max(other.left()), self.top().max(other.top())),
+            Point::new(self.right().min(other.right()), self.bottom().min(other.bottom())),
+        ))
+    }
+
+    /// El menor rectángulo que contiene a ambos.
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::from_corners(
+            Point::new(self.left().min(other.left()), self.top().min(other.top())),
+            Point::new(self.right().max(other.right()), self.bottom().max(other.bottom())),
+        )
+    }
+}
+
 impl Point {
-    fn new(x: f64, y: f64) -> Self {
+    pub fn new(x: f64, y: f64) -> Self {
         Self { x, y }
     }
 
+    /// `true` si cada coordenada difiere en a lo sumo `epsilon` (inclusive).
+    pub fn approx_eq(&self, other: &Point, epsilon: f64) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
     fn distance_to_origin(&self) -> f64 {
-        (self.x.powi(2) + self.y.powi(2)).sqrt()
+        self.length()
+    }
+
+    /// Producto punto: 0 si los vectores son perpendiculares.
+    pub fn dot(&self, other: &Point) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Producto cruz en 2D (la componente z del producto en 3D): positivo si
+    /// `other` queda girando a la izquierda de `self` (con `y` hacia arriba),
+    /// negativo a la derecha y 0 si son paralelos.
+    pub fn cross(&self, other: &Point) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Largo del vector desde el origen hasta el punto.
+    pub fn length(&self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    /// Vector con la misma dirección y largo 1.
+    ///
+    /// El vector nulo no tiene dirección: como en la división por 0, el
+    /// resultado sigue IEEE 754 y sus componentes son NaN.
+    pub fn normalized(&self) -> Point {
+        *self / self.length()
+    }
+
+    pub fn distance_to(&self, other: &Point) -> f64 {
+        (*self - *other).length()
+    }
+}
+
+/// Imprime `(x, y)`; respeta la precisión pedida, p. ej. `{:.2}`.
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "({:.p$}, {:.p$})", self.x, self.y),
+            None => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
+}
+
+/*
+Operadores
+
+Un `Point` sirve también como vector (desplazamiento). Los operadores se
+implementan para `Point` por valor: como es `Copy`, `a + b` no consume nada.
+
+    a + b, a - b, -a        componente a componente
+    a * k, a / k            escalar por `f64`
+    a += b, a -= b          en el lugar
+
+Dividir por 0.0 NO hace panic: sigue IEEE 754 como cualquier `f64`, así que
+(1, -2) / 0 = (inf, -inf) y (0, 0) / 0 = (NaN, NaN).
+*/
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<f64> for Point {
+    type Output = Point;
+
+    fn mul(self, k: f64) -> Point {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+impl Div<f64> for Point {
+    type Output = Point;
+
+    fn div(self, k: f64) -> Point {
+        Point::new(self.x / k, self.y / k)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        self.x -= other.x;
+        self.y -= other.y;
     }
 }
 
 impl Circle {
-    fn new(x: f64, y: f64, radius: f64) -> Self {
+    pub fn new(x: f64, y: f64, radius: f64) -> Self {
         Self {
             center: Point::new(x, y),
             radius,
         }
     }
+
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
 }
 
 impl Square {
-    fn new(x: f64, y: f64, side: f64) -> Self {
+    pub fn new(x: f64, y: f64, side: f64) -> Self {
         Self {
             top_left: Point::new(x, y),
             side,
@@ -45,47 +314,481 @@ impl Square {
     }
 }
 
+impl Triangle {
+    /// Crea el triángulo `p1 p2 p3`, o falla si los puntos son colineales.
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Result<Self, TriangleError> {
+        let triangle = Self { a: p1, b: p2, c: p3 };
+        // Colineal si el área es despreciable frente al tamaño de los lados
+        let longest = triangle.sides().into_iter().fold(0.0, f64::max);
+        if triangle.area() <= EPSILON * longest * longest {
+            return Err(TriangleError::Collinear);
+        }
+        Ok(triangle)
+    }
+
+    pub fn vertices(&self) -> [Point; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Largos de los lados ab, bc y ca.
+    fn sides(&self) -> [f64; 3] {
+        [self.a.distance_to(&self.b), self.b.distance_to(&self.c), self.c.distance_to(&self.a)]
+    }
+
+    /// `true` si tiene un ángulo recto (Pitágoras con tolerancia).
+    pub fn is_right(&self) -> bool {
+        let mut sides = self.sides();
+        sides.sort_by(f64::total_cmp);
+        let [a, b, c] = sides;
+        (a * a + b * b - c * c).abs() <= EPSILON * c * c
+    }
+
+    /// `true` si los tres lados miden lo mismo (con tolerancia).
+    pub fn is_equilateral(&self) -> bool {
+        let [ab, bc, ca] = self.sides();
+        let tolerance = EPSILON * ab.max(bc).max(ca);
+        (ab - bc).abs() <= tolerance && (bc - ca).abs() <= tolerance
+    }
+
+    /// Baricentro: el promedio de los vértices.
+    pub fn centroid(&self) -> Point {
+        (self.a + self.b + self.c) / 3.0
+    }
+}
+
+/*
+Punto dentro de un polígono: ray casting
+
+Se lanza un rayo horizontal desde `p` hacia la derecha y se cuentan los lados
+que cruza. Impar = adentro, par = afuera.
+
+         ┌─────────┐
+         │    p ───┼──────►   1 cruce  → adentro
+         │    ┌────┘
+         │    │  q ─────►     0 cruces → afuera (la muesca de la "L")
+         └────┘
+
+Un lado (a, b) cuenta si `p.y` queda en [min(a.y, b.y), max(a.y, b.y)) —
+semiabierto, para que un vértice compartido por dos lados no se cuente dos
+veces— y el cruce cae a la derecha de `p`.
+
+El borde es ambiguo para el conteo (según el lado, un punto sobre él puede
+dar par o impar), así que se revisa aparte y cuenta como ADENTRO, igual que en
+el resto de las figuras.
+*/
+
+impl Polygon {
+    /// Crea el polígono con `vertices`, o falla si hay menos de 3.
+    pub fn new(vertices: Vec<Point>) -> Result<Self, PolygonError> {
+        if vertices.len() < 3 {
+            return Err(PolygonError::TooFewVertices(vertices.len()));
+        }
+        Ok(Self { vertices })
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Lados (a, b) en orden, incluido el que cierra del último al primero.
+    fn edges(&self) -> impl Iterator<Item = (&Point, &Point)> {
+        self.vertices.iter().zip(self.vertices.iter().cycle().skip(1))
+    }
+
+    /// `true` si todos los giros entre lados consecutivos van en el mismo
+    /// sentido. Los vértices colineales (giro nulo) no cuentan.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        let mut sign = 0.0;
+        for i in 0..n {
+            let (a, b, c) = (
+                &self.vertices[i],
+                &self.vertices[(i + 1) % n],
+                &self.vertices[(i + 2) % n],
+            );
+            let turn = cross(a, b, c);
+            if turn.abs() <= EPSILON {
+                continue;
+            }
+            if sign * turn < 0.0 {
+                return false;
+            }
+            sign = turn;
+        }
+        true
+    }
+}
+
+/// Producto cruz (b - a) × (c - a): positivo si a → b → c gira a la izquierda
+/// (con `y` hacia arriba), negativo a la derecha y 0 si son colineales.
+fn cross(a: &Point, b: &Point, c: &Point) -> f64 {
+    (*b - *a).cross(&(*c - *a))
+}
+
+/// Caja de un conjunto NO vacío de puntos: mínimos y máximos por eje.
+fn points_bounding_box(points: &[Point]) -> Rect {
+    let first = points[0];
+    let (min, max) = points.iter().fold((first, first), |(min, max), p| {
+        (
+            Point::new(min.x.min(p.x), min.y.min(p.y)),
+            Point::new(max.x.max(p.x), max.y.max(p.y)),
+        )
+    });
+    Rect::from_corners(min, max)
+}
+
+impl From<&Square> for Rect {
+    fn from(square: &Square) -> Self {
+        Rect::new(square.top_left, square.side, square.side)
+    }
+}
+
 // ---------- Traits (Interfaces) ----------
-trait Drawable {
-    fn draw(&self);
+/// Figura que se puede dibujar y medir.
+///
+/// Es "object safe" (ningún método es genérico ni retorna `Self`), así que se
+/// puede usar como `dyn Drawable` en colecciones de figuras distintas.
+///
+/// Toda figura se puede exportar a SVG ([`ToSvg`]) y a JSON ([`ToJson`]),
+/// así una `Scene` de `dyn Drawable` puede generar un documento completo o
+/// guardarse.
+pub trait Drawable: ToSvg + ToJson {
+    /// Descripción de una línea (sin `\n`) de lo que se dibujaría. Retorna
+    /// el texto en vez de imprimirlo para que quien llama decida dónde va.
+    fn draw(&self) -> String;
+
+    /// Área de la figura.
+    fn area(&self) -> f64;
+
+    /// Largo del contorno de la figura.
+    fn perimeter(&self) -> f64;
+
+    /// Menor rectángulo alineado a los ejes que contiene a la figura.
+    fn bounding_box(&self) -> Rect;
+
+    /// Retorna `true` si `p` está dentro de la figura. En todas las figuras
+    /// el borde cuenta como adentro.
+    fn contains_point(&self, p: &Point) -> bool;
+
+    /// Distancia de `p` a la figura: 0 si está adentro (o en el borde) y si
+    /// no, la distancia al punto más cercano del borde.
+    fn distance_to_point(&self, p: &Point) -> f64;
+
+    /// La figura como círculo, si lo es. Lo usa `geometry::collision` para
+    /// elegir una prueba exacta; por defecto `None`.
+    fn as_circle(&self) -> Option<&Circle> {
+        None
+    }
+
+    /// La figura como rectángulo alineado a los ejes, si lo es exactamente.
+    /// Por defecto `None`.
+    fn as_rect(&self) -> Option<Rect> {
+        None
+    }
 }
 
 // Implementación del trait para Circle
 impl Drawable for Circle {
-    fn draw(&self) {
-        println!(
+    fn draw(&self) -> String {
+        format!(
             "Dibujando un círculo en ({}, {}) con radio {}",
             self.center.x, self.center.y, self.radius
-        );
+        )
+    }
+
+    /// πr²
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    /// 2πr
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    /// centro ± r
+    fn bounding_box(&self) -> Rect {
+        let r = Point::new(self.radius, self.radius);
+        Rect::from_corners(self.center - r, self.center + r)
+    }
+
+    /// |p - centro| ≤ r, comparando cuadrados para no sacar raíz.
+    fn contains_point(&self, p: &Point) -> bool {
+        let d = *p - self.center;
+        d.dot(&d) <= self.radius * self.radius
+    }
+
+    /// |p - centro| - r, o 0 si es negativo (adentro).
+    fn distance_to_point(&self, p: &Point) -> f64 {
+        (p.distance_to(&self.center) - self.radius).max(0.0)
+    }
+
+    fn as_circle(&self) -> Option<&Circle> {
+        Some(self)
     }
 }
 
 // Implementación del trait para Square
 impl Drawable for Square {
-    fn draw(&self) {
-        println!(
+    fn draw(&self) -> String {
+        format!(
             "Dibujando un cuadrado en ({}, {}) con lado {}",
             self.top_left.x, self.top_left.y, self.side
-        );
+        )
+    }
+
+    /// lado²
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    /// 4 · lado
+    fn perimeter(&self) -> f64 {
+        4.0 * self.side
+    }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::from(self)
+    }
+
+    fn contains_point(&self, p: &Point) -> bool {
+        Rect::from(self).contains_point(p)
+    }
+
+    fn distance_to_point(&self, p: &Point) -> f64 {
+        Rect::from(self).distance_to_point(p)
+    }
+
+    fn as_rect(&self) -> Option<Rect> {
+        Some(Rect::from(self))
+    }
+}
+
+// Implementación del trait para Rect
+impl Drawable for Rect {
+    fn draw(&self) -> String {
+        format!(
+            "Dibujando un rectángulo en ({}, {}) de {} x {}",
+            self.top_left.x, self.top_left.y, self.width, self.height
+        )
+    }
+
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    fn bounding_box(&self) -> Rect {
+        *self
+    }
+
+    fn contains_point(&self, p: &Point) -> bool {
+        (self.left()..=self.right()).contains(&p.x) && (self.top()..=self.bottom()).contains(&p.y)
+    }
+
+    /// Cuánto se sale `p` del rect en cada eje (0 si está dentro de ese
+    /// intervalo), combinado con Pitágoras.
+    fn distance_to_point(&self, p: &Point) -> f64 {
+        let dx = (self.left() - p.x).max(p.x - self.right()).max(0.0);
+        let dy = (self.top() - p.y).max(p.y - self.bottom()).max(0.0);
+        dx.hypot(dy)
+    }
+
+    fn as_rect(&self) -> Option<Rect> {
+        Some(*self)
+    }
+}
+
+// Implementación del trait para Triangle
+impl Drawable for Triangle {
+    fn draw(&self) -> String {
+        format!(
+            "Dibujando un triángulo en ({}, {}), ({}, {}), ({}, {})",
+            self.a.x, self.a.y, self.b.x, self.b.y, self.c.x, self.c.y
+        )
+    }
+
+    /// Fórmula del cordón (shoelace): |(b - a) × (c - a)| / 2
+    fn area(&self) -> f64 {
+        cross(&self.a, &self.b, &self.c).abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.sides().iter().sum()
+    }
+
+    fn bounding_box(&self) -> Rect {
+        points_bounding_box(&self.vertices())
+    }
+
+    /// Adentro si `p` no queda a la izquierda de un lado y a la derecha de
+    /// otro: los tres productos cruz tienen el mismo signo (o alguno es 0, el
+    /// borde).
+    fn contains_point(&self, p: &Point) -> bool {
+        let turns = [cross(&self.a, &self.b, p), cross(&self.b, &self.c, p), cross(&self.c, &self.a, p)];
+        let left = turns.iter().any(|&t| t > 0.0);
+        let right = turns.iter().any(|&t| t < 0.0);
+        !(left && right)
+    }
+
+    fn distance_to_point(&self, p: &Point) -> f64 {
+        if self.contains_point(p) {
+            return 0.0;
+        }
+        [(self.a, self.b), (self.b, self.c), (self.c, self.a)]
+            .into_iter()
+            .map(|(a, b)| Segment::new(a, b).distance_to_point(p))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+// Implementación del trait para Polygon
+impl Drawable for Polygon {
+    fn draw(&self) -> String {
+        format!("Dibujando un polígono de {} vértices", self.vertices.len())
+    }
+
+    /// Fórmula del cordón: |Σ (xᵢ·yᵢ₊₁ - xᵢ₊₁·yᵢ)| / 2. El valor absoluto
+    /// hace que no importe el sentido de giro.
+    fn area(&self) -> f64 {
+        let twice: f64 = self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+        twice.abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.distance_to(b)).sum()
+    }
+
+    fn bounding_box(&self) -> Rect {
+        points_bounding_box(&self.vertices)
+    }
+
+    /// Ray casting, ver el comentario sobre `impl Polygon`.
+    ///
+    /// # Complejidad
+    /// **O(n)** en la cantidad de vértices.
+    fn contains_point(&self, p: &Point) -> bool {
+        if self
+            .edges()
+            .any(|(a, b)| Segment::new(*a, *b).point_on_segment(p))
+        {
+            return true;
+        }
+
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y <= p.y) != (b.y <= p.y) {
+                let cross_x = a.x + (p.y - a.y) * (b.x - a.x) / (b.y - a.y);
+                if cross_x > p.x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// 0 adentro; si no, la menor distancia a un lado.
+    fn distance_to_point(&self, p: &Point) -> f64 {
+        if self.contains_point(p) {
+            return 0.0;
+        }
+        self.edges()
+            .map(|(a, b)| Segment::new(*a, *b).distance_to_point(p))
+            .fold(f64::INFINITY, f64::min)
     }
 }
 
 // ---------- Funciones polimórficas ----------
 
 // Usando trait objects (dinámico)
-fn render(shape: &dyn Drawable) {
-    shape.draw();
+fn render(shape: &dyn Drawable) -> String {
+    shape.draw()
 }
 
-// Usando genéricos (estático)
-fn render_all<T: Drawable>(shapes: Vec<T>) {
-    for s in shapes {
-        s.draw();
+/// Dibuja figuras de tipos distintos, una línea por figura.
+pub fn render_all_dyn(shapes: &[Box<dyn Drawable>]) -> String {
+    render_refs(shapes.iter().map(|s| s.as_ref()))
+}
+
+/// Como [`render_all_dyn`], pero con figuras prestadas que pueden vivir en
+/// cualquier lado (un `Vec<&dyn Drawable>`, los valores de un mapa, ...).
+pub fn render_refs<'a>(shapes: impl IntoIterator<Item = &'a dyn Drawable>) -> String {
+    let mut out = String::new();
+    for shape in shapes {
+        out.push_str(&shape.draw());
+        out.push('\n');
     }
+    out
+}
+
+/// Suma de las áreas de una colección de figuras distintas.
+pub fn total_area(shapes: &[Box<dyn Drawable>]) -> f64 {
+    shapes.iter().map(|s| s.area()).sum()
 }
 
-// ---------- Main ----------
-fn main() {
+/// Caja que contiene a todas las figuras, o `None` si no hay ninguna.
+pub fn bounding_box_of(shapes: &[Box<dyn Drawable>]) -> Option<Rect> {
+    shapes
+        .iter()
+        .map(|s| s.bounding_box())
+        .reduce(|acc, b| acc.union(&b))
+}
+
+/// Compara dos figuras por área con un orden TOTAL (`f64::total_cmp`): un
+/// área NaN (figura degenerada) no rompe un sort, queda después de todas las
+/// demás.
+pub fn cmp_by_area(a: &dyn Drawable, b: &dyn Drawable) -> Ordering {
+    a.area().total_cmp(&b.area())
+}
+
+/// Envuelve una figura para ordenarla por área, p. ej. en un `BTreeSet`.
+///
+/// Dos figuras distintas de igual área son "iguales" para este orden: en un
+/// `BTreeSet` solo entra una de ellas.
+pub struct ShapeOrd<S>(pub S);
+
+impl<S: Drawable> PartialEq for ShapeOrd<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S: Drawable> Eq for ShapeOrd<S> {}
+
+impl<S: Drawable> PartialOrd for ShapeOrd<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: Drawable> Ord for ShapeOrd<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_by_area(&self.0, &other.0)
+    }
+}
+
+/// Índices de las figuras que contienen a `p`, en orden.
+pub fn shapes_at_point(shapes: &[Box<dyn Drawable>], p: Point) -> Vec<usize> {
+    shapes
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| s.contains_point(&p))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+// Usando genéricos (estático): se genera una copia por cada `T`, sin
+// despacho dinámico, pero todas las figuras deben ser del mismo tipo
+/// Dibuja figuras de un solo tipo, una línea por figura.
+pub fn render_all<T: Drawable>(shapes: &[T]) -> String {
+    render_refs(shapes.iter().map(|s| s as &dyn Drawable))
+}
+
+// ---------- Tour ----------
+pub fn poo_tour() {
     let p = Point::new(3.0, 4.0);
     println!("Distancia de p al origen: {}", p.distance_to_origin());
 
@@ -94,11 +797,26 @@ fn main() {
 
     // Polimorfismo con trait objects
     println!("\n--- Render individual ---");
-    render(&c);
-    render(&s);
+    println!("{}", render(&c));
+    println!("{}", render(&s));
+
+    // Los métodos del trait también se despachan dinámicamente
+    let mixed: Vec<Box<dyn Drawable>> = vec![Box::new(c), Box::new(s)];
+    print!("{}", render_all_dyn(&mixed));
+    println!("Área total: {:.2}", total_area(&mixed));
 
     // Polimorfismo con genéricos
     println!("\n--- Render en batch ---");
     let shapes = vec![Circle::new(2.0, 2.0, 1.5), Circle::new(-1.0, -1.0, 2.0)];
-    render_all(shapes);
+    print!("{}", render_all(&shapes));
+
+    // Una escena es dueña de las figuras y les da ids estables
+    println!("\n--- Escena ---");
+    let mut scene = Scene::new();
+    scene.add(Circle::new(0.0, 0.0, 1.0));
+    let square = scene.add(Square::new(2.0, 2.0, 2.0));
+    scene.add(Rect::new(Point::new(-3.0, 0.0), 2.0, 1.0));
+    scene.remove(square);
+    print!("{}", scene.render_all());
+    println!("Figuras: {}, área total: {:.2}", scene.shape_count(), scene.total_area());
 }
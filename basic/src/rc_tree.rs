@@ -0,0 +1,129 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+/*
+-----------------------------------------------------------
+   Árbol con punteros al padre: Rc + Weak
+-----------------------------------------------------------
+
+Cada nodo necesita llegar a sus hijos Y a su padre. Si ambos enlaces fueran
+`Rc`, padre e hijo se mantendrían vivos mutuamente: un ciclo de referencias
+fuertes que nunca llega a strong_count = 0, es decir, una fuga de memoria.
+
+La solución clásica:
+
+- padre ──Rc──► hijo     (el padre es DUEÑO de sus hijos)
+- hijo ──Weak──► padre   (el hijo solo OBSERVA a su padre)
+
+`Weak` no cuenta para strong_count. Para usarlo hay que llamar `upgrade()`,
+que retorna `None` si el padre ya fue liberado. Así, soltar la raíz libera
+todo el árbol de arriba hacia abajo.
+
+`RefCell` es necesario porque `add_child` modifica la lista de hijos de un
+nodo que está compartido (dentro de un `Rc`), y `Rc` solo da acceso `&T`.
+*/
+
+/// Referencia compartida a un nodo del árbol.
+pub type TreeRef<T> = Rc<RefCell<TreeNode<T>>>;
+
+/// Nodo de un árbol n-ario con enlace débil a su padre.
+///
+/// ```
+/// use basic::rc_tree::TreeNode;
+///
+/// let root = TreeNode::new("raíz");
+/// let child = TreeNode::add_child(&root, "hijo");
+/// let grandchild = TreeNode::add_child(&child, "nieto");
+///
+/// assert_eq!(grandchild.borrow().depth(), 2);
+/// assert_eq!(TreeNode::traverse_preorder(&root), ["raíz", "hijo", "nieto"]);
+/// ```
+pub struct TreeNode<T> {
+    pub value: T,
+    children: Vec<TreeRef<T>>,
+    parent: Weak<RefCell<TreeNode<T>>>,
+}
+
+impl<T> TreeNode<T> {
+    /// Crea un nodo raíz (sin padre ni hijos).
+    pub fn new(value: T) -> TreeRef<T> {
+        Rc::new(RefCell::new(TreeNode {
+            value,
+            children: Vec::new(),
+            parent: Weak::new(),
+        }))
+    }
+
+    /// Crea un hijo de `parent` con `value` y lo retorna.
+    pub fn add_child(parent: &TreeRef<T>, value: T) -> TreeRef<T> {
+        let child = Rc::new(RefCell::new(TreeNode {
+            value,
+            children: Vec::new(),
+            // El hijo guarda solo un Weak: no aumenta strong_count del padre
+            parent: Rc::downgrade(parent),
+        }));
+
+        parent.borrow_mut().children.push(Rc::clone(&child));
+        child
+    }
+
+    /// Retorna el padre, o `None` si es la raíz o el padre ya fue liberado.
+    pub fn parent(&self) -> Option<TreeRef<T>> {
+        self.parent.upgrade()
+    }
+
+    /// Retorna los hijos del nodo.
+    pub fn children(&self) -> &[TreeRef<T>] {
+        &self.children
+    }
+
+    /// Retorna la profundidad del nodo (la raíz tiene profundidad 0).
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut current = self.parent();
+        while let Some(node) = current {
+            depth += 1;
+            current = node.borrow().parent();
+        }
+        depth
+    }
+
+    /// Retorna un iterador sobre los ancestros de `node`, del padre a la raíz.
+    pub fn ancestors(node: &TreeRef<T>) -> Ancestors<T> {
+        Ancestors {
+            next: node.borrow().parent(),
+        }
+    }
+
+    /// Retorna los valores del subárbol de `node` en preorden (nodo, luego
+    /// cada hijo de izquierda a derecha).
+    pub fn traverse_preorder(node: &TreeRef<T>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        // Pila explícita: los hijos se apilan al revés para salir en orden
+        let mut visited = Vec::new();
+        let mut stack = vec![Rc::clone(node)];
+        while let Some(current) = stack.pop() {
+            let current = current.borrow();
+            visited.push(current.value.clone());
+            stack.extend(current.children.iter().rev().cloned());
+        }
+        visited
+    }
+}
+
+/// Iterador sobre los ancestros de un nodo, creado por [`TreeNode::ancestors`].
+pub struct Ancestors<T> {
+    next: Option<TreeRef<T>>,
+}
+
+impl<T> Iterator for Ancestors<T> {
+    type Item = TreeRef<T>;
+
+    fn next(&mut self) -> Option<TreeRef<T>> {
+        let current = self.next.take()?;
+        self.next = current.borrow().parent();
+        Some(current)
+    }
+}
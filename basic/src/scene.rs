@@ -0,0 +1,271 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use arena::{Key, Slab};
+
+use crate::json::Value;
+use crate::poo::{Drawable, Point, Rect, cmp_by_area, render_refs};
+use crate::shape_json::{SceneLoadError, shape_from_json};
+use crate::svg::{escape_xml, num};
+
+/*
+Escena: dueña de figuras de tipos distintos
+
+Las figuras se guardan como `Box<dyn Drawable>` en un `Slab` generacional (ver
+`arena::Slab`), no en un `Vec` plano. Con un `Vec`, el id de una figura sería
+su posición y quitar la figura del medio correría a todas las siguientes:
+
+    Vec:   [c, s, t]  remove(1)  →  [c, t]    el id 2 ya no existe
+    Slab:  [c, s, t]  remove(1)  →  [c, _, t] el id de `t` sigue valiendo
+
+Y un id de una figura ya quitada no "revive" si su casilla se reutiliza: la
+generación no coincide.
+
+El orden de dibujo va aparte, en un `Vec<ShapeId>`: al principio es el de
+inserción, y reordenar (p. ej. `sort_by_area`) solo permuta ese vector, así
+que las figuras no se mueven de casilla y los ids siguen valiendo.
+
+    shapes: [c, s, t]     order: [s, t, c]   → se dibuja s, t, c
+*/
+
+/// Identificador estable de una figura dentro de una [`Scene`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ShapeId(Key);
+
+/// Colección de figuras heterogéneas con ids estables.
+///
+/// ```
+/// use basic::poo::{Circle, Square};
+/// use basic::scene::Scene;
+///
+/// let mut scene = Scene::new();
+/// let circle = scene.add(Circle::new(0.0, 0.0, 1.0));
+/// let square = scene.add(Square::new(5.0, 5.0, 2.0));
+/// assert!(scene.remove(circle));
+/// assert!(scene.get(square).is_some());
+/// assert_eq!(scene.total_area(), 4.0);
+/// ```
+#[derive(Default)]
+pub struct Scene {
+    shapes: Slab<Box<dyn Drawable>>,
+    order: Vec<ShapeId>,
+    labels: HashMap<ShapeId, String>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Agrega `shape` y retorna su id.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado.
+    pub fn add<S: Drawable + 'static>(&mut self, shape: S) -> ShapeId {
+        self.insert_boxed(Box::new(shape))
+    }
+
+    fn insert_boxed(&mut self, shape: Box<dyn Drawable>) -> ShapeId {
+        let id = ShapeId(self.shapes.insert(shape));
+        self.order.push(id);
+        id
+    }
+
+    /// Quita la figura `id`. Retorna `false` si no estaba (o ya se quitó).
+    ///
+    /// # Complejidad
+    /// **O(n)**: hay que sacarla también del orden de dibujo.
+    pub fn remove(&mut self, id: ShapeId) -> bool {
+        if self.shapes.remove(id.0).is_none() {
+            return false;
+        }
+        self.labels.remove(&id);
+        self.order.retain(|&other| other != id);
+        true
+    }
+
+    /// Pone a la figura `id` una etiqueta de texto libre (se exporta como
+    /// `<title>` en SVG). Retorna `false` si la figura no existe.
+    pub fn set_label(&mut self, id: ShapeId, label: impl Into<String>) -> bool {
+        if !self.shapes.contains(id.0) {
+            return false;
+        }
+        self.labels.insert(id, label.into());
+        true
+    }
+
+    pub fn label(&self, id: ShapeId) -> Option<&str> {
+        self.labels.get(&id).map(String::as_str)
+    }
+
+    pub fn get(&self, id: ShapeId) -> Option<&dyn Drawable> {
+        self.shapes.get(id.0).map(|shape| shape.as_ref())
+    }
+
+    /// Cantidad de figuras en la escena.
+    pub fn shape_count(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Recorre las figuras en orden de dibujo (el de inserción, salvo que se
+    /// haya reordenado).
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Drawable> {
+        self.entries().map(|(_, shape)| shape)
+    }
+
+    /// Como `iter`, con el id de cada figura.
+    fn entries(&self) -> impl Iterator<Item = (ShapeId, &dyn Drawable)> {
+        self.order
+            .iter()
+            .map(|&id| (id, self.shapes.get(id.0).unwrap().as_ref()))
+    }
+
+    /// La figura de mayor área, según [`cmp_by_area`]; `None` si está vacía.
+    pub fn largest(&self) -> Option<&dyn Drawable> {
+        self.iter().max_by(|a, b| cmp_by_area(*a, *b))
+    }
+
+    /// La figura de menor área, según [`cmp_by_area`]; `None` si está vacía.
+    pub fn smallest(&self) -> Option<&dyn Drawable> {
+        self.iter().min_by(|a, b| cmp_by_area(*a, *b))
+    }
+
+    /// Reordena el orden de dibujo de menor a mayor área. Es estable (las
+    /// figuras de igual área conservan su orden) y los ids no cambian.
+    ///
+    /// # Complejidad
+    /// **O(n log n)**.
+    pub fn sort_by_area(&mut self) {
+        let shapes = &self.shapes;
+        let shape = |id: &ShapeId| shapes.get(id.0).unwrap().as_ref();
+        self.order.sort_by(|a, b| cmp_by_area(shape(a), shape(b)));
+    }
+
+    /// Las figuras de menor a mayor área, sin cambiar la escena.
+    pub fn sorted_by_area(&self) -> Vec<&dyn Drawable> {
+        let mut shapes: Vec<_> = self.iter().collect();
+        shapes.sort_by(|a, b| cmp_by_area(*a, *b));
+        shapes
+    }
+
+    /// Dibuja todas las figuras, una línea por figura, en el orden de
+    /// [`Scene::iter`].
+    pub fn render_all(&self) -> String {
+        render_refs(self.iter())
+    }
+
+    /// Suma de las áreas de todas las figuras.
+    pub fn total_area(&self) -> f64 {
+        self.iter().map(|shape| shape.area()).sum()
+    }
+
+    /// Caja que contiene a todas las figuras, o `None` si la escena está vacía.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.iter()
+            .map(|shape| shape.bounding_box())
+            .reduce(|acc, b| acc.union(&b))
+    }
+
+    /// Documento SVG con todas las figuras, de `width` x `height` unidades en
+    /// pantalla.
+    ///
+    /// El `viewBox` es la caja de la escena, así que se ve completa sin
+    /// importar sus coordenadas; una escena vacía usa `0 0 width height`. Las
+    /// figuras con etiqueta van en un `<g>` con un `<title>` escapado.
+    pub fn to_svg_document(&self, width: f64, height: f64) -> String {
+        let view = self
+            .bounding_box()
+            .unwrap_or(Rect::new(Point::new(0.0, 0.0), width, height));
+
+        let mut out = String::new();
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"{} {} {} {}\" fill=\"none\" stroke=\"black\">",
+            num(width),
+            num(height),
+            num(view.left()),
+            num(view.top()),
+            num(view.width),
+            num(view.height)
+        )
+        .unwrap();
+        for (id, shape) in self.entries() {
+            match self.labels.get(&id) {
+                Some(label) => writeln!(
+                    out,
+                    "  <g><title>{}</title>{}</g>",
+                    escape_xml(label),
+                    shape.to_svg()
+                ),
+                None => writeln!(out, "  {}", shape.to_svg()),
+            }
+            .unwrap();
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// Guarda la escena como `{"shapes": [...]}`, una figura por objeto (ver
+    /// `shape_json`) y con su etiqueta en "label" si la tiene.
+    pub fn save_json(&self) -> String {
+        let shapes = self
+            .entries()
+            .map(|(id, shape)| {
+                let mut json = shape.to_json();
+                if let (Value::Object(entries), Some(label)) = (&mut json, self.labels.get(&id)) {
+                    entries.push(("label".to_string(), Value::Str(label.clone())));
+                }
+                json
+            })
+            .collect();
+        Value::Object(vec![("shapes".to_string(), Value::Array(shapes))]).to_string_pretty(2)
+    }
+
+    /// Reconstruye una escena guardada con [`Scene::save_json`].
+    ///
+    /// Los ids de la escena cargada son nuevos; las figuras quedan en el
+    /// mismo orden.
+    pub fn load_json(input: &str) -> Result<Scene, SceneLoadError> {
+        let doc = Value::parse(input)?;
+        let Some(Value::Array(items)) = doc.get("shapes") else {
+            return Err(SceneLoadError::NotAScene);
+        };
+
+        let mut scene = Scene::new();
+        for (index, item) in items.iter().enumerate() {
+            let id = scene.insert_boxed(shape_from_json(index, item)?);
+            if let Some(Value::Str(label)) = item.get("label") {
+                scene.labels.insert(id, label.clone());
+            }
+        }
+        Ok(scene)
+    }
+
+    /// La figura más cercana a `p` y su distancia (0 si `p` está dentro de
+    /// ella); `None` si la escena está vacía. En un empate gana la primera en
+    /// orden de dibujo.
+    ///
+    /// # Complejidad
+    /// **O(n)** llamadas a `distance_to_point`.
+    pub fn nearest(&self, p: Point) -> Option<(ShapeId, f64)> {
+        self.entries()
+            .map(|(id, shape)| (id, shape.distance_to_point(&p)))
+            .reduce(|best, next| if next.1.total_cmp(&best.1).is_lt() { next } else { best })
+    }
+
+    /// Ids de las figuras que contienen a `p` (el borde cuenta).
+    ///
+    /// # Complejidad
+    /// **O(n)** pruebas de `contains_point`.
+    pub fn find_at(&self, p: Point) -> Vec<ShapeId> {
+        self.entries()
+            .filter(|(_, shape)| shape.contains_point(&p))
+            .map(|(id, _)| id)
+            .collect()
+    }
+}
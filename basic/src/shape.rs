@@ -0,0 +1,166 @@
+use crate::json::Value;
+use crate::poo::{Circle, Drawable, Point, Polygon, Rect, Square, Triangle, render_refs};
+use crate::shape_json::ToJson;
+use crate::svg::ToSvg;
+
+/*
+Enum vs trait object
+
+Hay dos formas de tener una colección de figuras distintas:
+
+    Vec<Box<dyn Drawable>>              Vec<Shape>
+
+    ┌─────┬─────┬─────┐                 ┌──────────┬──────────┬──────────┐
+    │ ptr │ ptr │ ptr │                 │ Circle   │ Square   │ Polygon  │
+    └──┬──┴──┬──┴──┬──┘                 │ (inline) │ (inline) │ (inline) │
+       ▼     ▼     ▼                    └──────────┴──────────┴──────────┘
+     heap  heap  heap
+     + vtable por figura
+
+- `dyn Drawable`: cada figura vive en su propio Box y cada llamada pasa por la
+  vtable (despacho dinámico). Es ABIERTO: cualquiera puede agregar una figura
+  nueva implementando el trait, sin tocar este código.
+- `Shape`: las figuras van una tras otra en el mismo Vec y cada llamada es un
+  `match` que el compilador puede inlinear. Es CERRADO: agregar una figura
+  obliga a tocar el enum y todos los `match` (el compilador avisa cuáles).
+  Cada elemento ocupa lo que la variante más grande.
+
+El enum implementa el mismo trait `Drawable`, así que todo lo que acepta
+`&dyn Drawable` (render_refs, colisiones...) acepta también un `&Shape`.
+*/
+
+/// Una de las figuras conocidas, sin Box ni vtable.
+pub enum Shape {
+    Circle(Circle),
+    Square(Square),
+    Rect(Rect),
+    Triangle(Triangle),
+    Polygon(Polygon),
+}
+
+/// Llama a `$call` sobre la figura que haya dentro de `$shape`.
+macro_rules! dispatch {
+    ($shape:expr, $s:ident => $call:expr) => {
+        match $shape {
+            Shape::Circle($s) => $call,
+            Shape::Square($s) => $call,
+            Shape::Rect($s) => $call,
+            Shape::Triangle($s) => $call,
+            Shape::Polygon($s) => $call,
+        }
+    };
+}
+
+impl ToSvg for Shape {
+    fn to_svg(&self) -> String {
+        dispatch!(self, s => s.to_svg())
+    }
+}
+
+impl ToJson for Shape {
+    fn to_json(&self) -> Value {
+        dispatch!(self, s => s.to_json())
+    }
+}
+
+impl Drawable for Shape {
+    fn draw(&self) -> String {
+        dispatch!(self, s => s.draw())
+    }
+
+    fn area(&self) -> f64 {
+        dispatch!(self, s => s.area())
+    }
+
+    fn perimeter(&self) -> f64 {
+        dispatch!(self, s => s.perimeter())
+    }
+
+    fn bounding_box(&self) -> Rect {
+        dispatch!(self, s => s.bounding_box())
+    }
+
+    fn contains_point(&self, p: &Point) -> bool {
+        dispatch!(self, s => s.contains_point(p))
+    }
+
+    fn distance_to_point(&self, p: &Point) -> f64 {
+        dispatch!(self, s => s.distance_to_point(p))
+    }
+
+    fn as_circle(&self) -> Option<&Circle> {
+        dispatch!(self, s => s.as_circle())
+    }
+
+    fn as_rect(&self) -> Option<Rect> {
+        dispatch!(self, s => s.as_rect())
+    }
+}
+
+macro_rules! impl_from {
+    ($($variant:ident),*) => {
+        $(impl From<$variant> for Shape {
+            fn from(shape: $variant) -> Self {
+                Shape::$variant(shape)
+            }
+        })*
+    };
+}
+
+impl_from!(Circle, Square, Rect, Triangle, Polygon);
+
+/// Versión con enum de una escena: un `Vec<Shape>` con las mismas
+/// operaciones que [`crate::poo`] ofrece para `&[Box<dyn Drawable>]`.
+#[derive(Default)]
+pub struct EnumScene(pub Vec<Shape>);
+
+impl EnumScene {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, shape: impl Into<Shape>) {
+        self.0.push(shape.into());
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Dibuja todas las figuras, una línea por figura.
+    pub fn render_all(&self) -> String {
+        render_refs(self.0.iter().map(|s| s as &dyn Drawable))
+    }
+
+    pub fn total_area(&self) -> f64 {
+        self.0.iter().map(|s| s.area()).sum()
+    }
+
+    /// Caja que contiene a todas las figuras, o `None` si no hay ninguna.
+    pub fn bounding_box(&self) -> Option<Rect> {
+        self.0
+            .iter()
+            .map(|s| s.bounding_box())
+            .reduce(|acc, b| acc.union(&b))
+    }
+
+    /// Índices de las figuras que contienen a `p`, en orden.
+    pub fn find_at(&self, p: Point) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.contains_point(&p))
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+impl FromIterator<Shape> for EnumScene {
+    fn from_iter<I: IntoIterator<Item = Shape>>(iter: I) -> Self {
+        EnumScene(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,203 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::json::{ParseError, Value};
+use crate::poo::{Circle, Drawable, Point, Polygon, Rect, Square, Triangle};
+
+/*
+Figuras ⇄ JSON
+
+Cada figura es un objeto con una etiqueta "type" que dice qué figura es
+(representación "internamente etiquetada") y sus medidas al lado:
+
+    {"type": "circle",   "cx": 0, "cy": 0, "r": 1}
+    {"type": "rect",     "x": 0, "y": 0, "width": 2, "height": 1}
+    {"type": "square",   "x": 0, "y": 0, "side": 2}
+    {"type": "triangle", "points": [[0, 0], [4, 0], [0, 3]]}
+    {"type": "polygon",  "points": [[0, 0], [1, 0], [1, 1], [0, 1]]}
+
+Guardar es fácil: cada figura sabe convertirse (`ToJson`). Cargar es el
+camino inverso y no hay un objeto todavía al que preguntarle: se mira "type"
+y se elige el constructor. Como el resultado es un `Box<dyn Drawable>`, quien
+llama no necesita saber qué figura salió.
+
+Al cargar se valida igual que al construir: un triángulo colineal o un
+polígono de dos vértices son errores, no figuras inválidas.
+*/
+
+/// Valores que se pueden convertir a [`Value`].
+pub trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+/// Error al cargar una escena desde JSON.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SceneLoadError {
+    /// El texto no es JSON válido.
+    Json(ParseError),
+    /// El JSON no es un objeto con un arreglo "shapes".
+    NotAScene,
+    /// La figura `index` tiene un "type" que no se conoce.
+    UnknownType { index: usize, name: String },
+    /// La figura `index` tiene campos faltantes o medidas inválidas.
+    InvalidShape { index: usize, reason: String },
+}
+
+impl fmt::Display for SceneLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneLoadError::Json(e) => write!(f, "invalid JSON: {e}"),
+            SceneLoadError::NotAScene => {
+                write!(f, "expected an object with a \"shapes\" array")
+            }
+            SceneLoadError::UnknownType { index, name } => {
+                write!(f, "shape {index}: unknown type \"{name}\"")
+            }
+            SceneLoadError::InvalidShape { index, reason } => {
+                write!(f, "shape {index}: {reason}")
+            }
+        }
+    }
+}
+
+impl Error for SceneLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SceneLoadError::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for SceneLoadError {
+    fn from(e: ParseError) -> Self {
+        SceneLoadError::Json(e)
+    }
+}
+
+fn object(kind: &str, fields: &[(&str, f64)]) -> Value {
+    let mut entries = vec![("type".to_string(), Value::Str(kind.to_string()))];
+    entries.extend(
+        fields
+            .iter()
+            .map(|&(name, n)| (name.to_string(), Value::Number(n))),
+    );
+    Value::Object(entries)
+}
+
+fn with_points(kind: &str, points: &[Point]) -> Value {
+    let points = points
+        .iter()
+        .map(|p| Value::Array(vec![Value::Number(p.x), Value::Number(p.y)]))
+        .collect();
+    Value::Object(vec![
+        ("type".to_string(), Value::Str(kind.to_string())),
+        ("points".to_string(), Value::Array(points)),
+    ])
+}
+
+impl ToJson for Circle {
+    fn to_json(&self) -> Value {
+        let c = self.center();
+        object("circle", &[("cx", c.x), ("cy", c.y), ("r", self.radius())])
+    }
+}
+
+impl ToJson for Rect {
+    fn to_json(&self) -> Value {
+        object(
+            "rect",
+            &[
+                ("x", self.left()),
+                ("y", self.top()),
+                ("width", self.width),
+                ("height", self.height),
+            ],
+        )
+    }
+}
+
+impl ToJson for Square {
+    fn to_json(&self) -> Value {
+        let r = Rect::from(self);
+        object(
+            "square",
+            &[("x", r.left()), ("y", r.top()), ("side", r.width)],
+        )
+    }
+}
+
+impl ToJson for Triangle {
+    fn to_json(&self) -> Value {
+        with_points("triangle", &self.vertices())
+    }
+}
+
+impl ToJson for Polygon {
+    fn to_json(&self) -> Value {
+        with_points("polygon", self.vertices())
+    }
+}
+
+/// Reconstruye la figura `index` de una escena a partir de su objeto JSON.
+pub fn shape_from_json(index: usize, value: &Value) -> Result<Box<dyn Drawable>, SceneLoadError> {
+    let invalid = |reason: String| SceneLoadError::InvalidShape { index, reason };
+    let number = |field: &str| match value.get(field) {
+        Some(Value::Number(n)) => Ok(*n),
+        _ => Err(invalid(format!("missing or non-numeric field \"{field}\""))),
+    };
+    let points = || -> Result<Vec<Point>, SceneLoadError> {
+        let Some(Value::Array(items)) = value.get("points") else {
+            return Err(invalid("missing or non-array field \"points\"".to_string()));
+        };
+        items
+            .iter()
+            .map(|item| match item {
+                Value::Array(xy) => match xy.as_slice() {
+                    [Value::Number(x), Value::Number(y)] => Ok(Point::new(*x, *y)),
+                    _ => Err(invalid("each point must be [x, y]".to_string())),
+                },
+                _ => Err(invalid("each point must be [x, y]".to_string())),
+            })
+            .collect()
+    };
+
+    let Some(Value::Str(kind)) = value.get("type") else {
+        return Err(invalid("missing string field \"type\"".to_string()));
+    };
+    let shape: Box<dyn Drawable> = match kind.as_str() {
+        "circle" => Box::new(
+            Circle::builder()
+                .center(number("cx")?, number("cy")?)
+                .radius(number("r")?)
+                .build()
+                .map_err(|e| invalid(e.to_string()))?,
+        ),
+        "rect" => Box::new(
+            Rect::builder()
+                .top_left(number("x")?, number("y")?)
+                .size(number("width")?, number("height")?)
+                .build()
+                .map_err(|e| invalid(e.to_string()))?,
+        ),
+        "square" => Box::new(
+            Square::builder()
+                .top_left(number("x")?, number("y")?)
+                .side(number("side")?)
+                .build()
+                .map_err(|e| invalid(e.to_string()))?,
+        ),
+        "triangle" => match points()?.as_slice() {
+            &[a, b, c] => Box::new(Triangle::new(a, b, c).map_err(|e| invalid(e.to_string()))?),
+            _ => return Err(invalid("a triangle needs exactly 3 points".to_string())),
+        },
+        "polygon" => Box::new(Polygon::new(points()?).map_err(|e| invalid(e.to_string()))?),
+        other => {
+            return Err(SceneLoadError::UnknownType {
+                index,
+                name: other.to_string(),
+            });
+        }
+    };
+    Ok(shape)
+}
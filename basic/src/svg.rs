@@ -0,0 +1,107 @@
+use std::fmt::Write;
+
+use crate::poo::{Circle, Point, Polygon, Rect, Square, Triangle};
+
+/*
+Exportar a SVG
+
+Cada figura se convierte en un elemento SVG:
+
+    Circle            <circle cx=".." cy=".." r=".."/>
+    Rect, Square      <rect x=".." y=".." width=".." height=".."/>
+    Triangle, Polygon <polygon points="x1,y1 x2,y2 ..."/>
+
+SVG usa `y` hacia abajo, igual que `Rect`, así que las coordenadas se copian
+tal cual.
+
+Los números se escriben con a lo sumo 3 decimales y sin ceros de más
+(`2.5`, no `2.500`; `-0` se escribe `0`): la salida es la misma en cualquier
+plataforma y se puede comparar como texto.
+*/
+
+/// Figuras que se pueden exportar como un elemento SVG.
+pub trait ToSvg {
+    /// Un único elemento SVG, sin salto de línea final.
+    fn to_svg(&self) -> String;
+}
+
+/// Formatea `x` con precisión fija, sin ceros finales.
+pub(crate) fn num(x: f64) -> String {
+    let s = format!("{x:.3}");
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+/// Escapa `&`, `<`, `>`, `"` y `'` para usar `text` dentro de XML.
+pub fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn polygon_element(points: &[Point]) -> String {
+    let mut out = String::from("<polygon points=\"");
+    for (i, p) in points.iter().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        write!(out, "{},{}", num(p.x), num(p.y)).unwrap();
+    }
+    out.push_str("\"/>");
+    out
+}
+
+impl ToSvg for Circle {
+    fn to_svg(&self) -> String {
+        let c = self.center();
+        format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{}\"/>",
+            num(c.x),
+            num(c.y),
+            num(self.radius())
+        )
+    }
+}
+
+impl ToSvg for Rect {
+    fn to_svg(&self) -> String {
+        format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+            num(self.left()),
+            num(self.top()),
+            num(self.width),
+            num(self.height)
+        )
+    }
+}
+
+impl ToSvg for Square {
+    fn to_svg(&self) -> String {
+        Rect::from(self).to_svg()
+    }
+}
+
+impl ToSvg for Triangle {
+    fn to_svg(&self) -> String {
+        polygon_element(&self.vertices())
+    }
+}
+
+impl ToSvg for Polygon {
+    fn to_svg(&self) -> String {
+        polygon_element(self.vertices())
+    }
+}
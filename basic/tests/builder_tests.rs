@@ -0,0 +1,112 @@
+use basic::builder::BuildError;
+use basic::poo::{Circle, Point, Rect, Square, Triangle};
+
+#[test]
+fn test_full_build_equals_positional_constructor() {
+    let circle = Circle::builder().center(1.0, -2.0).radius(5.0).build();
+    assert_eq!(circle, Ok(Circle::new(1.0, -2.0, 5.0)));
+
+    let rect = Rect::builder().top_left(3.0, 4.0).size(2.0, 1.0).build();
+    assert_eq!(rect, Ok(Rect::new(Point::new(3.0, 4.0), 2.0, 1.0)));
+
+    let square = Square::builder().top_left(1.0, 1.0).side(2.0).build();
+    assert_eq!(square, Ok(Square::new(1.0, 1.0, 2.0)));
+
+    let (a, b, c) = (
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(0.0, 3.0),
+    );
+    let triangle = Triangle::builder().vertex(a).vertex(b).vertex(c).build();
+    assert_eq!(triangle, Triangle::new(a, b, c).map_err(BuildError::from));
+}
+
+#[test]
+fn test_defaults_to_origin() {
+    assert_eq!(
+        Circle::builder().radius(1.0).build(),
+        Ok(Circle::new(0.0, 0.0, 1.0))
+    );
+    assert_eq!(
+        Rect::builder().size(1.0, 2.0).build().unwrap().top_left,
+        Point::new(0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_missing_fields() {
+    let err = Circle::builder().center(1.0, 1.0).build().unwrap_err();
+    assert_eq!(err, BuildError::MissingField("radius"));
+    assert_eq!(err.to_string(), "missing required field `radius`");
+
+    assert_eq!(
+        Rect::builder().build(),
+        Err(BuildError::MissingField("width"))
+    );
+    assert_eq!(
+        Square::builder().top_left(1.0, 1.0).build(),
+        Err(BuildError::MissingField("side"))
+    );
+}
+
+#[test]
+fn test_invalid_lengths_are_rejected() {
+    let err = Square::builder().side(-2.0).build().unwrap_err();
+    assert_eq!(
+        err,
+        BuildError::InvalidLength {
+            field: "side",
+            value: -2.0
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "`side` must be a non-negative number, got -2"
+    );
+
+    assert_eq!(
+        Rect::builder().size(1.0, -0.5).build(),
+        Err(BuildError::InvalidLength {
+            field: "height",
+            value: -0.5
+        })
+    );
+    assert!(matches!(
+        Circle::builder().radius(f64::NAN).build(),
+        Err(BuildError::InvalidLength {
+            field: "radius",
+            ..
+        })
+    ));
+    assert!(Circle::builder().radius(0.0).build().is_ok());
+}
+
+#[test]
+fn test_triangle_vertex_count_and_collinear() {
+    let two = Triangle::builder()
+        .vertex(Point::new(0.0, 0.0))
+        .vertex(Point::new(1.0, 0.0));
+    assert_eq!(two.build(), Err(BuildError::TooFewVertices(2)));
+
+    let four = two
+        .clone()
+        .vertex(Point::new(0.0, 1.0))
+        .vertex(Point::new(1.0, 1.0));
+    assert_eq!(four.build(), Err(BuildError::TooManyVertices(4)));
+
+    let flat = two.vertex(Point::new(2.0, 0.0));
+    assert_eq!(flat.build(), Err(BuildError::Collinear));
+}
+
+#[test]
+fn test_builders_are_reusable() {
+    let template = Circle::builder().radius(2.0);
+    let first = template.build().unwrap();
+    let second = template.build().unwrap();
+    assert_eq!(first, second);
+
+    // Y sirven de plantilla para variantes
+    let moved = template.clone().center(5.0, 5.0).build().unwrap();
+    assert_eq!(moved, Circle::new(5.0, 5.0, 2.0));
+    assert_eq!(template.build().unwrap().center(), Point::new(0.0, 0.0));
+}
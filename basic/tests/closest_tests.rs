@@ -0,0 +1,79 @@
+use basic::geometry::closest::{closest_pair, closest_pair_brute};
+use basic::poo::Point;
+use vectors::SimpleRng;
+
+fn pts(coords: &[(f64, f64)]) -> Vec<Point> {
+    coords.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+#[test]
+fn test_fewer_than_two_points() {
+    assert!(closest_pair(&[]).is_none());
+    assert!(closest_pair(&pts(&[(1.0, 1.0)])).is_none());
+    assert!(closest_pair_brute(&pts(&[(1.0, 1.0)])).is_none());
+}
+
+#[test]
+fn test_exactly_two_points() {
+    let (a, b, d) = closest_pair(&pts(&[(0.0, 0.0), (3.0, 4.0)])).unwrap();
+    assert_eq!(d, 5.0);
+    assert_eq!(a.distance_to(&b), 5.0);
+}
+
+#[test]
+fn test_duplicates_give_zero() {
+    let points = pts(&[(5.0, 5.0), (1.0, 2.0), (9.0, 0.0), (1.0, 2.0), (-3.0, 7.0)]);
+    let (a, b, d) = closest_pair(&points).unwrap();
+    assert_eq!(d, 0.0);
+    assert_eq!((a, b), (Point::new(1.0, 2.0), Point::new(1.0, 2.0)));
+}
+
+#[test]
+fn test_pair_straddling_the_split() {
+    // Ordenados por x la mitad cae entre x = 4 y x = 5: cada mitad por sí sola
+    // tiene pares lejanos y el más cercano cruza la recta
+    let points = pts(&[
+        (-50.0, -50.0),
+        (0.0, 0.0),
+        (0.0, 100.0),
+        (4.0, 50.0),
+        (5.0, 50.5),
+        (60.0, 60.0),
+        (100.0, 0.0),
+        (100.0, 100.0),
+    ]);
+    let (a, b, d) = closest_pair(&points).unwrap();
+    let mut pair = [a, b];
+    pair.sort_by(|p, q| p.x.total_cmp(&q.x));
+    assert_eq!(pair, [Point::new(4.0, 50.0), Point::new(5.0, 50.5)]);
+    assert_eq!(d, closest_pair_brute(&points).unwrap().2);
+}
+
+#[test]
+fn test_random_sets_match_brute_force() {
+    let mut rng = SimpleRng::from_seed(11);
+    for n in [2, 3, 4, 5, 10, 100, 1000, 10_000] {
+        // La referencia es O(n²): con 10k puntos basta una ronda
+        let rounds = if n > 1000 { 1 } else { 3 };
+        for _ in 0..rounds {
+            let points: Vec<Point> = (0..n)
+                .map(|_| {
+                    Point::new(
+                        rng.below(1_000_000) as f64 / 100.0,
+                        rng.below(1_000_000) as f64 / 100.0,
+                    )
+                })
+                .collect();
+            let (a, b, d) = closest_pair(&points).unwrap();
+            assert_eq!(d, closest_pair_brute(&points).unwrap().2, "n = {n}");
+            assert_eq!(a.distance_to(&b), d);
+        }
+    }
+}
+
+#[test]
+fn test_points_on_a_vertical_line() {
+    // Todas con la misma x: la franja contiene a todos los puntos
+    let points: Vec<Point> = (0..200).map(|i| Point::new(1.0, (i * i) as f64)).collect();
+    assert_eq!(closest_pair(&points).unwrap().2, 1.0);
+}
@@ -0,0 +1,107 @@
+use basic::geometry::collision::{
+    Collision, any_collisions, circle_circle, circle_rect, collide, intersects, rect_rect,
+};
+use basic::poo::{Circle, Drawable, Point, Polygon, Rect, Square, Triangle};
+
+fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Rect {
+    Rect::from_corners(Point::new(x0, y0), Point::new(x1, y1))
+}
+
+#[test]
+fn test_tangent_circles_collide() {
+    let a = Circle::new(0.0, 0.0, 1.0);
+    assert!(circle_circle(&a, &Circle::new(3.0, 0.0, 2.0)));
+    assert!(circle_circle(&a, &Circle::new(0.0, -2.0, 1.0)));
+    assert!(!circle_circle(&a, &Circle::new(3.0, 0.0, 1.5)));
+}
+
+#[test]
+fn test_rect_rect() {
+    let a = rect(0.0, 0.0, 2.0, 2.0);
+    assert!(rect_rect(&a, &rect(1.0, 1.0, 3.0, 3.0)));
+    assert!(rect_rect(&a, &rect(2.0, 0.0, 3.0, 1.0)));
+    assert!(!rect_rect(&a, &rect(2.5, 0.0, 3.0, 1.0)));
+}
+
+#[test]
+fn test_circle_rect_uses_nearest_point() {
+    let r = rect(0.0, 0.0, 2.0, 2.0);
+    // Frente a un lado y frente a una esquina, tocando justo
+    assert!(circle_rect(&Circle::new(3.0, 1.0, 1.0), &r));
+    assert!(circle_rect(&Circle::new(5.0, 6.0, 5.0), &r));
+    // Cerca de la esquina: las cajas se tocan pero el círculo no
+    assert!(!circle_rect(&Circle::new(2.8, 2.8, 1.0), &r));
+    assert!(rect_rect(&Circle::new(2.8, 2.8, 1.0).bounding_box(), &r));
+}
+
+#[test]
+fn test_nested_shapes_collide() {
+    let big = Square::new(0.0, 0.0, 10.0);
+    let small = Circle::new(5.0, 5.0, 1.0);
+    let inner = Rect::new(Point::new(4.0, 4.0), 0.5, 0.5);
+    assert!(intersects(&big, &small));
+    assert!(intersects(&small, &big));
+    assert!(intersects(&big, &inner));
+    assert!(intersects(&small, &inner));
+}
+
+#[test]
+fn test_disjoint_shapes() {
+    let c = Circle::new(0.0, 0.0, 1.0);
+    let s = Square::new(5.0, 5.0, 1.0);
+    let t = Triangle::new(
+        Point::new(10.0, 0.0),
+        Point::new(12.0, 0.0),
+        Point::new(11.0, 1.0),
+    )
+    .unwrap();
+    assert_eq!(
+        collide(&c, &s),
+        Collision {
+            hit: false,
+            conservative: false
+        }
+    );
+    assert_eq!(
+        collide(&c, &t),
+        Collision {
+            hit: false,
+            conservative: true
+        }
+    );
+    assert!(!intersects(&s, &t));
+}
+
+#[test]
+fn test_fallback_is_conservative() {
+    // La hipotenusa deja libre la esquina (2, 2) de la caja del triángulo
+    let t = Triangle::new(
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(0.0, 2.0),
+    )
+    .unwrap();
+    let c = Circle::new(2.2, 2.2, 0.3);
+    let result = collide(&t, &c);
+    assert!(result.conservative);
+    assert!(result.hit, "las cajas se tocan aunque las figuras no");
+}
+
+#[test]
+fn test_any_collisions_reports_pairs() {
+    let shapes: Vec<Box<dyn Drawable>> = vec![
+        Box::new(Circle::new(0.0, 0.0, 2.0)),
+        Box::new(Square::new(1.0, 1.0, 2.0)),
+        Box::new(Circle::new(20.0, 20.0, 1.0)),
+        Box::new(
+            Polygon::new(vec![
+                Point::new(-1.0, 1.0),
+                Point::new(2.0, 1.0),
+                Point::new(0.0, 4.0),
+            ])
+            .unwrap(),
+        ),
+    ];
+    assert_eq!(any_collisions(&shapes), [(0, 1), (0, 3), (1, 3)]);
+    assert!(any_collisions(&shapes[2..3]).is_empty());
+}
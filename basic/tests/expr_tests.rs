@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use basic::expr::{BinOp, EvalError, Expr, MAX_DEPTH, ParseError, ParseErrorKind};
+
+fn eval(input: &str) -> Result<f64, EvalError> {
+    Expr::parse(input).unwrap().eval(&HashMap::new())
+}
+
+#[test]
+fn test_precedence_and_associativity() {
+    assert_eq!(eval("2+3*4"), Ok(14.0));
+    assert_eq!(eval("(2+3)*4"), Ok(20.0));
+    assert_eq!(eval("8 - 3 - 2"), Ok(3.0));
+    assert_eq!(eval("16 / 4 / 2"), Ok(2.0));
+    assert_eq!(eval("2 ^ 3 ^ 2"), Ok(512.0));
+    assert_eq!(eval("1.5 * 2"), Ok(3.0));
+}
+
+#[test]
+fn test_unary_minus() {
+    assert_eq!(eval("-3 + 5"), Ok(2.0));
+    assert_eq!(eval("2 - -3"), Ok(5.0));
+    assert_eq!(eval("--4"), Ok(4.0));
+    assert_eq!(eval("-2 ^ 2"), Ok(-4.0));
+    assert_eq!(eval("2 ^ -1"), Ok(0.5));
+    assert_eq!(
+        Expr::parse("-x").unwrap(),
+        Expr::Neg(Box::new(Expr::Var("x".into())))
+    );
+}
+
+#[test]
+fn test_nested_parens_and_variables() {
+    let expr = Expr::parse("((a + 1) * (b - (2 / c)))").unwrap();
+    let vars = HashMap::from([("a".into(), 2.0), ("b".into(), 5.0), ("c".into(), 4.0)]);
+    assert_eq!(expr.eval(&vars), Ok(13.5));
+
+    let Expr::BinaryOp { op, .. } = expr else {
+        panic!("la raíz debería ser una multiplicación");
+    };
+    assert_eq!(op, BinOp::Mul);
+}
+
+#[test]
+fn test_eval_errors() {
+    let expr = Expr::parse("x + y").unwrap();
+    let vars = HashMap::from([("x".into(), 1.0)]);
+    assert_eq!(expr.eval(&vars), Err(EvalError::UndefinedVariable("y".into())));
+
+    assert_eq!(eval("1 / (2 - 2)"), Err(EvalError::DivisionByZero));
+    assert_eq!(EvalError::DivisionByZero.to_string(), "division by zero");
+}
+
+#[test]
+fn test_parse_errors_report_offset() {
+    let err = |input: &str| Expr::parse(input).unwrap_err();
+
+    assert_eq!(
+        err("2 * (3 + 4"),
+        ParseError { kind: ParseErrorKind::UnclosedParen, offset: 4 }
+    );
+    assert_eq!(
+        err("1 + 2) * 3"),
+        ParseError { kind: ParseErrorKind::TrailingInput, offset: 5 }
+    );
+    assert_eq!(
+        err("3 4"),
+        ParseError { kind: ParseErrorKind::TrailingInput, offset: 2 }
+    );
+    assert_eq!(
+        err("1 + "),
+        ParseError { kind: ParseErrorKind::UnexpectedEnd, offset: 4 }
+    );
+    assert_eq!(
+        err("1 + $"),
+        ParseError { kind: ParseErrorKind::UnexpectedChar('$'), offset: 4 }
+    );
+    assert_eq!(err("1..2").kind, ParseErrorKind::UnexpectedChar('1'));
+    assert_eq!(err("(1 + ").to_string(), "unexpected end of input at byte 5");
+}
+
+#[test]
+fn test_deep_nesting_is_an_error_not_a_crash() {
+    let n = 50_000;
+    let parens = format!("{}1{}", "(".repeat(n), ")".repeat(n));
+    let err = Expr::parse(&parens).unwrap_err();
+    // Justo después del `(` que pasa el límite
+    assert_eq!(err, ParseError { kind: ParseErrorKind::TooDeep, offset: MAX_DEPTH + 1 });
+    assert_eq!(err.to_string(), "nesting deeper than 128 levels at byte 129");
+
+    let minus = format!("{}x", "-".repeat(n));
+    assert_eq!(Expr::parse(&minus).unwrap_err().kind, ParseErrorKind::TooDeep);
+
+    let powers = vec!["2"; n].join("^");
+    assert_eq!(Expr::parse(&powers).unwrap_err().kind, ParseErrorKind::TooDeep);
+
+    // Una cadena plana también arma un árbol profundo (uno por operador)
+    let sum = vec!["1"; 2_000_000].join("+");
+    let err = Expr::parse(&sum).unwrap_err();
+    assert_eq!(err, ParseError { kind: ParseErrorKind::TooDeep, offset: 2 * MAX_DEPTH + 1 });
+    let product = vec!["x"; n].join(" * ");
+    assert_eq!(Expr::parse(&product).unwrap_err().kind, ParseErrorKind::TooDeep);
+    let mixed = vec!["1 * 2"; n].join(" - ");
+    assert_eq!(Expr::parse(&mixed).unwrap_err().kind, ParseErrorKind::TooDeep);
+
+    // Justo en el límite todavía se acepta
+    let sum = vec!["1"; MAX_DEPTH + 1].join("+");
+    assert_eq!(eval(&sum), Ok((MAX_DEPTH + 1) as f64));
+    assert_eq!(Expr::parse(&sum).unwrap().to_string().parse::<Expr>(), Expr::parse(&sum));
+    let ok = format!("{}7{}", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH));
+    assert_eq!(eval(&ok), Ok(7.0));
+    let ok = format!("{}7", "-".repeat(MAX_DEPTH));
+    assert_eq!(eval(&ok), Ok(7.0));
+}
+
+#[test]
+fn test_display_uses_minimal_parens_and_round_trips() {
+    let cases = [
+        ("3 + 4 * (2 - x)", "3 + 4 * (2 - x)"),
+        ("(1 + 2) + 3", "1 + 2 + 3"),
+        ("1 + (2 + 3)", "1 + (2 + 3)"),
+        ("a - (b - c)", "a - (b - c)"),
+        ("(a * b) / c", "a * b / c"),
+        ("(2 ^ 3) ^ 2", "(2 ^ 3) ^ 2"),
+        ("2 ^ (3 ^ 2)", "2 ^ 3 ^ 2"),
+        ("-(x + 1)", "-(x + 1)"),
+        ("-(x ^ 2)", "-x ^ 2"),
+        ("(-x) ^ 2", "(-x) ^ 2"),
+        ("2 ^ (-1)", "2 ^ -1"),
+        ("((((7))))", "7"),
+    ];
+
+    for (input, expected) in cases {
+        let expr = Expr::parse(input).unwrap();
+        let printed = expr.to_string();
+        assert_eq!(printed, expected, "imprimiendo {input}");
+        assert_eq!(Expr::parse(&printed).unwrap(), expr, "reparseando {printed}");
+    }
+}
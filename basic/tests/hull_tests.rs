@@ -0,0 +1,90 @@
+use basic::geometry::hull::convex_hull;
+use basic::poo::{Drawable, Point, Polygon};
+use vectors::SimpleRng;
+
+fn pts(coords: &[(f64, f64)]) -> Vec<Point> {
+    coords.iter().map(|&(x, y)| Point::new(x, y)).collect()
+}
+
+fn turn(a: Point, b: Point, c: Point) -> f64 {
+    (b - a).cross(&(c - a))
+}
+
+#[test]
+fn test_square_with_interior_points() {
+    let points = pts(&[
+        (1.0, 1.0),
+        (0.0, 0.0),
+        (2.0, 2.0),
+        (0.0, 2.0),
+        (0.5, 1.5),
+        (2.0, 0.0),
+        (1.0, 0.0), // sobre un lado: no es vértice
+        (2.0, 2.0), // repetido
+    ]);
+    let hull = convex_hull(&points);
+    assert_eq!(hull, pts(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]));
+}
+
+#[test]
+fn test_collinear_points_give_extremes() {
+    let points = pts(&[(2.0, 2.0), (0.0, 0.0), (3.0, 3.0), (1.0, 1.0), (3.0, 3.0)]);
+    assert_eq!(convex_hull(&points), pts(&[(0.0, 0.0), (3.0, 3.0)]));
+
+    let vertical = pts(&[(1.0, 5.0), (1.0, -1.0), (1.0, 2.0)]);
+    assert_eq!(convex_hull(&vertical), pts(&[(1.0, -1.0), (1.0, 5.0)]));
+}
+
+#[test]
+fn test_fewer_than_three_points() {
+    assert!(convex_hull(&[]).is_empty());
+    assert_eq!(
+        convex_hull(&pts(&[(1.0, 1.0), (1.0, 1.0)])),
+        pts(&[(1.0, 1.0)])
+    );
+    assert_eq!(
+        convex_hull(&pts(&[(3.0, 0.0), (1.0, 1.0)])),
+        pts(&[(1.0, 1.0), (3.0, 0.0)])
+    );
+}
+
+#[test]
+fn test_random_hull_has_every_point_on_the_left() {
+    let mut rng = SimpleRng::from_seed(2024);
+    for round in 0..50 {
+        let n = 3 + rng.below(300) as usize;
+        let points: Vec<Point> = (0..n)
+            .map(|_| Point::new(rng.below(100) as f64, rng.below(100) as f64))
+            .collect();
+        let hull = convex_hull(&points);
+
+        for i in 0..hull.len() {
+            let (a, b) = (hull[i], hull[(i + 1) % hull.len()]);
+            for &p in &points {
+                assert!(
+                    turn(a, b, p) >= 0.0,
+                    "ronda {round}: {p} a la derecha de {a} → {b}"
+                );
+            }
+        }
+        // Antihorario y estrictamente convexo: giros positivos en cada vértice
+        if hull.len() >= 3 {
+            for i in 0..hull.len() {
+                let n = hull.len();
+                assert!(turn(hull[i], hull[(i + 1) % n], hull[(i + 2) % n]) > 0.0);
+            }
+            let area = Polygon::new(hull.clone()).unwrap().area();
+            assert!(area > 0.0);
+        }
+    }
+}
+
+#[test]
+fn test_hull_is_idempotent() {
+    let mut rng = SimpleRng::from_seed(7);
+    let points: Vec<Point> = (0..500)
+        .map(|_| Point::new(rng.below(1000) as f64 / 10.0, rng.below(1000) as f64 / 10.0))
+        .collect();
+    let hull = convex_hull(&points);
+    assert_eq!(convex_hull(&hull), hull);
+}
@@ -0,0 +1,128 @@
+use basic::json::{MAX_DEPTH, ParseError, ParseErrorKind, Value};
+
+const DOCUMENT: &str = r#"
+{
+    "nombre": "Ana \"la\" programadora",
+    "edad": 31,
+    "altura": 1.68,
+    "activa": true,
+    "jefe": null,
+    "tags": ["rust", "json", []],
+    "dirección": {
+        "ciudad": "Bogotá",
+        "coords": [4.711, -74.0721, 2.6e3],
+        "vacío": {}
+    }
+}
+"#;
+
+#[test]
+fn test_parse_nested_document() {
+    let v = Value::parse(DOCUMENT).unwrap();
+    assert_eq!(v.get("edad"), Some(&Value::Number(31.0)));
+    assert_eq!(v.get("jefe"), Some(&Value::Null));
+    assert_eq!(
+        v.get("nombre"),
+        Some(&Value::Str("Ana \"la\" programadora".into()))
+    );
+
+    let address = v.get("dirección").unwrap();
+    assert_eq!(address.get("ciudad"), Some(&Value::Str("Bogotá".into())));
+    assert_eq!(
+        address.get("coords"),
+        Some(&Value::Array(vec![
+            Value::Number(4.711),
+            Value::Number(-74.0721),
+            Value::Number(2600.0),
+        ]))
+    );
+    assert_eq!(v.get("falta"), None);
+}
+
+#[test]
+fn test_round_trip_compact_and_pretty() {
+    let v = Value::parse(DOCUMENT).unwrap();
+
+    let compact = v.to_string();
+    assert_eq!(Value::parse(&compact).unwrap(), v);
+
+    for indent in [0, 2, 4] {
+        let pretty = v.to_string_pretty(indent);
+        assert_eq!(Value::parse(&pretty).unwrap(), v);
+    }
+    assert!(v.to_string_pretty(4).contains("\n    \"edad\": 31,\n"));
+}
+
+#[test]
+fn test_escape_sequences() {
+    let v = Value::parse(r#""a\nb \"c\" \\ \/ \t A é 🦀""#).unwrap();
+    assert_eq!(v, Value::Str("a\nb \"c\" \\ / \t A é 🦀".into()));
+
+    // Al imprimir se escapan de nuevo y el resultado vuelve a parsear igual
+    let printed = Value::Str("tab\tnull\u{0}\"".into()).to_string();
+    assert_eq!(printed, r#""tab\tnull\u0000\"""#);
+    assert_eq!(Value::parse(&printed).unwrap(), Value::Str("tab\tnull\u{0}\"".into()));
+
+    let err = Value::parse(r#""\q""#).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::InvalidEscape);
+    assert_eq!(Value::parse(r#""\uD83E""#).unwrap_err().kind, ParseErrorKind::InvalidEscape);
+    assert_eq!(Value::parse(r#""\u00G1""#).unwrap_err().kind, ParseErrorKind::InvalidEscape);
+}
+
+#[test]
+fn test_missing_comma_reports_line_and_column() {
+    let input = "{\n  \"a\": 1\n  \"b\": 2\n}";
+    assert_eq!(
+        Value::parse(input),
+        Err(ParseError { kind: ParseErrorKind::UnexpectedChar('"'), line: 3, column: 3 })
+    );
+
+    let err = Value::parse("[1, 2 3]").unwrap_err();
+    assert_eq!((err.line, err.column), (1, 7));
+    assert_eq!(err.to_string(), "unexpected `3` at line 1, column 7");
+}
+
+#[test]
+fn test_unterminated_string_points_at_opening_quote() {
+    let err = Value::parse("[\n  \"ok\",\n  \"sin cerrar]").unwrap_err();
+    assert_eq!(
+        err,
+        ParseError { kind: ParseErrorKind::UnterminatedString, line: 3, column: 3 }
+    );
+    assert_eq!(Value::parse("\"a\nb\"").unwrap_err().kind, ParseErrorKind::UnterminatedString);
+}
+
+#[test]
+fn test_numbers_and_other_errors() {
+    assert_eq!(Value::parse("-0.5e-2"), Ok(Value::Number(-0.005)));
+    assert_eq!(Value::parse("0"), Ok(Value::Number(0.0)));
+
+    for bad in ["01", "1.", "-", ".5", "1e", "+1"] {
+        let err = Value::parse(bad).unwrap_err();
+        assert!(
+            matches!(err.kind, ParseErrorKind::InvalidNumber | ParseErrorKind::UnexpectedChar(_)),
+            "{bad}: {err}"
+        );
+    }
+
+    assert_eq!(Value::parse("[1,]").unwrap_err().kind, ParseErrorKind::UnexpectedChar(']'));
+    assert_eq!(Value::parse("{\"a\" 1}").unwrap_err().kind, ParseErrorKind::UnexpectedChar('1'));
+    assert_eq!(Value::parse("[true").unwrap_err().kind, ParseErrorKind::UnexpectedEnd);
+    assert_eq!(Value::parse("nul").unwrap_err().kind, ParseErrorKind::UnexpectedChar('n'));
+    assert_eq!(Value::parse("1 2").unwrap_err().kind, ParseErrorKind::TrailingInput);
+    assert_eq!(Value::parse("").unwrap_err().kind, ParseErrorKind::UnexpectedEnd);
+}
+
+#[test]
+fn test_deep_nesting_is_limited_not_a_crash() {
+    let ok = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+    assert!(Value::parse(&ok).is_ok());
+
+    let too_deep = "[".repeat(MAX_DEPTH + 1);
+    let err = Value::parse(&too_deep).unwrap_err();
+    assert_eq!(err.kind, ParseErrorKind::TooDeep);
+    assert_eq!(err.column, MAX_DEPTH + 1);
+
+    let hostile = "[{\"a\":".repeat(100_000);
+    assert_eq!(Value::parse(&hostile).unwrap_err().kind, ParseErrorKind::TooDeep);
+}
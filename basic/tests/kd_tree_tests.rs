@@ -0,0 +1,87 @@
+use basic::kd_tree::KdTree;
+use basic::poo::{Drawable, Point, Rect};
+use vectors::SimpleRng;
+
+fn random_point(rng: &mut SimpleRng) -> Point {
+    Point::new(rng.below(10_000) as f64 / 10.0, rng.below(10_000) as f64 / 10.0)
+}
+
+fn dist(a: &Point, b: &Point) -> f64 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt()
+}
+
+/// Distancias a todos los puntos, ordenadas: la referencia por fuerza bruta.
+fn brute_force_distances(points: &[Point], query: &Point) -> Vec<f64> {
+    let mut all: Vec<f64> = points.iter().map(|p| dist(p, query)).collect();
+    all.sort_by(f64::total_cmp);
+    all
+}
+
+#[test]
+fn test_nearest_and_k_nearest_against_brute_force() {
+    let mut rng = SimpleRng::from_seed(0x4D);
+    let points: Vec<Point> = (0..1_000).map(|_| random_point(&mut rng)).collect();
+    let tree = KdTree::new(&points);
+    assert_eq!(tree.len(), 1_000);
+
+    for _ in 0..100 {
+        let query = random_point(&mut rng);
+        let expected = brute_force_distances(&points, &query);
+
+        let (p, d) = tree.nearest(&query).unwrap();
+        assert_eq!(d, expected[0]);
+        assert_eq!(dist(&p, &query), d);
+
+        let k_best: Vec<f64> = tree.k_nearest(&query, 7).iter().map(|(_, d)| *d).collect();
+        assert_eq!(k_best, expected[..7]);
+    }
+}
+
+#[test]
+fn test_duplicate_points() {
+    let same = Point::new(1.0, 1.0);
+    let points = [same, same, same, Point::new(3.0, 3.0)];
+    let tree = KdTree::new(&points);
+
+    let (p, d) = tree.nearest(&Point::new(1.0, 1.0)).unwrap();
+    assert_eq!((p.x, p.y, d), (1.0, 1.0, 0.0));
+
+    let three: Vec<f64> = tree.k_nearest(&same, 3).iter().map(|(_, d)| *d).collect();
+    assert_eq!(three, [0.0, 0.0, 0.0]);
+    assert_eq!(tree.k_nearest(&same, 10).len(), 4);
+    assert_eq!(tree.range_query(&Rect::from_corners(same, same)).len(), 3);
+}
+
+#[test]
+fn test_empty_tree() {
+    let tree = KdTree::new(&[]);
+    assert!(tree.is_empty());
+    assert!(tree.nearest(&Point::new(0.0, 0.0)).is_none());
+    assert!(tree.k_nearest(&Point::new(0.0, 0.0), 3).is_empty());
+}
+
+#[test]
+fn test_range_query_is_boundary_inclusive() {
+    let mut rng = SimpleRng::from_seed(0x4E);
+    let mut points: Vec<Point> = (0..500).map(|_| random_point(&mut rng)).collect();
+    // Puntos justo sobre los bordes y esquinas
+    points.extend([
+        Point::new(100.0, 100.0),
+        Point::new(300.0, 250.0),
+        Point::new(100.0, 180.0),
+        Point::new(200.0, 250.0),
+    ]);
+    let tree = KdTree::new(&points);
+    let rect = Rect::from_corners(Point::new(100.0, 100.0), Point::new(300.0, 250.0));
+
+    let key = |p: &Point| (p.x.to_bits(), p.y.to_bits());
+    let mut found: Vec<_> = tree.range_query(&rect).iter().map(key).collect();
+    let mut expected: Vec<_> = points.iter().filter(|p| rect.contains_point(p)).map(key).collect();
+    found.sort();
+    expected.sort();
+
+    assert_eq!(found, expected);
+    for corner in [(100.0, 100.0), (300.0, 250.0)] {
+        assert!(found.contains(&key(&Point::new(corner.0, corner.1))));
+    }
+}
@@ -0,0 +1,139 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::rc::Rc;
+
+use basic::my_box::MyBox;
+
+/// Allocator que cuenta las llamadas a `alloc` del hilo actual, para que
+/// los tests que corren en paralelo no se mezclen.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocs() -> usize {
+    ALLOCS.with(Cell::get)
+}
+
+/// Incrementa el contador compartido al destruirse.
+struct DropCounter(Rc<Cell<usize>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+trait Animal {
+    fn sound(&self) -> String;
+}
+
+struct Dog;
+impl Animal for Dog {
+    fn sound(&self) -> String {
+        String::from("Guau!")
+    }
+}
+
+struct Named {
+    name: String,
+    _drops: DropCounter,
+}
+impl Animal for Named {
+    fn sound(&self) -> String {
+        format!("{} maúlla", self.name)
+    }
+}
+
+#[test]
+fn test_deref_calls_methods() {
+    let mut b = MyBox::new(String::from("hola"));
+    assert_eq!(b.len(), 4);
+    b.push_str(" mundo");
+    assert_eq!(*b, "hola mundo");
+    assert_eq!(b.to_uppercase(), "HOLA MUNDO");
+
+    let mut n: MyBox<i32> = 41.into();
+    *n += 1;
+    assert_eq!(*n, 42);
+    assert_eq!(format!("{n} {n:?}"), "42 42");
+}
+
+#[test]
+fn test_drop_runs_once() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let _b = MyBox::new(DropCounter(drops.clone()));
+        assert_eq!(drops.get(), 0);
+    }
+    assert_eq!(drops.get(), 1);
+
+    let boxes: Vec<_> = (0..5)
+        .map(|_| MyBox::new(DropCounter(drops.clone())))
+        .collect();
+    drop(boxes);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_into_inner_does_not_double_drop() {
+    let drops = Rc::new(Cell::new(0));
+    let b = MyBox::new(DropCounter(drops.clone()));
+    let inner = MyBox::into_inner(b);
+    assert_eq!(drops.get(), 0);
+    drop(inner);
+    assert_eq!(drops.get(), 1);
+
+    assert_eq!(MyBox::into_inner(MyBox::new(vec![1, 2, 3])), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_zero_sized_skips_allocator() {
+    let before = allocs();
+    let b = MyBox::new(());
+    let d = MyBox::new(Dog);
+    MyBox::into_inner(b);
+    assert_eq!(allocs(), before);
+    assert_eq!(d.sound(), "Guau!");
+    drop(d);
+
+    let before = allocs();
+    let n = MyBox::new(7u64);
+    assert_eq!(allocs(), before + 1);
+    drop(n);
+}
+
+#[test]
+fn test_trait_objects_from_box() {
+    let drops = Rc::new(Cell::new(0));
+    let animals: Vec<MyBox<dyn Animal>> = vec![
+        MyBox::from_box(Box::new(Dog)),
+        MyBox::from_box(Box::new(Named {
+            name: String::from("Michi"),
+            _drops: DropCounter(drops.clone()),
+        })),
+    ];
+    let sounds: Vec<_> = animals.iter().map(|a| a.sound()).collect();
+    assert_eq!(sounds, ["Guau!", "Michi maúlla"]);
+
+    drop(animals);
+    assert_eq!(drops.get(), 1);
+
+    let s: MyBox<str> = MyBox::from_box(Box::from("texto"));
+    assert_eq!(&*s, "texto");
+}
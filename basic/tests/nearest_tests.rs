@@ -0,0 +1,156 @@
+use std::f64::consts::TAU;
+
+use basic::geometry::segment::Segment;
+use basic::poo::{Circle, Drawable, Point, Polygon, Rect, Square, Triangle};
+use basic::scene::Scene;
+use vectors::SimpleRng;
+
+/// Puntos del borde: `per_edge` por lado (o en total, para el círculo).
+fn boundary_samples(vertices: &[Point], per_edge: usize) -> Vec<Point> {
+    let n = vertices.len();
+    (0..n)
+        .flat_map(|i| {
+            let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+            (0..per_edge).map(move |k| a + (b - a) * (k as f64 / per_edge as f64))
+        })
+        .collect()
+}
+
+fn sampled_distance(samples: &[Point], p: Point) -> f64 {
+    samples
+        .iter()
+        .map(|s| s.distance_to(&p))
+        .fold(f64::INFINITY, f64::min)
+}
+
+fn rect_vertices(r: &Rect) -> Vec<Point> {
+    vec![
+        r.top_left,
+        Point::new(r.right(), r.top()),
+        r.bottom_right(),
+        Point::new(r.left(), r.bottom()),
+    ]
+}
+
+#[test]
+fn test_inside_is_zero() {
+    let p = Point::new(1.0, 1.0);
+    assert_eq!(Circle::new(0.0, 0.0, 2.0).distance_to_point(&p), 0.0);
+    assert_eq!(Square::new(0.0, 0.0, 2.0).distance_to_point(&p), 0.0);
+    assert_eq!(
+        Rect::new(Point::new(1.0, 0.0), 1.0, 1.0).distance_to_point(&p),
+        0.0
+    );
+    let t = Triangle::new(
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(0.0, 4.0),
+    )
+    .unwrap();
+    assert_eq!(t.distance_to_point(&p), 0.0);
+}
+
+#[test]
+fn test_exact_distances() {
+    assert_eq!(
+        Circle::new(0.0, 0.0, 2.0).distance_to_point(&Point::new(3.0, 4.0)),
+        3.0
+    );
+
+    let r = Rect::new(Point::new(0.0, 0.0), 2.0, 1.0);
+    assert_eq!(r.distance_to_point(&Point::new(1.0, 4.0)), 3.0);
+    assert_eq!(r.distance_to_point(&Point::new(5.0, 5.0)), 5.0);
+
+    let s = Segment::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0));
+    assert_eq!(s.distance_to_point(&Point::new(2.0, -3.0)), 3.0);
+    assert_eq!(s.distance_to_point(&Point::new(7.0, 4.0)), 5.0);
+    assert_eq!(
+        Segment::new(Point::new(1.0, 1.0), Point::new(1.0, 1.0))
+            .distance_to_point(&Point::new(4.0, 5.0)),
+        5.0
+    );
+}
+
+#[test]
+fn test_matches_boundary_sampling() {
+    let circle = Circle::new(1.0, -1.0, 3.0);
+    let circle_samples: Vec<Point> = (0..4000)
+        .map(|k| {
+            let angle = TAU * k as f64 / 4000.0;
+            circle.center() + Point::new(angle.cos(), angle.sin()) * circle.radius()
+        })
+        .collect();
+
+    let rect = Rect::new(Point::new(-2.0, 3.0), 5.0, 2.0);
+    let triangle = Triangle::new(
+        Point::new(0.0, 0.0),
+        Point::new(6.0, 1.0),
+        Point::new(2.0, 5.0),
+    )
+    .unwrap();
+    let l_shape = Polygon::new(
+        [
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 2.0),
+            (2.0, 2.0),
+            (2.0, 4.0),
+            (0.0, 4.0),
+        ]
+        .iter()
+        .map(|&(x, y)| Point::new(x, y))
+        .collect(),
+    )
+    .unwrap();
+
+    let cases: Vec<(&dyn Drawable, Vec<Point>)> = vec![
+        (&circle, circle_samples),
+        (&rect, boundary_samples(&rect_vertices(&rect), 1000)),
+        (&triangle, boundary_samples(&triangle.vertices(), 1000)),
+        (&l_shape, boundary_samples(l_shape.vertices(), 1000)),
+    ];
+
+    let mut rng = SimpleRng::from_seed(5);
+    for (shape, samples) in &cases {
+        for _ in 0..200 {
+            let p = Point::new(
+                rng.below(2000) as f64 / 100.0 - 10.0,
+                rng.below(2000) as f64 / 100.0 - 10.0,
+            );
+            let exact = shape.distance_to_point(&p);
+            if shape.contains_point(&p) {
+                assert_eq!(exact, 0.0);
+                continue;
+            }
+            let sampled = sampled_distance(samples, p);
+            // El muestreo solo puede sobrestimar, y por poco
+            assert!(exact <= sampled + 1e-9, "{p}: {exact} > {sampled}");
+            assert!(sampled - exact < 1e-2, "{p}: {exact} vs {sampled}");
+        }
+    }
+}
+
+#[test]
+fn test_scene_nearest() {
+    assert!(Scene::new().nearest(Point::new(0.0, 0.0)).is_none());
+
+    let mut scene = Scene::new();
+    let circle = scene.add(Circle::new(0.0, 0.0, 1.0));
+    let square = scene.add(Square::new(4.0, -1.0, 2.0));
+
+    // Entre ambos: el borde del círculo está en x = 1 y el del cuadrado en x = 4
+    assert_eq!(
+        scene.nearest(Point::new(2.4, 0.0)),
+        Some((circle, 1.4))
+    );
+    let (id, d) = scene.nearest(Point::new(2.6, 0.0)).unwrap();
+    assert_eq!(id, square);
+    assert!((d - 1.4).abs() < 1e-12);
+
+    // El centro del cuadrado está más lejos que el del círculo, pero su borde no
+    let (id, _) = scene.nearest(Point::new(3.0, 2.5)).unwrap();
+    assert_eq!(id, square);
+
+    // Adentro: distancia 0
+    assert_eq!(scene.nearest(Point::new(5.0, 0.0)), Some((square, 0.0)));
+}
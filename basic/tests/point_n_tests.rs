@@ -0,0 +1,58 @@
+use basic::geometry::point::{Point2f, Point2i, Point3f, Point3i, PointN, Scalar};
+use basic::poo::{Circle, Drawable, Point};
+
+/// Cuadrado del largo para cualquier punto, usando solo las cotas genéricas.
+fn norm_squared<T: Scalar, const N: usize>(p: &PointN<T, N>) -> T {
+    p.dot(p)
+}
+
+#[test]
+fn test_3d_distance() {
+    let a = Point3f::new([1.0, 2.0, 3.0]);
+    let b = Point3f::new([3.0, 5.0, 9.0]);
+    assert_eq!(a.distance_to(&b), 7.0);
+    assert_eq!(a.distance_to(&b), b.distance_to(&a));
+    assert_eq!((b - a).z(), 6.0);
+    assert_eq!(a.dim(), 3);
+}
+
+#[test]
+fn test_integer_points_are_exact() {
+    let a = Point2i::new([i64::MAX / 4, -3]);
+    let b = Point2i::new([i64::MAX / 4, 5]);
+    assert_eq!(a + b - b, a);
+    assert_eq!((a + b)[0], i64::MAX / 4 * 2);
+    assert_eq!(-a, Point2i::new([-(i64::MAX / 4), 3]));
+    assert_eq!(Point2i::new([3, -4]) * 3, Point2i::new([9, -12]));
+
+    let mut c = Point3i::origin();
+    c += Point3i::new([1, 2, 3]);
+    c -= Point3i::new([0, 0, 5]);
+    c[0] = 10;
+    assert_eq!(c, Point3i::new([10, 2, -2]));
+}
+
+#[test]
+fn test_generic_dot() {
+    assert_eq!(norm_squared(&Point2i::new([3, 4])), 25);
+    assert_eq!(norm_squared(&Point3f::new([1.0, 2.0, 2.0])), 9.0);
+    assert_eq!(norm_squared(&PointN::<u8, 4>::new([1, 1, 1, 1])), 4);
+
+    // Perpendiculares
+    assert_eq!(Point3i::new([1, 0, 0]).dot(&Point3i::new([0, 7, 0])), 0);
+}
+
+#[test]
+fn test_conversion_with_shape_point() {
+    let p = Point::new(3.0, 4.0);
+    let generic = Point2f::from(p);
+    assert_eq!((generic.x(), generic.y()), (3.0, 4.0));
+    assert_eq!(generic.length(), p.length());
+
+    let back: Point = (generic * 2.0).into();
+    assert_eq!(back, Point::new(6.0, 8.0));
+
+    // Las figuras siguen usando `Point`
+    let circle = Circle::new(back.x, back.y, 1.0);
+    assert!(circle.contains_point(&Point::from(generic * 2.0)));
+}
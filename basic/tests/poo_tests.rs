@@ -0,0 +1,422 @@
+use std::f64::consts::PI;
+
+use basic::poo::{
+    Circle, Drawable, Point, Polygon, PolygonError, Rect, Square, Triangle, TriangleError,
+    bounding_box_of, render_all, render_all_dyn, render_refs, shapes_at_point, total_area,
+};
+
+const EPS: f64 = 1e-9;
+
+#[test]
+fn test_circle_area_and_perimeter() {
+    let c = Circle::new(0.0, 0.0, 1.0);
+    assert!((c.area() - PI).abs() < EPS);
+    assert!((c.perimeter() - 2.0 * PI).abs() < EPS);
+
+    let c = Circle::new(3.0, -2.0, 2.5);
+    assert!((c.area() - 6.25 * PI).abs() < EPS);
+}
+
+#[test]
+fn test_square_area_and_perimeter() {
+    let s = Square::new(1.0, 1.0, 3.0);
+    assert_eq!(s.area(), 9.0);
+    assert_eq!(s.perimeter(), 12.0);
+}
+
+#[test]
+fn test_total_area_over_mixed_collection() {
+    let shapes: Vec<Box<dyn Drawable>> = vec![
+        Box::new(Circle::new(0.0, 0.0, 1.0)),
+        Box::new(Square::new(0.0, 0.0, 2.0)),
+        Box::new(Square::new(5.0, 5.0, 0.5)),
+    ];
+    assert!((total_area(&shapes) - (PI + 4.0 + 0.25)).abs() < EPS);
+    assert_eq!(total_area(&[]), 0.0);
+}
+
+#[test]
+fn test_right_triangle_3_4_5() {
+    let t = Triangle::new(Point::new(0.0, 0.0), Point::new(3.0, 0.0), Point::new(0.0, 4.0)).unwrap();
+    assert_eq!(t.area(), 6.0);
+    assert_eq!(t.perimeter(), 12.0);
+    assert!(t.is_right());
+    assert!(!t.is_equilateral());
+}
+
+#[test]
+fn test_equilateral_with_float_tolerance() {
+    let h = 3f64.sqrt() / 2.0;
+    let t = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.5, h)).unwrap();
+    assert!(t.is_equilateral());
+    assert!(!t.is_right());
+    assert!((t.area() - h / 2.0).abs() < EPS);
+
+    let almost = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.5, 0.8)).unwrap();
+    assert!(!almost.is_equilateral());
+}
+
+#[test]
+fn test_collinear_points_are_rejected() {
+    let err = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 2.0));
+    assert_eq!(err.err(), Some(TriangleError::Collinear));
+
+    let repeated = Triangle::new(Point::new(1.0, 1.0), Point::new(1.0, 1.0), Point::new(5.0, 0.0));
+    assert!(repeated.is_err());
+    assert_eq!(TriangleError::Collinear.to_string(), "triangle vertices are collinear");
+}
+
+#[test]
+fn test_centroid() {
+    let t = Triangle::new(Point::new(0.0, 0.0), Point::new(6.0, 0.0), Point::new(0.0, 9.0)).unwrap();
+    let g = t.centroid();
+    assert_eq!(g, Point::new(2.0, 3.0));
+    assert_eq!(t.vertices()[1].x, 6.0);
+}
+
+fn edges(r: &Rect) -> (f64, f64, f64, f64) {
+    (r.left(), r.top(), r.right(), r.bottom())
+}
+
+#[test]
+fn test_rect_from_corners_normalizes() {
+    let r = Rect::from_corners(Point::new(4.0, 1.0), Point::new(1.0, 3.0));
+    assert_eq!(edges(&r), (1.0, 1.0, 4.0, 3.0));
+    assert_eq!((r.width, r.height), (3.0, 2.0));
+    assert_eq!(r.area(), 6.0);
+    assert_eq!(r.perimeter(), 10.0);
+}
+
+#[test]
+fn test_rect_intersection() {
+    let a = Rect::new(Point::new(0.0, 0.0), 4.0, 4.0);
+    let b = Rect::new(Point::new(2.0, 1.0), 5.0, 2.0);
+    let overlap = a.intersection(&b).unwrap();
+    assert_eq!(edges(&overlap), (2.0, 1.0, 4.0, 3.0));
+    assert_eq!(edges(&b.intersection(&a).unwrap()), edges(&overlap));
+
+    // Tocarse por un borde cuenta: la intersección es un segmento (ancho 0)
+    let touching = Rect::new(Point::new(4.0, 2.0), 3.0, 3.0);
+    assert!(a.intersects(&touching));
+    let edge = a.intersection(&touching).unwrap();
+    assert_eq!(edges(&edge), (4.0, 2.0, 4.0, 4.0));
+    assert_eq!(edge.area(), 0.0);
+
+    let disjoint = Rect::new(Point::new(4.5, 0.0), 1.0, 1.0);
+    assert!(!a.intersects(&disjoint));
+    assert!(a.intersection(&disjoint).is_none());
+}
+
+#[test]
+fn test_rect_union_bounds_both() {
+    let a = Rect::new(Point::new(0.0, 0.0), 2.0, 2.0);
+    let b = Rect::new(Point::new(5.0, -1.0), 1.0, 1.0);
+    let u = a.union(&b);
+    assert_eq!(edges(&u), (0.0, -1.0, 6.0, 2.0));
+    for corner in [a.top_left, a.bottom_right(), b.top_left, b.bottom_right()] {
+        assert!(u.contains_point(&corner));
+    }
+}
+
+#[test]
+fn test_rect_contains_point_includes_boundary() {
+    let r = Rect::new(Point::new(1.0, 1.0), 2.0, 3.0);
+    assert!(r.contains_point(&Point::new(2.0, 2.0)));
+    assert!(r.contains_point(&Point::new(1.0, 1.0)));
+    assert!(r.contains_point(&Point::new(3.0, 4.0)));
+    assert!(r.contains_point(&Point::new(3.0, 2.5)));
+    assert!(!r.contains_point(&Point::new(3.000001, 2.5)));
+    assert!(!r.contains_point(&Point::new(2.0, 0.5)));
+}
+
+#[test]
+fn test_rect_from_square() {
+    let square = Square::new(1.0, 2.0, 3.0);
+    let r = Rect::from(&square);
+    assert_eq!(edges(&r), (1.0, 2.0, 4.0, 5.0));
+    assert_eq!(r.area(), square.area());
+    assert_eq!(r.perimeter(), square.perimeter());
+}
+
+#[test]
+#[should_panic]
+fn test_rect_negative_width_panics() {
+    Rect::new(Point::new(0.0, 0.0), -1.0, 1.0);
+}
+
+fn polygon(points: &[(f64, f64)]) -> Polygon {
+    Polygon::new(points.iter().map(|&(x, y)| Point::new(x, y)).collect()).unwrap()
+}
+
+#[test]
+fn test_polygon_needs_three_vertices() {
+    let err = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)]).err();
+    assert_eq!(err, Some(PolygonError::TooFewVertices(2)));
+    assert_eq!(
+        PolygonError::TooFewVertices(2).to_string(),
+        "polygon needs at least 3 vertices, got 2"
+    );
+}
+
+#[test]
+fn test_unit_square_polygon() {
+    let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+    assert!((square.area() - 1.0).abs() < EPS);
+    assert!((square.perimeter() - 4.0).abs() < EPS);
+    assert!(square.contains_point(&Point::new(0.5, 0.5)));
+    assert!(!square.contains_point(&Point::new(1.5, 0.5)));
+    assert!(square.is_convex());
+
+    // El sentido de giro no cambia el área
+    let clockwise = polygon(&[(0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)]);
+    assert!((clockwise.area() - 1.0).abs() < EPS);
+}
+
+#[test]
+fn test_polygon_boundary_counts_as_inside() {
+    let square = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+    for p in [(0.0, 0.0), (2.0, 1.0), (1.0, 2.0), (0.0, 1.0), (2.0, 2.0)] {
+        assert!(square.contains_point(&Point::new(p.0, p.1)), "{p:?}");
+    }
+    // A la altura de un vértice, pero afuera
+    assert!(!square.contains_point(&Point::new(-1.0, 2.0)));
+    assert!(!square.contains_point(&Point::new(3.0, 0.0)));
+}
+
+#[test]
+fn test_concave_l_shape() {
+    //  (0,2)──(1,2)
+    //    │      │
+    //    │    (1,1)──(2,1)
+    //    │             │
+    //  (0,0)────────(2,0)
+    let l = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+    assert!((l.area() - 3.0).abs() < EPS);
+    assert!((l.perimeter() - 8.0).abs() < EPS);
+    assert!(!l.is_convex());
+
+    assert!(!l.contains_point(&Point::new(1.5, 1.5)));
+    assert!(l.contains_point(&Point::new(0.5, 1.5)));
+    assert!(l.contains_point(&Point::new(1.5, 0.5)));
+    assert!(l.contains_point(&Point::new(1.0, 1.5)));
+}
+
+#[test]
+fn test_collinear_vertices_keep_convexity() {
+    let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+    assert!(square.is_convex());
+}
+
+#[test]
+fn test_triangle_polygon_matches_triangle() {
+    let (a, b, c) = (Point::new(1.0, 1.0), Point::new(5.0, 2.0), Point::new(2.0, 6.0));
+    let triangle = Triangle::new(a, b, c).unwrap();
+    let poly = Polygon::new(vec![a, b, c]).unwrap();
+    assert!((poly.area() - triangle.area()).abs() < EPS);
+    assert!((poly.perimeter() - triangle.perimeter()).abs() < EPS);
+    assert!(poly.is_convex());
+}
+
+fn close(p: Point, q: Point) -> bool {
+    p.approx_eq(&q, EPS)
+}
+
+#[test]
+fn test_point_arithmetic() {
+    let a = Point::new(1.5, -2.0);
+    let b = Point::new(0.25, 4.0);
+    assert!(close((a + b) - b, a));
+    assert_eq!(a - a, Point::new(0.0, 0.0));
+    assert!(close(-a, Point::new(-1.5, 2.0)));
+    assert!(close(a * 2.0, a + a));
+    assert!(close((a * 3.0) / 3.0, a));
+
+    let mut c = a;
+    c += b;
+    assert!(close(c, a + b));
+    c -= b;
+    assert!(close(c, a));
+}
+
+#[test]
+fn test_point_dot_and_cross() {
+    let a = Point::new(3.0, 1.0);
+    let perpendicular = Point::new(-1.0, 3.0);
+    assert!(a.dot(&perpendicular).abs() < EPS);
+    assert_eq!(a.dot(&a), 10.0);
+
+    // Girar a la izquierda da positivo; paralelos dan 0
+    assert!(a.cross(&perpendicular) > 0.0);
+    assert!(perpendicular.cross(&a) < 0.0);
+    assert_eq!(a.cross(&(a * -2.0)), 0.0);
+}
+
+#[test]
+fn test_point_length_and_distance() {
+    let a = Point::new(3.0, 4.0);
+    assert_eq!(a.length(), 5.0);
+    assert!((a.normalized().length() - 1.0).abs() < EPS);
+    assert!(close(a.normalized(), Point::new(0.6, 0.8)));
+
+    let b = Point::new(-1.0, 7.0);
+    assert_eq!(a.distance_to(&b), 5.0);
+    assert_eq!(a.distance_to(&b), b.distance_to(&a));
+    assert_eq!(a.distance_to(&a), 0.0);
+}
+
+#[test]
+fn test_point_division_by_zero_follows_ieee() {
+    let p = Point::new(1.0, -2.0) / 0.0;
+    assert_eq!((p.x, p.y), (f64::INFINITY, f64::NEG_INFINITY));
+
+    let zero = Point::new(0.0, 0.0);
+    let q = zero / 0.0;
+    assert!(q.x.is_nan() && q.y.is_nan());
+    assert!(zero.normalized().x.is_nan());
+}
+
+#[test]
+fn test_point_display_respects_precision() {
+    let p = Point::new(1.0, -2.5);
+    assert_eq!(p.to_string(), "(1, -2.5)");
+    assert_eq!(format!("{p:.2}"), "(1.00, -2.50)");
+    assert_eq!(format!("{:.0}", Point::new(0.4, 2.6)), "(0, 3)");
+    assert_eq!(format!("{p:?}"), "Point { x: 1.0, y: -2.5 }");
+}
+
+#[test]
+fn test_point_approx_eq_boundary() {
+    let p = Point::new(1.0, 1.0);
+    // Diferencias exactas en binario para probar el borde sin ruido
+    assert!(p.approx_eq(&Point::new(1.25, 0.75), 0.25));
+    assert!(!p.approx_eq(&Point::new(1.25, 1.0), 0.125));
+    assert!(!p.approx_eq(&Point::new(1.0, 1.5), 0.25));
+
+    let sum = Point::new(0.1, 0.2) + Point::new(0.2, 0.1);
+    assert_ne!(sum, Point::new(0.3, 0.3));
+    assert!(sum.approx_eq(&Point::new(0.3, 0.3), EPS));
+}
+
+#[test]
+fn test_point_is_copy() {
+    fn shift(p: Point) -> Point {
+        p + Point::new(1.0, 0.0)
+    }
+
+    let p = Point::new(2.0, 3.0);
+    assert_eq!(shift(p), Point::new(3.0, 3.0));
+    assert_eq!(shift(p), shift(p));
+    assert_eq!(p, Point::new(2.0, 3.0));
+}
+
+fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Rect {
+    Rect::from_corners(Point::new(x0, y0), Point::new(x1, y1))
+}
+
+#[test]
+fn test_bounding_box_of_each_shape() {
+    assert_eq!(Circle::new(1.0, -2.0, 3.0).bounding_box(), rect(-2.0, -5.0, 4.0, 1.0));
+    assert_eq!(Square::new(1.0, 2.0, 3.0).bounding_box(), rect(1.0, 2.0, 4.0, 5.0));
+
+    let r = Rect::new(Point::new(-1.0, 0.5), 2.0, 4.0);
+    assert_eq!(r.bounding_box(), r);
+
+    let t = Triangle::new(Point::new(3.0, 1.0), Point::new(-1.0, 4.0), Point::new(2.0, -2.0)).unwrap();
+    assert_eq!(t.bounding_box(), rect(-1.0, -2.0, 3.0, 4.0));
+
+    let l = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+    assert_eq!(l.bounding_box(), rect(0.0, 0.0, 2.0, 2.0));
+}
+
+#[test]
+fn test_bounding_box_of_mixed_shapes() {
+    assert_eq!(bounding_box_of(&[]), None);
+
+    let shapes: Vec<Box<dyn Drawable>> = vec![
+        Box::new(Circle::new(0.0, 0.0, 1.0)),
+        Box::new(Square::new(5.0, 5.0, 2.0)),
+        Box::new(polygon(&[(-3.0, 2.0), (0.0, 3.0), (-1.0, 4.0)])),
+    ];
+    assert_eq!(bounding_box_of(&shapes), Some(rect(-3.0, -1.0, 7.0, 7.0)));
+    assert_eq!(bounding_box_of(&shapes[..1]), Some(rect(-1.0, -1.0, 1.0, 1.0)));
+}
+
+fn hits(shape: &dyn Drawable, points: &[(f64, f64)]) -> Vec<bool> {
+    points.iter().map(|&(x, y)| shape.contains_point(&Point::new(x, y))).collect()
+}
+
+#[test]
+fn test_contains_point_circle() {
+    let c = Circle::new(1.0, 1.0, 2.0);
+    // Adentro, sobre el borde (arriba y a la izquierda), afuera
+    let got = hits(&c, &[(1.5, 0.5), (1.0, 3.0), (-1.0, 1.0), (2.5, 2.5)]);
+    assert_eq!(got, [true, true, true, false]);
+}
+
+#[test]
+fn test_contains_point_square_and_rect() {
+    let s = Square::new(0.0, 0.0, 2.0);
+    assert_eq!(hits(&s, &[(1.0, 1.0), (2.0, 0.5), (0.0, 0.0), (2.5, 1.0)]), [true, true, true, false]);
+
+    let r = Rect::new(Point::new(-1.0, -1.0), 4.0, 1.0);
+    assert_eq!(hits(&r, &[(0.0, -0.5), (3.0, 0.0), (0.0, 0.5), (-2.0, -1.0)]), [true, true, false, false]);
+}
+
+#[test]
+fn test_contains_point_triangle() {
+    let t = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 4.0)).unwrap();
+    let points = [(1.0, 1.0), (2.0, 2.0), (2.0, 0.0), (0.0, 4.0), (2.5, 2.0), (-0.5, 1.0)];
+    assert_eq!(hits(&t, &points), [true, true, true, true, false, false]);
+
+    // El sentido de los vértices no importa
+    let cw = Triangle::new(Point::new(0.0, 4.0), Point::new(4.0, 0.0), Point::new(0.0, 0.0)).unwrap();
+    assert_eq!(hits(&cw, &points), hits(&t, &points));
+}
+
+#[test]
+fn test_contains_point_polygon() {
+    let l = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0), (1.0, 2.0), (0.0, 2.0)]);
+    let points = [(0.5, 1.5), (1.0, 1.0), (2.0, 0.5), (1.5, 1.5), (3.0, 1.0)];
+    assert_eq!(hits(&l, &points), [true, true, true, false, false]);
+}
+
+#[test]
+fn test_shapes_at_point_with_overlaps() {
+    let shapes: Vec<Box<dyn Drawable>> = vec![
+        Box::new(Square::new(0.0, 0.0, 4.0)),
+        Box::new(Circle::new(4.0, 4.0, 1.0)),
+        Box::new(Rect::new(Point::new(10.0, 10.0), 1.0, 1.0)),
+        Box::new(polygon(&[(2.0, 2.0), (6.0, 2.0), (6.0, 6.0)])),
+    ];
+    assert_eq!(shapes_at_point(&shapes, Point::new(3.5, 3.0)), [0, 3]);
+    assert_eq!(shapes_at_point(&shapes, Point::new(4.0, 4.0)), [0, 1, 3]);
+    assert_eq!(shapes_at_point(&shapes, Point::new(10.5, 11.0)), [2]);
+    assert!(shapes_at_point(&shapes, Point::new(-1.0, 0.0)).is_empty());
+    assert!(shapes_at_point(&[], Point::new(0.0, 0.0)).is_empty());
+}
+
+#[test]
+fn test_render_mixed_shapes_in_order() {
+    let shapes: Vec<Box<dyn Drawable>> = vec![
+        Box::new(Circle::new(0.0, 1.0, 2.5)),
+        Box::new(Square::new(1.0, 1.0, 3.0)),
+        Box::new(Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0)).unwrap()),
+    ];
+    let expected = "Dibujando un círculo en (0, 1) con radio 2.5\n\
+                    Dibujando un cuadrado en (1, 1) con lado 3\n\
+                    Dibujando un triángulo en (0, 0), (4, 0), (0, 3)\n";
+    assert_eq!(render_all_dyn(&shapes), expected);
+
+    let refs: Vec<&dyn Drawable> = shapes.iter().map(|s| s.as_ref()).collect();
+    assert_eq!(render_refs(refs), expected);
+    assert_eq!(render_refs(Vec::new()), "");
+}
+
+#[test]
+fn test_render_all_generic() {
+    let circles = [Circle::new(0.0, 0.0, 1.0), Circle::new(-1.0, 2.0, 0.5)];
+    assert_eq!(
+        render_all(&circles),
+        "Dibujando un círculo en (0, 0) con radio 1\nDibujando un círculo en (-1, 2) con radio 0.5\n"
+    );
+}
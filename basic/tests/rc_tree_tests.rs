@@ -0,0 +1,74 @@
+use std::rc::Rc;
+
+use basic::rc_tree::TreeNode;
+
+#[test]
+fn test_parent_depth_and_ancestors() {
+    let root = TreeNode::new(1);
+    let a = TreeNode::add_child(&root, 2);
+    let b = TreeNode::add_child(&root, 3);
+    let a1 = TreeNode::add_child(&a, 4);
+
+    assert!(root.borrow().parent().is_none());
+    assert!(Rc::ptr_eq(&a1.borrow().parent().unwrap(), &a));
+    assert_eq!(root.borrow().depth(), 0);
+    assert_eq!(b.borrow().depth(), 1);
+    assert_eq!(a1.borrow().depth(), 2);
+
+    let ancestors: Vec<i32> = TreeNode::ancestors(&a1).map(|n| n.borrow().value).collect();
+    assert_eq!(ancestors, vec![2, 1]);
+    assert_eq!(TreeNode::ancestors(&root).count(), 0);
+}
+
+#[test]
+fn test_traverse_preorder() {
+    let root = TreeNode::new('a');
+    let b = TreeNode::add_child(&root, 'b');
+    TreeNode::add_child(&b, 'c');
+    TreeNode::add_child(&b, 'd');
+    let e = TreeNode::add_child(&root, 'e');
+    TreeNode::add_child(&e, 'f');
+
+    assert_eq!(TreeNode::traverse_preorder(&root), vec!['a', 'b', 'c', 'd', 'e', 'f']);
+    assert_eq!(TreeNode::traverse_preorder(&e), vec!['e', 'f']);
+    assert_eq!(root.borrow().children().len(), 2);
+}
+
+#[test]
+fn test_dropping_root_frees_the_tree_without_cycles() {
+    let root = TreeNode::new(String::from("raíz"));
+    let child = TreeNode::add_child(&root, String::from("hijo"));
+    let leaf = TreeNode::add_child(&child, String::from("hoja"));
+
+    // Los hijos no son dueños del padre: solo la variable `root` lo es
+    assert_eq!(Rc::strong_count(&root), 1);
+    assert_eq!(Rc::weak_count(&root), 1);
+    // `child` lo tienen la variable y el Vec de hijos de la raíz
+    assert_eq!(Rc::strong_count(&child), 2);
+
+    let weak_root = Rc::downgrade(&root);
+    let weak_child = Rc::downgrade(&child);
+    let weak_leaf = Rc::downgrade(&leaf);
+    drop(child);
+    drop(leaf);
+
+    // Todavía vivos: la raíz es dueña de todo
+    assert!(weak_child.upgrade().is_some());
+    assert!(weak_leaf.upgrade().is_some());
+
+    drop(root);
+    assert!(weak_root.upgrade().is_none());
+    assert!(weak_child.upgrade().is_none());
+    assert!(weak_leaf.upgrade().is_none());
+}
+
+#[test]
+fn test_child_outlives_parent() {
+    let root = TreeNode::new(0);
+    let child = TreeNode::add_child(&root, 1);
+
+    drop(root);
+    // El Weak al padre ya no se puede subir: el hijo no lo mantuvo vivo
+    assert!(child.borrow().parent().is_none());
+    assert_eq!(child.borrow().depth(), 0);
+}
@@ -0,0 +1,158 @@
+use basic::json::Value;
+use basic::poo::{Circle, Point, Polygon, Rect, Square, Triangle};
+use basic::scene::Scene;
+use basic::shape_json::{SceneLoadError, ToJson};
+
+fn sample_scene() -> Scene {
+    let mut scene = Scene::new();
+    scene.add(Circle::new(1.5, -2.0, 0.75));
+    let square = scene.add(Square::new(0.0, 0.0, 3.0));
+    scene.add(Rect::new(Point::new(-4.0, 1.0), 2.0, 0.5));
+    scene.add(
+        Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        )
+        .unwrap(),
+    );
+    scene.add(
+        Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ])
+        .unwrap(),
+    );
+    scene.set_label(square, "base \"cuadrada\"");
+    scene
+}
+
+#[test]
+fn test_shapes_use_internal_tags() {
+    let circle = Circle::new(1.0, 2.0, 3.0).to_json();
+    assert_eq!(
+        circle.to_string(),
+        r#"{"type":"circle","cx":1,"cy":2,"r":3}"#
+    );
+
+    let t = Triangle::new(
+        Point::new(0.0, 0.0),
+        Point::new(1.0, 0.0),
+        Point::new(0.0, 1.0),
+    )
+    .unwrap();
+    assert_eq!(
+        t.to_json().to_string(),
+        r#"{"type":"triangle","points":[[0,0],[1,0],[0,1]]}"#
+    );
+}
+
+#[test]
+fn test_round_trip_keeps_every_shape() {
+    let scene = sample_scene();
+    let saved = scene.save_json();
+    let loaded = Scene::load_json(&saved).unwrap();
+
+    assert_eq!(loaded.shape_count(), scene.shape_count());
+    for (a, b) in scene.iter().zip(loaded.iter()) {
+        assert_eq!(a.area(), b.area());
+        assert_eq!(a.perimeter(), b.perimeter());
+        assert_eq!(a.bounding_box(), b.bounding_box());
+        assert_eq!(a.draw(), b.draw());
+    }
+    // Guardar de nuevo da el mismo texto, etiqueta incluida
+    assert_eq!(loaded.save_json(), saved);
+    assert!(saved.contains(r#""label": "base \"cuadrada\"""#));
+}
+
+#[test]
+fn test_unknown_type_is_reported() {
+    let doc = r#"{"shapes": [
+        {"type": "circle", "cx": 0, "cy": 0, "r": 1},
+        {"type": "hexagon", "side": 2}
+    ]}"#;
+    let err = Scene::load_json(doc).err().unwrap();
+    assert_eq!(
+        err,
+        SceneLoadError::UnknownType {
+            index: 1,
+            name: "hexagon".to_string()
+        }
+    );
+    assert_eq!(err.to_string(), "shape 1: unknown type \"hexagon\"");
+}
+
+#[test]
+fn test_malformed_documents() {
+    let err = Scene::load_json(r#"{"shapes": [{"type": "circle",}]}"#)
+        .err()
+        .unwrap();
+    assert!(matches!(err, SceneLoadError::Json(_)));
+    assert!(err.to_string().starts_with("invalid JSON: "));
+
+    assert_eq!(
+        Scene::load_json("[1, 2]").err(),
+        Some(SceneLoadError::NotAScene)
+    );
+    assert_eq!(
+        Scene::load_json(r#"{"shapes": 3}"#).err(),
+        Some(SceneLoadError::NotAScene)
+    );
+
+    let missing = Scene::load_json(r#"{"shapes": [{"type": "circle", "cx": 0, "r": 1}]}"#);
+    assert_eq!(
+        missing.err().unwrap().to_string(),
+        "shape 0: missing or non-numeric field \"cy\""
+    );
+
+    let untyped = Scene::load_json(r#"{"shapes": [{"cx": 0}]}"#);
+    assert!(matches!(
+        untyped,
+        Err(SceneLoadError::InvalidShape { index: 0, .. })
+    ));
+
+    let negative = [
+        (
+            r#"{"type": "circle", "cx": 0, "cy": 0, "r": -1}"#,
+            "`radius` must be a non-negative number, got -1",
+        ),
+        (
+            r#"{"type": "square", "x": 0, "y": 0, "side": -2.5}"#,
+            "`side` must be a non-negative number, got -2.5",
+        ),
+        (
+            r#"{"type": "rect", "x": 0, "y": 0, "width": 3, "height": -4}"#,
+            "`height` must be a non-negative number, got -4",
+        ),
+    ];
+    for (shape, reason) in negative {
+        let err = Scene::load_json(&format!(r#"{{"shapes": [{shape}]}}"#)).err();
+        assert_eq!(
+            err,
+            Some(SceneLoadError::InvalidShape {
+                index: 0,
+                reason: reason.to_string()
+            })
+        );
+    }
+
+    let flat = r#"{"shapes": [{"type": "triangle", "points": [[0, 0], [1, 1], [2, 2]]}]}"#;
+    assert_eq!(
+        Scene::load_json(flat).err().unwrap().to_string(),
+        "shape 0: triangle vertices are collinear"
+    );
+}
+
+#[test]
+fn test_empty_scene_round_trip() {
+    let saved = Scene::new().save_json();
+    assert_eq!(
+        Value::parse(&saved).unwrap().to_string(),
+        r#"{"shapes":[]}"#
+    );
+    assert!(Scene::load_json(&saved).unwrap().is_empty());
+}
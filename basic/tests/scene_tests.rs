@@ -0,0 +1,219 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::f64::consts::PI;
+use std::rc::Rc;
+
+use basic::json::Value;
+use basic::poo::{Circle, Drawable, Point, Rect, ShapeOrd, Square, Triangle, cmp_by_area};
+use basic::scene::{Scene, ShapeId};
+use basic::shape::Shape;
+use basic::shape_json::ToJson;
+use basic::svg::ToSvg;
+
+/// Figura de prueba que anota cada llamada a `draw` en un registro compartido.
+struct Recorder {
+    name: &'static str,
+    log: Rc<RefCell<Vec<&'static str>>>,
+}
+
+impl ToSvg for Recorder {
+    fn to_svg(&self) -> String {
+        String::new()
+    }
+}
+
+impl ToJson for Recorder {
+    fn to_json(&self) -> Value {
+        Value::Null
+    }
+}
+
+impl Drawable for Recorder {
+    fn draw(&self) -> String {
+        self.log.borrow_mut().push(self.name);
+        self.name.to_string()
+    }
+
+    fn area(&self) -> f64 {
+        0.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        0.0
+    }
+
+    fn bounding_box(&self) -> Rect {
+        Rect::new(Point::new(0.0, 0.0), 0.0, 0.0)
+    }
+
+    fn contains_point(&self, _: &Point) -> bool {
+        false
+    }
+
+    fn distance_to_point(&self, _: &Point) -> f64 {
+        f64::INFINITY
+    }
+}
+
+#[test]
+fn test_ids_survive_removal_of_others() {
+    let mut scene = Scene::new();
+    let a = scene.add(Circle::new(0.0, 0.0, 1.0));
+    let b = scene.add(Square::new(0.0, 0.0, 2.0));
+    let c = scene.add(Rect::new(Point::new(0.0, 0.0), 3.0, 1.0));
+
+    assert!(scene.remove(b));
+    assert_eq!(scene.shape_count(), 2);
+    assert!(scene.get(b).is_none());
+    assert!((scene.get(a).unwrap().area() - PI).abs() < 1e-9);
+    assert_eq!(scene.get(c).unwrap().area(), 3.0);
+
+    // Quitar dos veces falla, y el id viejo no apunta a la figura que reutiliza la casilla
+    assert!(!scene.remove(b));
+    let d = scene.add(Square::new(0.0, 0.0, 5.0));
+    assert!(scene.get(b).is_none());
+    assert_eq!(scene.get(d).unwrap().area(), 25.0);
+    assert_ne!(b, d);
+}
+
+#[test]
+fn test_render_all_draws_every_shape_once() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut scene = Scene::new();
+    for name in ["a", "b", "c"] {
+        scene.add(Recorder {
+            name,
+            log: Rc::clone(&log),
+        });
+    }
+    assert!(scene.find_at(Point::new(0.0, 0.0)).is_empty());
+
+    assert_eq!(scene.render_all(), "a\nb\nc\n");
+    assert_eq!(*log.borrow(), ["a", "b", "c"]);
+}
+
+#[test]
+fn test_total_area_of_mixed_scene() {
+    let mut scene = Scene::new();
+    assert!(scene.is_empty());
+    assert_eq!(scene.total_area(), 0.0);
+
+    scene.add(Circle::new(0.0, 0.0, 2.0));
+    scene.add(Square::new(0.0, 0.0, 3.0));
+    scene.add(
+        Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 2.0),
+        )
+        .unwrap(),
+    );
+    assert!((scene.total_area() - (4.0 * PI + 9.0 + 4.0)).abs() < 1e-9);
+    assert_eq!(scene.iter().count(), 3);
+}
+
+#[test]
+fn test_find_at_returns_ids_of_hits() {
+    let mut scene = Scene::new();
+    let big = scene.add(Square::new(0.0, 0.0, 10.0));
+    let small = scene.add(Circle::new(2.0, 2.0, 1.0));
+    let far = scene.add(Rect::new(Point::new(20.0, 20.0), 1.0, 1.0));
+
+    assert_eq!(scene.find_at(Point::new(2.0, 2.5)), [big, small]);
+    assert_eq!(scene.find_at(Point::new(20.0, 21.0)), [far]);
+    scene.remove(big);
+    assert_eq!(scene.find_at(Point::new(2.0, 2.5)), [small]);
+}
+
+fn sample() -> (Scene, [ShapeId; 4]) {
+    let mut scene = Scene::new();
+    let big = scene.add(Square::new(0.0, 0.0, 4.0));
+    let tiny = scene.add(Rect::new(Point::new(0.0, 0.0), 1.0, 0.5));
+    let circle = scene.add(Circle::new(0.0, 0.0, 1.0));
+    let mid = scene.add(
+        Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(0.0, 3.0),
+        )
+        .unwrap(),
+    );
+    (scene, [big, tiny, circle, mid])
+}
+
+#[test]
+fn test_sort_by_area_keeps_ids() {
+    let (mut scene, [big, tiny, circle, mid]) = sample();
+    let areas = |scene: &Scene| scene.iter().map(|s| s.area()).collect::<Vec<_>>();
+
+    let sorted: Vec<f64> = scene.sorted_by_area().iter().map(|s| s.area()).collect();
+    assert_eq!(sorted, [0.5, PI, 6.0, 16.0]);
+    assert_eq!(
+        areas(&scene),
+        [16.0, 0.5, PI, 6.0],
+        "sorted_by_area no cambia la escena"
+    );
+
+    scene.sort_by_area();
+    assert_eq!(areas(&scene), [0.5, PI, 6.0, 16.0]);
+    assert_eq!(scene.get(big).unwrap().area(), 16.0);
+    assert_eq!(scene.get(tiny).unwrap().area(), 0.5);
+    assert_eq!(scene.get(circle).unwrap().area(), PI);
+    assert_eq!(scene.get(mid).unwrap().area(), 6.0);
+    assert_eq!(
+        scene.find_at(Point::new(0.25, 0.25)),
+        [tiny, circle, mid, big]
+    );
+
+    // Lo agregado después va al final, y quitar no desordena el resto
+    scene.add(Circle::new(0.0, 0.0, 0.1));
+    scene.remove(circle);
+    assert_eq!(areas(&scene)[..3], [0.5, 6.0, 16.0]);
+}
+
+#[test]
+fn test_largest_and_smallest() {
+    let empty = Scene::new();
+    assert!(empty.largest().is_none());
+    assert!(empty.smallest().is_none());
+
+    let (scene, _) = sample();
+    assert_eq!(scene.largest().unwrap().area(), 16.0);
+    assert_eq!(scene.smallest().unwrap().area(), 0.5);
+}
+
+#[test]
+fn test_nan_area_does_not_panic() {
+    let (mut scene, _) = sample();
+    let broken = scene.add(Circle::new(0.0, 0.0, f64::NAN));
+    scene.sort_by_area();
+    assert!(scene.iter().last().unwrap().area().is_nan());
+    assert!(scene.get(broken).is_some());
+    assert_eq!(scene.smallest().unwrap().area(), 0.5);
+}
+
+#[test]
+fn test_shape_ord_in_btree_set() {
+    let mut set = BTreeSet::new();
+    set.insert(ShapeOrd(Shape::from(Square::new(0.0, 0.0, 3.0))));
+    set.insert(ShapeOrd(Shape::from(Circle::new(0.0, 0.0, 1.0))));
+    set.insert(ShapeOrd(Shape::from(Rect::new(
+        Point::new(0.0, 0.0),
+        2.0,
+        1.0,
+    ))));
+    // Igual área que el cuadrado: para el orden es el mismo elemento
+    assert!(!set.insert(ShapeOrd(Shape::from(Rect::new(
+        Point::new(5.0, 5.0),
+        9.0,
+        1.0
+    )))));
+
+    let areas: Vec<f64> = set.iter().map(|s| s.0.area()).collect();
+    assert_eq!(areas, [2.0, PI, 9.0]);
+    assert_eq!(
+        cmp_by_area(&Square::new(0.0, 0.0, 1.0), &Circle::new(0.0, 0.0, 1.0)),
+        Ordering::Less
+    );
+}
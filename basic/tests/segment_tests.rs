@@ -0,0 +1,103 @@
+use basic::geometry::segment::{Segment, polygon_self_intersects};
+use basic::poo::{Point, Polygon};
+
+fn seg(ax: f64, ay: f64, bx: f64, by: f64) -> Segment {
+    Segment::new(Point::new(ax, ay), Point::new(bx, by))
+}
+
+fn polygon(coords: &[(f64, f64)]) -> Polygon {
+    Polygon::new(coords.iter().map(|&(x, y)| Point::new(x, y)).collect()).unwrap()
+}
+
+#[test]
+fn test_crossing_segments() {
+    let a = seg(0.0, 0.0, 4.0, 4.0);
+    let b = seg(0.0, 4.0, 4.0, 0.0);
+    assert!(a.intersects(&b));
+    assert_eq!(a.intersection_point(&b), Some(Point::new(2.0, 2.0)));
+    assert_eq!(b.intersection_point(&a), Some(Point::new(2.0, 2.0)));
+
+    let p = seg(0.0, 0.0, 3.0, 1.0)
+        .intersection_point(&seg(1.0, 2.0, 2.0, -1.0))
+        .unwrap();
+    assert!(p.approx_eq(&Point::new(1.5, 0.5), 1e-12));
+}
+
+#[test]
+fn test_touching_at_an_endpoint() {
+    let a = seg(0.0, 0.0, 2.0, 0.0);
+    // En T: el extremo de b cae en medio de a
+    let b = seg(1.0, 0.0, 1.0, 5.0);
+    assert!(a.intersects(&b));
+    assert_eq!(a.intersection_point(&b), Some(Point::new(1.0, 0.0)));
+
+    // Extremo con extremo
+    let c = seg(2.0, 0.0, 3.0, 3.0);
+    assert_eq!(a.intersection_point(&c), Some(Point::new(2.0, 0.0)));
+}
+
+#[test]
+fn test_collinear_overlapping() {
+    let a = seg(0.0, 0.0, 4.0, 0.0);
+    let b = seg(6.0, 0.0, 2.0, 0.0);
+    assert!(a.intersects(&b));
+    // El punto retornado es el de menor (x, y) de la superposición [2, 4]
+    assert_eq!(a.intersection_point(&b), Some(Point::new(2.0, 0.0)));
+    assert_eq!(b.intersection_point(&a), Some(Point::new(2.0, 0.0)));
+
+    let inner = seg(1.0, 1.0, 2.0, 2.0);
+    assert_eq!(
+        seg(0.0, 0.0, 3.0, 3.0).intersection_point(&inner),
+        Some(Point::new(1.0, 1.0))
+    );
+}
+
+#[test]
+fn test_collinear_disjoint_and_parallel() {
+    let a = seg(0.0, 0.0, 2.0, 0.0);
+    assert!(!a.intersects(&seg(3.0, 0.0, 5.0, 0.0)));
+    assert_eq!(a.intersection_point(&seg(3.0, 0.0, 5.0, 0.0)), None);
+
+    assert!(!a.intersects(&seg(0.0, 1.0, 2.0, 1.0)));
+    assert!(!seg(0.0, 0.0, 1.0, 1.0).intersects(&seg(1.0, 0.0, 2.0, 1.0)));
+
+    // Las rectas se cruzan, pero fuera de los segmentos
+    assert!(!a.intersects(&seg(3.0, -1.0, 3.0, 1.0)));
+}
+
+#[test]
+fn test_point_on_segment() {
+    let s = seg(0.0, 0.0, 3.0, 3.0);
+    assert!(s.point_on_segment(&Point::new(1.0, 1.0)));
+    assert!(s.point_on_segment(&Point::new(3.0, 3.0)));
+    assert!(s.point_on_segment(&Point::new(0.1 + 0.2, 0.3)));
+    assert!(!s.point_on_segment(&Point::new(4.0, 4.0)));
+    assert!(!s.point_on_segment(&Point::new(1.0, 1.1)));
+    assert_eq!(s.length(), 18.0f64.sqrt());
+}
+
+#[test]
+fn test_polygon_self_intersection() {
+    let bowtie = polygon(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+    assert!(polygon_self_intersects(&bowtie));
+
+    let square = polygon(&[(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+    assert!(!polygon_self_intersects(&square));
+
+    let l = polygon(&[
+        (0.0, 0.0),
+        (2.0, 0.0),
+        (2.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 2.0),
+        (0.0, 2.0),
+    ]);
+    assert!(!polygon_self_intersects(&l));
+
+    let triangle = polygon(&[(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)]);
+    assert!(!polygon_self_intersects(&triangle));
+
+    // Un lado que vuelve sobre el anterior
+    let spike = polygon(&[(0.0, 0.0), (4.0, 0.0), (2.0, 0.0), (2.0, 3.0)]);
+    assert!(polygon_self_intersects(&spike));
+}
@@ -0,0 +1,132 @@
+use std::time::Instant;
+
+use basic::geometry::collision::any_collisions;
+use basic::poo::{
+    Circle, Drawable, Point, Polygon, Rect, Square, Triangle, bounding_box_of, render_all_dyn,
+    shapes_at_point, total_area,
+};
+use basic::shape::{EnumScene, Shape};
+use vectors::SimpleRng;
+
+/// Coordenada en [-50, 50) con pasos de 0.25 (exacta en binario).
+fn coord(rng: &mut SimpleRng) -> f64 {
+    rng.below(400) as f64 / 4.0 - 50.0
+}
+
+fn random_shape(rng: &mut SimpleRng) -> Shape {
+    let (x, y) = (coord(rng), coord(rng));
+    let size = 0.25 + rng.below(40) as f64 / 4.0;
+    match rng.below(5) {
+        0 => Circle::new(x, y, size).into(),
+        1 => Square::new(x, y, size).into(),
+        2 => Rect::new(Point::new(x, y), size, size / 2.0).into(),
+        3 => Triangle::new(
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x, y + size),
+        )
+        .unwrap()
+        .into(),
+        _ => Polygon::new(vec![
+            Point::new(x, y),
+            Point::new(x + size, y),
+            Point::new(x + size, y + size),
+            Point::new(x + size / 2.0, y + size / 2.0),
+            Point::new(x, y + size),
+        ])
+        .unwrap()
+        .into(),
+    }
+}
+
+/// La misma figura, pero detrás de un trait object.
+fn boxed(shape: Shape) -> Box<dyn Drawable> {
+    match shape {
+        Shape::Circle(s) => Box::new(s),
+        Shape::Square(s) => Box::new(s),
+        Shape::Rect(s) => Box::new(s),
+        Shape::Triangle(s) => Box::new(s),
+        Shape::Polygon(s) => Box::new(s),
+    }
+}
+
+/// Genera la misma escena dos veces: como enum y como trait objects.
+fn both_ways(seed: u64, n: usize) -> (EnumScene, Vec<Box<dyn Drawable>>) {
+    let mut rng = SimpleRng::from_seed(seed);
+    let enums: EnumScene = (0..n).map(|_| random_shape(&mut rng)).collect();
+    let mut rng = SimpleRng::from_seed(seed);
+    let dyns = (0..n).map(|_| boxed(random_shape(&mut rng))).collect();
+    (enums, dyns)
+}
+
+#[test]
+fn test_from_and_dispatch() {
+    let shape: Shape = Square::new(1.0, 1.0, 2.0).into();
+    assert!(matches!(shape, Shape::Square(_)));
+    assert_eq!(shape.area(), 4.0);
+    assert_eq!(shape.perimeter(), 8.0);
+    assert_eq!(shape.draw(), "Dibujando un cuadrado en (1, 1) con lado 2");
+    assert!(shape.contains_point(&Point::new(3.0, 3.0)));
+    assert_eq!(
+        shape.as_rect(),
+        Some(Rect::new(Point::new(1.0, 1.0), 2.0, 2.0))
+    );
+    assert!(shape.as_circle().is_none());
+
+    let mut scene = EnumScene::new();
+    assert!(scene.is_empty());
+    scene.push(Circle::new(0.0, 0.0, 1.0));
+    scene.push(shape);
+    assert_eq!(scene.len(), 2);
+}
+
+#[test]
+fn test_enum_and_dyn_agree_on_the_same_scene() {
+    let (enums, dyns) = both_ways(7, 500);
+
+    assert_eq!(enums.render_all(), render_all_dyn(&dyns));
+    assert_eq!(enums.total_area(), total_area(&dyns));
+    assert_eq!(enums.bounding_box(), bounding_box_of(&dyns));
+
+    let mut rng = SimpleRng::from_seed(99);
+    for _ in 0..200 {
+        let p = Point::new(coord(&mut rng), coord(&mut rng));
+        assert_eq!(enums.find_at(p), shapes_at_point(&dyns, p));
+    }
+
+    // Un `Shape` también sirve donde se espera `dyn Drawable`
+    let as_dyn: Vec<Box<dyn Drawable>> = enums
+        .0
+        .into_iter()
+        .take(50)
+        .map(|s| Box::new(s) as _)
+        .collect();
+    assert_eq!(any_collisions(&as_dyn), any_collisions(&dyns[..50]));
+}
+
+#[test]
+fn bench_total_area_enum_vs_dyn() {
+    const N: usize = 100_000;
+    const ROUNDS: u32 = 10;
+    let (enums, dyns) = both_ways(42, N);
+
+    let start = Instant::now();
+    let mut enum_sum = 0.0;
+    for _ in 0..ROUNDS {
+        enum_sum += std::hint::black_box(&enums).total_area();
+    }
+    let enum_time = start.elapsed();
+
+    let start = Instant::now();
+    let mut dyn_sum = 0.0;
+    for _ in 0..ROUNDS {
+        dyn_sum += total_area(std::hint::black_box(&dyns));
+    }
+    let dyn_time = start.elapsed();
+
+    println!(
+        "total_area de {N} figuras x {ROUNDS}: enum {enum_time:?}, dyn {dyn_time:?} ({:.2}x)",
+        dyn_time.as_secs_f64() / enum_time.as_secs_f64().max(f64::EPSILON)
+    );
+    assert_eq!(enum_sum, dyn_sum);
+}
@@ -0,0 +1,105 @@
+use basic::poo::{Circle, Point, Polygon, Rect, Square, Triangle};
+use basic::scene::Scene;
+use basic::svg::{ToSvg, escape_xml};
+
+#[test]
+fn test_single_circle() {
+    assert_eq!(
+        Circle::new(10.0, -2.5, 3.0).to_svg(),
+        r#"<circle cx="10" cy="-2.5" r="3"/>"#
+    );
+}
+
+#[test]
+fn test_each_shape_element() {
+    assert_eq!(
+        Rect::new(Point::new(1.0, 2.0), 3.5, 4.0).to_svg(),
+        r#"<rect x="1" y="2" width="3.5" height="4"/>"#
+    );
+    assert_eq!(
+        Square::new(0.0, 0.0, 2.0).to_svg(),
+        r#"<rect x="0" y="0" width="2" height="2"/>"#
+    );
+
+    let t = Triangle::new(
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(0.0, 3.0),
+    )
+    .unwrap();
+    assert_eq!(t.to_svg(), r#"<polygon points="0,0 4,0 0,3"/>"#);
+
+    let p = Polygon::new(vec![
+        Point::new(0.5, 0.0),
+        Point::new(1.0, 1.0),
+        Point::new(0.0, 1.0),
+        Point::new(-1.0, 0.5),
+    ]);
+    assert_eq!(
+        p.unwrap().to_svg(),
+        r#"<polygon points="0.5,0 1,1 0,1 -1,0.5"/>"#
+    );
+}
+
+#[test]
+fn test_numbers_have_fixed_precision() {
+    let c = Circle::new(1.0 / 3.0, -0.0001, 2.0f64.sqrt());
+    assert_eq!(c.to_svg(), r#"<circle cx="0.333" cy="0" r="1.414"/>"#);
+}
+
+#[test]
+fn test_document_for_mixed_scene() {
+    let mut scene = Scene::new();
+    scene.add(Circle::new(0.0, 0.0, 1.0));
+    scene.add(Square::new(2.0, 2.0, 2.0));
+    scene.add(
+        Triangle::new(
+            Point::new(0.0, 5.0),
+            Point::new(1.0, 5.0),
+            Point::new(0.0, 6.0),
+        )
+        .unwrap(),
+    );
+
+    let doc = scene.to_svg_document(200.0, 150.0);
+    assert!(doc.starts_with(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="150" viewBox="-1 -1 5 7""#
+    ));
+    assert!(doc.ends_with("</svg>\n"));
+    for tag in ["<circle ", "<rect ", "<polygon "] {
+        assert_eq!(doc.matches(tag).count(), 1, "{tag}");
+    }
+}
+
+#[test]
+fn test_labels_are_escaped() {
+    assert_eq!(
+        escape_xml(r#"a < b && "c" > 'd'"#),
+        "a &lt; b &amp;&amp; &quot;c&quot; &gt; &apos;d&apos;"
+    );
+
+    let mut scene = Scene::new();
+    let id = scene.add(Circle::new(0.0, 0.0, 1.0));
+    assert!(scene.set_label(id, "<script>&"));
+    assert_eq!(scene.label(id), Some("<script>&"));
+
+    let doc = scene.to_svg_document(10.0, 10.0);
+    assert!(
+        doc.contains(r#"<g><title>&lt;script&gt;&amp;</title><circle cx="0" cy="0" r="1"/></g>"#)
+    );
+    assert!(!doc.contains("<script>"));
+
+    scene.remove(id);
+    assert!(!scene.set_label(id, "gone"));
+    assert_eq!(scene.label(id), None);
+}
+
+#[test]
+fn test_empty_scene_document() {
+    let doc = Scene::new().to_svg_document(100.0, 50.0);
+    assert_eq!(
+        doc,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"100\" height=\"50\" viewBox=\"0 0 100 50\" \
+         fill=\"none\" stroke=\"black\">\n</svg>\n"
+    );
+}
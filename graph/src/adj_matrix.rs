@@ -0,0 +1,285 @@
+use std::fmt;
+
+use vectors::{Matrix2D, MyVec};
+
+use crate::{Graph, NodeId};
+
+/*
+Grafo con matriz de adyacencia
+
+En vez de una lista por nodo, una matriz V × V donde la celda (a, b) guarda
+el peso de la arista a → b, o None si no existe:
+
+          A      B      C      D
+    A  [None, Some7, Some9, None]          A ──7──► B
+    B  [None, None,  None,  Some2]         │        │
+    C  [None, None,  None,  Some1]         9        2
+    D  [None, None,  None,  None ]         ▼        ▼
+                                           C ──1──► D
+
+Comparación con las listas de adyacencia de `Graph`:
+
+                         listas           matriz
+    memoria              O(V + E)         O(V²)
+    ¿existe a → b?       O(grado(a))      O(1)
+    vecinos de a         O(grado(a))      O(V)
+    aristas paralelas    sí               no (una celda por par)
+
+La matriz conviene en grafos DENSOS (E cerca de V²), donde las listas no
+ahorran memoria y la consulta O(1) gana. En grafos dispersos desperdicia casi
+toda la memoria en celdas vacías.
+
+En un grafo no dirigido la matriz es simétrica: (a, b) y (b, a) se escriben
+juntas.
+
+Para que `add_node` no tenga que rearmar la matriz cada vez, se reserva una
+matriz de `capacidad × capacidad` que se duplica al llenarse, igual que un
+MyVec: O(V) amortizado por nodo en vez de O(V²).
+*/
+
+/// Grafo con pesos `N` en los nodos y `W` en las aristas, guardado como
+/// matriz de adyacencia.
+///
+/// Ocupa **O(V²)** memoria sin importar cuántas aristas tenga, a cambio de
+/// responder [`has_edge`](Self::has_edge) en **O(1)**. Conviene para grafos
+/// densos; para grafos dispersos es mejor [`Graph`]. Entre un par de nodos
+/// hay a lo sumo una arista: agregar otra reemplaza su peso.
+///
+/// ```
+/// use graph::{AdjMatrixGraph, Graph};
+///
+/// let mut m = AdjMatrixGraph::new();
+/// let a = m.add_node("A");
+/// let b = m.add_node("B");
+/// m.add_edge(a, b, 3);
+/// assert!(m.has_edge(a, b));
+/// assert!(!m.has_edge(b, a));
+///
+/// let g = Graph::from(&m);
+/// assert_eq!(g.edge_count(), 1);
+/// ```
+pub struct AdjMatrixGraph<N, W> {
+    nodes: MyVec<N>,
+    // capacidad × capacidad; solo se usan las primeras node_count filas/columnas
+    matrix: Matrix2D<Option<W>>,
+    edge_count: usize,
+    directed: bool,
+}
+
+impl<N, W> AdjMatrixGraph<N, W> {
+    /// Crea un grafo dirigido vacío.
+    pub fn new() -> Self {
+        Self {
+            nodes: MyVec::new(),
+            matrix: Matrix2D::from_fn(0, 0, |_, _| None),
+            edge_count: 0,
+            directed: true,
+        }
+    }
+
+    /// Crea un grafo no dirigido vacío.
+    pub fn new_undirected() -> Self {
+        Self {
+            directed: false,
+            ..Self::new()
+        }
+    }
+
+    /// Retorna `true` si las aristas tienen dirección.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Retorna la cantidad de nodos.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Retorna la cantidad de aristas (una arista no dirigida cuenta una vez).
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Retorna el peso del nodo `id`.
+    pub fn node(&self, id: NodeId) -> Option<&N> {
+        self.nodes.get(id.0)
+    }
+
+    /// Retorna un iterador sobre los identificadores de todos los nodos.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + use<N, W> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    /// Agrega un nodo y retorna su identificador.
+    ///
+    /// # Complejidad
+    /// **O(V)** amortizado: cada tanto la matriz duplica su lado.
+    pub fn add_node(&mut self, weight: N) -> NodeId {
+        let n = self.nodes.len();
+        if n == self.matrix.rows() {
+            let new_cap = (n * 2).max(4);
+            let mut old = std::mem::replace(&mut self.matrix, Matrix2D::from_fn(0, 0, |_, _| None));
+            self.matrix = Matrix2D::from_fn(new_cap, new_cap, |r, c| {
+                old.get_mut(r, c).and_then(Option::take)
+            });
+        }
+
+        self.nodes.push_back(weight);
+        NodeId(n)
+    }
+
+    fn check(&self, a: NodeId, b: NodeId) {
+        assert!(
+            a.0 < self.node_count() && b.0 < self.node_count(),
+            "arista {a} → {b} con nodos fuera del grafo"
+        );
+    }
+
+    /// Retorna `true` si existe la arista `a → b`.
+    ///
+    /// # Panics
+    /// Si `a` o `b` no pertenecen a este grafo.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn has_edge(&self, a: NodeId, b: NodeId) -> bool {
+        self.edge(a, b).is_some()
+    }
+
+    /// Retorna el peso de la arista `a → b`, si existe.
+    ///
+    /// # Panics
+    /// Si `a` o `b` no pertenecen a este grafo.
+    pub fn edge(&self, a: NodeId, b: NodeId) -> Option<&W> {
+        self.check(a, b);
+        self.matrix[(a.0, b.0)].as_ref()
+    }
+
+    /// Quita la arista `a → b` (y `b → a` si no es dirigido) y retorna su peso.
+    ///
+    /// # Panics
+    /// Si `a` o `b` no pertenecen a este grafo.
+    pub fn remove_edge(&mut self, a: NodeId, b: NodeId) -> Option<W> {
+        self.check(a, b);
+        let removed = self.matrix[(a.0, b.0)].take()?;
+        if !self.directed {
+            self.matrix[(b.0, a.0)] = None;
+        }
+        self.edge_count -= 1;
+        Some(removed)
+    }
+
+    /// Retorna las aristas que salen de `id` como `(destino, &peso)`, en orden
+    /// de destino.
+    ///
+    /// # Panics
+    /// Si `id` no pertenece a este grafo.
+    ///
+    /// # Complejidad
+    /// **O(V)** para recorrerlo entero, aunque el nodo tenga pocos vecinos.
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &W)> {
+        self.check(id, id);
+        self.matrix.row(id.0)[..self.node_count()]
+            .iter()
+            .enumerate()
+            .filter_map(|(target, weight)| weight.as_ref().map(|w| (NodeId(target), w)))
+    }
+
+    /// Retorna cuántas aristas salen de `id` (un lazo cuenta una vez).
+    ///
+    /// # Panics
+    /// Si `id` no pertenece a este grafo.
+    ///
+    /// # Complejidad
+    /// **O(V)**
+    pub fn degree(&self, id: NodeId) -> usize {
+        self.neighbors(id).count()
+    }
+}
+
+impl<N, W: Clone> AdjMatrixGraph<N, W> {
+    /// Agrega la arista `a → b` (y `b → a` si no es dirigido). Si ya existía,
+    /// reemplaza su peso y retorna el anterior.
+    ///
+    /// # Panics
+    /// Si `a` o `b` no pertenecen a este grafo.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId, weight: W) -> Option<W> {
+        self.check(a, b);
+        if !self.directed && a != b {
+            self.matrix[(b.0, a.0)] = Some(weight.clone());
+        }
+        let previous = self.matrix[(a.0, b.0)].replace(weight);
+        if previous.is_none() {
+            self.edge_count += 1;
+        }
+        previous
+    }
+}
+
+impl<N, W> Default for AdjMatrixGraph<N, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: fmt::Debug, W: fmt::Debug> fmt::Debug for AdjMatrixGraph<N, W> {
+    /// Muestra cada nodo con sus aristas salientes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        for id in self.node_ids() {
+            let edges: Vec<_> = self.neighbors(id).collect();
+            map.entry(&(id, self.node(id).unwrap()), &edges);
+        }
+        map.finish()
+    }
+}
+
+/// Las aristas paralelas de `graph` se colapsan en una (queda el último peso).
+impl<N: Clone, W: Clone> From<&Graph<N, W>> for AdjMatrixGraph<N, W> {
+    fn from(graph: &Graph<N, W>) -> Self {
+        let mut matrix = if graph.is_directed() {
+            Self::new()
+        } else {
+            Self::new_undirected()
+        };
+        for id in graph.node_ids() {
+            matrix.add_node(graph.node(id).unwrap().clone());
+        }
+
+        for a in graph.node_ids() {
+            for (b, weight) in graph.neighbors(a) {
+                // Una arista no dirigida está en las dos listas: se toma una vez
+                if graph.is_directed() || a <= b {
+                    matrix.add_edge(a, b, weight.clone());
+                }
+            }
+        }
+        matrix
+    }
+}
+
+/// Conserva los `NodeId`: el nodo `i` de la matriz es el nodo `i` del grafo.
+impl<N: Clone, W: Clone> From<&AdjMatrixGraph<N, W>> for Graph<N, W> {
+    fn from(matrix: &AdjMatrixGraph<N, W>) -> Self {
+        let mut graph = if matrix.is_directed() {
+            Graph::new()
+        } else {
+            Graph::new_undirected()
+        };
+        for id in matrix.node_ids() {
+            graph.add_node(matrix.node(id).unwrap().clone());
+        }
+
+        for a in matrix.node_ids() {
+            for (b, weight) in matrix.neighbors(a) {
+                if matrix.is_directed() || a <= b {
+                    graph.add_edge(a, b, weight.clone());
+                }
+            }
+        }
+        graph
+    }
+}
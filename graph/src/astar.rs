@@ -0,0 +1,208 @@
+use std::cmp::Reverse;
+
+use heap_max::MyMinHeap;
+use vectors::BitVec;
+
+/*
+A* sobre una grilla
+
+Como Dijkstra, pero la cola se ordena por  f = g + h:
+
+- g: costo real desde el inicio hasta la celda.
+- h: estimación (heurística) de lo que falta hasta la meta.
+
+Si h nunca sobreestima (es "admisible"), el primer camino que llega a la meta
+es óptimo; y como h empuja la búsqueda hacia la meta, se exploran muchas menos
+celdas que con Dijkstra.
+
+    S . . . .        S = inicio, G = meta, # = bloqueada
+    . # # # .        h(Manhattan) de S = |4 - 0| + |2 - 0| = 6,
+    . . . # G        pero el muro obliga a rodear
+
+Heurística según el movimiento (todo paso cuesta 1):
+- 4 direcciones: distancia Manhattan  |dx| + |dy|.
+- 8 direcciones: distancia Chebyshev  max(|dx|, |dy|); Manhattan
+  sobreestimaría las diagonales y dejaría de ser admisible.
+
+La cola es un `MyMinHeap` con borrado perezoso: si una celda mejora se encola
+otra vez, y al sacar una entrada vieja (con g peor que el actual) se descarta.
+*/
+
+/// Coordenadas `(x, y)` de una celda; `x` es la columna y `y` la fila.
+pub type Cell = (usize, usize);
+
+/// Direcciones permitidas al moverse por la grilla.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Movement {
+    /// Arriba, abajo, izquierda y derecha.
+    FourWay,
+    /// Además las cuatro diagonales, con el mismo costo.
+    EightWay,
+}
+
+/// Grilla rectangular con celdas libres o bloqueadas.
+///
+/// ```
+/// use graph::GridMap;
+///
+/// let grid = GridMap::from_ascii(
+///     "..#
+///      ...",
+/// );
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert!(grid.is_blocked((2, 0)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct GridMap {
+    width: usize,
+    height: usize,
+    blocked: BitVec,
+}
+
+impl GridMap {
+    /// Crea una grilla de `width × height` celdas, todas libres.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            blocked: BitVec::with_len(width * height),
+        }
+    }
+
+    /// Crea una grilla a partir de texto: una fila por línea, `#` bloqueada y
+    /// cualquier otro carácter libre. Se ignoran los espacios al inicio y al
+    /// final de cada línea.
+    ///
+    /// # Panics
+    /// Si las filas no tienen todas el mismo ancho.
+    pub fn from_ascii(text: &str) -> Self {
+        let rows: Vec<&str> = text.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+        let width = rows.first().map_or(0, |r| r.chars().count());
+        let mut grid = Self::new(width, rows.len());
+
+        for (y, row) in rows.iter().enumerate() {
+            assert_eq!(row.chars().count(), width, "la fila {y} tiene otro ancho");
+            for (x, c) in row.chars().enumerate() {
+                grid.set_blocked((x, y), c == '#');
+            }
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Retorna `true` si `cell` está dentro de la grilla.
+    pub fn contains(&self, (x, y): Cell) -> bool {
+        x < self.width && y < self.height
+    }
+
+    fn index(&self, cell: Cell) -> usize {
+        assert!(self.contains(cell), "la celda {cell:?} está fuera de la grilla");
+        cell.1 * self.width + cell.0
+    }
+
+    /// Bloquea o libera `cell`.
+    ///
+    /// # Panics
+    /// Si `cell` está fuera de la grilla.
+    pub fn set_blocked(&mut self, cell: Cell, blocked: bool) {
+        let index = self.index(cell);
+        self.blocked.set(index, blocked);
+    }
+
+    /// Retorna `true` si `cell` está bloqueada.
+    ///
+    /// # Panics
+    /// Si `cell` está fuera de la grilla.
+    pub fn is_blocked(&self, cell: Cell) -> bool {
+        self.blocked.get(self.index(cell)) == Some(true)
+    }
+
+    /// Celdas vecinas libres de `cell` según `movement`.
+    fn free_neighbors(&self, (x, y): Cell, movement: Movement) -> impl Iterator<Item = Cell> + '_ {
+        const STRAIGHT: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+        const DIAGONAL: [(isize, isize); 4] = [(1, -1), (1, 1), (-1, 1), (-1, -1)];
+
+        let diagonals: &[(isize, isize)] = match movement {
+            Movement::FourWay => &[],
+            Movement::EightWay => &DIAGONAL,
+        };
+
+        STRAIGHT
+            .iter()
+            .chain(diagonals)
+            .filter_map(move |&(dx, dy)| Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?)))
+            .filter(|&cell| self.contains(cell) && !self.is_blocked(cell))
+    }
+}
+
+fn heuristic((ax, ay): Cell, (bx, by): Cell, movement: Movement) -> usize {
+    let (dx, dy) = (ax.abs_diff(bx), ay.abs_diff(by));
+    match movement {
+        Movement::FourWay => dx + dy,
+        Movement::EightWay => dx.max(dy),
+    }
+}
+
+/// Busca el camino más corto de `start` a `goal` moviéndose en 4
+/// direcciones.
+///
+/// Retorna las celdas del camino, con ambos extremos incluidos, o `None` si
+/// no hay camino (o si `start` o `goal` están bloqueadas).
+///
+/// # Panics
+/// Si `start` o `goal` están fuera de la grilla.
+pub fn astar(grid: &GridMap, start: Cell, goal: Cell) -> Option<Vec<Cell>> {
+    astar_with(grid, start, goal, Movement::FourWay)
+}
+
+/// Como [`astar`], eligiendo las direcciones de movimiento.
+pub fn astar_with(grid: &GridMap, start: Cell, goal: Cell, movement: Movement) -> Option<Vec<Cell>> {
+    if grid.is_blocked(start) || grid.is_blocked(goal) {
+        return None;
+    }
+
+    let cells = grid.width * grid.height;
+    let mut best_g = vec![usize::MAX; cells];
+    let mut came_from: Vec<Option<Cell>> = vec![None; cells];
+    let mut open: MyMinHeap<(usize, usize, Cell)> = MyMinHeap::new();
+
+    best_g[grid.index(start)] = 0;
+    open.push(Reverse((heuristic(start, goal, movement), 0, start)));
+
+    while let Some(Reverse((_, g, cell))) = open.pop() {
+        if cell == goal {
+            let mut path = vec![goal];
+            let mut current = goal;
+            while let Some(prev) = came_from[grid.index(current)] {
+                path.push(prev);
+                current = prev;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        // Entrada vieja: esta celda ya se alcanzó por un camino mejor
+        if g > best_g[grid.index(cell)] {
+            continue;
+        }
+
+        for next in grid.free_neighbors(cell, movement) {
+            let next_g = g + 1;
+            let index = grid.index(next);
+            if next_g < best_g[index] {
+                best_g[index] = next_g;
+                came_from[index] = Some(cell);
+                open.push(Reverse((next_g + heuristic(next, goal, movement), next_g, next)));
+            }
+        }
+    }
+
+    None
+}
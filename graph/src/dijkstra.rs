@@ -0,0 +1,119 @@
+use heap_max::IndexedHeap;
+
+use crate::{Graph, NodeId};
+
+/*
+Dijkstra
+
+Mantiene una cola de prioridad con la mejor distancia CONOCIDA de cada nodo
+pendiente. Al sacar el mínimo, esa distancia ya es definitiva: cualquier otro
+camino pasaría por un nodo pendiente, que está al menos igual de lejos, y los
+pesos no son negativos. Luego se "relajan" sus aristas:
+
+    dist[v] > dist[u] + w(u, v)   ⇒   dist[v] = dist[u] + w(u, v), pred[v] = u
+
+Con un heap normal habría que encolar v otra vez y descartar la entrada vieja
+al sacarla ("lazy deletion"). Con `IndexedHeap` se baja su prioridad en el
+lugar con `decrease_key`, así que cada nodo está en la cola a lo sumo una vez.
+
+Complejidad: O((V + E) log V).
+
+Los pesos son `u64`, así que un peso negativo —que rompería el argumento de
+arriba— ni siquiera se puede construir: lo rechaza el compilador.
+*/
+
+/// Calcula las distancias mínimas desde `source` a todos los nodos.
+///
+/// La posición `i` del resultado corresponde al nodo con índice `i`:
+/// `None` si no es alcanzable, o `Some((distancia, predecesor))`, donde el
+/// predecesor es `None` solo para `source`.
+///
+/// # Panics
+/// Si `source` no pertenece al grafo, o si alguna distancia no cabe en `u64`.
+///
+/// ```
+/// use graph::{Graph, dijkstra};
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// g.add_edge(a, b, 4);
+/// assert_eq!(dijkstra(&g, a), [Some((0, None)), Some((4, Some(a)))]);
+/// ```
+///
+/// # Pesos negativos
+/// No se pueden expresar con `u64`:
+///
+/// ```compile_fail
+/// use graph::{Graph, dijkstra};
+///
+/// let mut g: Graph<(), u64> = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// g.add_edge(a, b, -1);
+/// dijkstra(&g, a);
+/// ```
+pub fn dijkstra<N>(graph: &Graph<N, u64>, source: NodeId) -> Vec<Option<(u64, Option<NodeId>)>> {
+    assert!(
+        source.index() < graph.node_count(),
+        "el nodo {source} no pertenece al grafo"
+    );
+
+    let mut best: Vec<Option<(u64, Option<NodeId>)>> = vec![None; graph.node_count()];
+    let mut settled = vec![false; graph.node_count()];
+    let mut queue: IndexedHeap<NodeId, u64> = IndexedHeap::new();
+
+    best[source.index()] = Some((0, None));
+    queue.push(source, 0);
+
+    while let Some((node, dist)) = queue.pop() {
+        settled[node.index()] = true;
+
+        for (next, &weight) in graph.neighbors(node) {
+            if settled[next.index()] {
+                continue;
+            }
+            let candidate = dist
+                .checked_add(weight)
+                .expect("la distancia no cabe en u64");
+
+            match best[next.index()] {
+                None => {
+                    best[next.index()] = Some((candidate, Some(node)));
+                    queue.push(next, candidate);
+                }
+                Some((current, _)) if candidate < current => {
+                    best[next.index()] = Some((candidate, Some(node)));
+                    queue.decrease_key(next, candidate);
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    best
+}
+
+/// Retorna la distancia mínima de `source` a `target` y el camino, con
+/// ambos extremos incluidos, o `None` si `target` no es alcanzable.
+///
+/// # Panics
+/// Igual que [`dijkstra`], o si `target` no pertenece al grafo.
+pub fn shortest_path<N>(graph: &Graph<N, u64>, source: NodeId, target: NodeId) -> Option<(u64, Vec<NodeId>)> {
+    let best = dijkstra(graph, source);
+    let (distance, _) = best
+        .get(target.index())
+        .unwrap_or_else(|| panic!("el nodo {target} no pertenece al grafo"))
+        .as_ref()?;
+
+    // Se reconstruye de atrás para adelante siguiendo predecesores
+    let mut path = vec![target];
+    let mut current = target;
+    while let Some((_, Some(pred))) = best[current.index()] {
+        path.push(pred);
+        current = pred;
+    }
+    path.reverse();
+
+    Some((*distance, path))
+}
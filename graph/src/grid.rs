@@ -0,0 +1,135 @@
+//! Relleno por inundación y etiquetado de componentes sobre un [`Matrix2D`].
+//!
+//! Las posiciones son `(fila, columna)`, igual que al indexar la matriz.
+
+use vectors::{Matrix2D, MyVec};
+
+/*
+Relleno por inundación (flood fill)
+
+Desde una celda se pintan todas las celdas alcanzables moviéndose en 4
+direcciones (arriba, abajo, izquierda, derecha) sin salir del valor original:
+
+    . . # . .          x x # . .
+    . # . # .          x # . # .       desde (0, 0) con 'x'
+    # . . . #    ──►   # . . . #       el centro no se pinta: solo se
+    . # . # .          . # . # .       llega a él en diagonal
+    . . # . .          . . # . .
+
+La versión recursiva es la más corta, pero en una grilla de 2000 × 2000 toda
+igual haría 4 millones de llamadas anidadas y desbordaría la pila. Aquí la
+pila es un MyVec explícito: una celda se pinta AL APILARLA, así nunca entra
+dos veces y la pila mide a lo sumo el número de celdas.
+
+Si el valor nuevo es igual al viejo no hay nada que hacer (y la marca "ya
+pintada" no serviría: la celda seguiría pareciendo sin visitar).
+
+Etiquetado de componentes: se recorre la grilla en orden; cada celda sin
+etiqueta arranca un relleno con la siguiente etiqueta sobre la matriz de
+etiquetas, siguiendo a los vecinos con el MISMO valor.
+*/
+
+/// Vecinos en 4 direcciones de `(r, c)` dentro de una matriz `rows × cols`.
+fn neighbors4(
+    (r, c): (usize, usize),
+    (rows, cols): (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> {
+    let up = r.checked_sub(1).map(|r| (r, c));
+    let down = (r + 1 < rows).then_some((r + 1, c));
+    let left = c.checked_sub(1).map(|c| (r, c));
+    let right = (c + 1 < cols).then_some((r, c + 1));
+    [up, down, left, right].into_iter().flatten()
+}
+
+/// Reemplaza por `new_value` la región 4-conexa de celdas iguales a la de
+/// `start` y retorna cuántas celdas cambió.
+///
+/// ```
+/// use graph::grid::flood_fill;
+/// use vectors::Matrix2D;
+///
+/// let mut grid = Matrix2D::from_slice(2, 3, &[0, 0, 1, 1, 0, 0]);
+/// assert_eq!(flood_fill(&mut grid, (0, 0), 7), 4);
+/// assert_eq!(grid.row(0), [7, 7, 1]);
+/// assert_eq!(grid.row(1), [1, 7, 7]);
+/// ```
+///
+/// # Panics
+/// Si `start` está fuera de la matriz.
+///
+/// # Complejidad
+/// **O(filas × columnas)** tiempo y memoria auxiliar en el peor caso.
+pub fn flood_fill<T: Clone + PartialEq>(
+    grid: &mut Matrix2D<T>,
+    start: (usize, usize),
+    new_value: T,
+) -> usize {
+    let old_value = grid[start].clone();
+    if old_value == new_value {
+        return 0;
+    }
+
+    let shape = grid.shape();
+    let mut stack = MyVec::new();
+    grid[start] = new_value.clone();
+    stack.push_back(start);
+    let mut filled = 1;
+
+    while let Some(cell) = stack.pop_back() {
+        for next in neighbors4(cell, shape) {
+            if grid[next] == old_value {
+                grid[next] = new_value.clone();
+                stack.push_back(next);
+                filled += 1;
+            }
+        }
+    }
+    filled
+}
+
+/// Etiqueta cada región 4-conexa de celdas iguales con un número
+/// `0..count`, en el orden en que aparece su primera celda (por filas).
+/// Retorna la matriz de etiquetas y `count`.
+///
+/// ```
+/// use graph::grid::connected_components;
+/// use vectors::Matrix2D;
+///
+/// let grid = Matrix2D::from_slice(2, 3, &['a', 'a', 'b', 'b', 'a', 'b']);
+/// let (labels, count) = connected_components(&grid);
+/// assert_eq!(count, 3);
+/// assert_eq!(labels.row(0), [0, 0, 1]);
+/// assert_eq!(labels.row(1), [2, 0, 1]);
+/// ```
+///
+/// # Complejidad
+/// **O(filas × columnas)**
+pub fn connected_components<T: PartialEq>(grid: &Matrix2D<T>) -> (Matrix2D<u32>, u32) {
+    const UNLABELED: u32 = u32::MAX;
+
+    let shape = grid.shape();
+    let mut labels = Matrix2D::new(shape.0, shape.1, UNLABELED);
+    let mut stack = MyVec::new();
+    let mut count = 0;
+
+    for r in 0..shape.0 {
+        for c in 0..shape.1 {
+            if labels[(r, c)] != UNLABELED {
+                continue;
+            }
+
+            labels[(r, c)] = count;
+            stack.push_back((r, c));
+            while let Some(cell) = stack.pop_back() {
+                for next in neighbors4(cell, shape) {
+                    if labels[next] == UNLABELED && grid[next] == grid[cell] {
+                        labels[next] = count;
+                        stack.push_back(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+    }
+    (labels, count)
+}
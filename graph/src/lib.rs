@@ -0,0 +1,303 @@
+use std::fmt;
+
+use vectors::MyVec;
+
+mod adj_matrix;
+mod astar;
+mod dijkstra;
+pub mod grid;
+mod scc;
+mod traversal;
+
+pub use adj_matrix::AdjMatrixGraph;
+pub use astar::{Cell, GridMap, Movement, astar, astar_with};
+pub use dijkstra::{dijkstra, shortest_path};
+pub use scc::{condensation, strongly_connected_components};
+pub use traversal::{Bfs, Dfs};
+
+/*
+Grafo con listas de adyacencia
+
+Los nodos viven en un vector y se identifican por su posición. Para cada nodo
+se guarda la lista de aristas que SALEN de él, como pares (destino, peso):
+
+    nodos:      0:"A"   1:"B"   2:"C"   3:"D"
+
+    adyacencia:
+      0 → [(1, 7), (2, 9)]          A ──7──► B
+      1 → [(3, 2)]                  │        │
+      2 → [(3, 1)]                  9        2
+      3 → []                        ▼        ▼
+                                    C ──1──► D
+
+- Espacio O(V + E), y recorrer los vecinos de un nodo cuesta O(grado).
+- En un grafo no dirigido cada arista se guarda en las DOS listas; por eso
+  `add_edge` pide `E: Clone`.
+- `NodeId` envuelve el índice para que no se confunda con cualquier `usize`.
+*/
+
+/// Identificador de un nodo dentro de un [`Graph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Posición del nodo en el grafo (`0..node_count`).
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl fmt::Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "n{}", self.0)
+    }
+}
+
+/// Grafo con pesos `N` en los nodos y `E` en las aristas, dirigido o no.
+///
+/// ```
+/// use graph::Graph;
+///
+/// let mut g = Graph::new_undirected();
+/// let a = g.add_node("A");
+/// let b = g.add_node("B");
+/// g.add_edge(a, b, 5);
+/// assert_eq!(g.neighbors(b).map(|(n, _)| n).collect::<Vec<_>>(), [a]);
+/// assert_eq!(g.edge_count(), 1);
+/// ```
+pub struct Graph<N, E> {
+    nodes: MyVec<N>,
+    adjacency: MyVec<MyVec<(NodeId, E)>>,
+    edge_count: usize,
+    directed: bool,
+}
+
+impl<N, E> Graph<N, E> {
+    /// Crea un grafo dirigido vacío.
+    pub fn new() -> Self {
+        Self {
+            nodes: MyVec::new(),
+            adjacency: MyVec::new(),
+            edge_count: 0,
+            directed: true,
+        }
+    }
+
+    /// Crea un grafo no dirigido vacío.
+    pub fn new_undirected() -> Self {
+        Self {
+            directed: false,
+            ..Self::new()
+        }
+    }
+
+    /// Retorna `true` si las aristas tienen dirección.
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Agrega un nodo y retorna su identificador.
+    pub fn add_node(&mut self, weight: N) -> NodeId {
+        self.nodes.push_back(weight);
+        self.adjacency.push_back(MyVec::new());
+        NodeId(self.nodes.len() - 1)
+    }
+
+    /// Retorna la cantidad de nodos.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Retorna la cantidad de aristas (una arista no dirigida cuenta una vez).
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Retorna el peso del nodo `id`.
+    pub fn node(&self, id: NodeId) -> Option<&N> {
+        self.nodes.get(id.0)
+    }
+
+    /// Retorna una referencia mutable al peso del nodo `id`.
+    pub fn node_mut(&mut self, id: NodeId) -> Option<&mut N> {
+        self.nodes.get_mut(id.0)
+    }
+
+    /// Retorna un iterador sobre los identificadores de todos los nodos.
+    pub fn node_ids(&self) -> impl Iterator<Item = NodeId> + use<N, E> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    /// Retorna las aristas que salen de `id` como `(destino, &peso)`, en el
+    /// orden en que se agregaron.
+    ///
+    /// # Panics
+    /// Si `id` no pertenece a este grafo.
+    pub fn neighbors(&self, id: NodeId) -> Neighbors<'_, E> {
+        Neighbors {
+            edges: self.edges(id).iter(),
+        }
+    }
+
+    /// Lista de adyacencia de `id`.
+    pub(crate) fn edges(&self, id: NodeId) -> &[(NodeId, E)] {
+        self.adjacency
+            .get(id.0)
+            .unwrap_or_else(|| panic!("el nodo {id} no pertenece al grafo"))
+            .as_slice()
+    }
+
+    /// Retorna un iterador en anchura (BFS) desde `start`, en orden de visita.
+    ///
+    /// # Panics
+    /// Si `start` no pertenece a este grafo.
+    pub fn bfs(&self, start: NodeId) -> Bfs<'_, N, E> {
+        Bfs::new(self, start)
+    }
+
+    /// Retorna un iterador en profundidad (DFS, preorden) desde `start`.
+    ///
+    /// Visita los vecinos en el mismo orden que la versión recursiva.
+    ///
+    /// # Panics
+    /// Si `start` no pertenece a este grafo.
+    pub fn dfs(&self, start: NodeId) -> Dfs<'_, N, E> {
+        Dfs::new(self, start)
+    }
+
+    /// Agrupa los nodos en componentes conexas, cada una en orden BFS desde
+    /// su nodo de menor índice.
+    ///
+    /// # Panics
+    /// Si el grafo es dirigido: ahí "conexo" no está bien definido sin elegir
+    /// entre conectividad débil y fuerte.
+    pub fn connected_components(&self) -> Vec<Vec<NodeId>> {
+        assert!(
+            !self.directed,
+            "connected_components solo aplica a grafos no dirigidos"
+        );
+
+        let mut assigned = vec![false; self.node_count()];
+        let mut components = Vec::new();
+        for id in self.node_ids() {
+            if assigned[id.0] {
+                continue;
+            }
+            let component: Vec<NodeId> = self.bfs(id).collect();
+            for node in &component {
+                assigned[node.0] = true;
+            }
+            components.push(component);
+        }
+        components
+    }
+
+    /// Retorna `true` si el grafo dirigido tiene algún ciclo (un lazo
+    /// `a → a` cuenta).
+    ///
+    /// Es un DFS iterativo con tres colores: blanco (sin visitar), gris (en
+    /// el camino actual) y negro (terminado). Una arista hacia un nodo gris
+    /// vuelve a un ancestro del camino: eso es un ciclo.
+    ///
+    /// # Panics
+    /// Si el grafo no es dirigido: ahí toda arista sería un ciclo de ida y
+    /// vuelta.
+    pub fn has_cycle(&self) -> bool {
+        assert!(self.directed, "has_cycle solo aplica a grafos dirigidos");
+
+        #[derive(Clone, Copy, PartialEq)]
+        enum Color {
+            White,
+            Gray,
+            Black,
+        }
+
+        let mut color = vec![Color::White; self.node_count()];
+        for root in self.node_ids() {
+            if color[root.0] != Color::White {
+                continue;
+            }
+
+            // Cada marco guarda el nodo y cuántas de sus aristas ya se vieron
+            color[root.0] = Color::Gray;
+            let mut stack = vec![(root, 0)];
+            while let Some((node, next_edge)) = stack.last_mut() {
+                match self.edges(*node).get(*next_edge) {
+                    Some(&(target, _)) => {
+                        *next_edge += 1;
+                        match color[target.0] {
+                            Color::Gray => return true,
+                            Color::White => {
+                                color[target.0] = Color::Gray;
+                                stack.push((target, 0));
+                            }
+                            Color::Black => {}
+                        }
+                    }
+                    None => {
+                        color[node.0] = Color::Black;
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        false
+    }
+}
+
+impl<N, E: Clone> Graph<N, E> {
+    /// Agrega una arista de `a` a `b` (y de `b` a `a` si el grafo no es
+    /// dirigido).
+    ///
+    /// # Panics
+    /// Si `a` o `b` no pertenecen a este grafo.
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId, weight: E) {
+        assert!(
+            a.0 < self.node_count() && b.0 < self.node_count(),
+            "arista {a} → {b} con nodos fuera del grafo"
+        );
+
+        if !self.directed && a != b {
+            self.adjacency.get_mut(b.0).unwrap().push_back((a, weight.clone()));
+        }
+        self.adjacency.get_mut(a.0).unwrap().push_back((b, weight));
+        self.edge_count += 1;
+    }
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: fmt::Debug, E: fmt::Debug> fmt::Debug for Graph<N, E> {
+    /// Muestra cada nodo con su lista de adyacencia.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        for id in self.node_ids() {
+            map.entry(&(id, self.node(id).unwrap()), &self.edges(id));
+        }
+        map.finish()
+    }
+}
+
+/// Iterador sobre las aristas salientes de un nodo, creado por
+/// [`Graph::neighbors`].
+pub struct Neighbors<'a, E> {
+    edges: std::slice::Iter<'a, (NodeId, E)>,
+}
+
+impl<'a, E> Iterator for Neighbors<'a, E> {
+    type Item = (NodeId, &'a E);
+
+    fn next(&mut self) -> Option<(NodeId, &'a E)> {
+        self.edges.next().map(|(target, weight)| (*target, weight))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+impl<E> ExactSizeIterator for Neighbors<'_, E> {}
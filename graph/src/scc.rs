@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+
+use crate::{Graph, NodeId};
+
+/*
+Componentes fuertemente conexas (Tarjan)
+
+Dos nodos están en la misma componente si cada uno alcanza al otro. Tarjan las
+encuentra con un solo DFS:
+
+- `index[v]`: orden en que el DFS descubrió a v.
+- `low[v]`: el menor índice alcanzable desde el subárbol de v usando a lo sumo
+  una arista "hacia atrás" a un nodo que siga en la pila de Tarjan.
+- Al terminar v, si `low[v] == index[v]` entonces v es la raíz de una
+  componente: se desapilan nodos hasta v y esos forman la componente.
+
+    0 ──► 1 ──► 2
+          ▲     │
+          │     ▼
+          └──── 3 ──► 4
+
+    se emiten en este orden: {4}, {1, 2, 3}, {0}
+
+Una componente se emite solo cuando todo lo que alcanza ya se emitió, así que
+salen en orden topológico INVERSO del grafo condensado (primero los
+"sumideros").
+
+El DFS es iterativo: cada marco de la pila de llamadas guarda el nodo y cuántas
+de sus aristas ya se recorrieron, para que una cadena de 100_000 nodos no
+desborde la pila del programa.
+*/
+
+/// Calcula las componentes fuertemente conexas de un grafo dirigido.
+///
+/// Las componentes salen en orden topológico inverso de la condensación: si
+/// hay una arista de la componente `A` a la `B` (con `A != B`), `B` aparece
+/// antes que `A`.
+///
+/// # Complejidad
+/// **O(V + E)**.
+pub fn strongly_connected_components<N, E>(graph: &Graph<N, E>) -> Vec<Vec<NodeId>> {
+    let mut state = Tarjan::new(graph.node_count());
+
+    for root in graph.node_ids() {
+        if state.index[root.index()].is_some() {
+            continue;
+        }
+
+        state.discover(root);
+        let mut calls = vec![(root, 0)];
+
+        while let Some((v, next_edge)) = calls.last_mut() {
+            let v = *v;
+            if let Some(&(w, _)) = graph.edges(v).get(*next_edge) {
+                *next_edge += 1;
+                match state.index[w.index()] {
+                    // "Llamada recursiva" a w
+                    None => {
+                        state.discover(w);
+                        calls.push((w, 0));
+                    }
+                    Some(w_index) if state.on_stack[w.index()] => {
+                        state.low[v.index()] = state.low[v.index()].min(w_index);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            // v terminó: "retornar" al padre propagando low
+            calls.pop();
+            if let Some(&(parent, _)) = calls.last() {
+                state.low[parent.index()] = state.low[parent.index()].min(state.low[v.index()]);
+            }
+            if Some(state.low[v.index()]) == state.index[v.index()] {
+                state.emit_component(v);
+            }
+        }
+    }
+
+    state.components
+}
+
+/// Estado del algoritmo de Tarjan, indexado por `NodeId::index`.
+struct Tarjan {
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<NodeId>,
+    next_index: usize,
+    components: Vec<Vec<NodeId>>,
+}
+
+impl Tarjan {
+    fn new(n: usize) -> Self {
+        Self {
+            index: vec![None; n],
+            low: vec![0; n],
+            on_stack: vec![false; n],
+            stack: Vec::new(),
+            next_index: 0,
+            components: Vec::new(),
+        }
+    }
+
+    fn discover(&mut self, v: NodeId) {
+        self.index[v.index()] = Some(self.next_index);
+        self.low[v.index()] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(v);
+        self.on_stack[v.index()] = true;
+    }
+
+    /// Desapila hasta `root` (inclusive): esos nodos forman una componente.
+    fn emit_component(&mut self, root: NodeId) {
+        let mut component = Vec::new();
+        loop {
+            let w = self.stack.pop().unwrap();
+            self.on_stack[w.index()] = false;
+            component.push(w);
+            if w == root {
+                break;
+            }
+        }
+        self.components.push(component);
+    }
+}
+
+/// Construye el grafo condensado: un nodo por componente fuertemente conexa
+/// (con sus miembros como peso) y una arista `A → B` si algún nodo de `A`
+/// tiene una arista hacia alguno de `B`.
+///
+/// El resultado siempre es un DAG. Los nodos siguen el orden de
+/// [`strongly_connected_components`] y no hay aristas repetidas.
+pub fn condensation<N, E>(graph: &Graph<N, E>) -> Graph<Vec<NodeId>, ()> {
+    let components = strongly_connected_components(graph);
+
+    let mut component_of = vec![NodeId(0); graph.node_count()];
+    let mut dag = Graph::new();
+    for members in components {
+        let id = dag.add_node(Vec::new());
+        for &node in &members {
+            component_of[node.index()] = id;
+        }
+        *dag.node_mut(id).unwrap() = members;
+    }
+
+    let mut seen = HashSet::new();
+    for u in graph.node_ids() {
+        for (v, _) in graph.neighbors(u) {
+            let (a, b) = (component_of[u.index()], component_of[v.index()]);
+            if a != b && seen.insert((a, b)) {
+                dag.add_edge(a, b, ());
+            }
+        }
+    }
+    dag
+}
@@ -0,0 +1,98 @@
+use std::collections::VecDeque;
+
+use vectors::BitVec;
+
+use crate::{Graph, NodeId};
+
+/*
+Recorridos
+
+Ambos marcan los nodos visitados en un `BitVec` (un bit por nodo) para no
+entrar en ciclos, y ambos son iteradores perezosos: el trabajo se hace a
+medida que se piden nodos.
+
+- BFS usa una COLA: visita por capas, primero todo lo que está a distancia 1,
+  luego a distancia 2, etc. Se marca al ENCOLAR para no encolar dos veces.
+- DFS usa una PILA explícita en vez de recursión, así un camino de 100_000
+  nodos no desborda la pila del programa. Se marca al SACAR de la pila, y los
+  vecinos se apilan al revés para que el primero sea el primero en visitarse,
+  igual que en la versión recursiva.
+*/
+
+/// Iterador en anchura creado por [`Graph::bfs`].
+pub struct Bfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    queue: VecDeque<NodeId>,
+    discovered: BitVec,
+}
+
+impl<'a, N, E> Bfs<'a, N, E> {
+    pub(crate) fn new(graph: &'a Graph<N, E>, start: NodeId) -> Self {
+        let mut discovered = BitVec::with_len(graph.node_count());
+        discovered.set(start.index(), true);
+        Self {
+            graph,
+            queue: VecDeque::from([start]),
+            discovered,
+        }
+    }
+}
+
+impl<N, E> Iterator for Bfs<'_, N, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        let node = self.queue.pop_front()?;
+        for (next, _) in self.graph.neighbors(node) {
+            if self.discovered.get(next.index()) == Some(false) {
+                self.discovered.set(next.index(), true);
+                self.queue.push_back(next);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// Iterador en profundidad (preorden) creado por [`Graph::dfs`].
+pub struct Dfs<'a, N, E> {
+    graph: &'a Graph<N, E>,
+    stack: Vec<NodeId>,
+    visited: BitVec,
+}
+
+impl<'a, N, E> Dfs<'a, N, E> {
+    pub(crate) fn new(graph: &'a Graph<N, E>, start: NodeId) -> Self {
+        assert!(
+            start.index() < graph.node_count(),
+            "el nodo {start} no pertenece al grafo"
+        );
+        Self {
+            graph,
+            stack: vec![start],
+            visited: BitVec::with_len(graph.node_count()),
+        }
+    }
+}
+
+impl<N, E> Iterator for Dfs<'_, N, E> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        while let Some(node) = self.stack.pop() {
+            if self.visited.get(node.index()) == Some(true) {
+                continue;
+            }
+            self.visited.set(node.index(), true);
+
+            let unvisited: Vec<NodeId> = self
+                .graph
+                .neighbors(node)
+                .map(|(next, _)| next)
+                .filter(|next| self.visited.get(next.index()) == Some(false))
+                .collect();
+            self.stack.extend(unvisited.into_iter().rev());
+            return Some(node);
+        }
+        None
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::BTreeSet;
+
+use graph::{AdjMatrixGraph, Graph, NodeId};
+
+fn edge_set<W: Copy + Ord>(graph: &Graph<usize, W>) -> BTreeSet<(NodeId, NodeId, W)> {
+    graph
+        .node_ids()
+        .flat_map(|a| graph.neighbors(a).map(move |(b, w)| (a, b, *w)))
+        .collect()
+}
+
+fn sample(directed: bool) -> Graph<usize, u32> {
+    let mut g = if directed {
+        Graph::new()
+    } else {
+        Graph::new_undirected()
+    };
+    let ids: Vec<_> = (0..6).map(|i| g.add_node(i)).collect();
+    for (a, b, w) in [
+        (0, 1, 7),
+        (0, 2, 9),
+        (1, 3, 2),
+        (2, 3, 1),
+        (4, 4, 5),
+        (3, 0, 4),
+    ] {
+        g.add_edge(ids[a], ids[b], w);
+    }
+    g
+}
+
+#[test]
+fn test_round_trip_preserves_edges() {
+    for directed in [true, false] {
+        let g = sample(directed);
+        let m = AdjMatrixGraph::from(&g);
+        assert_eq!(m.is_directed(), directed);
+        assert_eq!(m.node_count(), 6);
+        assert_eq!(m.edge_count(), g.edge_count());
+
+        let back = Graph::from(&m);
+        assert_eq!(back.edge_count(), g.edge_count());
+        assert_eq!(edge_set(&back), edge_set(&g));
+        assert!(back.node_ids().all(|id| back.node(id) == g.node(id)));
+    }
+}
+
+#[test]
+fn test_has_edge_and_neighbors_on_dense_graph() {
+    let n = 200;
+    let mut m = AdjMatrixGraph::new();
+    let ids: Vec<_> = (0..n).map(|i| m.add_node(i)).collect();
+    for &a in &ids {
+        for &b in &ids {
+            if a != b && (a.index() + b.index()) % 3 != 0 {
+                m.add_edge(a, b, a.index() * n + b.index());
+            }
+        }
+    }
+
+    for &a in &ids {
+        for &b in &ids {
+            let expected = a != b && (a.index() + b.index()) % 3 != 0;
+            assert_eq!(m.has_edge(a, b), expected);
+        }
+    }
+    let (a, b) = (ids[1], ids[3]);
+    assert_eq!(m.edge(a, b), Some(&(n + 3)));
+    assert!(m.neighbors(a).all(|(t, w)| *w == n + t.index()));
+    assert_eq!(m.degree(a), m.neighbors(a).count());
+}
+
+#[test]
+fn test_self_loops_and_replacing_edges() {
+    let mut m = AdjMatrixGraph::new_undirected();
+    let a = m.add_node('a');
+    let b = m.add_node('b');
+
+    assert_eq!(m.add_edge(a, a, 1), None);
+    assert!(m.has_edge(a, a));
+    assert_eq!(m.degree(a), 1);
+
+    assert_eq!(m.add_edge(a, b, 2), None);
+    assert_eq!(m.add_edge(b, a, 3), Some(2));
+    assert_eq!(m.edge(a, b), Some(&3));
+    assert_eq!(m.edge_count(), 2);
+
+    assert_eq!(m.remove_edge(b, a), Some(3));
+    assert!(!m.has_edge(a, b));
+    assert_eq!(m.remove_edge(a, b), None);
+    assert_eq!(m.edge_count(), 1);
+    assert_eq!(
+        format!("{m:?}"),
+        "{(NodeId(0), 'a'): [(NodeId(0), 1)], (NodeId(1), 'b'): []}"
+    );
+}
+
+#[test]
+fn test_bfs_on_converted_graph_visits_same_nodes() {
+    let mut m = AdjMatrixGraph::new();
+    let ids: Vec<_> = (0..8).map(|i| m.add_node(i)).collect();
+    for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (5, 6), (6, 7)] {
+        m.add_edge(ids[a], ids[b], ());
+    }
+
+    let g = Graph::from(&m);
+    let visited: BTreeSet<_> = g.bfs(ids[0]).collect();
+    assert_eq!(visited, BTreeSet::from([ids[0], ids[1], ids[2], ids[3]]));
+
+    let again = Graph::from(&AdjMatrixGraph::from(&g));
+    assert!(again.bfs(ids[5]).eq(g.bfs(ids[5])));
+}
+
+#[test]
+#[should_panic(expected = "fuera del grafo")]
+fn test_has_edge_with_foreign_node_panics() {
+    let mut m: AdjMatrixGraph<(), ()> = AdjMatrixGraph::new();
+    let a = m.add_node(());
+    let mut other: AdjMatrixGraph<(), ()> = AdjMatrixGraph::new();
+    other.add_node(());
+    let far = other.add_node(());
+    m.has_edge(a, far);
+}
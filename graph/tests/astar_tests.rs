@@ -0,0 +1,87 @@
+use graph::{Cell, GridMap, Movement, astar, astar_with};
+
+/// Verifica que `path` vaya de `start` a `goal` por celdas libres contiguas.
+fn assert_valid_path(grid: &GridMap, path: &[Cell], start: Cell, goal: Cell, movement: Movement) {
+    assert_eq!(path.first(), Some(&start));
+    assert_eq!(path.last(), Some(&goal));
+    for &cell in path {
+        assert!(!grid.is_blocked(cell), "el camino pasa por {cell:?}, que está bloqueada");
+    }
+    for pair in path.windows(2) {
+        let (dx, dy) = (pair[0].0.abs_diff(pair[1].0), pair[0].1.abs_diff(pair[1].1));
+        let adjacent = match movement {
+            Movement::FourWay => dx + dy == 1,
+            Movement::EightWay => dx.max(dy) == 1,
+        };
+        assert!(adjacent, "{:?} → {:?} no es un paso válido", pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn test_open_grid_has_manhattan_length() {
+    let grid = GridMap::new(10, 8);
+    let (start, goal) = ((1, 1), (8, 6));
+    let path = astar(&grid, start, goal).unwrap();
+
+    assert_valid_path(&grid, &path, start, goal, Movement::FourWay);
+    // Celdas = pasos + 1
+    assert_eq!(path.len(), 7 + 5 + 1);
+}
+
+#[test]
+fn test_wall_forces_known_detour() {
+    // Un muro vertical con una sola abertura abajo
+    let grid = GridMap::from_ascii(
+        "....#....
+         ....#....
+         ....#....
+         ....#....
+         .........",
+    );
+    let (start, goal) = ((0, 0), (8, 0));
+    let path = astar(&grid, start, goal).unwrap();
+
+    assert_valid_path(&grid, &path, start, goal, Movement::FourWay);
+    // Bajar 4, cruzar 8, subir 4
+    assert_eq!(path.len(), 4 + 8 + 4 + 1);
+    assert!(path.contains(&(4, 4)));
+}
+
+#[test]
+fn test_blocked_or_unreachable_goal_is_none() {
+    let mut grid = GridMap::new(5, 5);
+    grid.set_blocked((4, 4), true);
+    assert_eq!(astar(&grid, (0, 0), (4, 4)), None);
+
+    // Meta libre pero encerrada
+    let walled = GridMap::from_ascii(
+        ".....
+         ..###
+         ..#.#
+         ..###",
+    );
+    assert_eq!(astar(&walled, (0, 0), (3, 2)), None);
+}
+
+#[test]
+fn test_start_equals_goal() {
+    let grid = GridMap::new(3, 3);
+    assert_eq!(astar(&grid, (1, 2), (1, 2)), Some(vec![(1, 2)]));
+}
+
+#[test]
+fn test_eight_way_movement_uses_diagonals() {
+    let grid = GridMap::new(10, 10);
+    let (start, goal) = ((0, 0), (6, 9));
+    let path = astar_with(&grid, start, goal, Movement::EightWay).unwrap();
+
+    assert_valid_path(&grid, &path, start, goal, Movement::EightWay);
+    // Distancia Chebyshev: max(6, 9) = 9 pasos
+    assert_eq!(path.len(), 10);
+}
+
+#[test]
+#[should_panic(expected = "fuera de la grilla")]
+fn test_out_of_bounds_start_panics() {
+    astar(&GridMap::new(2, 2), (5, 0), (0, 0));
+}
@@ -0,0 +1,128 @@
+use graph::{Graph, NodeId, dijkstra, shortest_path};
+use vectors::SimpleRng;
+
+/// Grafo dirigido de 6 nodos verificado a mano.
+///
+/// Aristas: 0→1 (7), 0→2 (9), 0→5 (14), 1→2 (10), 1→3 (15), 2→3 (11),
+/// 2→5 (2), 3→4 (6), 4→5 (9), 5→4 (9).
+///
+/// Desde 0: el atajo 0→2→5 (11) le gana a 0→5 (14), y a 4 se llega por
+/// 0→2→5→4 (20), empatando con 0→2→3→4 (20) pero descubierto antes.
+fn six_nodes() -> (Graph<(), u64>, Vec<NodeId>) {
+    let mut g = Graph::new();
+    let ids: Vec<NodeId> = (0..6).map(|_| g.add_node(())).collect();
+    let edges = [
+        (0, 1, 7),
+        (0, 2, 9),
+        (0, 5, 14),
+        (1, 2, 10),
+        (1, 3, 15),
+        (2, 3, 11),
+        (2, 5, 2),
+        (3, 4, 6),
+        (4, 5, 9),
+        (5, 4, 9),
+    ];
+    for (a, b, w) in edges {
+        g.add_edge(ids[a], ids[b], w);
+    }
+    (g, ids)
+}
+
+#[test]
+fn test_hand_checked_distances() {
+    let (g, ids) = six_nodes();
+    let result = dijkstra(&g, ids[0]);
+
+    let distances: Vec<Option<u64>> = result.iter().map(|r| r.map(|(d, _)| d)).collect();
+    assert_eq!(distances, [Some(0), Some(7), Some(9), Some(20), Some(20), Some(11)]);
+
+    let preds: Vec<Option<usize>> = result
+        .iter()
+        .map(|r| r.unwrap().1.map(NodeId::index))
+        .collect();
+    assert_eq!(preds, [None, Some(0), Some(0), Some(2), Some(5), Some(2)]);
+}
+
+#[test]
+fn test_path_reconstruction_matches_distance() {
+    let (g, ids) = six_nodes();
+    let (distance, path) = shortest_path(&g, ids[0], ids[4]).unwrap();
+    assert_eq!(distance, 20);
+    assert_eq!(path, [ids[0], ids[2], ids[5], ids[4]]);
+
+    // La suma de los pesos del camino es la distancia
+    let total: u64 = path
+        .windows(2)
+        .map(|pair| {
+            g.neighbors(pair[0])
+                .filter(|(n, _)| *n == pair[1])
+                .map(|(_, w)| *w)
+                .min()
+                .unwrap()
+        })
+        .sum();
+    assert_eq!(total, distance);
+
+    assert_eq!(shortest_path(&g, ids[3], ids[3]), Some((0, vec![ids[3]])));
+}
+
+#[test]
+fn test_unreachable_nodes_are_none() {
+    let (mut g, ids) = six_nodes();
+    let island = g.add_node(());
+    let result = dijkstra(&g, ids[3]);
+
+    assert_eq!(result[island.index()], None);
+    // Desde 3 solo se llega a 4 y 5
+    assert_eq!(result.iter().filter(|r| r.is_some()).count(), 3);
+    assert_eq!(shortest_path(&g, ids[3], ids[0]), None);
+}
+
+#[test]
+fn test_zero_weight_edges() {
+    let mut g = Graph::new_undirected();
+    let ids: Vec<NodeId> = (0..4).map(|_| g.add_node(())).collect();
+    g.add_edge(ids[0], ids[1], 0);
+    g.add_edge(ids[1], ids[2], 0);
+    g.add_edge(ids[0], ids[2], 1);
+    g.add_edge(ids[2], ids[3], 5);
+
+    let (distance, path) = shortest_path(&g, ids[0], ids[3]).unwrap();
+    assert_eq!(distance, 5);
+    assert_eq!(path, [ids[0], ids[1], ids[2], ids[3]]);
+}
+
+/// Bellman-Ford ingenuo como referencia.
+fn reference_distances(g: &Graph<(), u64>, source: NodeId) -> Vec<Option<u64>> {
+    let mut dist = vec![None; g.node_count()];
+    dist[source.index()] = Some(0);
+    for _ in 0..g.node_count() {
+        for u in g.node_ids() {
+            let Some(du) = dist[u.index()] else { continue };
+            for (v, &w) in g.neighbors(u) {
+                if dist[v.index()].is_none_or(|dv| du + w < dv) {
+                    dist[v.index()] = Some(du + w);
+                }
+            }
+        }
+    }
+    dist
+}
+
+#[test]
+fn test_random_graphs_against_bellman_ford() {
+    let mut rng = SimpleRng::from_seed(0xD1);
+    for _ in 0..30 {
+        let mut g = Graph::new();
+        let ids: Vec<NodeId> = (0..40).map(|_| g.add_node(())).collect();
+        for _ in 0..120 {
+            let a = ids[rng.below(40) as usize];
+            let b = ids[rng.below(40) as usize];
+            g.add_edge(a, b, rng.below(20));
+        }
+
+        let got: Vec<Option<u64>> = dijkstra(&g, ids[0]).iter().map(|r| r.map(|(d, _)| d)).collect();
+        assert_eq!(got, reference_distances(&g, ids[0]));
+    }
+}
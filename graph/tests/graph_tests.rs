@@ -0,0 +1,111 @@
+use graph::{Graph, NodeId};
+
+/// Grafo no dirigido de ejemplo:
+///
+/// ```text
+///     0 ─ 1 ─ 3
+///     │   │
+///     2 ─ 4   5 (aislado)
+/// ```
+fn sample() -> (Graph<char, ()>, Vec<NodeId>) {
+    let mut g = Graph::new_undirected();
+    let ids: Vec<NodeId> = "abcdef".chars().map(|c| g.add_node(c)).collect();
+    for (a, b) in [(0, 1), (0, 2), (1, 3), (1, 4), (2, 4)] {
+        g.add_edge(ids[a], ids[b], ());
+    }
+    (g, ids)
+}
+
+fn indices(nodes: impl Iterator<Item = NodeId>) -> Vec<usize> {
+    nodes.map(NodeId::index).collect()
+}
+
+#[test]
+fn test_building_and_neighbors() {
+    let (g, ids) = sample();
+    assert!(!g.is_directed());
+    assert_eq!(g.node_count(), 6);
+    assert_eq!(g.edge_count(), 5);
+    assert_eq!(g.node(ids[3]), Some(&'d'));
+    assert_eq!(indices(g.neighbors(ids[1]).map(|(n, _)| n)), [0, 3, 4]);
+    assert_eq!(g.neighbors(ids[5]).len(), 0);
+
+    let mut directed = Graph::new();
+    let a = directed.add_node(1);
+    let b = directed.add_node(2);
+    directed.add_edge(a, b, 10u32);
+    assert_eq!(directed.neighbors(a).collect::<Vec<_>>(), [(b, &10)]);
+    assert_eq!(directed.neighbors(b).count(), 0);
+    *directed.node_mut(b).unwrap() += 40;
+    assert_eq!(directed.node(b), Some(&42));
+}
+
+#[test]
+fn test_bfs_order() {
+    let (g, ids) = sample();
+    assert_eq!(indices(g.bfs(ids[0])), [0, 1, 2, 3, 4]);
+    assert_eq!(indices(g.bfs(ids[3])), [3, 1, 0, 4, 2]);
+}
+
+#[test]
+fn test_dfs_order_matches_recursive() {
+    let (g, ids) = sample();
+    assert_eq!(indices(g.dfs(ids[0])), [0, 1, 3, 4, 2]);
+}
+
+#[test]
+fn test_dfs_on_cycle_terminates() {
+    let mut g = Graph::new();
+    let ids: Vec<NodeId> = (0..4).map(|i| g.add_node(i)).collect();
+    for i in 0..4 {
+        g.add_edge(ids[i], ids[(i + 1) % 4], ());
+    }
+    g.add_edge(ids[2], ids[0], ());
+
+    assert_eq!(indices(g.dfs(ids[1])), [1, 2, 3, 0]);
+    assert_eq!(indices(g.bfs(ids[1])), [1, 2, 3, 0]);
+}
+
+#[test]
+fn test_traversal_from_isolated_node() {
+    let (g, ids) = sample();
+    assert_eq!(indices(g.bfs(ids[5])), [5]);
+    assert_eq!(indices(g.dfs(ids[5])), [5]);
+}
+
+#[test]
+fn test_long_chain_dfs_does_not_overflow() {
+    let mut g = Graph::new();
+    let first = g.add_node(());
+    let mut prev = first;
+    for _ in 0..100_000 {
+        let next = g.add_node(());
+        g.add_edge(prev, next, ());
+        prev = next;
+    }
+    assert_eq!(g.dfs(first).count(), 100_001);
+}
+
+#[test]
+fn test_connected_components_on_forest() {
+    let mut g = Graph::new_undirected();
+    let ids: Vec<NodeId> = (0..7).map(|i| g.add_node(i)).collect();
+    // Árboles {0, 1, 2}, {3, 4} y {5}, {6} sueltos
+    for (a, b) in [(0, 1), (1, 2), (4, 3)] {
+        g.add_edge(ids[a], ids[b], ());
+    }
+
+    let components: Vec<Vec<usize>> = g
+        .connected_components()
+        .into_iter()
+        .map(|c| indices(c.into_iter()))
+        .collect();
+    assert_eq!(components, vec![vec![0, 1, 2], vec![3, 4], vec![5], vec![6]]);
+}
+
+#[test]
+#[should_panic(expected = "no dirigidos")]
+fn test_connected_components_rejects_directed() {
+    let g: Graph<(), ()> = Graph::new();
+    g.connected_components();
+}
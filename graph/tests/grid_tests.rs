@@ -0,0 +1,76 @@
+use graph::grid::{connected_components, flood_fill};
+use vectors::Matrix2D;
+
+fn from_ascii(rows: &[&str]) -> Matrix2D<char> {
+    let cells: Vec<char> = rows.iter().flat_map(|r| r.chars()).collect();
+    Matrix2D::from_slice(rows.len(), rows[0].len(), &cells)
+}
+
+fn to_ascii(grid: &Matrix2D<char>) -> Vec<String> {
+    grid.iter_rows().map(|r| r.iter().collect()).collect()
+}
+
+#[test]
+fn test_fill_does_not_leak_through_diagonal_gaps() {
+    let mut grid = from_ascii(&[
+        "#####", //
+        "#..##", //
+        "#.#.#", //
+        "##..#", //
+        "#####",
+    ]);
+    // (1, 1) y (2, 3) solo se tocan en diagonal a través de (2, 2)
+    assert_eq!(flood_fill(&mut grid, (1, 1), 'o'), 3);
+    assert_eq!(
+        to_ascii(&grid),
+        ["#####", "#oo##", "#o#.#", "##..#", "#####"]
+    );
+}
+
+#[test]
+fn test_fill_with_same_value_terminates() {
+    let mut grid = Matrix2D::new(50, 50, 1u8);
+    assert_eq!(flood_fill(&mut grid, (10, 10), 1), 0);
+    assert!(grid.iter_rows().all(|r| r.iter().all(|&v| v == 1)));
+}
+
+#[test]
+fn test_full_grid_fill_does_not_overflow_stack() {
+    let mut grid = Matrix2D::new(2_000, 2_000, false);
+    assert_eq!(flood_fill(&mut grid, (1_999, 0), true), 4_000_000);
+    assert!(grid.iter_rows().all(|r| r.iter().all(|&v| v)));
+}
+
+#[test]
+fn test_checkerboard_has_one_component_per_cell() {
+    let n = 8;
+    let grid = Matrix2D::from_fn(n, n, |r, c| (r + c) % 2);
+    let (labels, count) = connected_components(&grid);
+    assert_eq!(count, (n * n) as u32);
+    assert_eq!(labels[(0, 0)], 0);
+    assert_eq!(labels[(n - 1, n - 1)], (n * n - 1) as u32);
+}
+
+#[test]
+fn test_single_component_and_regions() {
+    let (labels, count) = connected_components(&Matrix2D::new(300, 200, 'x'));
+    assert_eq!(count, 1);
+    assert!(labels.iter_rows().all(|r| r.iter().all(|&l| l == 0)));
+
+    let grid = from_ascii(&[
+        "aab", //
+        "bab", //
+        "aab",
+    ]);
+    let (labels, count) = connected_components(&grid);
+    assert_eq!(count, 3);
+    assert_eq!(labels.row(0), [0, 0, 1]);
+    assert_eq!(labels.row(1), [2, 0, 1]);
+    assert_eq!(labels.row(2), [0, 0, 1]);
+}
+
+#[test]
+fn test_empty_grid_has_no_components() {
+    let grid: Matrix2D<u8> = Matrix2D::new(0, 0, 0);
+    assert_eq!(connected_components(&grid).1, 0);
+}
@@ -0,0 +1,108 @@
+use graph::{Graph, NodeId, condensation, strongly_connected_components};
+use vectors::SimpleRng;
+
+fn directed(n: usize, edges: &[(usize, usize)]) -> (Graph<usize, ()>, Vec<NodeId>) {
+    let mut g = Graph::new();
+    let ids: Vec<NodeId> = (0..n).map(|i| g.add_node(i)).collect();
+    for &(a, b) in edges {
+        g.add_edge(ids[a], ids[b], ());
+    }
+    (g, ids)
+}
+
+/// Componentes como listas de índices ordenadas, para comparar sin depender
+/// del orden interno de cada una.
+fn sorted_members(components: &[Vec<NodeId>]) -> Vec<Vec<usize>> {
+    components
+        .iter()
+        .map(|c| {
+            let mut members: Vec<usize> = c.iter().map(|n| n.index()).collect();
+            members.sort();
+            members
+        })
+        .collect()
+}
+
+#[test]
+fn test_two_interlinked_cycles() {
+    // Ciclo {0, 1, 2} con una arista hacia el ciclo {3, 4, 5}
+    let (g, _) = directed(6, &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)]);
+    let components = strongly_connected_components(&g);
+
+    // Orden topológico inverso: el ciclo sumidero primero
+    assert_eq!(sorted_members(&components), [vec![3, 4, 5], vec![0, 1, 2]]);
+}
+
+#[test]
+fn test_dag_gives_singletons_in_reverse_topological_order() {
+    let (g, _) = directed(5, &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+    let components = strongly_connected_components(&g);
+    assert_eq!(components.len(), 5);
+    assert!(components.iter().all(|c| c.len() == 1));
+
+    let position: Vec<usize> = {
+        let mut pos = vec![0; 5];
+        for (i, c) in components.iter().enumerate() {
+            pos[c[0].index()] = i;
+        }
+        pos
+    };
+    for (a, b) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+        assert!(position[b] < position[a], "{b} debe salir antes que {a}");
+    }
+}
+
+#[test]
+fn test_self_loop_is_its_own_component() {
+    let (g, _) = directed(3, &[(0, 0), (0, 1), (1, 2), (2, 1)]);
+    assert!(g.has_cycle());
+    assert_eq!(sorted_members(&strongly_connected_components(&g)), [vec![1, 2], vec![0]]);
+}
+
+#[test]
+fn test_has_cycle() {
+    assert!(!directed(4, &[(0, 1), (1, 2), (0, 2), (2, 3)]).0.has_cycle());
+    assert!(directed(4, &[(0, 1), (1, 2), (2, 3), (3, 1)]).0.has_cycle());
+    assert!(!directed(0, &[]).0.has_cycle());
+}
+
+#[test]
+fn test_condensation_is_acyclic() {
+    let mut rng = SimpleRng::from_seed(0x5CC);
+    for _ in 0..50 {
+        let edges: Vec<(usize, usize)> = (0..60)
+            .map(|_| (rng.below(30) as usize, rng.below(30) as usize))
+            .collect();
+        let (g, _) = directed(30, &edges);
+
+        let dag = condensation(&g);
+        assert!(!dag.has_cycle());
+
+        // Cada nodo original aparece en exactamente una componente
+        let mut members: Vec<usize> = dag
+            .node_ids()
+            .flat_map(|c| dag.node(c).unwrap().iter().map(|n| n.index()))
+            .collect();
+        members.sort();
+        assert_eq!(members, (0..30).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_condensation_edges() {
+    let (g, _) = directed(6, &[(0, 1), (1, 0), (1, 2), (0, 2), (2, 3), (3, 2), (4, 5)]);
+    let dag = condensation(&g);
+    assert_eq!(dag.node_count(), 4);
+    // {0,1} → {2,3} una sola vez, aunque hay dos aristas originales
+    assert_eq!(dag.edge_count(), 2);
+}
+
+#[test]
+fn test_long_chain_does_not_overflow() {
+    let n = 100_000;
+    let edges: Vec<(usize, usize)> = (0..n - 1).map(|i| (i, i + 1)).chain([(n - 1, 0)]).collect();
+    let (g, _) = directed(n, &edges);
+    let components = strongly_connected_components(&g);
+    assert_eq!(components.len(), 1);
+    assert_eq!(components[0].len(), n);
+}
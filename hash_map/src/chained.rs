@@ -0,0 +1,232 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use vectors::MyVec;
+
+/*
+Encadenamiento separado (separate chaining)
+
+Cada bucket es una lista pequeña con todos los pares cuyo hash cae ahí. Una
+colisión no "empuja" a nadie a otro slot: simplemente alarga la cadena.
+
+    bucket
+      0  → []
+      1  → [(k1, v1)]
+      2  → [(k2, v2), (k7, v7), (k9, v9)]   ← tres claves colisionaron
+      3  → []
+
+- Borrar es quitar de la cadena: no hacen falta lápidas.
+- El costo de una búsqueda es el largo de la cadena. Mientras el largo
+  PROMEDIO (len / buckets) se mantenga acotado, todo es O(1) promedio; por eso
+  se duplica la cantidad de buckets cuando el promedio pasa de `MAX_MEAN_CHAIN`.
+- Con un hasher malo todas las claves caen en el mismo bucket: sigue siendo
+  correcto, pero degenera en una búsqueda lineal O(n).
+*/
+
+const MIN_BUCKETS: usize = 8;
+
+/// Largo promedio de cadena a partir del cual se duplican los buckets.
+const MAX_MEAN_CHAIN: usize = 2;
+
+/// Foto del reparto de pares entre buckets, retornada por
+/// [`ChainedHashMap::bucket_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BucketStats {
+    pub buckets: usize,
+    pub empty_buckets: usize,
+    pub max_chain: usize,
+    pub mean_chain: f64,
+}
+
+/// Mapa hash con encadenamiento separado: un arreglo de buckets donde cada
+/// bucket es un `MyVec` de pares `(K, V)`.
+///
+/// ```
+/// use hash_map::ChainedHashMap;
+///
+/// let mut map = ChainedHashMap::new();
+/// map.insert(1, "uno");
+/// assert_eq!(map.get(&1), Some(&"uno"));
+/// assert!(map.bucket_stats().max_chain >= 1);
+/// ```
+pub struct ChainedHashMap<K, V, S = RandomState> {
+    buckets: MyVec<MyVec<(K, V)>>,
+    len: usize,
+    hasher: S,
+}
+
+impl<K: Hash + Eq, V> ChainedHashMap<K, V> {
+    /// Crea un mapa vacío (sin reservar memoria).
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> ChainedHashMap<K, V, S> {
+    /// Crea un mapa vacío que usa `hasher` para hashear las claves.
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            buckets: MyVec::new(),
+            len: 0,
+            hasher,
+        }
+    }
+
+    /// Retorna la cantidad de pares en el mapa.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si el mapa no tiene pares.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn bucket_index<Q: ?Sized + Hash>(&self, key: &Q) -> usize {
+        self.hasher.hash_one(key) as usize & (self.buckets.len() - 1)
+    }
+
+    fn bucket<Q>(&self, key: &Q) -> Option<&MyVec<(K, V)>>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        self.buckets.get(self.bucket_index(key))
+    }
+
+    fn bucket_mut<Q>(&mut self, key: &Q) -> Option<&mut MyVec<(K, V)>>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let index = self.bucket_index(key);
+        self.buckets.get_mut(index)
+    }
+
+    /// Redistribuye todos los pares en `new_buckets` buckets.
+    fn rehash(&mut self, new_buckets: usize) {
+        let old = mem::replace(&mut self.buckets, MyVec::from_fn(new_buckets, |_| MyVec::new()));
+
+        for chain in old {
+            for (k, v) in chain {
+                let index = self.bucket_index(&k);
+                self.buckets.get_mut(index).unwrap().push_back((k, v));
+            }
+        }
+    }
+
+    /// Inserta `value` bajo `key` y retorna el valor anterior, si había.
+    ///
+    /// # Complejidad
+    /// **O(1)** promedio; **O(n)** si todas las claves colisionan.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(chain) = self.bucket_mut(&key)
+            && let Some((_, v)) = chain.iter_mut().find(|(k, _)| *k == key)
+        {
+            return Some(mem::replace(v, value));
+        }
+
+        if (self.len + 1) > self.buckets.len() * MAX_MEAN_CHAIN {
+            self.rehash((self.buckets.len() * 2).max(MIN_BUCKETS));
+        }
+
+        let index = self.bucket_index(&key);
+        self.buckets.get_mut(index).unwrap().push_back((key, value));
+        self.len += 1;
+        None
+    }
+
+    /// Retorna una referencia al valor de `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.bucket(key)?
+            .iter()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Retorna una referencia mutable al valor de `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.bucket_mut(key)?
+            .iter_mut()
+            .find(|(k, _)| k.borrow() == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Retorna `true` si el mapa contiene `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Quita `key` del mapa y retorna su valor.
+    ///
+    /// El orden dentro de la cadena no importa, así que se quita con
+    /// swap + pop en vez de desplazar el resto.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let chain = self.bucket_mut(key)?;
+        let pos = chain.iter().position(|(k, _)| k.borrow() == key)?;
+        let last = chain.len() - 1;
+        chain.swap(pos, last);
+        let (_, v) = chain.pop_back().unwrap();
+        self.len -= 1;
+        Some(v)
+    }
+
+    /// Retorna un iterador sobre `(&K, &V)` en un orden arbitrario.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.buckets.iter().flat_map(|chain| chain.iter().map(|(k, v)| (k, v)))
+    }
+
+    /// Retorna cuántos buckets hay y cómo están repartidos los pares.
+    pub fn bucket_stats(&self) -> BucketStats {
+        let buckets = self.buckets.len();
+        let mean_chain = if buckets == 0 {
+            0.0
+        } else {
+            self.len as f64 / buckets as f64
+        };
+
+        BucketStats {
+            buckets,
+            empty_buckets: self.buckets.iter().filter(|c| c.is_empty()).count(),
+            max_chain: self.buckets.iter().map(|c| c.len()).max().unwrap_or(0),
+            mean_chain,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for ChainedHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + fmt::Debug, V: fmt::Debug, S: BuildHasher> fmt::Debug for ChainedHashMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,341 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+
+use vectors::MyVec;
+
+mod chained;
+mod lru;
+
+pub use chained::{BucketStats, ChainedHashMap};
+pub use lru::{Iter as LruIter, LruCache};
+
+/*
+Direccionamiento abierto (open addressing)
+
+Todos los pares viven directamente en un único arreglo de slots. La clave se
+hashea a un índice inicial; si ese slot está ocupado por otra clave se prueba
+el siguiente (sondeo lineal), dando la vuelta al final del arreglo:
+
+    hash(k) & mask = 5
+
+    slot:   0     1     2     3     4     5     6     7
+          ┌─────┬─────┬─────┬─────┬─────┬─────┬─────┬─────┐
+          │     │     │     │     │     │  x  │  †  │  k  │
+          └─────┴─────┴─────┴─────┴─────┴─────┴─────┴─────┘
+                                          ocupado  lápida  ¡aquí!
+
+- La capacidad es potencia de dos: `hash % cap` se vuelve `hash & (cap - 1)`.
+- Borrar no puede dejar el slot vacío: cortaría la cadena de sondeo de las
+  claves que se insertaron después y quedaron más adelante. Se deja una
+  LÁPIDA (†): las búsquedas la saltan y las inserciones la reutilizan.
+- Cuando slots ocupados + lápidas superan el 70% de la capacidad se reconstruye
+  el arreglo: las cadenas de sondeo se alargan muy rápido cerca del 100%.
+*/
+
+const MIN_CAPACITY: usize = 8;
+
+/// Factor de carga máximo, expresado como fracción `LOAD_NUM / LOAD_DEN`.
+const LOAD_NUM: usize = 7;
+const LOAD_DEN: usize = 10;
+
+enum Slot<K, V> {
+    Empty,
+    Tombstone,
+    Full(K, V),
+}
+
+/// Resultado de buscar una clave: dónde está o dónde debería insertarse.
+enum Probe {
+    Found(usize),
+    Vacant(usize),
+}
+
+/// Mapa hash con direccionamiento abierto y sondeo lineal.
+///
+/// El parámetro `S` elige cómo se hashean las claves, como en
+/// `std::collections::HashMap`.
+///
+/// ```
+/// use hash_map::MyHashMap;
+///
+/// let mut ages = MyHashMap::new();
+/// ages.insert("ana", 31);
+/// assert_eq!(ages.insert("ana", 32), Some(31));
+/// assert_eq!(ages.get("ana"), Some(&32));
+/// assert_eq!(ages.remove("ana"), Some(32));
+/// assert!(ages.is_empty());
+/// ```
+pub struct MyHashMap<K, V, S = RandomState> {
+    slots: MyVec<Slot<K, V>>,
+    len: usize,
+    tombstones: usize,
+    hasher: S,
+}
+
+impl<K: Hash + Eq, V> MyHashMap<K, V> {
+    /// Crea un mapa vacío (sin reservar memoria).
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    /// Crea un mapa con espacio para al menos `cap` pares sin crecer.
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, RandomState::new())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MyHashMap<K, V, S> {
+    /// Crea un mapa vacío que usa `hasher` para hashear las claves.
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            slots: MyVec::new(),
+            len: 0,
+            tombstones: 0,
+            hasher,
+        }
+    }
+
+    /// Como `with_capacity`, pero con el `hasher` dado.
+    pub fn with_capacity_and_hasher(cap: usize, hasher: S) -> Self {
+        let mut map = Self::with_hasher(hasher);
+        if cap > 0 {
+            map.rebuild(Self::slots_for(cap));
+        }
+        map
+    }
+
+    /// Cantidad de slots (potencia de dos) para guardar `n` pares sin pasar
+    /// el factor de carga.
+    fn slots_for(n: usize) -> usize {
+        (n * LOAD_DEN / LOAD_NUM + 1).next_power_of_two().max(MIN_CAPACITY)
+    }
+
+    /// Retorna cuántos pares caben antes de tener que crecer.
+    pub fn capacity(&self) -> usize {
+        self.slots.len() * LOAD_NUM / LOAD_DEN
+    }
+
+    /// Retorna la cantidad de pares en el mapa.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si el mapa no tiene pares.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Busca `key` siguiendo la cadena de sondeo.
+    ///
+    /// Si no la encuentra, retorna el primer slot reutilizable del camino
+    /// (la primera lápida, o el vacío donde terminó la cadena).
+    /// Requiere que haya al menos un slot vacío, cosa que garantiza el factor de carga.
+    fn probe<Q>(&self, key: &Q) -> Probe
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let mask = self.slots.len() - 1;
+        let mut index = self.hasher.hash_one(key) as usize & mask;
+        let mut first_tombstone = None;
+
+        loop {
+            match self.slots.get(index).unwrap() {
+                Slot::Empty => return Probe::Vacant(first_tombstone.unwrap_or(index)),
+                Slot::Tombstone => {
+                    first_tombstone.get_or_insert(index);
+                }
+                Slot::Full(k, _) if k.borrow() == key => return Probe::Found(index),
+                Slot::Full(..) => {}
+            }
+            index = (index + 1) & mask;
+        }
+    }
+
+    fn find<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        if self.slots.is_empty() {
+            return None;
+        }
+
+        match self.probe(key) {
+            Probe::Found(index) => Some(index),
+            Probe::Vacant(_) => None,
+        }
+    }
+
+    /// Reinserta todos los pares en un arreglo nuevo de `new_slots` slots.
+    ///
+    /// De paso elimina todas las lápidas.
+    fn rebuild(&mut self, new_slots: usize) {
+        let old = mem::replace(&mut self.slots, MyVec::from_fn(new_slots, |_| Slot::Empty));
+        self.tombstones = 0;
+
+        for slot in old {
+            if let Slot::Full(k, v) = slot
+                && let Probe::Vacant(index) = self.probe(&k)
+            {
+                *self.slots.get_mut(index).unwrap() = Slot::Full(k, v);
+            }
+        }
+    }
+
+    /// Garantiza espacio para un par más sin pasar el factor de carga.
+    fn reserve_one(&mut self) {
+        let used = self.len + self.tombstones + 1;
+        if used * LOAD_DEN <= self.slots.len() * LOAD_NUM {
+            return;
+        }
+
+        // Si son las lápidas las que llenan el arreglo, basta con limpiarlas
+        // sin crecer
+        let new_slots = Self::slots_for(self.len + 1).max(self.slots.len());
+        self.rebuild(new_slots);
+    }
+
+    /// Inserta `value` bajo `key` y retorna el valor anterior, si había.
+    ///
+    /// # Complejidad
+    /// **O(1)** promedio.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.reserve_one();
+
+        match self.probe(&key) {
+            Probe::Found(index) => match self.slots.get_mut(index).unwrap() {
+                Slot::Full(_, v) => Some(mem::replace(v, value)),
+                _ => unreachable!(),
+            },
+            Probe::Vacant(index) => {
+                let slot = self.slots.get_mut(index).unwrap();
+                if matches!(slot, Slot::Tombstone) {
+                    self.tombstones -= 1;
+                }
+                *slot = Slot::Full(key, value);
+                self.len += 1;
+                None
+            }
+        }
+    }
+
+    /// Retorna una referencia al valor de `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        match self.slots.get(self.find(key)?) {
+            Some(Slot::Full(_, v)) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Retorna una referencia mutable al valor de `key`.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let index = self.find(key)?;
+        match self.slots.get_mut(index) {
+            Some(Slot::Full(_, v)) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Retorna `true` si el mapa contiene `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        self.find(key).is_some()
+    }
+
+    /// Quita `key` del mapa y retorna su valor, dejando una lápida.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Hash + Eq,
+    {
+        let index = self.find(key)?;
+        let slot = mem::replace(self.slots.get_mut(index).unwrap(), Slot::Tombstone);
+        self.len -= 1;
+        self.tombstones += 1;
+
+        match slot {
+            Slot::Full(_, v) => Some(v),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Retorna un iterador sobre `(&K, &V)` en un orden arbitrario.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: self.slots.iter(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Default for MyHashMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + fmt::Debug, V: fmt::Debug, S: BuildHasher> fmt::Debug for MyHashMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for MyHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (k, v) in iter {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+/// Iterador sobre `(&K, &V)`, creado por [`MyHashMap::iter`].
+pub struct Iter<'a, K, V> {
+    slots: vectors::Iter<'a, Slot<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        for slot in self.slots.by_ref() {
+            if let Slot::Full(k, v) = slot {
+                self.remaining -= 1;
+                return Some((k, v));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher> IntoIterator for &'a MyHashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
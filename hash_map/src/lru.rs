@@ -0,0 +1,288 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::MyHashMap;
+
+/*
+Caché LRU (least recently used)
+
+Dos estructuras trabajando juntas:
+
+- Un MAPA clave → nodo, para encontrar cualquier entrada en O(1).
+- Una LISTA doblemente enlazada en orden de uso: al frente la más reciente,
+  al final la que se usó hace más tiempo (la próxima a desalojar).
+
+      mapa                     lista de recencia
+    ┌──────┐           head                           tail
+    │  a ──┼──────────► [a] ◄─► [c] ◄─► [b]  ◄────────
+    │  b ──┼─────────────────────────────┘
+    │  c ──┼──────────────────┘
+    └──────┘
+
+get(b): el mapa da el nodo, se desengancha y se pone al frente → [b, a, c].
+put(d) con el caché lleno: se desaloja el tail (c) y d entra al frente.
+
+Los nodos son `Box` convertidos a `NonNull`, igual que en
+`MyDoublyLinkedList`: el caché es dueño de todos y los libera exactamente una
+vez (al desalojar o en Drop). Un nodo nunca se mueve de lugar en memoria, así
+que el mapa puede usar como clave un puntero a la clave guardada DENTRO del
+nodo (`KeyRef`) y no hace falta exigir `K: Clone` para tenerla dos veces.
+*/
+
+type Link<K, V> = Option<NonNull<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    prev: Link<K, V>,
+    next: Link<K, V>,
+}
+
+/// Puntero a la clave de un nodo vivo, que se hashea y compara como la
+/// clave misma.
+struct KeyRef<K>(*const K);
+
+impl<K> KeyRef<K> {
+    fn key(&self) -> &K {
+        // SAFETY: solo se crean KeyRef a claves de nodos vivos del caché, y la
+        // entrada del mapa se quita antes de liberar el nodo
+        unsafe { &*self.0 }
+    }
+}
+
+impl<K: Hash> Hash for KeyRef<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state)
+    }
+}
+
+impl<K: PartialEq> PartialEq for KeyRef<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<K: Eq> Eq for KeyRef<K> {}
+
+// Permite buscar en el mapa con un `&K` común
+impl<K> Borrow<K> for KeyRef<K> {
+    fn borrow(&self) -> &K {
+        self.key()
+    }
+}
+
+/// Caché de capacidad fija que desaloja la entrada usada hace más tiempo.
+///
+/// ```
+/// use hash_map::LruCache;
+///
+/// let mut cache = LruCache::new(2);
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// cache.get(&"a");                        // "a" pasa a ser la más reciente
+/// assert_eq!(cache.put("c", 3), Some(("b", 2)));
+/// assert!(cache.peek(&"a").is_some());
+/// ```
+pub struct LruCache<K: Hash + Eq, V> {
+    map: MyHashMap<KeyRef<K>, NonNull<Node<K, V>>>,
+    /// La entrada más reciente.
+    head: Link<K, V>,
+    /// La entrada menos reciente.
+    tail: Link<K, V>,
+    capacity: usize,
+    _marker: PhantomData<Box<Node<K, V>>>,
+}
+
+// SAFETY: el caché es el único dueño de sus nodos, como un `Vec<(K, V)>`.
+unsafe impl<K: Hash + Eq + Send, V: Send> Send for LruCache<K, V> {}
+unsafe impl<K: Hash + Eq + Sync, V: Sync> Sync for LruCache<K, V> {}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Crea un caché vacío con espacio para `capacity` entradas.
+    ///
+    /// # Panics
+    /// Si `capacity` es 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "la capacidad de un LruCache debe ser mayor que 0");
+        Self {
+            map: MyHashMap::with_capacity(capacity),
+            head: None,
+            tail: None,
+            capacity,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Retorna la cantidad de entradas.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Retorna `true` si el caché no tiene entradas.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Retorna la cantidad máxima de entradas.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Saca `node` de la lista de recencia sin liberarlo.
+    ///
+    /// # Safety
+    /// `node` debe ser un nodo vivo enganchado en esta lista.
+    unsafe fn detach(&mut self, node: NonNull<Node<K, V>>) {
+        let (prev, next) = unsafe { ((*node.as_ptr()).prev, (*node.as_ptr()).next) };
+        match prev {
+            Some(prev) => unsafe { (*prev.as_ptr()).next = next },
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => unsafe { (*next.as_ptr()).prev = prev },
+            None => self.tail = prev,
+        }
+    }
+
+    /// Engancha `node` al frente (más reciente).
+    ///
+    /// # Safety
+    /// `node` debe ser un nodo vivo de este caché que no esté en la lista.
+    unsafe fn attach_front(&mut self, node: NonNull<Node<K, V>>) {
+        unsafe {
+            (*node.as_ptr()).prev = None;
+            (*node.as_ptr()).next = self.head;
+        }
+        match self.head {
+            Some(old) => unsafe { (*old.as_ptr()).prev = Some(node) },
+            None => self.tail = Some(node),
+        }
+        self.head = Some(node);
+    }
+
+    /// Retorna el valor de `key` y la marca como la entrada más reciente.
+    ///
+    /// # Complejidad
+    /// **O(1)** promedio.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let node = *self.map.get(key)?;
+        // SAFETY: todo nodo del mapa está vivo y enganchado en la lista
+        unsafe {
+            self.detach(node);
+            self.attach_front(node);
+            Some(&(*node.as_ptr()).value)
+        }
+    }
+
+    /// Retorna el valor de `key` sin cambiar su recencia.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Retorna `true` si `key` está en el caché, sin cambiar su recencia.
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Inserta o actualiza `key` y la marca como la entrada más reciente.
+    ///
+    /// Si la clave es nueva y el caché está lleno, desaloja la entrada menos
+    /// reciente y la retorna. Actualizar una clave existente nunca desaloja.
+    ///
+    /// # Complejidad
+    /// **O(1)** promedio.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(&node) = self.map.get(&key) {
+            // SAFETY: todo nodo del mapa está vivo y enganchado en la lista
+            unsafe {
+                (*node.as_ptr()).value = value;
+                self.detach(node);
+                self.attach_front(node);
+            }
+            return None;
+        }
+
+        let evicted = if self.len() == self.capacity {
+            self.pop_lru()
+        } else {
+            None
+        };
+
+        let node = NonNull::from(Box::leak(Box::new(Node {
+            key,
+            value,
+            prev: None,
+            next: None,
+        })));
+        // SAFETY: el nodo se acaba de crear y no está en la lista
+        unsafe {
+            self.map.insert(KeyRef(&(*node.as_ptr()).key), node);
+            self.attach_front(node);
+        }
+        evicted
+    }
+
+    /// Quita y retorna la entrada menos reciente.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let node = self.tail?;
+        // SAFETY: tail es un nodo vivo; se quita del mapa ANTES de liberarlo
+        // para que ningún KeyRef quede colgando
+        unsafe {
+            self.detach(node);
+            self.map.remove(&(*node.as_ptr()).key);
+            let boxed = Box::from_raw(node.as_ptr());
+            Some((boxed.key, boxed.value))
+        }
+    }
+
+    /// Retorna un iterador sobre `(&K, &V)` de la más reciente a la menos
+    /// reciente.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            current: self.head,
+            remaining: self.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Drop for LruCache<K, V> {
+    fn drop(&mut self) {
+        while self.pop_lru().is_some() {}
+    }
+}
+
+impl<K: Hash + Eq + fmt::Debug, V: fmt::Debug> fmt::Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Iterador en orden de recencia, creado por [`LruCache::iter`].
+pub struct Iter<'a, K, V> {
+    current: Link<K, V>,
+    remaining: usize,
+    _marker: PhantomData<&'a Node<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        self.current.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.current = node.next;
+            self.remaining -= 1;
+            (&node.key, &node.value)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use hash_map::ChainedHashMap;
+use vectors::SimpleRng;
+
+/// Hasher que manda todas las claves al mismo bucket.
+#[derive(Default)]
+struct CollidingHasher;
+
+impl Hasher for CollidingHasher {
+    fn finish(&self) -> u64 {
+        7
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+#[test]
+fn test_insert_get_remove() {
+    let mut map = ChainedHashMap::new();
+    assert_eq!(map.get("x"), None);
+    assert_eq!(map.remove("x"), None);
+    assert_eq!(map.bucket_stats().buckets, 0);
+
+    assert_eq!(map.insert(String::from("a"), 1), None);
+    assert_eq!(map.insert(String::from("b"), 2), None);
+    assert_eq!(map.insert(String::from("a"), 3), Some(1));
+    assert_eq!(map.len(), 2);
+
+    *map.get_mut("b").unwrap() *= 10;
+    assert_eq!(map.get("b"), Some(&20));
+    assert_eq!(map.remove("a"), Some(3));
+    assert!(!map.contains_key("a"));
+    assert_eq!(format!("{map:?}"), r#"{"b": 20}"#);
+}
+
+#[test]
+fn test_rehash_keeps_mean_chain_bounded() {
+    let mut map = ChainedHashMap::new();
+    let mut last_buckets = 0;
+
+    for i in 0..5_000 {
+        map.insert(i, i);
+        let stats = map.bucket_stats();
+        assert!(stats.mean_chain <= 2.0, "promedio {} con {i}", stats.mean_chain);
+        assert!(stats.buckets >= last_buckets);
+        last_buckets = stats.buckets;
+    }
+
+    assert!(last_buckets >= 2_500);
+    assert!(last_buckets.is_power_of_two());
+    assert_eq!(map.iter().count(), 5_000);
+}
+
+#[test]
+fn test_bad_hasher_grows_one_chain_but_stays_correct() {
+    let mut map: ChainedHashMap<u32, u32, BuildHasherDefault<CollidingHasher>> =
+        ChainedHashMap::with_hasher(BuildHasherDefault::default());
+
+    for i in 0..300 {
+        map.insert(i, i + 1);
+    }
+
+    // Rehashear no ayuda: todo sigue en un solo bucket
+    let stats = map.bucket_stats();
+    assert_eq!(stats.max_chain, 300);
+    assert_eq!(stats.empty_buckets, stats.buckets - 1);
+
+    for i in 0..300 {
+        assert_eq!(map.get(&i), Some(&(i + 1)));
+    }
+    for i in (0..300).step_by(2) {
+        assert_eq!(map.remove(&i), Some(i + 1));
+    }
+    assert_eq!(map.bucket_stats().max_chain, 150);
+    for i in 0..300 {
+        assert_eq!(map.contains_key(&i), i % 2 == 1);
+    }
+}
+
+#[test]
+fn test_random_operations_against_std() {
+    let mut rng = SimpleRng::from_seed(0xC4A1);
+    let mut mine = ChainedHashMap::new();
+    let mut reference = HashMap::new();
+
+    for step in 0..20_000u64 {
+        let key = rng.below(400);
+        match rng.below(3) {
+            0 | 1 => assert_eq!(mine.insert(key, step), reference.insert(key, step)),
+            _ => assert_eq!(mine.remove(&key), reference.remove(&key)),
+        }
+        assert_eq!(mine.len(), reference.len());
+    }
+
+    for (k, v) in &reference {
+        assert_eq!(mine.get(k), Some(v));
+    }
+    assert_eq!(mine.iter().count(), reference.len());
+}
@@ -0,0 +1,123 @@
+use std::rc::Rc;
+
+use hash_map::LruCache;
+use vectors::SimpleRng;
+
+fn recency<K: std::hash::Hash + Eq + Copy, V>(cache: &LruCache<K, V>) -> Vec<K> {
+    cache.iter().map(|(k, _)| *k).collect()
+}
+
+#[test]
+fn test_get_rescues_entry_from_eviction() {
+    let mut cache = LruCache::new(3);
+    assert_eq!(cache.put(1, "uno"), None);
+    assert_eq!(cache.put(2, "dos"), None);
+    assert_eq!(cache.put(3, "tres"), None);
+    assert_eq!(recency(&cache), [3, 2, 1]);
+
+    // Sin el get, el 1 sería el desalojado
+    assert_eq!(cache.get(&1), Some(&"uno"));
+    assert_eq!(cache.put(4, "cuatro"), Some((2, "dos")));
+    assert_eq!(recency(&cache), [4, 1, 3]);
+
+    // peek no cambia la recencia: 3 sigue siendo el próximo
+    assert_eq!(cache.peek(&3), Some(&"tres"));
+    assert_eq!(cache.put(5, "cinco"), Some((3, "tres")));
+    assert!(!cache.contains(&3));
+    assert_eq!(cache.get(&3), None);
+}
+
+#[test]
+fn test_update_existing_key_keeps_len() {
+    let mut cache = LruCache::new(2);
+    cache.put("a", 1);
+    cache.put("b", 2);
+    assert_eq!(cache.put("a", 10), None);
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.capacity(), 2);
+    assert_eq!(recency(&cache), ["a", "b"]);
+    assert_eq!(cache.peek(&"a"), Some(&10));
+    assert_eq!(format!("{cache:?}"), r#"{"a": 10, "b": 2}"#);
+}
+
+#[test]
+fn test_capacity_one() {
+    let mut cache = LruCache::new(1);
+    assert!(cache.is_empty());
+    assert_eq!(cache.put('x', 1), None);
+    assert_eq!(cache.put('x', 2), None);
+    assert_eq!(cache.put('y', 3), Some(('x', 2)));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.pop_lru(), Some(('y', 3)));
+    assert_eq!(cache.pop_lru(), None);
+}
+
+#[test]
+#[should_panic(expected = "mayor que 0")]
+fn test_zero_capacity_panics() {
+    let _cache: LruCache<u8, u8> = LruCache::new(0);
+}
+
+#[test]
+fn test_drop_releases_everything() {
+    let tracker = Rc::new(());
+    {
+        let mut cache = LruCache::new(4);
+        for i in 0..10 {
+            cache.put(i, Rc::clone(&tracker));
+        }
+        assert_eq!(Rc::strong_count(&tracker), 5);
+    }
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+/// Modelo de referencia: un Vec ordenado de más a menos reciente.
+struct NaiveLru {
+    entries: Vec<(u64, u64)>,
+    capacity: usize,
+}
+
+impl NaiveLru {
+    fn get(&mut self, key: u64) -> Option<u64> {
+        let i = self.entries.iter().position(|&(k, _)| k == key)?;
+        let entry = self.entries.remove(i);
+        self.entries.insert(0, entry);
+        Some(entry.1)
+    }
+
+    fn put(&mut self, key: u64, value: u64) -> Option<(u64, u64)> {
+        if let Some(i) = self.entries.iter().position(|&(k, _)| k == key) {
+            self.entries.remove(i);
+            self.entries.insert(0, (key, value));
+            return None;
+        }
+        let evicted = if self.entries.len() == self.capacity {
+            self.entries.pop()
+        } else {
+            None
+        };
+        self.entries.insert(0, (key, value));
+        evicted
+    }
+}
+
+#[test]
+fn test_random_operations_against_naive_model() {
+    let mut rng = SimpleRng::from_seed(0x1EA5);
+    let mut cache = LruCache::new(8);
+    let mut naive = NaiveLru {
+        entries: Vec::new(),
+        capacity: 8,
+    };
+
+    for step in 0..10_000 {
+        let key = rng.below(20);
+        if rng.below(2) == 0 {
+            assert_eq!(cache.get(&key).copied(), naive.get(key));
+        } else {
+            assert_eq!(cache.put(key, step), naive.put(key, step));
+        }
+        let order: Vec<(u64, u64)> = cache.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(order, naive.entries);
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+
+use hash_map::MyHashMap;
+use vectors::SimpleRng;
+
+/// Hasher que manda todas las claves al mismo slot inicial.
+#[derive(Default)]
+struct CollidingHasher;
+
+impl Hasher for CollidingHasher {
+    fn finish(&self) -> u64 {
+        42
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {}
+}
+
+type Colliding = BuildHasherDefault<CollidingHasher>;
+
+#[test]
+fn test_insert_get_replace_remove() {
+    let mut map = MyHashMap::new();
+    assert_eq!(map.get("x"), None);
+    assert_eq!(map.remove("x"), None);
+
+    assert_eq!(map.insert(String::from("uno"), 1), None);
+    assert_eq!(map.insert(String::from("dos"), 2), None);
+    assert_eq!(map.insert(String::from("uno"), 10), Some(1));
+    assert_eq!(map.len(), 2);
+
+    // Búsqueda con &str sobre claves String (Borrow)
+    assert_eq!(map.get("uno"), Some(&10));
+    *map.get_mut("dos").unwrap() += 5;
+    assert_eq!(map.get("dos"), Some(&7));
+    assert!(map.contains_key("dos"));
+
+    assert_eq!(map.remove("uno"), Some(10));
+    assert!(!map.contains_key("uno"));
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_collision_heavy_keys() {
+    let mut map: MyHashMap<u32, u32, Colliding> = MyHashMap::with_hasher(Colliding::default());
+
+    for i in 0..200 {
+        map.insert(i, i * 2);
+    }
+    for i in 0..200 {
+        assert_eq!(map.get(&i), Some(&(i * 2)));
+    }
+    assert_eq!(map.get(&999), None);
+    assert_eq!(map.len(), 200);
+}
+
+#[test]
+fn test_delete_then_reinsert_through_tombstones() {
+    let mut map: MyHashMap<u32, &str, Colliding> = MyHashMap::with_hasher(Colliding::default());
+    map.insert(1, "a");
+    map.insert(2, "b");
+    map.insert(3, "c");
+
+    // Borrar el del medio deja una lápida: 3 sigue siendo alcanzable
+    assert_eq!(map.remove(&2), Some("b"));
+    assert_eq!(map.get(&3), Some(&"c"));
+
+    // Reinsertar 3 no debe duplicarlo aunque haya una lápida antes
+    assert_eq!(map.insert(3, "C"), Some("c"));
+    assert_eq!(map.insert(2, "B"), None);
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.iter().count(), 3);
+
+    // Muchas vueltas de borrar/insertar no deben llenar el arreglo de lápidas
+    for round in 0..1000 {
+        map.insert(100 + round, "tmp");
+        map.remove(&(100 + round));
+    }
+    assert_eq!(map.len(), 3);
+    assert!(map.capacity() < 64);
+}
+
+#[test]
+fn test_growth_preserves_entries() {
+    let mut map = MyHashMap::with_capacity(4);
+    let initial = map.capacity();
+    assert!(initial >= 4);
+
+    for i in 0..10_000u64 {
+        map.insert(i, i.to_string());
+    }
+    assert!(map.capacity() >= 10_000);
+    assert_eq!(map.len(), 10_000);
+    for i in 0..10_000u64 {
+        assert_eq!(map.get(&i).map(String::as_str), Some(i.to_string().as_str()));
+    }
+
+    let mut keys: Vec<u64> = map.iter().map(|(k, _)| *k).collect();
+    keys.sort();
+    assert_eq!(keys, (0..10_000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_random_operations_against_std() {
+    let mut rng = SimpleRng::from_seed(0xA5A5);
+    let mut mine = MyHashMap::new();
+    let mut reference = HashMap::new();
+
+    for step in 0..20_000u64 {
+        let key = rng.below(500);
+        match rng.below(3) {
+            0 | 1 => assert_eq!(mine.insert(key, step), reference.insert(key, step)),
+            _ => assert_eq!(mine.remove(&key), reference.remove(&key)),
+        }
+        assert_eq!(mine.len(), reference.len());
+    }
+
+    for (k, v) in &reference {
+        assert_eq!(mine.get(k), Some(v));
+    }
+    assert_eq!(mine.iter().count(), reference.len());
+}
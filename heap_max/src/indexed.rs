@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use vectors::MyVec;
+
+/*
+Heap indexado (min-heap con decrease_key)
+
+Un heap normal no sabe DÓNDE quedó cada elemento, así que para cambiar una
+prioridad habría que buscarlo en O(n). Aquí se guarda además un mapa
+clave → posición en el arreglo:
+
+    heap:      [(b,1), (a,4), (c,7)]
+    posición:  { b: 0, a: 1, c: 2 }
+
+decrease_key(c, 0) encuentra a `c` en O(1), baja su prioridad y lo sube
+(sift-up) en O(log n):
+
+    heap:      [(c,0), (a,4), (b,1)]
+    posición:  { c: 0, a: 1, b: 2 }
+
+Invariante: TODO intercambio dentro del arreglo debe actualizar también las
+dos entradas del mapa. Por eso hay un único lugar que intercambia: `swap`.
+*/
+
+/// Min-heap de pares `(clave, prioridad)` que permite bajar la prioridad de
+/// una clave ya encolada, como necesita Dijkstra.
+///
+/// ```
+/// use heap_max::IndexedHeap;
+///
+/// let mut heap = IndexedHeap::new();
+/// heap.push('a', 10);
+/// heap.push('b', 20);
+/// assert!(heap.decrease_key('b', 5));
+/// assert_eq!(heap.pop(), Some(('b', 5)));
+/// ```
+pub struct IndexedHeap<K: Copy + Eq + Hash, P: Ord> {
+    data: MyVec<(K, P)>,
+    positions: HashMap<K, usize>,
+}
+
+impl<K: Copy + Eq + Hash, P: Ord> IndexedHeap<K, P> {
+    /// Crea un heap vacío.
+    pub fn new() -> Self {
+        Self {
+            data: MyVec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Retorna la cantidad de claves encoladas.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Retorna `true` si no hay claves encoladas.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Retorna `true` si `key` está encolada.
+    pub fn contains(&self, key: K) -> bool {
+        self.positions.contains_key(&key)
+    }
+
+    /// Retorna la prioridad actual de `key`, si está encolada.
+    pub fn priority_of(&self, key: K) -> Option<&P> {
+        let &pos = self.positions.get(&key)?;
+        self.data.get(pos).map(|(_, p)| p)
+    }
+
+    /// Retorna el par de menor prioridad sin sacarlo.
+    pub fn peek(&self) -> Option<(K, &P)> {
+        self.data.get(0).map(|(k, p)| (*k, p))
+    }
+
+    /// Encola `key` con `priority`.
+    ///
+    /// Si la clave ya estaba, no se duplica: retorna `false` y no cambia
+    /// nada (para eso está [`IndexedHeap::decrease_key`]).
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn push(&mut self, key: K, priority: P) -> bool {
+        if self.contains(key) {
+            return false;
+        }
+
+        let pos = self.len();
+        self.data.push_back((key, priority));
+        self.positions.insert(key, pos);
+        self.sift_up(pos);
+        true
+    }
+
+    /// Saca y retorna el par de menor prioridad.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        let last = self.len().checked_sub(1)?;
+        self.swap(0, last);
+        let (key, priority) = self.data.pop_back().unwrap();
+        self.positions.remove(&key);
+        self.sift_down(0);
+        Some((key, priority))
+    }
+
+    /// Baja la prioridad de `key` a `new_priority`.
+    ///
+    /// Retorna `false` sin cambiar nada si la clave no está o si
+    /// `new_priority` no es menor que la actual.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn decrease_key(&mut self, key: K, new_priority: P) -> bool {
+        let Some(&pos) = self.positions.get(&key) else {
+            return false;
+        };
+
+        let (_, current) = self.data.get_mut(pos).unwrap();
+        if new_priority >= *current {
+            return false;
+        }
+        *current = new_priority;
+        self.sift_up(pos);
+        true
+    }
+
+    /// Intercambia dos posiciones del arreglo manteniendo el mapa al día.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.data.swap(a, b);
+        let key_a = self.data.get(a).unwrap().0;
+        let key_b = self.data.get(b).unwrap().0;
+        self.positions.insert(key_a, a);
+        self.positions.insert(key_b, b);
+    }
+
+    fn priority_at(&self, pos: usize) -> &P {
+        &self.data.get(pos).unwrap().1
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.priority_at(pos) >= self.priority_at(parent) {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        let len = self.len();
+        loop {
+            let left = 2 * pos + 1;
+            if left >= len {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < len && self.priority_at(right) < self.priority_at(left) {
+                right
+            } else {
+                left
+            };
+            if self.priority_at(pos) <= self.priority_at(child) {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash, P: Ord> Default for IndexedHeap<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
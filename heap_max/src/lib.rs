@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use vectors::{Iter, MyVec};
+
+mod indexed;
+
+pub use indexed::IndexedHeap;
+
+/*
+Heap binario (max-heap) guardado en un arreglo
+
+El árbol es completo, así que no hacen falta punteros: los hijos del nodo `i`
+están en `2i + 1` y `2i + 2`, y su padre en `(i - 1) / 2`.
+
+                 90                 índice:  0   1   2   3   4   5
+               /    \                      ┌───┬───┬───┬───┬───┬───┐
+             70      80                    │90 │70 │80 │10 │40 │50 │
+            /  \    /                      └───┴───┴───┴───┴───┴───┘
+          10    40 50
+
+Invariante: cada padre es >= que sus hijos, por lo que el máximo está en 0.
+
+- push: se agrega al final y se "sube" (sift-up) mientras sea mayor que su padre.
+- pop: se intercambia la raíz con el último, se saca, y la nueva raíz se
+  "baja" (sift-down) cambiándola por su hijo mayor.
+- heapify: aplicar sift-down desde el último padre hasta la raíz es O(n), no
+  O(n log n): la mitad de los nodos son hojas y casi no se mueven.
+*/
+
+/// Cola de prioridad max-heap sobre un `MyVec`.
+///
+/// ```
+/// use heap_max::MyBinaryHeap;
+///
+/// let mut heap = MyBinaryHeap::new();
+/// heap.push(3);
+/// heap.push(9);
+/// heap.push(1);
+/// assert_eq!(heap.pop(), Some(9));
+/// assert_eq!(heap.peek(), Some(&3));
+/// ```
+pub struct MyBinaryHeap<T: Ord> {
+    data: MyVec<T>,
+}
+
+/// Min-heap: el mismo heap con las comparaciones invertidas por
+/// [`std::cmp::Reverse`].
+///
+/// ```
+/// use std::cmp::Reverse;
+/// use heap_max::MyMinHeap;
+///
+/// let mut heap = MyMinHeap::new();
+/// heap.push(Reverse(3));
+/// heap.push(Reverse(1));
+/// assert_eq!(heap.pop(), Some(Reverse(1)));
+/// ```
+pub type MyMinHeap<T> = MyBinaryHeap<Reverse<T>>;
+
+impl<T: Ord> MyBinaryHeap<T> {
+    /// Crea un heap vacío.
+    pub fn new() -> Self {
+        Self { data: MyVec::new() }
+    }
+
+    /// Construye un heap a partir de un vector arbitrario.
+    ///
+    /// # Complejidad
+    /// **O(n)**: heapify de abajo hacia arriba.
+    pub fn from_vec(data: MyVec<T>) -> Self {
+        let mut heap = Self { data };
+        let len = heap.len();
+        for i in (0..len / 2).rev() {
+            heap.sift_down(i, len);
+        }
+        heap
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Retorna `true` si el heap no tiene elementos.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Retorna el máximo sin sacarlo.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.get(0)
+    }
+
+    /// Agrega un elemento.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn push(&mut self, value: T) {
+        self.data.push_back(value);
+        self.sift_up(self.len() - 1);
+    }
+
+    /// Saca y retorna el máximo.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn pop(&mut self) -> Option<T> {
+        let last = self.len().checked_sub(1)?;
+        self.data.swap(0, last);
+        let top = self.data.pop_back();
+        self.sift_down(0, last);
+        top
+    }
+
+    /// Retorna un iterador sobre los elementos en el orden del arreglo
+    /// (no ordenado).
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Devuelve el arreglo interno tal cual, sin ordenar.
+    pub fn into_vec(self) -> MyVec<T> {
+        self.data
+    }
+
+    /// Consume el heap y retorna sus elementos en orden ascendente.
+    ///
+    /// Es heapsort: se lleva el máximo al final y se achica el heap en uno.
+    ///
+    /// # Complejidad
+    /// **O(n log n)**, sin memoria extra.
+    pub fn into_sorted_vec(mut self) -> MyVec<T> {
+        for end in (1..self.len()).rev() {
+            self.data.swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.data
+    }
+
+    /// Sube el elemento en `pos` mientras sea mayor que su padre.
+    fn sift_up(&mut self, mut pos: usize) {
+        let data = self.data.as_mut_slice();
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if data[pos] <= data[parent] {
+                break;
+            }
+            data.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    /// Baja el elemento en `pos` considerando solo los primeros `end` elementos.
+    fn sift_down(&mut self, mut pos: usize, end: usize) {
+        let data = self.data.as_mut_slice();
+        loop {
+            let left = 2 * pos + 1;
+            if left >= end {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < end && data[right] > data[left] {
+                right
+            } else {
+                left
+            };
+            if data[pos] >= data[child] {
+                break;
+            }
+            data.swap(pos, child);
+            pos = child;
+        }
+    }
+}
+
+impl<T: Ord> Default for MyBinaryHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> From<MyVec<T>> for MyBinaryHeap<T> {
+    fn from(data: MyVec<T>) -> Self {
+        Self::from_vec(data)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MyBinaryHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for MyBinaryHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
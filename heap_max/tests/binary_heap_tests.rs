@@ -0,0 +1,101 @@
+use std::cell::Cell;
+use std::cmp::Reverse;
+
+use heap_max::{MyBinaryHeap, MyMinHeap};
+use vectors::{MyVec, SimpleRng};
+
+fn random_values(seed: u64, n: usize) -> Vec<u64> {
+    let mut rng = SimpleRng::from_seed(seed);
+    (0..n).map(|_| rng.below(1_000)).collect()
+}
+
+#[test]
+fn test_peek_and_pop_on_empty() {
+    let mut heap: MyBinaryHeap<i32> = MyBinaryHeap::new();
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek(), None);
+    assert_eq!(heap.pop(), None);
+
+    heap.push(5);
+    assert_eq!(heap.peek(), Some(&5));
+    assert_eq!(heap.pop(), Some(5));
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn test_pops_in_non_increasing_order() {
+    let values = random_values(11, 2_000);
+    let mut heap = MyBinaryHeap::new();
+    for &v in &values {
+        heap.push(v);
+    }
+    assert_eq!(heap.len(), values.len());
+
+    let mut popped = Vec::new();
+    while let Some(v) = heap.pop() {
+        popped.push(v);
+    }
+    assert!(popped.windows(2).all(|w| w[0] >= w[1]));
+
+    let mut expected = values;
+    expected.sort_by(|a, b| b.cmp(a));
+    assert_eq!(popped, expected);
+}
+
+#[test]
+fn test_heapify_matches_push_one_by_one() {
+    let values = random_values(23, 777);
+
+    let heapified = MyBinaryHeap::from_vec(values.iter().copied().collect::<MyVec<_>>());
+    let mut pushed = MyBinaryHeap::new();
+    for &v in &values {
+        pushed.push(v);
+    }
+
+    // Los arreglos internos pueden diferir, pero el orden de salida no
+    let a = heapified.into_sorted_vec();
+    let b = pushed.into_sorted_vec();
+    assert_eq!(a.as_slice(), b.as_slice());
+
+    let mut expected = values;
+    expected.sort();
+    assert_eq!(a.as_slice(), expected.as_slice());
+}
+
+#[test]
+fn test_iter_and_min_heap() {
+    let heap: MyBinaryHeap<i32> = [4, 8, 1, 9].into_iter().collect();
+    let mut seen: Vec<i32> = heap.iter().copied().collect();
+    seen.sort();
+    assert_eq!(seen, [1, 4, 8, 9]);
+    assert_eq!(heap.peek(), Some(&9));
+
+    let mut min: MyMinHeap<i32> = [4, 8, 1, 9].into_iter().map(Reverse).collect();
+    assert_eq!(min.pop(), Some(Reverse(1)));
+    assert_eq!(min.pop(), Some(Reverse(4)));
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Counted<'a>(u32, &'a Cell<usize>);
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
+
+#[test]
+fn test_every_element_dropped_once() {
+    let drops = Cell::new(0);
+    {
+        let mut heap = MyBinaryHeap::new();
+        for i in 0..50 {
+            heap.push(Counted(i, &drops));
+        }
+        for _ in 0..20 {
+            heap.pop();
+        }
+        assert_eq!(drops.get(), 20);
+    }
+    assert_eq!(drops.get(), 50);
+}
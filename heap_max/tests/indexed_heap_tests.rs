@@ -0,0 +1,114 @@
+use heap_max::IndexedHeap;
+use vectors::SimpleRng;
+
+#[test]
+fn test_push_pop_in_priority_order() {
+    let mut heap = IndexedHeap::new();
+    assert_eq!(heap.pop(), None);
+    assert_eq!(heap.peek(), None);
+
+    for (k, p) in [(1, 50), (2, 10), (3, 40), (4, 30)] {
+        assert!(heap.push(k, p));
+    }
+    // Clave repetida: se rechaza
+    assert!(!heap.push(3, 1));
+    assert_eq!(heap.priority_of(3), Some(&40));
+    assert_eq!(heap.len(), 4);
+
+    assert_eq!(heap.peek(), Some((2, &10)));
+    let order: Vec<_> = std::iter::from_fn(|| heap.pop()).collect();
+    assert_eq!(order, [(2, 10), (4, 30), (3, 40), (1, 50)]);
+    assert!(!heap.contains(2));
+}
+
+#[test]
+fn test_decrease_buried_key_pops_earlier() {
+    let mut heap = IndexedHeap::new();
+    for k in 0..100u32 {
+        heap.push(k, k * 10);
+    }
+
+    assert!(heap.decrease_key(99, 5));
+    assert_eq!(heap.priority_of(99), Some(&5));
+    assert_eq!(heap.pop(), Some((0, 0)));
+    assert_eq!(heap.pop(), Some((99, 5)));
+    assert_eq!(heap.pop(), Some((1, 10)));
+}
+
+#[test]
+fn test_increase_or_missing_is_rejected() {
+    let mut heap = IndexedHeap::new();
+    heap.push("a", 10);
+    heap.push("b", 20);
+
+    assert!(!heap.decrease_key("a", 15));
+    assert!(!heap.decrease_key("a", 10));
+    assert!(!heap.decrease_key("zzz", 0));
+    assert_eq!(heap.priority_of("a"), Some(&10));
+    assert_eq!(heap.priority_of("zzz"), None);
+    assert_eq!(heap.pop(), Some(("a", 10)));
+}
+
+/// Cola de prioridad ingenua: busca el mínimo recorriendo todo.
+#[derive(Default)]
+struct NaiveQueue {
+    items: Vec<(u64, u64)>,
+}
+
+impl NaiveQueue {
+    fn push(&mut self, key: u64, priority: u64) -> bool {
+        if self.items.iter().any(|&(k, _)| k == key) {
+            return false;
+        }
+        self.items.push((key, priority));
+        true
+    }
+
+    fn decrease_key(&mut self, key: u64, priority: u64) -> bool {
+        match self.items.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, p)) if priority < *p => {
+                *p = priority;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn min_priority(&self) -> Option<u64> {
+        self.items.iter().map(|&(_, p)| p).min()
+    }
+
+    fn remove(&mut self, key: u64) -> Option<u64> {
+        let i = self.items.iter().position(|&(k, _)| k == key)?;
+        Some(self.items.swap_remove(i).1)
+    }
+}
+
+#[test]
+fn test_random_operations_against_naive_queue() {
+    let mut rng = SimpleRng::from_seed(0x1D3A);
+    let mut heap = IndexedHeap::new();
+    let mut naive = NaiveQueue::default();
+
+    for _ in 0..10_000 {
+        let key = rng.below(200);
+        let priority = rng.below(1_000);
+        match rng.below(4) {
+            0 => assert_eq!(heap.push(key, priority), naive.push(key, priority)),
+            1 | 2 => assert_eq!(heap.decrease_key(key, priority), naive.decrease_key(key, priority)),
+            _ => {
+                // Con prioridades empatadas cualquiera de las claves es válida:
+                // se verifica que la sacada tenga la prioridad mínima
+                let expected = naive.min_priority();
+                match heap.pop() {
+                    Some((k, p)) => {
+                        assert_eq!(Some(p), expected);
+                        assert_eq!(naive.remove(k), Some(p));
+                    }
+                    None => assert_eq!(expected, None),
+                }
+            }
+        }
+        assert_eq!(heap.len(), naive.items.len());
+    }
+}
@@ -0,0 +1,383 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+/*
+Lista doblemente enlazada con punteros crudos
+
+Cada nodo apunta al anterior y al siguiente:
+
+             ┌──────┐    ┌──────┐    ┌──────┐
+    head ──► │  1   │ ◄─►│  2   │ ◄─►│  3   │ ◄── tail
+             └──────┘    └──────┘    └──────┘
+
+Con `Box` esto no se puede: un nodo tendría dos dueños (el anterior y el
+siguiente). `Rc<RefCell<..>>` funciona pero paga conteo de referencias y
+chequeos en tiempo de ejecución. Aquí la LISTA es dueña de todos los nodos y
+los enlaces son `NonNull` sin dueño:
+
+- Cada nodo se crea con `Box::new` y se convierte a puntero crudo con
+  `Box::into_raw`; se libera exactamente una vez con `Box::from_raw` al
+  desengancharlo (pop, remove o Drop).
+- Mientras la lista viva, todos los punteros en head/tail/prev/next apuntan a
+  nodos vivos. Cada método `unsafe` de abajo se apoya en esa invariante.
+- `PhantomData<Box<Node<T>>>` le dice al compilador que la lista es dueña de
+  valores `T` (drop check y varianza, igual que en MyVec).
+*/
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    prev: Link<T>,
+    next: Link<T>,
+}
+
+/// Lista doblemente enlazada con punteros `NonNull`.
+///
+/// ```
+/// use linked_list::MyDoublyLinkedList;
+///
+/// let mut list = MyDoublyLinkedList::new();
+/// list.push_back(2);
+/// list.push_front(1);
+/// list.push_back(3);
+/// assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+/// ```
+pub struct MyDoublyLinkedList<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _marker: PhantomData<Box<Node<T>>>,
+}
+
+// SAFETY: la lista es la única dueña de sus nodos, como un `Vec<T>`.
+unsafe impl<T: Send> Send for MyDoublyLinkedList<T> {}
+unsafe impl<T: Sync> Sync for MyDoublyLinkedList<T> {}
+
+impl<T> MyDoublyLinkedList<T> {
+    /// Crea una lista vacía.
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reserva un nodo en el heap y retorna un puntero sin dueño a él.
+    fn new_node(value: T, prev: Link<T>, next: Link<T>) -> NonNull<Node<T>> {
+        let node = Box::new(Node { value, prev, next });
+        // Box nunca es nulo
+        NonNull::from(Box::leak(node))
+    }
+
+    /// Inserta `value` al inicio.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn push_front(&mut self, value: T) {
+        let node = Self::new_node(value, None, self.head);
+        match self.head {
+            Some(old) => unsafe { (*old.as_ptr()).prev = Some(node) },
+            None => self.tail = Some(node),
+        }
+        self.head = Some(node);
+        self.len += 1;
+    }
+
+    /// Inserta `value` al final.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn push_back(&mut self, value: T) {
+        let node = Self::new_node(value, self.tail, None);
+        match self.tail {
+            Some(old) => unsafe { (*old.as_ptr()).next = Some(node) },
+            None => self.head = Some(node),
+        }
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Desengancha `node` de la lista y retorna su valor, liberando el nodo.
+    ///
+    /// # Safety
+    /// `node` debe ser un nodo vivo de esta lista.
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        // Se recupera el Box: al salir de esta función se libera el nodo
+        let boxed = unsafe { Box::from_raw(node.as_ptr()) };
+
+        match boxed.prev {
+            Some(prev) => unsafe { (*prev.as_ptr()).next = boxed.next },
+            None => self.head = boxed.next,
+        }
+        match boxed.next {
+            Some(next) => unsafe { (*next.as_ptr()).prev = boxed.prev },
+            None => self.tail = boxed.prev,
+        }
+
+        self.len -= 1;
+        boxed.value
+    }
+
+    /// Quita y retorna el primer elemento.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.map(|node| unsafe { self.unlink(node) })
+    }
+
+    /// Quita y retorna el último elemento.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.map(|node| unsafe { self.unlink(node) })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si la lista está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna un iterador de doble extremo sobre `&T`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Retorna un cursor posicionado en el primer elemento (o en la posición
+    /// "fantasma" si la lista está vacía).
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let index = if self.head.is_some() { Some(0) } else { None };
+        CursorMut {
+            current: self.head,
+            index,
+            list: self,
+        }
+    }
+
+    /// Retorna un cursor posicionado en el último elemento.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let index = self.len.checked_sub(1);
+        CursorMut {
+            current: self.tail,
+            index,
+            list: self,
+        }
+    }
+}
+
+impl<T> Drop for MyDoublyLinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Default for MyDoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyDoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for MyDoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for value in iter {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+/// Iterador de doble extremo sobre `&T`, creado por [`MyDoublyLinkedList::iter`].
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    // Los extremos se cruzan sin que ningún puntero sea None: se cuenta
+    remaining: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.front.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.remaining -= 1;
+            self.front = node.next;
+            &node.value
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.back.map(|node| unsafe {
+            let node = &*node.as_ptr();
+            self.remaining -= 1;
+            self.back = node.prev;
+            &node.value
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a MyDoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Cursor que puede moverse por la lista y modificarla en O(1).
+///
+/// Además de los elementos hay una posición "fantasma" entre el último y el
+/// primero (`current()` retorna `None` ahí). Avanzar desde el último lleva al
+/// fantasma, y avanzar desde el fantasma lleva al primero.
+///
+/// ```
+/// use linked_list::MyDoublyLinkedList;
+///
+/// let mut list: MyDoublyLinkedList<i32> = [1, 3].into_iter().collect();
+/// let mut cursor = list.cursor_front_mut();
+/// cursor.insert_after(2);
+/// cursor.move_next();
+/// assert_eq!(cursor.current(), Some(&mut 2));
+/// assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+/// ```
+pub struct CursorMut<'a, T> {
+    list: &'a mut MyDoublyLinkedList<T>,
+    current: Link<T>,
+    index: Option<usize>,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Retorna la posición del cursor, o `None` en la posición fantasma.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Retorna el elemento bajo el cursor.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Avanza al siguiente elemento.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { (*node.as_ptr()).next };
+                self.index = match self.current {
+                    Some(_) => self.index.map(|i| i + 1),
+                    None => None,
+                };
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = self.current.map(|_| 0);
+            }
+        }
+    }
+
+    /// Retrocede al elemento anterior.
+    pub fn move_prev(&mut self) {
+        match self.current {
+            Some(node) => {
+                self.current = unsafe { (*node.as_ptr()).prev };
+                self.index = match self.current {
+                    Some(_) => self.index.map(|i| i - 1),
+                    None => None,
+                };
+            }
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.checked_sub(1);
+            }
+        }
+    }
+
+    /// Inserta `value` justo después del cursor, sin moverlo.
+    ///
+    /// En la posición fantasma inserta al inicio de la lista.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn insert_after(&mut self, value: T) {
+        let Some(node) = self.current else {
+            self.list.push_front(value);
+            return;
+        };
+
+        unsafe {
+            let next = (*node.as_ptr()).next;
+            let new = MyDoublyLinkedList::new_node(value, Some(node), next);
+            (*node.as_ptr()).next = Some(new);
+            match next {
+                Some(next) => (*next.as_ptr()).prev = Some(new),
+                None => self.list.tail = Some(new),
+            }
+        }
+        self.list.len += 1;
+    }
+
+    /// Quita el elemento bajo el cursor y lo retorna; el cursor pasa al
+    /// siguiente (o al fantasma si era el último).
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+
+        self.current = unsafe { (*node.as_ptr()).next };
+        if self.current.is_none() {
+            self.index = None;
+        }
+        // SAFETY: `node` es un nodo vivo de la lista que el cursor presta en exclusiva
+        Some(unsafe { self.list.unlink(node) })
+    }
+}
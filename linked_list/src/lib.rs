@@ -0,0 +1,270 @@
+use std::fmt;
+
+mod doubly;
+mod persistent;
+
+pub use doubly::{CursorMut, Iter as DoublyIter, MyDoublyLinkedList};
+pub use persistent::PList;
+
+/*
+Lista simplemente enlazada con Box
+
+Cada nodo es dueño del siguiente a través de un `Box`; la lista solo guarda
+la cabeza:
+
+    head ──► [ 1 | ●─]──► [ 2 | ●─]──► [ 3 | None ]
+
+`Option<Box<Node<T>>>` no ocupa más que un puntero: `None` se representa con
+el puntero nulo (el Box nunca es nulo), así que el `Option` sale gratis.
+*/
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+/// Lista simplemente enlazada de nodos `Box<Node<T>>`.
+///
+/// ```
+/// use linked_list::MyLinkedList;
+///
+/// let mut list: MyLinkedList<i32> = [2, 3].into_iter().collect();
+/// list.push_front(1);
+/// list.reverse();
+/// assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+/// ```
+pub struct MyLinkedList<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> MyLinkedList<T> {
+    /// Crea una lista vacía.
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// Inserta `value` al inicio.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(Node { value, next }));
+        self.len += 1;
+    }
+
+    /// Quita y retorna el primer elemento.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Inserta `value` al final.
+    ///
+    /// # Complejidad
+    /// **O(n)** - La lista no guarda un puntero a la cola, así que hay que
+    /// recorrerla entera. Para inserciones frecuentes al final conviene una
+    /// lista doblemente enlazada.
+    pub fn push_back(&mut self, value: T) {
+        // `cursor` apunta al enlace vacío donde va el nodo nuevo
+        let mut cursor = &mut self.head;
+        while let Some(node) = cursor {
+            cursor = &mut node.next;
+        }
+
+        *cursor = Some(Box::new(Node { value, next: None }));
+        self.len += 1;
+    }
+
+    /// Retorna una referencia al primer elemento.
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si la lista está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Invierte la lista en su lugar redirigiendo los enlaces.
+    ///
+    /// No mueve ni copia valores: cada nodo se desengancha del frente de la
+    /// lista original y se engancha al frente de la invertida.
+    ///
+    /// ```text
+    /// reversed: None          rest: 1 → 2 → 3
+    /// reversed: 1             rest: 2 → 3
+    /// reversed: 2 → 1         rest: 3
+    /// reversed: 3 → 2 → 1     rest: None
+    /// ```
+    ///
+    /// # Complejidad
+    /// **O(n)** tiempo, **O(1)** memoria extra.
+    pub fn reverse(&mut self) {
+        let mut reversed: Link<T> = None;
+        let mut rest = self.head.take();
+
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+
+        self.head = reversed;
+    }
+
+    /// Retorna un iterador sobre `&T` del inicio al final.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Retorna un iterador sobre `&mut T` del inicio al final.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            next: self.head.as_deref_mut(),
+        }
+    }
+}
+
+/*
+¿Por qué Drop a mano?
+
+El Drop automático de la lista destruye `head`, cuyo Drop destruye su `next`,
+cuyo Drop destruye su `next`... una llamada anidada por nodo. Con un millón de
+nodos eso desborda el stack. Aquí se desengancha cada nodo antes de soltarlo,
+así que cada Box se destruye con `next = None` y no hay recursión.
+*/
+impl<T> Drop for MyLinkedList<T> {
+    fn drop(&mut self) {
+        let mut cursor = self.head.take();
+        while let Some(mut node) = cursor {
+            cursor = node.next.take();
+        }
+    }
+}
+
+impl<T> Default for MyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for MyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for MyLinkedList<T> {}
+
+impl<T> FromIterator<T> for MyLinkedList<T> {
+    /// Construye la lista en el mismo orden que el iterador, en O(n).
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        // Se mantiene un cursor al último enlace para no recorrer cada vez
+        let mut tail = &mut list.head;
+        for value in iter {
+            let node = tail.insert(Box::new(Node { value, next: None }));
+            tail = &mut node.next;
+            list.len += 1;
+        }
+        list
+    }
+}
+
+/// Iterador sobre `&T`, creado por [`MyLinkedList::iter`].
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+/// Iterador sobre `&mut T`, creado por [`MyLinkedList::iter_mut`].
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        // `take` deja None mientras separamos el &mut del nodo actual
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.value
+        })
+    }
+}
+
+/// Iterador que consume la lista, creado por `into_iter`.
+pub struct IntoIter<T>(MyLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> IntoIterator for MyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut MyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,261 @@
+use std::fmt;
+use std::rc::Rc;
+
+/*
+Lista persistente (inmutable) con Rc
+
+Ninguna operación modifica una lista existente: `cons` crea un nodo nuevo que
+apunta a la lista anterior. Como los nodos nunca cambian, varias listas pueden
+compartir la misma cola sin copiarla; `Rc` lleva la cuenta de cuántas listas
+usan cada nodo y lo libera cuando la última desaparece.
+
+    let base = [3, 4]
+    let a = cons(1, base)
+    let b = cons(2, base)
+
+    a ──► [1] ──┐
+                ├──► [3] ──► [4]        el nodo [3] tiene strong_count = 3
+    b ──► [2] ──┘    ▲                  (base, a y b)
+                     │
+    base ────────────┘
+*/
+
+struct Node<T> {
+    value: T,
+    next: Option<Rc<Node<T>>>,
+}
+
+/// Lista enlazada persistente cuyos nodos se comparten con `Rc`.
+///
+/// Clonar una `PList` es O(1): solo incrementa un contador.
+///
+/// ```
+/// use linked_list::PList;
+///
+/// let base: PList<i32> = [3, 4].into_iter().collect();
+/// let a = PList::cons(1, &base);
+/// let b = PList::cons(2, &base);
+/// assert_eq!(a.tail(), b.tail());
+/// assert_eq!(base.len(), 2);
+/// ```
+pub struct PList<T> {
+    head: Option<Rc<Node<T>>>,
+    len: usize,
+}
+
+impl<T> PList<T> {
+    /// Crea una lista vacía.
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    /// Crea una lista nueva con `head` al frente y `tail` como cola compartida.
+    ///
+    /// # Complejidad
+    /// **O(1)** - No copia `tail`, solo incrementa su contador.
+    pub fn cons(head: T, tail: &PList<T>) -> PList<T> {
+        PList {
+            head: Some(Rc::new(Node {
+                value: head,
+                next: tail.head.clone(),
+            })),
+            len: tail.len + 1,
+        }
+    }
+
+    /// Retorna el primer elemento.
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Retorna la lista sin su primer elemento (compartiendo los nodos).
+    ///
+    /// La cola de una lista vacía es la lista vacía.
+    pub fn tail(&self) -> PList<T> {
+        match &self.head {
+            Some(node) => PList {
+                head: node.next.clone(),
+                len: self.len - 1,
+            },
+            None => PList::new(),
+        }
+    }
+
+    /// Retorna la cantidad de elementos en O(1).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si la lista está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// Retorna un iterador sobre `&T` del inicio al final.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// Retorna cuántas referencias fuertes tiene el primer nodo (0 si la
+    /// lista está vacía): cuántas listas, o nodos de otras listas, lo comparten.
+    pub fn strong_count(&self) -> usize {
+        self.head.as_ref().map_or(0, Rc::strong_count)
+    }
+
+    /// Retorna los nodos de la lista en orden, para recorrerlos por índice.
+    fn nodes(&self) -> Vec<&Rc<Node<T>>> {
+        let mut nodes = Vec::with_capacity(self.len);
+        let mut cursor = self.head.as_ref();
+        while let Some(node) = cursor {
+            nodes.push(node);
+            cursor = node.next.as_ref();
+        }
+        nodes
+    }
+
+    /// Reconstruye una lista: los `prefix` nuevos van delante de `shared`,
+    /// que es un sufijo de `self` reutilizado sin copiar.
+    fn rebuild(prefix: Vec<T>, shared: Option<&Rc<Node<T>>>, shared_len: usize) -> PList<T> {
+        let mut list = PList {
+            head: shared.cloned(),
+            len: shared_len,
+        };
+        for value in prefix.into_iter().rev() {
+            list = PList::cons(value, &list);
+        }
+        list
+    }
+
+    /// Retorna una lista nueva con `f` aplicada a cada elemento.
+    ///
+    /// El sufijo más largo en el que `f(x) == x` para todos los elementos se
+    /// comparte con `self` en lugar de copiarse.
+    ///
+    /// # Complejidad
+    /// **O(n)** llamadas a `f`; solo se crean nodos para el prefijo que cambió.
+    pub fn map<F: FnMut(&T) -> T>(&self, mut f: F) -> PList<T>
+    where
+        T: PartialEq,
+    {
+        let nodes = self.nodes();
+        let mut mapped: Vec<T> = nodes.iter().map(|node| f(&node.value)).collect();
+
+        // Primer índice desde el que nada cambió
+        let mut start = nodes.len();
+        while start > 0 && mapped[start - 1] == nodes[start - 1].value {
+            start -= 1;
+        }
+
+        mapped.truncate(start);
+        Self::rebuild(mapped, nodes.get(start).copied(), self.len - start)
+    }
+
+    /// Retorna una lista nueva solo con los elementos que cumplen `pred`.
+    ///
+    /// El sufijo más largo en el que todos cumplen se comparte con `self`.
+    pub fn filter<F: FnMut(&T) -> bool>(&self, mut pred: F) -> PList<T>
+    where
+        T: Clone,
+    {
+        let nodes = self.nodes();
+        let keep: Vec<bool> = nodes.iter().map(|node| pred(&node.value)).collect();
+
+        let mut start = nodes.len();
+        while start > 0 && keep[start - 1] {
+            start -= 1;
+        }
+
+        let prefix = nodes[..start]
+            .iter()
+            .zip(&keep)
+            .filter(|(_, kept)| **kept)
+            .map(|(node, _)| node.value.clone())
+            .collect();
+        Self::rebuild(prefix, nodes.get(start).copied(), self.len - start)
+    }
+}
+
+/*
+Drop iterativo
+
+Igual que en la lista simple, el Drop automático sería recursivo. Además aquí
+solo podemos soltar un nodo si somos su ÚLTIMO dueño: `Rc::try_unwrap` nos da
+el nodo solo en ese caso. Si otra lista comparte el resto, nos detenemos y
+dejamos que esa lista lo libere cuando le toque.
+*/
+impl<T> Drop for PList<T> {
+    fn drop(&mut self) {
+        let mut cursor = self.head.take();
+        while let Some(node) = cursor {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => cursor = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+impl<T> Clone for PList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PList<T> {
+    /// Compara elemento por elemento (igualdad estructural, no de punteros).
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PList<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for PList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for PList<T> {
+    /// Construye la lista en el mismo orden que el iterador.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let values: Vec<T> = iter.into_iter().collect();
+        Self::rebuild(values, None, 0)
+    }
+}
+
+/// Iterador sobre `&T`, creado por [`PList::iter`].
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
@@ -0,0 +1,143 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use linked_list::{DoublyIter, MyDoublyLinkedList};
+
+fn items(list: &MyDoublyLinkedList<i32>) -> Vec<i32> {
+    let iter: DoublyIter<'_, i32> = list.iter();
+    iter.copied().collect()
+}
+
+#[test]
+fn test_empty_list_edge_cases() {
+    let mut list: MyDoublyLinkedList<i32> = MyDoublyLinkedList::new();
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.pop_back(), None);
+    assert_eq!(list.front(), None);
+    assert_eq!(list.back_mut(), None);
+    assert_eq!(list.iter().next(), None);
+
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.remove_current(), None);
+    cursor.move_next();
+    cursor.move_prev();
+    assert_eq!(cursor.index(), None);
+
+    // Insertar desde el fantasma agrega al inicio
+    cursor.insert_after(1);
+    assert_eq!(items(&list), vec![1]);
+}
+
+#[test]
+fn test_push_pop_both_ends() {
+    let mut list = MyDoublyLinkedList::new();
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.front(), Some(&1));
+    assert_eq!(list.back(), Some(&3));
+    *list.front_mut().unwrap() = 10;
+    assert_eq!(format!("{list:?}"), "[10, 2, 3]");
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![3, 2, 10]);
+
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.pop_front(), Some(10));
+    // Un solo nodo: head y tail son el mismo
+    assert_eq!(list.front(), list.back());
+    assert_eq!(list.pop_back(), Some(2));
+    assert!(list.is_empty());
+    assert_eq!(list.front(), None);
+    assert_eq!(list.back(), None);
+
+    list.push_front(5);
+    assert_eq!(items(&list), vec![5]);
+}
+
+#[test]
+fn test_iter_meets_in_the_middle() {
+    let list: MyDoublyLinkedList<i32> = (1..=5).collect();
+    let mut it = list.iter();
+
+    assert_eq!(it.next(), Some(&1));
+    assert_eq!(it.next_back(), Some(&5));
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(&2));
+    assert_eq!(it.next_back(), Some(&4));
+    assert_eq!(it.next(), Some(&3));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn test_cursor_splicing() {
+    let mut list: MyDoublyLinkedList<i32> = [1, 2, 4].into_iter().collect();
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    assert_eq!(cursor.index(), Some(1));
+    cursor.insert_after(3);
+    cursor.move_prev();
+    assert_eq!(cursor.remove_current(), Some(1));
+    // Tras quitar, el cursor queda sobre el siguiente
+    assert_eq!(cursor.current(), Some(&mut 2));
+    assert_eq!(items(&list), vec![2, 3, 4]);
+    assert_eq!(list.front(), Some(&2));
+}
+
+#[test]
+fn test_cursor_removes_last_node() {
+    let mut list: MyDoublyLinkedList<i32> = [1, 2].into_iter().collect();
+
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(cursor.remove_current(), Some(2));
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.index(), None);
+
+    // Desde el fantasma, retroceder lleva al nuevo último
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&mut 1));
+    cursor.insert_after(3);
+    assert_eq!(list.back(), Some(&3));
+    assert_eq!(items(&list), vec![1, 3]);
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.remove_current();
+    cursor.remove_current();
+    assert!(list.is_empty());
+    assert_eq!(list.back(), None);
+}
+
+#[test]
+fn test_drop_accounting() {
+    struct Counted(Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let mut list = MyDoublyLinkedList::new();
+    for i in 0..6 {
+        if i % 2 == 0 {
+            list.push_back(Counted(Rc::clone(&drops)));
+        } else {
+            list.push_front(Counted(Rc::clone(&drops)));
+        }
+    }
+
+    drop(list.pop_front());
+    drop(list.pop_back());
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    drop(cursor.remove_current());
+    assert_eq!(drops.get(), 3);
+
+    drop(list);
+    assert_eq!(drops.get(), 6);
+}
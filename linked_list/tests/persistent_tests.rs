@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use linked_list::PList;
+
+fn items(list: &PList<i32>) -> Vec<i32> {
+    list.iter().copied().collect()
+}
+
+#[test]
+fn test_cons_head_tail_len() {
+    let empty: PList<i32> = PList::new();
+    assert!(empty.is_empty());
+    assert_eq!(empty.head(), None);
+    assert!(empty.tail().is_empty());
+
+    let list = PList::cons(1, &PList::cons(2, &PList::cons(3, &empty)));
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.head(), Some(&1));
+    assert_eq!(items(&list.tail()), vec![2, 3]);
+    assert_eq!(list, [1, 2, 3].into_iter().collect());
+    assert_ne!(list, list.tail());
+    assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+}
+
+#[test]
+fn test_shared_suffix_is_not_cloned() {
+    let base: PList<Rc<String>> = ["c", "d"].iter().map(|s| Rc::new(s.to_string())).collect();
+    assert_eq!(base.strong_count(), 1);
+
+    let a = PList::cons(Rc::new("a".to_string()), &base);
+    let b = PList::cons(Rc::new("b".to_string()), &base);
+
+    // El nodo de "c" ahora lo apuntan base, a y b
+    assert_eq!(base.strong_count(), 3);
+    // ...y el valor dentro nunca se clonó
+    assert_eq!(Rc::strong_count(base.head().unwrap()), 1);
+
+    drop(a);
+    assert_eq!(base.strong_count(), 2);
+    drop(base);
+    let rest: Vec<&str> = b.iter().map(|s| s.as_str()).collect();
+    assert_eq!(rest, ["b", "c", "d"]);
+    assert_eq!(b.tail().strong_count(), 2);
+}
+
+#[test]
+fn test_map_and_filter_share_unchanged_suffix() {
+    let list: PList<i32> = (1..=6).collect();
+
+    // Solo cambian los dos primeros: [4, 5, 6] sigue siendo el mismo sufijo
+    let mapped = list.map(|&x| if x <= 2 { x * 10 } else { x });
+    assert_eq!(items(&mapped), vec![10, 20, 3, 4, 5, 6]);
+    let shared_suffix = list.tail().tail();
+    assert_eq!(shared_suffix.strong_count(), 3);
+
+    let filtered = list.filter(|&x| x != 2);
+    assert_eq!(items(&filtered), vec![1, 3, 4, 5, 6]);
+    assert_eq!(shared_suffix.strong_count(), 4);
+
+    // Nada cambió: el resultado es la misma lista, sin nodos nuevos
+    let unchanged = list.map(|&x| x);
+    assert_eq!(list.strong_count(), 2);
+    assert_eq!(unchanged, list);
+}
+
+#[test]
+fn test_drop_long_list_does_not_overflow_stack() {
+    let mut list = PList::new();
+    for i in 0..1_000_000 {
+        list = PList::cons(i, &list);
+    }
+    let shared = list.tail();
+    drop(list);
+    assert_eq!(shared.len(), 999_999);
+    drop(shared);
+}
@@ -0,0 +1,68 @@
+use linked_list::MyLinkedList;
+
+fn items<T: Clone>(list: &MyLinkedList<T>) -> Vec<T> {
+    list.iter().cloned().collect()
+}
+
+#[test]
+fn test_push_pop_front_and_back() {
+    let mut list = MyLinkedList::new();
+    assert!(list.is_empty());
+    assert_eq!(list.pop_front(), None);
+
+    list.push_back(2);
+    list.push_front(1);
+    list.push_back(3);
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.front(), Some(&1));
+    assert_eq!(items(&list), vec![1, 2, 3]);
+
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.pop_front(), None);
+    assert_eq!(list.len(), 0);
+}
+
+#[test]
+fn test_from_iter_iter_mut_and_into_iter() {
+    let mut list: MyLinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(list.len(), 3);
+
+    for s in &mut list {
+        s.push('!');
+    }
+    assert_eq!(format!("{list:?}"), r#"["a!", "b!", "c!"]"#);
+
+    let expected: MyLinkedList<String> = ["a!", "b!", "c!"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(list, expected);
+
+    let owned: Vec<String> = list.into_iter().collect();
+    assert_eq!(owned, ["a!", "b!", "c!"]);
+}
+
+#[test]
+fn test_reverse_in_place() {
+    let mut list: MyLinkedList<i32> = (1..=5).collect();
+    list.reverse();
+    assert_eq!(items(&list), vec![5, 4, 3, 2, 1]);
+    assert_eq!(list.len(), 5);
+
+    let mut empty: MyLinkedList<i32> = MyLinkedList::new();
+    empty.reverse();
+    assert!(empty.is_empty());
+
+    let mut single: MyLinkedList<i32> = std::iter::once(7).collect();
+    single.reverse();
+    assert_eq!(items(&single), vec![7]);
+}
+
+#[test]
+fn test_drop_long_list_does_not_overflow_stack() {
+    let mut list = MyLinkedList::new();
+    for i in 0..1_000_000 {
+        list.push_front(i);
+    }
+    assert_eq!(list.len(), 1_000_000);
+    drop(list);
+}
@@ -0,0 +1,307 @@
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut};
+use std::ptr::{self, NonNull};
+use std::slice;
+
+/// Cola de doble extremo sobre un buffer circular, al estilo de `VecDeque`.
+///
+/// A diferencia de [`MyQueue`](crate::MyQueue), se puede meter y sacar por
+/// ambos extremos en O(1): `head` retrocede (módulo la capacidad) en
+/// `push_front` y avanza en `pop_front`.
+///
+/// ```
+/// use queue::MyDeque;
+///
+/// let mut d = MyDeque::new();
+/// d.push_back(2);
+/// d.push_front(1);
+/// d.push_back(3);
+/// assert_eq!(d[0], 1);
+/// assert_eq!(d.pop_back(), Some(3));
+/// assert_eq!(d.make_contiguous(), &mut [1, 2]);
+/// ```
+pub struct MyDeque<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    head: usize,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: igual que `MyVec`, el deque es el único dueño de su bloque.
+unsafe impl<T: Send> Send for MyDeque<T> {}
+unsafe impl<T: Sync> Sync for MyDeque<T> {}
+
+impl<T> MyDeque<T> {
+    /// Crea un deque vacío (sin reservar memoria).
+    pub fn new() -> Self {
+        // Los tipos de tamaño cero nunca necesitan memoria: capacidad infinita
+        let cap = if mem::size_of::<T>() == 0 { usize::MAX } else { 0 };
+        Self {
+            ptr: NonNull::dangling(),
+            cap,
+            head: 0,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Retorna la cantidad de slots reservados.
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si el deque no tiene elementos.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Convierte una posición lógica (0 = frente) en un índice del buffer.
+    fn physical(&self, logical: usize) -> usize {
+        // Con tamaño cero todos los slots son la misma dirección
+        if mem::size_of::<T>() == 0 {
+            return 0;
+        }
+
+        // head < cap y logical <= cap, así que la suma no pasa de 2·cap
+        let i = self.head + logical;
+        if i >= self.cap { i - self.cap } else { i }
+    }
+
+    fn slot(&self, physical: usize) -> *mut T {
+        unsafe { self.ptr.as_ptr().add(physical) }
+    }
+
+    /// Duplica la capacidad, re-linealizando los elementos desde el índice 0.
+    fn grow(&mut self) {
+        let new_cap = (self.cap * 2).max(4);
+        let new_layout = Layout::array::<T>(new_cap).expect("capacity overflow");
+        let new_ptr = match NonNull::new(unsafe { alloc(new_layout) } as *mut T) {
+            Some(p) => p,
+            None => handle_alloc_error(new_layout),
+        };
+
+        if self.cap > 0 {
+            // El tramo [head, cap) va primero y el tramo dado vuelta [0, resto) detrás
+            let first = self.len.min(self.cap - self.head);
+            unsafe {
+                ptr::copy_nonoverlapping(self.slot(self.head), new_ptr.as_ptr(), first);
+                ptr::copy_nonoverlapping(self.slot(0), new_ptr.as_ptr().add(first), self.len - first);
+                dealloc(self.ptr.as_ptr() as *mut u8, Layout::array::<T>(self.cap).unwrap());
+            }
+        }
+
+        self.ptr = new_ptr;
+        self.cap = new_cap;
+        self.head = 0;
+    }
+
+    /// Agrega `elem` al final.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado
+    pub fn push_back(&mut self, elem: T) {
+        if self.len == self.cap {
+            self.grow();
+        }
+
+        unsafe { ptr::write(self.slot(self.physical(self.len)), elem) };
+        self.len += 1;
+    }
+
+    /// Agrega `elem` al frente.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado
+    pub fn push_front(&mut self, elem: T) {
+        if self.len == self.cap {
+            self.grow();
+        }
+
+        // Retroceder una posición dando la vuelta si head está en 0
+        self.head = if self.head == 0 { self.cap - 1 } else { self.head - 1 };
+        unsafe { ptr::write(self.slot(self.head), elem) };
+        self.len += 1;
+    }
+
+    /// Saca y retorna el elemento del frente.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let elem = unsafe { ptr::read(self.slot(self.head)) };
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(elem)
+    }
+
+    /// Saca y retorna el elemento del final.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(unsafe { ptr::read(self.slot(self.physical(self.len))) })
+    }
+
+    /// Retorna una referencia al elemento en la posición lógica `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(unsafe { &*self.slot(self.physical(index)) })
+    }
+
+    /// Retorna una referencia mutable al elemento en la posición lógica `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(unsafe { &mut *self.slot(self.physical(index)) })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|last| self.get(last))
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        let last = self.len.checked_sub(1)?;
+        self.get_mut(last)
+    }
+
+    /// Reacomoda el buffer para que los elementos queden contiguos y en orden,
+    /// y los retorna como un slice mutable.
+    ///
+    /// Si ya estaban contiguos no mueve nada.
+    ///
+    /// # Complejidad
+    /// **O(capacidad)** en el peor caso (deque dado vuelta).
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if mem::size_of::<T>() != 0 && self.head + self.len > self.cap {
+            /*
+            Dado vuelta:  [ c d . . a b ]  head = 4
+            rotate_left(head) lleva la posición `head` al 0:
+                          [ a b c d . . ]
+            Se rota como MaybeUninit<T>: solo se mueven bytes, sin leer ni
+            destruir los slots vacíos.
+            */
+            let buf = unsafe {
+                slice::from_raw_parts_mut(self.ptr.as_ptr() as *mut MaybeUninit<T>, self.cap)
+            };
+            buf.rotate_left(self.head);
+            self.head = 0;
+        }
+
+        unsafe { slice::from_raw_parts_mut(self.slot(self.head), self.len) }
+    }
+
+    /// Retorna un iterador del frente al final.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            deque: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+}
+
+impl<T> Drop for MyDeque<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+
+        if self.cap > 0 && mem::size_of::<T>() != 0 {
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, Layout::array::<T>(self.cap).unwrap()) };
+        }
+    }
+}
+
+impl<T> Default for MyDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<usize> for MyDeque<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Si `index >= len`.
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("index {index} out of bounds for deque of length {len}"))
+    }
+}
+
+impl<T> IndexMut<usize> for MyDeque<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index {index} out of bounds for deque of length {len}"))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyDeque<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Iterador del frente al final sobre `&T`, creado por [`MyDeque::iter`].
+pub struct Iter<'a, T> {
+    deque: &'a MyDeque<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let elem = self.deque.get(self.front);
+        self.front += 1;
+        elem
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.deque.get(self.back)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use vectors::MyVec;
+
+mod deque;
+mod ring_buffer;
+mod two_stack;
+
+pub use deque::{Iter as DequeIter, MyDeque};
+pub use ring_buffer::{Iter as RingBufferIter, RingBuffer};
+pub use two_stack::TwoStackQueue;
+
+/*
+Buffer circular (ring buffer)
+
+Una cola sobre un vector simple, donde `dequeue` hace `remove(0)`, cuesta O(n)
+porque hay que correr todos los elementos a la izquierda. El buffer circular
+evita mover nada: guarda el índice `head` del primer elemento y la cantidad
+`len`; las posiciones se calculan módulo la capacidad.
+
+    cap = 8, head = 6, len = 4
+
+    índice:  0   1   2   3   4   5   6   7
+           ┌───┬───┬───┬───┬───┬───┬───┬───┐
+           │ c │ d │   │   │   │   │ a │ b │
+           └───┴───┴───┴───┴───┴───┴───┴───┘
+                                     ▲
+                                    head      orden lógico: a b c d
+
+- enqueue escribe en (head + len) % cap
+- dequeue lee en head y hace head = (head + 1) % cap
+
+Al crecer no basta con copiar el bloque: los elementos "dados vuelta" (c, d)
+quedarían detrás de los del final. Hay que re-linealizarlos en orden lógico
+en el bloque nuevo, empezando en 0.
+*/
+
+/// Cola FIFO (primero en entrar, primero en salir) sobre un buffer circular.
+///
+/// ```
+/// use queue::MyQueue;
+///
+/// let mut q = MyQueue::new();
+/// q.enqueue("a");
+/// q.enqueue("b");
+/// assert_eq!(q.dequeue(), Some("a"));
+/// assert_eq!(q.front(), Some(&"b"));
+/// ```
+pub struct MyQueue<T> {
+    // Siempre buf.len() == capacidad; solo los `len` slots lógicos están inicializados
+    buf: MyVec<MaybeUninit<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> MyQueue<T> {
+    /// Crea una cola vacía (sin reservar memoria).
+    pub fn new() -> Self {
+        Self {
+            buf: MyVec::new(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Retorna la cantidad de slots reservados.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Convierte una posición lógica (0 = frente) en un índice del buffer.
+    fn physical(&self, logical: usize) -> usize {
+        (self.head + logical) % self.capacity()
+    }
+
+    /// Duplica la capacidad re-linealizando los elementos desde el índice 0.
+    fn grow(&mut self) {
+        let new_cap = (self.capacity() * 2).max(4);
+        let mut new_buf = MyVec::from_fn(new_cap, |_| MaybeUninit::uninit());
+
+        for i in 0..self.len {
+            let src = self.physical(i);
+            // Se mueve cada valor una vez; el buffer viejo es de MaybeUninit,
+            // así que al soltarse no destruye nada
+            let value = unsafe { self.buf.get(src).unwrap().assume_init_read() };
+            new_buf.as_mut_slice()[i].write(value);
+        }
+
+        self.buf = new_buf;
+        self.head = 0;
+    }
+
+    /// Agrega `elem` al final de la cola.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado - Solo crece cuando el buffer está lleno.
+    pub fn enqueue(&mut self, elem: T) {
+        if self.len == self.capacity() {
+            self.grow();
+        }
+
+        let tail = self.physical(self.len);
+        self.buf.as_mut_slice()[tail].write(elem);
+        self.len += 1;
+    }
+
+    /// Saca y retorna el elemento del frente, o `None` si la cola está vacía.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let elem = unsafe { self.buf.get(self.head).unwrap().assume_init_read() };
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(elem)
+    }
+
+    /// Retorna una referencia al elemento i-ésimo en orden FIFO (0 = frente).
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(unsafe { self.buf.get(self.physical(index)).unwrap().assume_init_ref() })
+    }
+
+    /// Retorna el elemento que saldrá primero.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Retorna el último elemento encolado.
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|last| self.get(last))
+    }
+
+    /// Retorna la cantidad de elementos en la cola.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si la cola no tiene elementos.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna un iterador en orden FIFO (del frente al final).
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            queue: self,
+            front: 0,
+            back: self.len,
+        }
+    }
+}
+
+impl<T> Drop for MyQueue<T> {
+    fn drop(&mut self) {
+        // Solo los `len` slots lógicos están vivos; el resto es basura
+        while self.dequeue().is_some() {}
+    }
+}
+
+impl<T> Default for MyQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Iterador FIFO sobre `&T`, creado por [`MyQueue::iter`].
+pub struct Iter<'a, T> {
+    queue: &'a MyQueue<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let elem = self.queue.get(self.front);
+        self.front += 1;
+        elem
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.queue.get(self.back)
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
@@ -0,0 +1,215 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::slice;
+
+use vectors::MyVec;
+
+/*
+Buffer circular de capacidad fija
+
+Misma idea que `MyQueue` (head + len, posiciones módulo la capacidad), pero la
+capacidad se decide al construirlo y NUNCA cambia: después de `with_capacity`
+no hay más llamadas al allocador. Cuando está lleno hay dos políticas:
+
+- `push`: rechaza el valor y lo devuelve en `Err`.
+- `push_overwrite`: desaloja el más viejo y lo devuelve.
+
+Como los elementos están en un solo bloque, siempre se pueden ver como a lo
+sumo DOS slices contiguos (`as_slices`):
+
+    cap = 6, head = 4, len = 4
+
+    índice:  0   1   2   3   4   5
+           ┌───┬───┬───┬───┬───┬───┐
+           │ c │ d │   │   │ a │ b │       primero: [a, b]  (head..cap)
+           └───┴───┴───┴───┴───┴───┘       segundo: [c, d]  (0..resto)
+*/
+
+/// Buffer circular de capacidad fija que no realoja después de construirse.
+///
+/// ```
+/// use queue::RingBuffer;
+///
+/// let mut rb = RingBuffer::with_capacity(2);
+/// rb.push(1).unwrap();
+/// rb.push(2).unwrap();
+/// assert_eq!(rb.push(3), Err(3));
+/// assert_eq!(rb.push_overwrite(3), Some(1));
+/// assert_eq!(rb.as_slices(), (&[2][..], &[3][..]));
+/// ```
+pub struct RingBuffer<T> {
+    // buf.len() es la capacidad; solo los `len` slots lógicos están inicializados
+    buf: MyVec<MaybeUninit<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Crea un buffer con espacio exacto para `capacity` elementos.
+    ///
+    /// Es la única reserva de memoria que hará el buffer.
+    ///
+    /// # Panics
+    /// Si `capacity` es 0.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "la capacidad de un RingBuffer debe ser mayor que 0");
+        Self {
+            buf: MyVec::from_fn(capacity, |_| MaybeUninit::uninit()),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Retorna la cantidad fija de slots.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si no hay elementos.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna `true` si no cabe ningún elemento más.
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    fn physical(&self, logical: usize) -> usize {
+        (self.head + logical) % self.capacity()
+    }
+
+    /// Agrega `elem` como el más nuevo, o lo devuelve en `Err` si el buffer
+    /// está lleno.
+    ///
+    /// # Complejidad
+    /// **O(1)**, sin asignar memoria.
+    pub fn push(&mut self, elem: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(elem);
+        }
+
+        let tail = self.physical(self.len);
+        self.buf.as_mut_slice()[tail].write(elem);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Agrega `elem` como el más nuevo; si el buffer está lleno, primero
+    /// desaloja el más viejo y lo retorna.
+    ///
+    /// # Complejidad
+    /// **O(1)**, sin asignar memoria.
+    pub fn push_overwrite(&mut self, elem: T) -> Option<T> {
+        let evicted = if self.is_full() { self.pop() } else { None };
+        // Después de sacar uno siempre hay lugar
+        let _ = self.push(elem);
+        evicted
+    }
+
+    /// Saca y retorna el elemento más viejo.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: head está dentro de los `len` slots inicializados y, al
+        // avanzar head, ese slot deja de considerarse vivo
+        let elem = unsafe { self.buf.get(self.head).unwrap().assume_init_read() };
+        self.head = self.physical(1);
+        self.len -= 1;
+        Some(elem)
+    }
+
+    /// Retorna el elemento más viejo sin sacarlo.
+    pub fn front(&self) -> Option<&T> {
+        self.as_slices().0.first()
+    }
+
+    /// Retorna el elemento más nuevo.
+    pub fn back(&self) -> Option<&T> {
+        let (first, second) = self.as_slices();
+        second.last().or(first.last())
+    }
+
+    /// Retorna los elementos como dos slices contiguos, del más viejo al más
+    /// nuevo: primero el tramo desde `head` hasta el final del bloque y luego
+    /// el que dio la vuelta al inicio (vacío si no dio la vuelta).
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let first_len = self.len.min(self.capacity() - self.head);
+        let second_len = self.len - first_len;
+        let base = self.buf.as_ptr() as *const T;
+
+        // SAFETY: MaybeUninit<T> tiene el mismo layout que T, y ambos tramos
+        // cubren exactamente los `len` slots inicializados
+        unsafe {
+            (
+                slice::from_raw_parts(base.add(self.head), first_len),
+                slice::from_raw_parts(base, second_len),
+            )
+        }
+    }
+
+    /// Retorna un iterador del más viejo al más nuevo.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (first, second) = self.as_slices();
+        Iter {
+            first: first.iter(),
+            second: second.iter(),
+        }
+    }
+
+    /// Saca todos los elementos, conservando la memoria.
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+        self.head = 0;
+    }
+}
+
+impl<T> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        // El MyVec es de MaybeUninit: hay que soltar a mano los slots vivos
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Iterador del más viejo al más nuevo, creado por [`RingBuffer::iter`].
+pub struct Iter<'a, T> {
+    first: slice::Iter<'a, T>,
+    second: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.first.next().or_else(|| self.second.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.first.len() + self.second.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.second.next_back().or_else(|| self.first.next_back())
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
@@ -0,0 +1,118 @@
+use std::fmt;
+
+use stack::MyStack;
+
+/*
+Cola con dos pilas
+
+Una pila invierte el orden; dos pilas lo invierten dos veces, es decir, lo
+dejan como estaba:
+
+    enqueue 1, 2, 3             dequeue (outbox vacía: se vuelca inbox)
+
+    inbox   outbox              inbox   outbox
+    │ 3 │   │   │               │   │   │ 1 │  ← tope: el más viejo
+    │ 2 │   │   │      ──►      │   │   │ 2 │
+    │ 1 │   │   │               │   │   │ 3 │
+
+- enqueue: push en inbox, siempre O(1).
+- dequeue: pop de outbox; si está vacía, primero se pasa TODO inbox a outbox.
+
+Un volcado puede costar O(n), pero cada elemento se mueve de inbox a outbox
+UNA sola vez en su vida: entra (1 push), se mueve (1 pop + 1 push) y sale
+(1 pop). Con n enqueue y n dequeue en cualquier orden, los movimientos
+entre pilas suman a lo sumo n: O(1) amortizado por operación.
+
+El contador `moves` existe para poder comprobar eso en un test.
+*/
+
+/// Cola FIFO construida con dos [`MyStack`].
+///
+/// ```
+/// use queue::TwoStackQueue;
+///
+/// let mut q = TwoStackQueue::new();
+/// q.enqueue(1);
+/// q.enqueue(2);
+/// assert_eq!(q.dequeue(), Some(1));
+/// q.enqueue(3);
+/// assert_eq!(q.iter().copied().collect::<Vec<_>>(), [2, 3]);
+/// ```
+pub struct TwoStackQueue<T> {
+    inbox: MyStack<T>,
+    outbox: MyStack<T>,
+    moves: usize,
+}
+
+impl<T> TwoStackQueue<T> {
+    /// Crea una cola vacía.
+    pub fn new() -> Self {
+        Self {
+            inbox: MyStack::new(),
+            outbox: MyStack::new(),
+            moves: 0,
+        }
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.inbox.len() + self.outbox.len()
+    }
+
+    /// Retorna `true` si la cola está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Cantidad total de elementos movidos de inbox a outbox desde que se
+    /// creó la cola.
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    /// Agrega `value` al final.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado.
+    pub fn enqueue(&mut self, value: T) {
+        self.inbox.push(value);
+    }
+
+    /// Quita y retorna el primer elemento.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado; **O(n)** en el peor caso, cuando vuelca inbox.
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.outbox.is_empty() {
+            while let Some(value) = self.inbox.pop() {
+                self.outbox.push(value);
+                self.moves += 1;
+            }
+        }
+        self.outbox.pop()
+    }
+
+    /// Retorna el primer elemento sin quitarlo.
+    pub fn front(&self) -> Option<&T> {
+        // Si outbox está vacía, el más viejo es el fondo de inbox
+        self.outbox.peek().or_else(|| self.inbox.iter().next_back())
+    }
+
+    /// Retorna un iterador en orden FIFO.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        // outbox del tope al fondo, después inbox del fondo al tope
+        self.outbox.iter().chain(self.inbox.iter().rev())
+    }
+}
+
+impl<T> Default for TwoStackQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for TwoStackQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,141 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use queue::{DequeIter, MyDeque};
+use vectors::SimpleRng;
+
+/// Deque de capacidad 4 con head = 2 y elementos a ambos lados del borde.
+fn wrapped() -> MyDeque<i32> {
+    let mut d = MyDeque::new();
+    for i in 0..4 {
+        d.push_back(i);
+    }
+    d.pop_front();
+    d.pop_front();
+    d.push_back(4);
+    d.push_back(5);
+    d
+}
+
+#[test]
+fn test_operations_across_wrap_point() {
+    let mut d = MyDeque::new();
+    // push_front sobre un deque vacío da la vuelta inmediatamente
+    d.push_front(1);
+    d.push_front(0);
+    d.push_back(2);
+    assert_eq!(d.capacity(), 4);
+    let iter: DequeIter<'_, i32> = d.iter();
+    assert_eq!(iter.copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+    assert_eq!((d[0], d[1], d[2]), (0, 1, 2));
+
+    *d.front_mut().unwrap() = 10;
+    *d.back_mut().unwrap() = 20;
+    d[1] = 15;
+    assert_eq!(format!("{d:?}"), "[10, 15, 20]");
+
+    assert_eq!(d.pop_back(), Some(20));
+    assert_eq!(d.pop_front(), Some(10));
+    assert_eq!(d.pop_front(), Some(15));
+    assert_eq!(d.pop_front(), None);
+    assert_eq!(d.pop_back(), None);
+    assert_eq!(d.front(), None);
+}
+
+#[test]
+fn test_growth_and_make_contiguous_on_wrapped_deque() {
+    let mut d = wrapped();
+    assert_eq!(d.make_contiguous(), &mut [2, 3, 4, 5]);
+
+    let mut d = wrapped();
+    d.push_front(1);
+    assert_eq!(d.capacity(), 8);
+    assert_eq!(d.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+    let slice = d.make_contiguous();
+    slice.sort_by(|a, b| b.cmp(a));
+    assert_eq!(d.iter().copied().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn test_index_out_of_bounds() {
+    let d = wrapped();
+    let _ = d[4];
+}
+
+#[test]
+fn test_drop_wrapped_deque_releases_each_element_once() {
+    struct Counted(Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let mut d = MyDeque::new();
+    for _ in 0..3 {
+        d.push_back(Counted(Rc::clone(&drops)));
+    }
+    for _ in 0..3 {
+        d.push_front(Counted(Rc::clone(&drops)));
+    }
+    drop(d.pop_back());
+    d.make_contiguous();
+    assert_eq!(drops.get(), 1);
+
+    drop(d);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_zero_sized_elements() {
+    let mut d = MyDeque::new();
+    for _ in 0..1000 {
+        d.push_front(());
+    }
+    assert_eq!(d.len(), 1000);
+    assert_eq!(d.pop_back(), Some(()));
+    assert_eq!(d.make_contiguous().len(), 999);
+}
+
+#[test]
+fn test_random_operations_against_vecdeque() {
+    let mut rng = SimpleRng::from_seed(0xDE0E);
+    let mut mine = MyDeque::new();
+    let mut reference = VecDeque::new();
+
+    for step in 0..5000u32 {
+        match rng.below(7) {
+            0 | 1 => {
+                mine.push_back(step);
+                reference.push_back(step);
+            }
+            2 | 3 => {
+                mine.push_front(step);
+                reference.push_front(step);
+            }
+            4 => assert_eq!(mine.pop_front(), reference.pop_front()),
+            5 => assert_eq!(mine.pop_back(), reference.pop_back()),
+            _ => {
+                if !reference.is_empty() {
+                    let i = rng.below(reference.len() as u64) as usize;
+                    assert_eq!(mine[i], reference[i]);
+                }
+                if step % 97 == 0 {
+                    assert_eq!(mine.make_contiguous(), reference.make_contiguous());
+                }
+            }
+        }
+
+        assert_eq!(mine.len(), reference.len());
+        assert_eq!(mine.front(), reference.front());
+        assert_eq!(mine.back(), reference.back());
+    }
+
+    assert!(mine.iter().eq(reference.iter()));
+    assert!(mine.iter().rev().eq(reference.iter().rev()));
+}
@@ -0,0 +1,103 @@
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+use queue::MyQueue;
+use vectors::SimpleRng;
+
+#[test]
+fn test_interleaved_operations_wrap_many_times() {
+    let mut q = MyQueue::new();
+    let mut next_in = 0;
+    let mut next_out = 0;
+
+    // Mantiene 3 elementos vivos en un buffer de 4: head da muchas vueltas
+    for _ in 0..3 {
+        q.enqueue(next_in);
+        next_in += 1;
+    }
+    for _ in 0..100 {
+        q.enqueue(next_in);
+        next_in += 1;
+        assert_eq!(q.dequeue(), Some(next_out));
+        next_out += 1;
+    }
+
+    assert_eq!(q.capacity(), 4);
+    assert_eq!(q.len(), 3);
+    assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![100, 101, 102]);
+}
+
+#[test]
+fn test_growth_while_wrapped_relinearizes() {
+    let mut q = MyQueue::new();
+    for i in 0..4 {
+        q.enqueue(i);
+    }
+    // head = 2: los siguientes dos quedan dados vuelta al inicio del buffer
+    q.dequeue();
+    q.dequeue();
+    q.enqueue(4);
+    q.enqueue(5);
+    assert_eq!(q.capacity(), 4);
+
+    // Lleno y dado vuelta: este enqueue fuerza el crecimiento
+    q.enqueue(6);
+    assert_eq!(q.capacity(), 8);
+    assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 5, 6]);
+    assert_eq!(q.front(), Some(&2));
+    assert_eq!(q.back(), Some(&6));
+    assert_eq!(format!("{q:?}"), "[2, 3, 4, 5, 6]");
+}
+
+#[test]
+fn test_drop_accounting() {
+    struct Counted(Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let mut q = MyQueue::new();
+    for _ in 0..6 {
+        q.enqueue(Counted(Rc::clone(&drops)));
+    }
+    for _ in 0..4 {
+        drop(q.dequeue());
+    }
+    // Dado vuelta y con crecimiento de por medio
+    for _ in 0..5 {
+        q.enqueue(Counted(Rc::clone(&drops)));
+    }
+    assert_eq!(drops.get(), 4);
+
+    drop(q);
+    assert_eq!(drops.get(), 11);
+}
+
+#[test]
+fn test_random_operations_against_vecdeque() {
+    let mut rng = SimpleRng::from_seed(0x51EE);
+    let mut mine = MyQueue::new();
+    let mut reference = VecDeque::new();
+
+    for step in 0..20_000u32 {
+        // 60% enqueue para que la cola crezca y se vacíe a ratos
+        if rng.below(10) < 6 {
+            mine.enqueue(step);
+            reference.push_back(step);
+        } else {
+            assert_eq!(mine.dequeue(), reference.pop_front());
+        }
+
+        assert_eq!(mine.len(), reference.len());
+        assert_eq!(mine.front(), reference.front());
+        assert_eq!(mine.back(), reference.back());
+    }
+
+    assert!(mine.iter().eq(reference.iter()));
+    assert!(mine.iter().rev().eq(reference.iter().rev()));
+}
@@ -0,0 +1,104 @@
+use std::rc::Rc;
+
+use queue::RingBuffer;
+
+fn contents(rb: &RingBuffer<u32>) -> Vec<u32> {
+    rb.iter().copied().collect()
+}
+
+#[test]
+fn test_push_rejects_when_full() {
+    let mut rb = RingBuffer::with_capacity(3);
+    assert!(rb.is_empty());
+    assert_eq!(rb.pop(), None);
+
+    for i in 0..3 {
+        assert_eq!(rb.push(i), Ok(()));
+    }
+    assert!(rb.is_full());
+    assert_eq!(rb.push(99), Err(99));
+    assert_eq!(contents(&rb), [0, 1, 2]);
+    assert_eq!((rb.front(), rb.back()), (Some(&0), Some(&2)));
+}
+
+#[test]
+fn test_wraparound_over_many_cycles() {
+    let mut rb = RingBuffer::with_capacity(5);
+    let ptr_before = rb.as_slices().0.as_ptr();
+    let mut next_in = 0;
+    let mut next_out = 0;
+
+    for round in 0..1_000 {
+        // Alterna entre llenarlo y vaciarlo parcialmente
+        let pushes = 1 + round % 5;
+        for _ in 0..pushes {
+            if rb.push(next_in).is_ok() {
+                next_in += 1;
+            }
+        }
+        for _ in 0..(round % 4) {
+            if let Some(v) = rb.pop() {
+                assert_eq!(v, next_out);
+                next_out += 1;
+            }
+        }
+        assert_eq!(rb.len(), (next_in - next_out) as usize);
+        assert_eq!(contents(&rb), (next_out..next_in).collect::<Vec<_>>());
+    }
+
+    // Nunca realojó: el bloque sigue en el mismo lugar
+    rb.clear();
+    assert_eq!(rb.as_slices().0.as_ptr(), ptr_before);
+    assert_eq!(rb.capacity(), 5);
+}
+
+#[test]
+fn test_push_overwrite_evicts_oldest_first() {
+    let mut rb = RingBuffer::with_capacity(3);
+    let evicted: Vec<Option<u32>> = (0..7).map(|i| rb.push_overwrite(i)).collect();
+    assert_eq!(evicted, [None, None, None, Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(contents(&rb), [4, 5, 6]);
+    assert_eq!(rb.iter().rev().copied().collect::<Vec<_>>(), [6, 5, 4]);
+}
+
+#[test]
+fn test_as_slices_contiguous_and_wrapped() {
+    let mut rb = RingBuffer::with_capacity(4);
+    rb.push(1).unwrap();
+    rb.push(2).unwrap();
+    assert_eq!(rb.as_slices(), (&[1, 2][..], &[][..]));
+
+    rb.pop();
+    rb.push(3).unwrap();
+    rb.push(4).unwrap();
+    // Exactamente hasta el final del bloque: todavía contiguo
+    assert_eq!(rb.as_slices(), (&[2, 3, 4][..], &[][..]));
+
+    rb.push(5).unwrap();
+    rb.pop();
+    rb.push(6).unwrap();
+    assert_eq!(rb.as_slices(), (&[3, 4][..], &[5, 6][..]));
+    assert_eq!(format!("{rb:?}"), "[3, 4, 5, 6]");
+}
+
+#[test]
+fn test_drop_while_wrapped_releases_each_element_once() {
+    let tracker = Rc::new(());
+    {
+        let mut rb = RingBuffer::with_capacity(4);
+        for _ in 0..10 {
+            rb.push_overwrite(Rc::clone(&tracker));
+        }
+        drop(rb.pop());
+        // head quedó a mitad del bloque y los vivos dan la vuelta
+        assert_eq!(rb.as_slices().1.len(), 2);
+        assert_eq!(Rc::strong_count(&tracker), 4);
+    }
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+#[should_panic(expected = "mayor que 0")]
+fn test_zero_capacity_panics() {
+    let _rb: RingBuffer<u8> = RingBuffer::with_capacity(0);
+}
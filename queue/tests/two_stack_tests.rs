@@ -0,0 +1,78 @@
+use std::collections::VecDeque;
+
+use queue::TwoStackQueue;
+use vectors::SimpleRng;
+
+#[test]
+fn test_fifo_order_front_and_iter() {
+    let mut q = TwoStackQueue::new();
+    assert_eq!(q.front(), None);
+
+    q.enqueue('a');
+    q.enqueue('b');
+    assert_eq!(q.front(), Some(&'a'));
+    assert_eq!(q.dequeue(), Some('a'));
+
+    q.enqueue('c');
+    // 'b' en outbox, 'c' en inbox
+    assert_eq!(q.front(), Some(&'b'));
+    assert_eq!(q.iter().collect::<String>(), "bc");
+    assert_eq!(format!("{q:?}"), "['b', 'c']");
+    assert_eq!(q.len(), 2);
+}
+
+#[test]
+fn test_dequeue_from_empty() {
+    let mut q: TwoStackQueue<i32> = TwoStackQueue::new();
+    assert_eq!(q.dequeue(), None);
+
+    q.enqueue(1);
+    assert_eq!(q.dequeue(), Some(1));
+    assert_eq!(q.dequeue(), None);
+    assert!(q.is_empty());
+    assert_eq!(q.moves(), 1);
+}
+
+#[test]
+fn test_random_operations_match_vecdeque() {
+    let mut rng = SimpleRng::from_seed(1143);
+    let mut q = TwoStackQueue::new();
+    let mut model = VecDeque::new();
+
+    for i in 0..20_000u64 {
+        if rng.below(5) < 3 {
+            q.enqueue(i);
+            model.push_back(i);
+        } else {
+            assert_eq!(q.dequeue(), model.pop_front());
+        }
+        assert_eq!(q.front(), model.front());
+        assert_eq!(q.len(), model.len());
+        if i % 1_000 == 0 {
+            assert!(q.iter().eq(model.iter()));
+        }
+    }
+}
+
+#[test]
+fn test_moves_are_amortized_constant() {
+    let mut rng = SimpleRng::from_seed(7);
+    let n = 10_000;
+    let mut q = TwoStackQueue::new();
+    let (mut enqueued, mut dequeued) = (0, 0);
+
+    // n enqueue y n dequeue intercalados al azar
+    while dequeued < n {
+        if enqueued < n && (q.is_empty() || rng.below(2) == 0) {
+            q.enqueue(enqueued);
+            enqueued += 1;
+        } else {
+            q.dequeue();
+            dequeued += 1;
+        }
+    }
+
+    // Cada elemento pasó de inbox a outbox exactamente una vez: n movimientos,
+    // dentro de la cota de 2n (contando también los push de enqueue)
+    assert_eq!(q.moves(), n);
+}
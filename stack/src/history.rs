@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+use std::mem;
+
+use crate::MyStack;
+
+/*
+Historial deshacer / rehacer
+
+Dos pilas alrededor del estado actual:
+
+    deshacer (pasado)            actual      rehacer (futuro)
+    [v1, v2, v3]        ◄──►      v4     ◄──►      [v6, v5]
+                  undo: v3 pasa a actual, v4 a rehacer
+                  redo: lo inverso
+
+- `push` de un estado nuevo VACÍA la pila de rehacer: después de deshacer y
+  editar, el futuro anterior ya no es alcanzable (la rama se descarta).
+- Con profundidad máxima, la pila de deshacer descarta sus entradas MÁS
+  VIEJAS; por eso es un VecDeque (sacar del fondo en O(1)) y no un MyStack.
+
+Dos variantes:
+- `History<T>`: guarda estados completos. Simple, pero cada paso cuesta un T.
+- `CommandHistory<C>`: guarda comandos que saben aplicarse y revertirse sobre
+  un documento. Solo cuesta lo que cambió, y permite fusionar ediciones
+  chicas consecutivas (tipear "hola" letra por letra se deshace de una vez)
+  con `Command::try_merge`.
+*/
+
+/// Historial de estados con deshacer/rehacer.
+///
+/// ```
+/// use stack::History;
+///
+/// let mut h = History::new("a");
+/// h.push("ab");
+/// h.push("abc");
+/// assert_eq!(h.undo(), Some(&"ab"));
+/// assert_eq!(h.redo(), Some(&"abc"));
+/// assert_eq!(h.redo(), None);
+/// ```
+#[derive(Debug)]
+pub struct History<T> {
+    current: T,
+    undo: VecDeque<T>,
+    redo: MyStack<T>,
+    max_depth: Option<usize>,
+}
+
+impl<T> History<T> {
+    /// Crea un historial sin límite de profundidad con `initial` como estado
+    /// actual.
+    pub fn new(initial: T) -> Self {
+        Self {
+            current: initial,
+            undo: VecDeque::new(),
+            redo: MyStack::new(),
+            max_depth: None,
+        }
+    }
+
+    /// Crea un historial que recuerda a lo sumo `max_depth` estados
+    /// anteriores.
+    ///
+    /// # Panics
+    /// Si `max_depth` es 0.
+    pub fn with_max_depth(initial: T, max_depth: usize) -> Self {
+        assert!(max_depth > 0, "la profundidad máxima debe ser mayor que 0");
+        Self {
+            max_depth: Some(max_depth),
+            ..Self::new(initial)
+        }
+    }
+
+    /// Retorna el estado actual.
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Reemplaza el estado actual por `state`, guardando el anterior para
+    /// deshacer y descartando lo que hubiera para rehacer.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado, más liberar la rama de rehacer.
+    pub fn push(&mut self, state: T) {
+        let previous = mem::replace(&mut self.current, state);
+        self.undo.push_back(previous);
+        if let Some(max) = self.max_depth
+            && self.undo.len() > max
+        {
+            self.undo.pop_front();
+        }
+        self.redo = MyStack::new();
+    }
+
+    /// Vuelve al estado anterior y lo retorna, o `None` si no hay.
+    pub fn undo(&mut self) -> Option<&T> {
+        let previous = self.undo.pop_back()?;
+        self.redo.push(mem::replace(&mut self.current, previous));
+        Some(&self.current)
+    }
+
+    /// Rehace el último estado deshecho y lo retorna, o `None` si no hay.
+    pub fn redo(&mut self) -> Option<&T> {
+        let next = self.redo.pop()?;
+        self.undo.push_back(mem::replace(&mut self.current, next));
+        Some(&self.current)
+    }
+
+    /// Retorna `true` si hay estados para deshacer.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Retorna `true` si hay estados para rehacer.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Retorna cuántos pasos se pueden deshacer.
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Retorna cuántos pasos se pueden rehacer.
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+}
+
+/// Edición reversible sobre un documento `Target`.
+pub trait Command {
+    type Target;
+
+    /// Aplica la edición.
+    fn apply(&mut self, target: &mut Self::Target);
+
+    /// Revierte la edición; se llama solo después de `apply`.
+    fn unapply(&mut self, target: &mut Self::Target);
+
+    /// Intenta absorber `next` (ya aplicado) en `self`, para que ambos se
+    /// deshagan como un solo paso. Por defecto no fusiona.
+    fn try_merge(&mut self, next: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = next;
+        false
+    }
+}
+
+/// Historial de comandos con deshacer/rehacer y fusión de ediciones.
+///
+/// ```
+/// use stack::{Command, CommandHistory};
+///
+/// struct Add(i32);
+///
+/// impl Command for Add {
+///     type Target = i32;
+///     fn apply(&mut self, total: &mut i32) { *total += self.0 }
+///     fn unapply(&mut self, total: &mut i32) { *total -= self.0 }
+/// }
+///
+/// let mut total = 0;
+/// let mut h = CommandHistory::new();
+/// h.execute(Add(5), &mut total);
+/// h.execute(Add(3), &mut total);
+/// assert!(h.undo(&mut total));
+/// assert_eq!(total, 5);
+/// ```
+pub struct CommandHistory<C: Command> {
+    undo: VecDeque<C>,
+    redo: MyStack<C>,
+    max_depth: Option<usize>,
+}
+
+impl<C: Command> CommandHistory<C> {
+    /// Crea un historial sin límite de profundidad.
+    pub fn new() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: MyStack::new(),
+            max_depth: None,
+        }
+    }
+
+    /// Crea un historial que recuerda a lo sumo `max_depth` comandos.
+    ///
+    /// # Panics
+    /// Si `max_depth` es 0.
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        assert!(max_depth > 0, "la profundidad máxima debe ser mayor que 0");
+        Self {
+            max_depth: Some(max_depth),
+            ..Self::new()
+        }
+    }
+
+    /// Aplica `command` sobre `target` y lo registra. Si el último comando lo
+    /// absorbe con `try_merge`, no ocupa un paso nuevo. Descarta lo que
+    /// hubiera para rehacer.
+    pub fn execute(&mut self, mut command: C, target: &mut C::Target) {
+        command.apply(target);
+        self.redo = MyStack::new();
+
+        if let Some(last) = self.undo.back_mut()
+            && last.try_merge(&command)
+        {
+            return;
+        }
+
+        self.undo.push_back(command);
+        if let Some(max) = self.max_depth
+            && self.undo.len() > max
+        {
+            self.undo.pop_front();
+        }
+    }
+
+    /// Revierte el último comando. Retorna `false` si no había.
+    pub fn undo(&mut self, target: &mut C::Target) -> bool {
+        let Some(mut command) = self.undo.pop_back() else {
+            return false;
+        };
+        command.unapply(target);
+        self.redo.push(command);
+        true
+    }
+
+    /// Vuelve a aplicar el último comando deshecho. Retorna `false` si no
+    /// había.
+    pub fn redo(&mut self, target: &mut C::Target) -> bool {
+        let Some(mut command) = self.redo.pop() else {
+            return false;
+        };
+        command.apply(target);
+        self.undo.push_back(command);
+        true
+    }
+
+    /// Retorna `true` si hay comandos para deshacer.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Retorna `true` si hay comandos para rehacer.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Retorna cuántos pasos se pueden deshacer.
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+}
+
+impl<C: Command> Default for CommandHistory<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+use std::iter::Rev;
+
+use vectors::{Iter, MyVec};
+
+mod history;
+
+pub use history::{Command, CommandHistory, History};
+
+/// Pila LIFO (último en entrar, primero en salir) construida sobre [`MyVec`].
+///
+/// El tope de la pila es el final del vector: `push` y `pop` trabajan sobre
+/// `push_back` / `pop_back`, así que ninguna operación desplaza elementos.
+///
+/// ```
+/// use stack::MyStack;
+///
+/// let mut s = MyStack::new();
+/// s.push(1);
+/// s.push(2);
+/// assert_eq!(s.peek(), Some(&2));
+/// assert_eq!(s.pop(), Some(2));
+/// assert_eq!(s.pop(), Some(1));
+/// assert_eq!(s.pop(), None);
+/// ```
+pub struct MyStack<T> {
+    items: MyVec<T>,
+}
+
+impl<T> MyStack<T> {
+    /// Crea una pila vacía (sin reservar memoria).
+    pub fn new() -> Self {
+        Self { items: MyVec::new() }
+    }
+
+    /// Apila `elem` en el tope.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado - Igual que `MyVec::push_back`.
+    pub fn push(&mut self, elem: T) {
+        self.items.push_back(elem);
+    }
+
+    /// Desapila y retorna el tope, o `None` si la pila está vacía.
+    ///
+    /// # Complejidad
+    /// **O(1)**
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
+
+    /// Retorna una referencia al tope sin desapilarlo.
+    pub fn peek(&self) -> Option<&T> {
+        self.items.len().checked_sub(1).and_then(|top| self.items.get(top))
+    }
+
+    /// Retorna una referencia mutable al tope sin desapilarlo.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        let top = self.items.len().checked_sub(1)?;
+        self.items.get_mut(top)
+    }
+
+    /// Retorna la cantidad de elementos en la pila.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Retorna `true` si la pila no tiene elementos.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Retorna un iterador del tope hacia el fondo (el orden en que `pop`
+    /// los entregaría).
+    pub fn iter(&self) -> Rev<Iter<'_, T>> {
+        self.items.iter().rev()
+    }
+}
+
+impl<T> Default for MyStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Se imprime del tope hacia el fondo, igual que `iter()`.
+impl<T: fmt::Debug> fmt::Debug for MyStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,123 @@
+use stack::{Command, CommandHistory, History};
+
+#[test]
+fn test_undo_redo_round_trip() {
+    let mut h = History::new(0);
+    for i in 1..=5 {
+        h.push(i);
+    }
+    assert_eq!(h.undo_len(), 5);
+
+    for expected in (0..5).rev() {
+        assert_eq!(h.undo(), Some(&expected));
+    }
+    assert_eq!(h.undo(), None);
+    assert!(!h.can_undo());
+    assert_eq!(h.current(), &0);
+
+    for expected in 1..=5 {
+        assert_eq!(h.redo(), Some(&expected));
+    }
+    assert!(!h.can_redo());
+    assert_eq!(h.current(), &5);
+}
+
+#[test]
+fn test_push_after_undo_truncates_redo_branch() {
+    let mut h = History::new(String::from("a"));
+    h.push("ab".into());
+    h.push("abc".into());
+    h.undo();
+    h.undo();
+    assert_eq!(h.redo_len(), 2);
+
+    h.push("ax".into());
+    assert!(!h.can_redo());
+    assert_eq!(h.redo(), None);
+    assert_eq!(h.undo().map(String::as_str), Some("a"));
+}
+
+#[test]
+fn test_max_depth_keeps_most_recent() {
+    let mut h = History::with_max_depth(0, 3);
+    for i in 1..=10 {
+        h.push(i);
+    }
+    assert_eq!(h.undo_len(), 3);
+    assert_eq!(h.undo(), Some(&9));
+    assert_eq!(h.undo(), Some(&8));
+    assert_eq!(h.undo(), Some(&7));
+    assert_eq!(h.undo(), None);
+}
+
+/// Inserta texto en una posición de un String.
+struct Insert {
+    at: usize,
+    text: String,
+}
+
+impl Command for Insert {
+    type Target = String;
+
+    fn apply(&mut self, doc: &mut String) {
+        doc.insert_str(self.at, &self.text);
+    }
+
+    fn unapply(&mut self, doc: &mut String) {
+        doc.replace_range(self.at..self.at + self.text.len(), "");
+    }
+
+    /// Tipear seguido (cada inserción justo al final de la anterior) se fusiona,
+    /// salvo que la nueva empiece con espacio: así se deshace palabra por palabra.
+    fn try_merge(&mut self, next: &Self) -> bool {
+        if next.at != self.at + self.text.len() || next.text.starts_with(' ') {
+            return false;
+        }
+        self.text.push_str(&next.text);
+        true
+    }
+}
+
+fn typing(doc: &mut String, h: &mut CommandHistory<Insert>, text: &str) {
+    for c in text.chars() {
+        let at = doc.len();
+        h.execute(Insert { at, text: c.to_string() }, doc);
+    }
+}
+
+#[test]
+fn test_command_history_against_toy_document() {
+    let mut doc = String::new();
+    let mut h = CommandHistory::new();
+
+    typing(&mut doc, &mut h, "hola mundo cruel");
+    assert_eq!(doc, "hola mundo cruel");
+    assert_eq!(h.undo_len(), 3);
+
+    assert!(h.undo(&mut doc));
+    assert_eq!(doc, "hola mundo");
+    assert!(h.undo(&mut doc));
+    assert_eq!(doc, "hola");
+    assert!(h.redo(&mut doc));
+    assert_eq!(doc, "hola mundo");
+
+    // Editar después de deshacer descarta " cruel"
+    h.execute(Insert { at: 0, text: "¡".into() }, &mut doc);
+    assert!(!h.can_redo());
+    assert_eq!(doc, "¡hola mundo");
+
+    while h.undo(&mut doc) {}
+    assert_eq!(doc, "");
+    assert!(!h.undo(&mut doc));
+}
+
+#[test]
+fn test_command_history_depth_limit() {
+    let mut doc = String::new();
+    let mut h = CommandHistory::with_max_depth(2);
+    typing(&mut doc, &mut h, "uno dos tres");
+    assert_eq!(h.undo_len(), 2);
+
+    while h.undo(&mut doc) {}
+    assert_eq!(doc, "uno");
+}
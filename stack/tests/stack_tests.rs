@@ -0,0 +1,88 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use stack::MyStack;
+
+/// Verifica que cada `(` `[` `{` se cierre en el orden correcto.
+fn is_balanced(text: &str) -> bool {
+    let mut open = MyStack::new();
+
+    for c in text.chars() {
+        match c {
+            '(' | '[' | '{' => open.push(c),
+            ')' | ']' | '}' => {
+                let expected = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                if open.pop() != Some(expected) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    open.is_empty()
+}
+
+#[test]
+fn test_balanced_parentheses() {
+    assert!(is_balanced(""));
+    assert!(is_balanced("fn main() { let v = [1, (2 + 3)]; }"));
+    assert!(is_balanced("{[()()]}"));
+
+    assert!(!is_balanced("("));
+    assert!(!is_balanced(")("));
+    assert!(!is_balanced("{[}]"));
+    assert!(!is_balanced("(()"));
+}
+
+#[test]
+fn test_push_pop_peek_lifo() {
+    let mut s = MyStack::new();
+    assert!(s.is_empty());
+    assert_eq!(s.peek(), None);
+    assert_eq!(s.peek_mut(), None);
+
+    for i in 1..=4 {
+        s.push(i);
+    }
+    assert_eq!(s.len(), 4);
+    assert_eq!(s.peek(), Some(&4));
+
+    *s.peek_mut().unwrap() = 40;
+    assert_eq!(s.iter().copied().collect::<Vec<_>>(), vec![40, 3, 2, 1]);
+    assert_eq!(format!("{s:?}"), "[40, 3, 2, 1]");
+
+    assert_eq!(s.pop(), Some(40));
+    assert_eq!(s.pop(), Some(3));
+    assert_eq!(s.len(), 2);
+}
+
+#[test]
+fn test_pop_and_drop_without_double_free() {
+    struct Counted(Rc<Cell<usize>>);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Rc::new(Cell::new(0));
+    let mut s = MyStack::new();
+    for _ in 0..5 {
+        s.push(Counted(Rc::clone(&drops)));
+    }
+
+    // Lo que sale con pop pertenece al llamador: se destruye una sola vez
+    drop(s.pop());
+    drop(s.pop());
+    assert_eq!(drops.get(), 2);
+
+    // El resto lo destruye la pila al soltarse
+    drop(s);
+    assert_eq!(drops.get(), 5);
+}
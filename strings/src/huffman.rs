@@ -0,0 +1,255 @@
+//! Codificación de Huffman: árbol de frecuencias, codificación y decodificación.
+
+use std::cmp::{Ordering, Reverse};
+use std::error::Error;
+use std::fmt;
+
+use heap_max::MyMinHeap;
+use vectors::BitVec;
+
+/*
+Código de Huffman
+
+Los bytes frecuentes reciben códigos cortos y los raros, largos. Para
+"abracadabra" (a:5, b:2, r:2, c:1, d:1):
+
+  1. Cada byte es una hoja con su frecuencia, todas en un min-heap.
+  2. Se sacan las dos de menor peso y se unen bajo un nodo interno cuyo peso
+     es la suma; el nodo vuelve al heap. Se repite hasta que queda uno.
+
+                 (11)
+               0/    \1
+             a:5     (6)
+                   0/   \1
+                 (2)     (4)
+                0/ \1   0/ \1
+              c:1 d:1  b:2 r:2
+
+  3. El código de un byte es el camino desde la raíz: izquierda = 0,
+     derecha = 1.  a = 0, c = 100, d = 101, b = 110, r = 111.
+
+     "abracadabra" ocupa 5·1 + 2·3 + 2·3 + 1·3 + 1·3 = 23 bits, no 88.
+
+Ningún código es prefijo de otro (los bytes solo están en las hojas), así que
+para decodificar alcanza con bajar por el árbol bit a bit y volver a la raíz
+al llegar a una hoja.
+
+Casos borde:
+- Entrada vacía: árbol sin raíz y flujo vacío.
+- Un solo byte distinto: la raíz sería una hoja con código vacío, y no se
+  podría saber cuántas veces se repite. Se le asigna el código de 1 bit "0".
+
+Empates: el heap desempata por un número de orden (los bytes por su valor,
+los nodos internos por orden de creación), así que el árbol es determinista.
+*/
+
+enum Node {
+    Leaf(u8),
+    Internal(Box<Node>, Box<Node>),
+}
+
+/// Nodo con peso para el heap; se ordena por `(weight, order)`.
+struct Weighted {
+    weight: usize,
+    order: usize,
+    node: Node,
+}
+
+impl PartialEq for Weighted {
+    fn eq(&self, other: &Self) -> bool {
+        (self.weight, self.order) == (other.weight, other.order)
+    }
+}
+
+impl Eq for Weighted {}
+
+impl PartialOrd for Weighted {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Weighted {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.weight, self.order).cmp(&(other.weight, other.order))
+    }
+}
+
+/// Árbol de Huffman con la tabla de códigos que se deriva de él.
+pub struct HuffmanTree {
+    root: Option<Node>,
+    // Código de cada byte; vacío si el byte no aparece
+    codes: Vec<Vec<bool>>,
+}
+
+impl HuffmanTree {
+    /// Retorna el código de `symbol`, o `None` si no aparecía en la entrada.
+    pub fn code(&self, symbol: u8) -> Option<&[bool]> {
+        let code = &self.codes[usize::from(symbol)];
+        (!code.is_empty()).then_some(code.as_slice())
+    }
+
+    /// Llena `codes` recorriendo el árbol; `path` es el camino hasta `node`.
+    fn assign_codes(node: &Node, path: &mut Vec<bool>, codes: &mut [Vec<bool>]) {
+        match node {
+            Node::Leaf(symbol) => codes[usize::from(*symbol)] = path.clone(),
+            Node::Internal(left, right) => {
+                path.push(false);
+                Self::assign_codes(left, path, codes);
+                path.pop();
+                path.push(true);
+                Self::assign_codes(right, path, codes);
+                path.pop();
+            }
+        }
+    }
+}
+
+impl fmt::Debug for HuffmanTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut map = f.debug_map();
+        for symbol in 0..=u8::MAX {
+            if let Some(code) = self.code(symbol) {
+                let bits: String = code.iter().map(|&b| if b { '1' } else { '0' }).collect();
+                map.entry(&(symbol as char), &bits);
+            }
+        }
+        map.finish()
+    }
+}
+
+/// Error de [`decode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// El flujo termina en medio de un código, después de `decoded` bytes.
+    Truncated { decoded: usize },
+    /// El bit en `position` no corresponde a ningún código del árbol.
+    InvalidCode { position: usize },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated { decoded } => {
+                write!(f, "bitstream truncated after {decoded} symbols")
+            }
+            DecodeError::InvalidCode { position } => {
+                write!(f, "invalid code at bit {position}")
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Construye el árbol de Huffman de las frecuencias de `data`.
+///
+/// # Complejidad
+/// **O(n + σ log σ)**, con σ ≤ 256 la cantidad de bytes distintos.
+pub fn build_tree(data: &[u8]) -> HuffmanTree {
+    let mut frequencies = [0usize; 256];
+    for &byte in data {
+        frequencies[usize::from(byte)] += 1;
+    }
+
+    let mut heap = MyMinHeap::new();
+    for (symbol, &weight) in frequencies.iter().enumerate() {
+        if weight > 0 {
+            heap.push(Reverse(Weighted {
+                weight,
+                order: symbol,
+                node: Node::Leaf(symbol as u8),
+            }));
+        }
+    }
+
+    // Los internos se numeran después de los 256 bytes posibles
+    let mut order = 256;
+    while heap.len() > 1 {
+        let Reverse(a) = heap.pop().expect("len > 1");
+        let Reverse(b) = heap.pop().expect("len > 1");
+        heap.push(Reverse(Weighted {
+            weight: a.weight + b.weight,
+            order,
+            node: Node::Internal(Box::new(a.node), Box::new(b.node)),
+        }));
+        order += 1;
+    }
+
+    let root = heap.pop().map(|Reverse(w)| w.node);
+    let mut codes = vec![Vec::new(); 256];
+    match &root {
+        None => {}
+        // Un solo byte distinto: código de 1 bit
+        Some(Node::Leaf(symbol)) => codes[usize::from(*symbol)] = vec![false],
+        Some(node) => HuffmanTree::assign_codes(node, &mut Vec::new(), &mut codes),
+    }
+
+    HuffmanTree { root, codes }
+}
+
+/// Codifica `data` y retorna el flujo de bits junto con el árbol necesario
+/// para decodificarlo.
+///
+/// ```
+/// use strings::huffman;
+///
+/// let (bits, tree) = huffman::encode(b"abracadabra");
+/// assert_eq!(bits.len(), 23);
+/// assert_eq!(huffman::decode(&bits, &tree).unwrap(), b"abracadabra");
+/// ```
+///
+/// # Complejidad
+/// **O(n + σ log σ)**
+pub fn encode(data: &[u8]) -> (BitVec, HuffmanTree) {
+    let tree = build_tree(data);
+    let mut bits = BitVec::new();
+    for &byte in data {
+        for &bit in &tree.codes[usize::from(byte)] {
+            bits.push(bit);
+        }
+    }
+    (bits, tree)
+}
+
+/// Decodifica `bits` bajando por `tree` bit a bit.
+///
+/// Si el flujo termina en medio de un código retorna
+/// [`DecodeError::Truncated`]. Un corte que cae justo entre dos códigos no se
+/// puede detectar: el resultado es un prefijo válido del mensaje.
+///
+/// # Complejidad
+/// **O(bits)**
+pub fn decode(bits: &BitVec, tree: &HuffmanTree) -> Result<Vec<u8>, DecodeError> {
+    let mut out = Vec::new();
+    let Some(root) = &tree.root else {
+        if bits.is_empty() {
+            return Ok(out);
+        }
+        return Err(DecodeError::InvalidCode { position: 0 });
+    };
+
+    let mut node = root;
+    for (position, bit) in bits.iter().enumerate() {
+        node = match (node, bit) {
+            // Árbol de un solo byte: su código es "0"
+            (Node::Leaf(symbol), false) => {
+                out.push(*symbol);
+                continue;
+            }
+            (Node::Leaf(_), true) => return Err(DecodeError::InvalidCode { position }),
+            (Node::Internal(left, _), false) => left,
+            (Node::Internal(_, right), true) => right,
+        };
+
+        if let Node::Leaf(symbol) = node {
+            out.push(*symbol);
+            node = root;
+        }
+    }
+
+    if !std::ptr::eq(node, root) {
+        return Err(DecodeError::Truncated { decoded: out.len() });
+    }
+    Ok(out)
+}
@@ -0,0 +1,6 @@
+pub mod huffman;
+mod rope;
+mod suffix_array;
+
+pub use rope::{Chunks, Rope};
+pub use suffix_array::SuffixArray;
@@ -0,0 +1,457 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
+
+/*
+Rope (cuerda)
+
+Un String es un bloque contiguo: insertar o borrar en el medio mueve todo lo
+que está a la derecha, O(n). Una cuerda parte el texto en trozos chicos y los
+cuelga de un árbol binario; el texto es la concatenación de las hojas de
+izquierda a derecha:
+
+                    Branch (chars = 11)
+                   /                   \
+          Branch (6)                  Leaf "mundo"
+          /        \
+    Leaf "ho"   Leaf "la, "
+
+Cada nodo sabe cuántos caracteres (y bytes) tiene su subárbol, así que para
+llegar al carácter i se baja comparando con el largo del hijo izquierdo, sin
+recorrer el texto: O(altura).
+
+Todo se arma con dos operaciones:
+
+- split(nodo, i): corta en dos árboles, [0, i) y [i, len). Solo se parte UNA
+  hoja (la que contiene a i); el resto de los subárboles se reutiliza entero.
+- join(a, b): un Branch nuevo con a y b como hijos. Si ambos son hojas chicas
+  se fusionan en una sola hoja, para no fragmentar el texto en trozos de un
+  carácter.
+
+    insert(i, s) = join(join(split(i).0, s), split(i).1)
+    remove(a..b) = join(split(a).0, split(b).1)
+
+Balance: join solo suma 1 a la altura, así que muchas ediciones seguidas en
+el mismo lugar pueden ir formando una "escalera". Cuando la altura pasa de
+MAX_HEIGHT se reconstruye el árbol completo, balanceado, a partir de sus
+hojas: O(n), pero solo cada tanto.
+
+UTF-8: todos los índices son de CARACTERES (`char`), no de bytes. Una hoja se
+corta siempre en el límite de un carácter, así que ninguna secuencia de varios
+bytes (é, 🦀) queda partida entre dos hojas.
+*/
+
+/// Bytes máximos de una hoja armada por `from_str` o por fusión.
+const MAX_LEAF: usize = 64;
+
+/// Altura a partir de la cual se rebalancea la cuerda.
+const MAX_HEIGHT: usize = 24;
+
+enum Node {
+    Leaf {
+        text: String,
+        chars: usize,
+    },
+    Branch {
+        left: Box<Node>,
+        right: Box<Node>,
+        chars: usize,
+        bytes: usize,
+        height: usize,
+    },
+}
+
+impl Node {
+    fn leaf(text: String) -> Self {
+        let chars = text.chars().count();
+        Node::Leaf { text, chars }
+    }
+
+    fn chars(&self) -> usize {
+        match self {
+            Node::Leaf { chars, .. } | Node::Branch { chars, .. } => *chars,
+        }
+    }
+
+    fn bytes(&self) -> usize {
+        match self {
+            Node::Leaf { text, .. } => text.len(),
+            Node::Branch { bytes, .. } => *bytes,
+        }
+    }
+
+    fn height(&self) -> usize {
+        match self {
+            Node::Leaf { .. } => 0,
+            Node::Branch { height, .. } => *height,
+        }
+    }
+
+    /// Concatena `left` y `right`, descartando vacíos y fusionando hojas chicas.
+    fn join(left: Node, right: Node) -> Node {
+        if left.bytes() == 0 {
+            return right;
+        }
+        if right.bytes() == 0 {
+            return left;
+        }
+
+        match (left, right) {
+            (
+                Node::Leaf {
+                    text: mut a,
+                    chars: ca,
+                },
+                Node::Leaf { text: b, chars: cb },
+            ) if a.len() + b.len() <= MAX_LEAF => {
+                a.push_str(&b);
+                Node::Leaf {
+                    text: a,
+                    chars: ca + cb,
+                }
+            }
+            (left, right) => Node::Branch {
+                chars: left.chars() + right.chars(),
+                bytes: left.bytes() + right.bytes(),
+                height: left.height().max(right.height()) + 1,
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+        }
+    }
+
+    /// Corta en `[0, index)` y `[index, len)`, con `index` en caracteres.
+    fn split(self, index: usize) -> (Node, Node) {
+        match self {
+            Node::Leaf { mut text, .. } => {
+                let byte = byte_offset(&text, index);
+                let right = text.split_off(byte);
+                (Node::leaf(text), Node::leaf(right))
+            }
+            Node::Branch { left, right, .. } => {
+                let left_chars = left.chars();
+                if index < left_chars {
+                    let (a, b) = left.split(index);
+                    (a, Node::join(b, *right))
+                } else {
+                    let (a, b) = right.split(index - left_chars);
+                    (Node::join(*left, a), b)
+                }
+            }
+        }
+    }
+
+    /// Agrega a `out` los caracteres `[start, end)` de este subárbol.
+    fn collect(&self, start: usize, end: usize, out: &mut String) {
+        if start >= end {
+            return;
+        }
+        match self {
+            Node::Leaf { text, .. } => {
+                let from = byte_offset(text, start);
+                let to = byte_offset(text, end);
+                out.push_str(&text[from..to]);
+            }
+            Node::Branch { left, right, .. } => {
+                let left_chars = left.chars();
+                if start < left_chars {
+                    left.collect(start, end.min(left_chars), out);
+                }
+                if end > left_chars {
+                    right.collect(start.saturating_sub(left_chars), end - left_chars, out);
+                }
+            }
+        }
+    }
+
+    /// Árbol balanceado cuyas hojas son `leaves`, en orden.
+    fn build(leaves: &mut [String]) -> Node {
+        match leaves {
+            [] => Node::leaf(String::new()),
+            [one] => Node::leaf(std::mem::take(one)),
+            _ => {
+                let (left, right) = leaves.split_at_mut(leaves.len() / 2);
+                Node::join(Node::build(left), Node::build(right))
+            }
+        }
+    }
+}
+
+/// Offset en bytes del carácter `index` de `text` (o `text.len()` al final).
+fn byte_offset(text: &str, index: usize) -> usize {
+    text.char_indices()
+        .nth(index)
+        .map_or(text.len(), |(byte, _)| byte)
+}
+
+/// Texto editable como árbol de trozos: inserción y borrado en
+/// **O(log n)** en vez del O(n) de un `String`.
+///
+/// Los índices son de caracteres, no de bytes.
+///
+/// ```
+/// use strings::Rope;
+///
+/// let mut rope = Rope::from("hola mundo");
+/// rope.insert(4, ", querido");
+/// rope.remove(0..1);
+/// assert_eq!(rope.to_string(), "ola, querido mundo");
+/// assert_eq!(rope.char_at(3), Some(','));
+/// assert_eq!(rope.slice(5..12), "querido");
+/// ```
+pub struct Rope {
+    root: Node,
+}
+
+impl Rope {
+    /// Crea una cuerda vacía.
+    pub fn new() -> Self {
+        Self {
+            root: Node::leaf(String::new()),
+        }
+    }
+
+    /// Retorna el largo en caracteres.
+    pub fn len_chars(&self) -> usize {
+        self.root.chars()
+    }
+
+    /// Retorna el largo en bytes (UTF-8).
+    pub fn len_bytes(&self) -> usize {
+        self.root.bytes()
+    }
+
+    /// Retorna `true` si la cuerda está vacía.
+    pub fn is_empty(&self) -> bool {
+        self.root.bytes() == 0
+    }
+
+    /// Retorna la altura del árbol (0 si es una sola hoja).
+    pub fn height(&self) -> usize {
+        self.root.height()
+    }
+
+    /// Envuelve `root`, rebalanceando si quedó demasiado alta.
+    fn from_root(root: Node) -> Self {
+        let mut rope = Self { root };
+        if rope.root.height() > MAX_HEIGHT {
+            rope.rebalance();
+        }
+        rope
+    }
+
+    /// Reconstruye el árbol balanceado a partir de sus hojas, fusionando las
+    /// hojas chicas vecinas.
+    ///
+    /// # Complejidad
+    /// **O(n)**
+    pub fn rebalance(&mut self) {
+        let mut leaves: Vec<String> = Vec::new();
+        for chunk in self.chunks() {
+            match leaves.last_mut() {
+                Some(last) if last.len() + chunk.len() <= MAX_LEAF => last.push_str(chunk),
+                _ => leaves.push(chunk.to_string()),
+            }
+        }
+        self.root = Node::build(&mut leaves);
+    }
+
+    /// Convierte un rango de caracteres en `(start, end)`.
+    ///
+    /// # Panics
+    /// Si el rango se sale de `0..len_chars` o su inicio es mayor que su fin.
+    fn char_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let len = self.len_chars();
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end && end <= len,
+            "rango {start}..{end} inválido para largo {len}"
+        );
+        (start, end)
+    }
+
+    /// Inserta `text` de modo que su primer carácter quede en `char_index`.
+    ///
+    /// # Panics
+    /// Si `char_index > len_chars()`.
+    ///
+    /// # Complejidad
+    /// **O(log n + |text|)**
+    pub fn insert(&mut self, char_index: usize, text: &str) {
+        let len = self.len_chars();
+        assert!(
+            char_index <= len,
+            "índice {char_index} fuera de rango (largo {len})"
+        );
+
+        let root = std::mem::replace(&mut self.root, Node::leaf(String::new()));
+        let (left, right) = root.split(char_index);
+        let middle = Rope::from(text).root;
+        *self = Self::from_root(Node::join(Node::join(left, middle), right));
+    }
+
+    /// Quita los caracteres de `range`.
+    ///
+    /// # Panics
+    /// Si el rango se sale de `0..len_chars` o su inicio es mayor que su fin.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn remove<R: RangeBounds<usize>>(&mut self, range: R) {
+        let (start, end) = self.char_range(range);
+
+        let root = std::mem::replace(&mut self.root, Node::leaf(String::new()));
+        let (left, rest) = root.split(start);
+        let (_, right) = rest.split(end - start);
+        *self = Self::from_root(Node::join(left, right));
+    }
+
+    /// Retorna la concatenación de `self` y `other`.
+    ///
+    /// # Complejidad
+    /// **O(1)** salvo cuando toca rebalancear.
+    pub fn concat(self, other: Rope) -> Rope {
+        Self::from_root(Node::join(self.root, other.root))
+    }
+
+    /// Corta la cuerda en `[0, char_index)` y `[char_index, len)`.
+    ///
+    /// # Panics
+    /// Si `char_index > len_chars()`.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn split(self, char_index: usize) -> (Rope, Rope) {
+        let len = self.len_chars();
+        assert!(
+            char_index <= len,
+            "índice {char_index} fuera de rango (largo {len})"
+        );
+
+        let (left, right) = self.root.split(char_index);
+        (Self::from_root(left), Self::from_root(right))
+    }
+
+    /// Retorna el carácter en `char_index`, o `None` si está fuera de rango.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn char_at(&self, char_index: usize) -> Option<char> {
+        let mut node = &self.root;
+        let mut index = char_index;
+        loop {
+            match node {
+                Node::Leaf { text, .. } => return text.chars().nth(index),
+                Node::Branch { left, right, .. } => {
+                    if index < left.chars() {
+                        node = left;
+                    } else {
+                        index -= left.chars();
+                        node = right;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Copia los caracteres de `range` a un `String` nuevo.
+    ///
+    /// # Panics
+    /// Si el rango se sale de `0..len_chars` o su inicio es mayor que su fin.
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> String {
+        let (start, end) = self.char_range(range);
+        let mut out = String::new();
+        self.root.collect(start, end, &mut out);
+        out
+    }
+
+    /// Retorna un iterador sobre los trozos de texto, en orden.
+    pub fn chunks(&self) -> Chunks<'_> {
+        Chunks {
+            stack: vec![&self.root],
+        }
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for Rope {
+    /// Arma una cuerda balanceada con hojas de hasta `MAX_LEAF` bytes.
+    fn from(text: &str) -> Self {
+        let mut leaves = Vec::new();
+        let mut rest = text;
+        while !rest.is_empty() {
+            // Retroceder hasta un límite de carácter para no partir un UTF-8
+            let mut cut = rest.len().min(MAX_LEAF);
+            while !rest.is_char_boundary(cut) {
+                cut -= 1;
+            }
+            leaves.push(rest[..cut].to_string());
+            rest = &rest[cut..];
+        }
+        Self {
+            root: Node::build(&mut leaves),
+        }
+    }
+}
+
+impl FromStr for Rope {
+    type Err = Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Infallible> {
+        Ok(Rope::from(text))
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Rope({:?})", self.to_string())
+    }
+}
+
+/// Iterador sobre los trozos de una [`Rope`], creado por [`Rope::chunks`].
+pub struct Chunks<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        while let Some(node) = self.stack.pop() {
+            match node {
+                Node::Leaf { text, .. } if text.is_empty() => {}
+                Node::Leaf { text, .. } => return Some(text),
+                Node::Branch { left, right, .. } => {
+                    // Derecho primero para sacar el izquierdo antes
+                    self.stack.push(right);
+                    self.stack.push(left);
+                }
+            }
+        }
+        None
+    }
+}
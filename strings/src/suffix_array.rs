@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+
+/*
+Arreglo de sufijos
+
+Se listan todos los sufijos del texto ordenados lexicográficamente y se guarda
+solo dónde empieza cada uno. Para "banana":
+
+    i   sa[i]  sufijo     lcp[i]
+    0     5    a            0
+    1     3    ana          1     ("a" en común con el anterior)
+    2     1    anana        3     ("ana")
+    3     0    banana       0
+    4     4    na           0
+    5     2    nana         2     ("na")
+
+- Buscar un patrón: todos los sufijos que empiezan con él quedan CONTIGUOS en
+  el arreglo, así que dos búsquedas binarias dan el bloque de apariciones:
+  O(m log n) comparando a lo sumo m bytes por paso.
+- LCP (prefijo común más largo) entre cada sufijo y el anterior: el máximo es
+  la subcadena repetida más larga ("ana" arriba).
+
+Construcción por duplicación de prefijos: primero se ordena por el primer
+byte; con el orden de los prefijos de largo k, el de largo 2k sale de ordenar
+por el par (rango[i], rango[i + k]). Después de log n rondas de un sort
+O(n log n) queda ordenado: O(n log² n).
+
+LCP por Kasai: recorriendo los sufijos en orden de TEXTO (no del arreglo), el
+LCP baja a lo sumo 1 de un sufijo al siguiente, así que se reutiliza lo ya
+comparado: O(n) en total.
+
+Se trabaja sobre BYTES: con texto no ASCII un patrón se compara por su
+codificación UTF-8, lo que da los mismos resultados que comparar caracteres,
+pero los offsets retornados son de bytes.
+*/
+
+/// Arreglo de sufijos con LCP sobre los bytes de un texto.
+///
+/// ```
+/// use strings::SuffixArray;
+///
+/// let sa = SuffixArray::new("banana");
+/// assert_eq!(sa.suffixes(), [5, 3, 1, 0, 4, 2]);
+/// assert_eq!(sa.find_all("ana"), [1, 3]);
+/// assert_eq!(sa.longest_repeated_substring(), "ana");
+/// ```
+pub struct SuffixArray<'a> {
+    text: &'a str,
+    sa: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<'a> SuffixArray<'a> {
+    /// Construye el arreglo de sufijos y su LCP.
+    ///
+    /// # Complejidad
+    /// **O(n log² n)**
+    pub fn new(text: &'a str) -> Self {
+        let sa = build_suffix_array(text.as_bytes());
+        let lcp = kasai(text.as_bytes(), &sa);
+        Self { text, sa, lcp }
+    }
+
+    /// Retorna el largo del texto en bytes.
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Retorna `true` si el texto está vacío.
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Posiciones de inicio de los sufijos, en orden lexicográfico.
+    pub fn suffixes(&self) -> &[usize] {
+        &self.sa
+    }
+
+    /// `lcp()[i]` es el largo del prefijo común entre los sufijos `i - 1` e
+    /// `i` del arreglo (0 para el primero).
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Compara el sufijo que empieza en `start` con `pattern`, mirando solo
+    /// sus primeros `pattern.len()` bytes.
+    fn cmp_prefix(&self, start: usize, pattern: &[u8]) -> Ordering {
+        let suffix = &self.text.as_bytes()[start..];
+        suffix[..suffix.len().min(pattern.len())].cmp(pattern)
+    }
+
+    /// Rango del arreglo cuyos sufijos empiezan con `pattern`.
+    fn matching_block(&self, pattern: &str) -> (usize, usize) {
+        let pattern = pattern.as_bytes();
+        let lo = self
+            .sa
+            .partition_point(|&s| self.cmp_prefix(s, pattern) == Ordering::Less);
+        let hi = self
+            .sa
+            .partition_point(|&s| self.cmp_prefix(s, pattern) != Ordering::Greater);
+        (lo, hi)
+    }
+
+    /// Retorna `true` si `pattern` aparece en el texto.
+    ///
+    /// # Complejidad
+    /// **O(m log n)**
+    pub fn contains(&self, pattern: &str) -> bool {
+        let (lo, hi) = self.matching_block(pattern);
+        lo < hi
+    }
+
+    /// Retorna los offsets (en bytes) de todas las apariciones de `pattern`,
+    /// de menor a mayor. El patrón vacío aparece en cada posición.
+    ///
+    /// # Complejidad
+    /// **O(m log n + k log k)**, con k la cantidad de apariciones.
+    pub fn find_all(&self, pattern: &str) -> Vec<usize> {
+        let (lo, hi) = self.matching_block(pattern);
+        let mut positions = self.sa[lo..hi].to_vec();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Retorna la subcadena más larga que aparece al menos dos veces (las
+    /// apariciones pueden solaparse). Si hay empate, la menor
+    /// lexicográficamente.
+    ///
+    /// Con texto no ASCII el resultado se recorta hacia adentro hasta límites
+    /// de carácter, para que sea un `&str` válido.
+    pub fn longest_repeated_substring(&self) -> &'a str {
+        let Some((index, &len)) = self
+            .lcp
+            .iter()
+            .enumerate()
+            .max_by_key(|&(i, &len)| (len, std::cmp::Reverse(i)))
+        else {
+            return "";
+        };
+
+        let mut start = self.sa[index];
+        let mut end = start + len;
+        while !self.text.is_char_boundary(start) {
+            start += 1;
+        }
+        while end > start && !self.text.is_char_boundary(end) {
+            end -= 1;
+        }
+        &self.text[start..end]
+    }
+}
+
+/// Duplicación de prefijos: O(n log² n).
+fn build_suffix_array(text: &[u8]) -> Vec<usize> {
+    let n = text.len();
+    let mut sa: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = text.iter().map(|&b| usize::from(b)).collect();
+    let mut next_rank = vec![0; n];
+    let mut k = 1;
+
+    while k < n {
+        // El sufijo que se queda sin segunda mitad va primero (None < Some)
+        let key = |i: usize| (rank[i], rank.get(i + k).copied());
+        sa.sort_unstable_by_key(|&i| key(i));
+
+        next_rank[sa[0]] = 0;
+        for w in 1..n {
+            let step = usize::from(key(sa[w - 1]) != key(sa[w]));
+            next_rank[sa[w]] = next_rank[sa[w - 1]] + step;
+        }
+        std::mem::swap(&mut rank, &mut next_rank);
+
+        // Todos los rangos distintos: ya está ordenado
+        if rank[sa[n - 1]] == n - 1 {
+            break;
+        }
+        k *= 2;
+    }
+
+    // Con n <= 1 el bucle no corre, y un solo sufijo ya está ordenado
+    sa
+}
+
+/// LCP de Kasai: O(n).
+fn kasai(text: &[u8], sa: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut position = vec![0; n];
+    for (i, &s) in sa.iter().enumerate() {
+        position[s] = i;
+    }
+
+    let mut lcp = vec![0; n];
+    let mut h: usize = 0;
+    for start in 0..n {
+        if position[start] == 0 {
+            h = 0;
+            continue;
+        }
+        let previous = sa[position[start] - 1];
+        while start + h < n && previous + h < n && text[start + h] == text[previous + h] {
+            h += 1;
+        }
+        lcp[position[start]] = h;
+        // El siguiente sufijo en el texto comparte al menos h - 1
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
@@ -0,0 +1,83 @@
+use strings::huffman::{self, DecodeError};
+use vectors::{BitVec, SimpleRng};
+
+fn round_trip(data: &[u8]) {
+    let (bits, tree) = huffman::encode(data);
+    assert_eq!(huffman::decode(&bits, &tree).unwrap(), data);
+}
+
+#[test]
+fn test_round_trip_arbitrary_bytes() {
+    let mut rng = SimpleRng::from_seed(1139);
+    for len in [1, 2, 10, 100, 1_000, 5_000] {
+        let data: Vec<u8> = (0..len).map(|_| rng.below(256) as u8).collect();
+        round_trip(&data);
+    }
+    round_trip("¡Huffman con UTF-8! 🦀🦀".as_bytes());
+}
+
+#[test]
+fn test_empty_and_single_symbol_inputs() {
+    let (bits, tree) = huffman::encode(b"");
+    assert!(bits.is_empty());
+    assert_eq!(huffman::decode(&bits, &tree).unwrap(), b"");
+
+    let data = [b'z'; 40];
+    let (bits, tree) = huffman::encode(&data);
+    assert_eq!(tree.code(b'z'), Some([false].as_slice()));
+    assert_eq!(bits.len(), 40);
+    assert_eq!(huffman::decode(&bits, &tree).unwrap(), data);
+}
+
+#[test]
+fn test_codes_are_prefix_free() {
+    let tree = huffman::build_tree(b"the quick brown fox jumps over the lazy dog");
+    let codes: Vec<&[bool]> = (0..=u8::MAX).filter_map(|b| tree.code(b)).collect();
+    assert_eq!(codes.len(), 27);
+    for (i, a) in codes.iter().enumerate() {
+        for b in &codes[i + 1..] {
+            assert!(!a.starts_with(b) && !b.starts_with(a));
+        }
+    }
+    assert_eq!(tree.code(b'#'), None);
+}
+
+#[test]
+fn test_skewed_distribution_compresses() {
+    let mut data = vec![b'a'; 900];
+    data.extend([b'b'; 60]);
+    data.extend([b'c'; 30]);
+    data.extend(b"defghijklm");
+
+    let (bits, tree) = huffman::encode(&data);
+    assert!(bits.len() < data.len() * 8 / 4);
+    assert_eq!(tree.code(b'a').unwrap().len(), 1);
+    assert_eq!(huffman::decode(&bits, &tree).unwrap(), data);
+}
+
+#[test]
+fn test_truncated_stream_is_an_error() {
+    // 'm' es el último y el más raro: su código tiene varios bits
+    let data = b"aaaaaaaabbbbcm";
+    let (bits, tree) = huffman::encode(data);
+    assert!(tree.code(b'm').unwrap().len() > 1);
+
+    let truncated: BitVec = bits.iter().take(bits.len() - 1).collect();
+    assert_eq!(
+        huffman::decode(&truncated, &tree),
+        Err(DecodeError::Truncated { decoded: 13 })
+    );
+}
+
+#[test]
+fn test_invalid_bits_for_degenerate_trees() {
+    let (_, single) = huffman::encode(b"xxx");
+    let bits: BitVec = [false, true].into_iter().collect();
+    assert_eq!(
+        huffman::decode(&bits, &single),
+        Err(DecodeError::InvalidCode { position: 1 })
+    );
+
+    let (_, empty) = huffman::encode(b"");
+    assert!(huffman::decode(&bits, &empty).is_err());
+}
@@ -0,0 +1,123 @@
+use strings::Rope;
+use vectors::SimpleRng;
+
+/// Inserta en un String por índice de carácter.
+fn string_insert(s: &mut String, char_index: usize, text: &str) {
+    let byte = s.char_indices().nth(char_index).map_or(s.len(), |(b, _)| b);
+    s.insert_str(byte, text);
+}
+
+fn string_remove(s: &mut String, start: usize, end: usize) {
+    let removed: String = s.chars().skip(start).take(end - start).collect();
+    let from = s.char_indices().nth(start).map_or(s.len(), |(b, _)| b);
+    s.replace_range(from..from + removed.len(), "");
+}
+
+#[test]
+fn test_random_edits_match_string() {
+    const PIECES: [&str; 6] = ["a", "hola ", "ñandú", "🦀", "línea\n", "xyz0123456789"];
+    let mut rng = SimpleRng::from_seed(1137);
+    let mut rope = Rope::new();
+    let mut model = String::new();
+
+    for step in 0..4_000 {
+        let len = model.chars().count();
+        if len == 0 || rng.below(3) != 0 {
+            let index = rng.below(len as u64 + 1) as usize;
+            let piece = PIECES[rng.below(PIECES.len() as u64) as usize];
+            rope.insert(index, piece);
+            string_insert(&mut model, index, piece);
+        } else {
+            let start = rng.below(len as u64) as usize;
+            let end = start + rng.below((len - start).min(20) as u64 + 1) as usize;
+            rope.remove(start..end);
+            string_remove(&mut model, start, end);
+        }
+
+        assert_eq!(rope.len_chars(), model.chars().count());
+        assert_eq!(rope.len_bytes(), model.len());
+        if step % 100 == 0 {
+            assert_eq!(rope.to_string(), model);
+        }
+    }
+
+    assert_eq!(rope.to_string(), model);
+    assert!(rope.height() <= 24);
+}
+
+#[test]
+fn test_emoji_and_multibyte_boundaries() {
+    let mut rope = Rope::from("año 🦀 ñ");
+    assert_eq!(rope.len_chars(), 7);
+    assert_eq!(rope.len_bytes(), "año 🦀 ñ".len());
+    assert_eq!(rope.char_at(1), Some('ñ'));
+    assert_eq!(rope.char_at(4), Some('🦀'));
+    assert_eq!(rope.char_at(7), None);
+
+    rope.insert(5, "👋🏽");
+    assert_eq!(rope.to_string(), "año 🦀👋🏽 ñ");
+    rope.remove(4..5);
+    assert_eq!(rope.slice(4..6), "👋🏽");
+
+    // Un texto largo de caracteres de 4 bytes no se corta a mitad de uno
+    let long = "🦀".repeat(100);
+    let rope = Rope::from(long.as_str());
+    assert!(rope.chunks().count() > 1);
+    assert!(rope.chunks().all(|c| c.chars().all(|ch| ch == '🦀')));
+}
+
+#[test]
+fn test_split_at_edges_and_middle() {
+    let text = "dividir esta cuerda";
+
+    let (left, right) = Rope::from(text).split(0);
+    assert!(left.is_empty());
+    assert_eq!(right.to_string(), text);
+
+    let (left, right) = Rope::from(text).split(text.len());
+    assert_eq!(left.to_string(), text);
+    assert!(right.is_empty());
+
+    let (left, right) = Rope::from(text).split(8);
+    assert_eq!(
+        (left.to_string(), right.to_string()),
+        ("dividir ".into(), "esta cuerda".into())
+    );
+}
+
+#[test]
+fn test_concat_is_associative() {
+    let parts = [
+        "primera parte, ",
+        "la segunda 🦀, ",
+        &"y una tercera muy larga ".repeat(10),
+    ];
+    let abc = |p: &[&str]| p.iter().map(|s| Rope::from(*s)).collect::<Vec<_>>();
+
+    let [a, b, c]: [Rope; 3] = abc(&parts).try_into().unwrap();
+    let left = a.concat(b).concat(c);
+    let [a, b, c]: [Rope; 3] = abc(&parts).try_into().unwrap();
+    let right = a.concat(b.concat(c));
+
+    assert_eq!(left.to_string(), right.to_string());
+    assert_eq!(left.to_string(), parts.concat());
+}
+
+#[test]
+fn test_many_appends_stay_shallow() {
+    let mut rope = Rope::new();
+    for i in 0..5_000 {
+        let len = rope.len_chars();
+        rope.insert(len, &format!("{i:>70}"));
+    }
+    assert_eq!(rope.len_chars(), 5_000 * 70);
+    assert!(rope.height() <= 24);
+    assert_eq!(rope.slice(70..140).trim(), "1");
+}
+
+#[test]
+#[should_panic(expected = "inválido")]
+fn test_remove_out_of_range_panics() {
+    let mut rope = Rope::from("abc");
+    rope.remove(2..4);
+}
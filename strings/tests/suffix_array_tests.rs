@@ -0,0 +1,77 @@
+use strings::SuffixArray;
+use vectors::SimpleRng;
+
+fn naive_find_all(text: &str, pattern: &str) -> Vec<usize> {
+    let (text, pattern) = (text.as_bytes(), pattern.as_bytes());
+    (0..=text.len().saturating_sub(pattern.len()))
+        .filter(|&i| text[i..].starts_with(pattern))
+        .collect()
+}
+
+#[test]
+fn test_banana() {
+    let sa = SuffixArray::new("banana");
+    assert_eq!(sa.suffixes(), [5, 3, 1, 0, 4, 2]);
+    assert_eq!(sa.lcp(), [0, 1, 3, 0, 0, 2]);
+    assert_eq!(sa.longest_repeated_substring(), "ana");
+    assert_eq!(sa.find_all("na"), [2, 4]);
+    assert!(!sa.contains("nab"));
+}
+
+#[test]
+fn test_find_all_matches_naive_scan_on_random_text() {
+    let mut rng = SimpleRng::from_seed(1138);
+    for _ in 0..50 {
+        let len = rng.below(300) as usize;
+        let text: String = (0..len).map(|_| (b'a' + rng.below(3) as u8) as char).collect();
+        let sa = SuffixArray::new(&text);
+
+        // Los sufijos quedan realmente ordenados
+        let suffixes: Vec<&str> = sa.suffixes().iter().map(|&s| &text[s..]).collect();
+        assert!(suffixes.is_sorted());
+
+        for _ in 0..30 {
+            let m = 1 + rng.below(5) as usize;
+            let pattern: String = (0..m).map(|_| (b'a' + rng.below(3) as u8) as char).collect();
+            let expected = naive_find_all(&text, &pattern);
+            assert_eq!(sa.find_all(&pattern), expected);
+            assert_eq!(sa.contains(&pattern), !expected.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_patterns_at_start_and_end() {
+    let text = "abracadabra";
+    let sa = SuffixArray::new(text);
+    assert_eq!(sa.find_all("abra"), [0, 7]);
+    assert_eq!(sa.find_all(text), [0]);
+    assert_eq!(sa.find_all("a"), [0, 3, 5, 7, 10]);
+    assert_eq!(sa.find_all("ra"), [2, 9]);
+    assert_eq!(sa.longest_repeated_substring(), "abra");
+}
+
+#[test]
+fn test_pattern_longer_than_text_and_empty_inputs() {
+    let sa = SuffixArray::new("abc");
+    assert!(!sa.contains("abcd"));
+    assert!(sa.find_all("abcabc").is_empty());
+    assert_eq!(sa.find_all(""), [0, 1, 2]);
+    assert_eq!(sa.longest_repeated_substring(), "");
+
+    let empty = SuffixArray::new("");
+    assert!(empty.is_empty());
+    assert!(!empty.contains("a"));
+    assert_eq!(empty.longest_repeated_substring(), "");
+
+    let single = SuffixArray::new("x");
+    assert_eq!(single.suffixes(), [0]);
+}
+
+#[test]
+fn test_utf8_offsets_are_bytes() {
+    let text = "año, otro año";
+    let sa = SuffixArray::new(text);
+    assert_eq!(sa.find_all("año"), [0, 11]);
+    assert_eq!(sa.longest_repeated_substring(), "año");
+}
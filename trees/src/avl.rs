@@ -0,0 +1,348 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::mem;
+
+/*
+Árbol AVL
+
+Es un BST que además guarda en cada nodo su altura y mantiene, para TODO
+nodo, |altura(izq) - altura(der)| <= 1 (el "factor de balance"). Eso acota la
+altura a ~1.44·log2(n), incluso insertando claves ordenadas.
+
+Cuando un insert o remove rompe el balance se arregla con rotaciones al volver
+de la recursión. Los cuatro casos según dónde quedó el exceso:
+
+  LL (izquierda-izquierda)         rotar a la derecha en z
+            z                          y
+           / \                       /   \
+          y   D                     x     z
+         / \          ──►          / \   / \
+        x   C                     A   B C   D
+       / \
+      A   B
+
+  RR: el espejo de LL, rotar a la izquierda en z.
+  LR: el exceso está en el hijo DERECHO del hijo izquierdo: primero rotar a la
+      izquierda en y (queda un caso LL) y luego a la derecha en z.
+  RL: el espejo de LR.
+
+Como la altura es O(log n), aquí sí se usa recursión sin miedo a la pila.
+*/
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    /// Altura del subárbol con raíz en este nodo; una hoja mide 1.
+    height: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+impl<K, V> Node<K, V> {
+    fn leaf(key: K, value: V) -> Box<Self> {
+        Box::new(Node {
+            key,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        })
+    }
+
+    fn update_height(&mut self) {
+        self.height = 1 + height(&self.left).max(height(&self.right));
+    }
+
+    /// Altura izquierda menos altura derecha.
+    fn balance_factor(&self) -> isize {
+        height(&self.left) as isize - height(&self.right) as isize
+    }
+}
+
+fn rotate_right<K, V>(mut z: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut y = z.left.take().expect("rotar a la derecha requiere hijo izquierdo");
+    z.left = y.right.take();
+    z.update_height();
+    y.right = Some(z);
+    y.update_height();
+    y
+}
+
+fn rotate_left<K, V>(mut z: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    let mut y = z.right.take().expect("rotar a la izquierda requiere hijo derecho");
+    z.right = y.left.take();
+    z.update_height();
+    y.left = Some(z);
+    y.update_height();
+    y
+}
+
+/// Recalcula la altura de `node` y aplica la rotación que corresponda si
+/// quedó desbalanceado.
+fn rebalance<K, V>(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+    node.update_height();
+    let balance = node.balance_factor();
+
+    if balance > 1 {
+        // LR: primero convertirlo en LL
+        if node.left.as_ref().unwrap().balance_factor() < 0 {
+            node.left = node.left.take().map(rotate_left);
+        }
+        return rotate_right(node);
+    }
+    if balance < -1 {
+        // RL: primero convertirlo en RR
+        if node.right.as_ref().unwrap().balance_factor() > 0 {
+            node.right = node.right.take().map(rotate_right);
+        }
+        return rotate_left(node);
+    }
+    node
+}
+
+fn insert<K: Ord, V>(link: Link<K, V>, key: K, value: V, old: &mut Option<V>) -> Box<Node<K, V>> {
+    let Some(mut node) = link else {
+        return Node::leaf(key, value);
+    };
+
+    match key.cmp(&node.key) {
+        Ordering::Less => node.left = Some(insert(node.left.take(), key, value, old)),
+        Ordering::Greater => node.right = Some(insert(node.right.take(), key, value, old)),
+        Ordering::Equal => {
+            *old = Some(mem::replace(&mut node.value, value));
+            return node;
+        }
+    }
+    rebalance(node)
+}
+
+/// Desengancha el mínimo del subárbol `node`; retorna lo que queda y el mínimo.
+fn take_min<K, V>(mut node: Box<Node<K, V>>) -> (Link<K, V>, Box<Node<K, V>>) {
+    match node.left.take() {
+        None => (node.right.take(), node),
+        Some(left) => {
+            let (rest, min) = take_min(left);
+            node.left = rest;
+            (Some(rebalance(node)), min)
+        }
+    }
+}
+
+fn remove<K: Ord, V>(link: Link<K, V>, key: &K, removed: &mut Option<V>) -> Link<K, V> {
+    let mut node = link?;
+
+    match key.cmp(&node.key) {
+        Ordering::Less => node.left = remove(node.left.take(), key, removed),
+        Ordering::Greater => node.right = remove(node.right.take(), key, removed),
+        Ordering::Equal => {
+            let Node { value, left, right, .. } = *node;
+            *removed = Some(value);
+            return match (left, right) {
+                (None, None) => None,
+                (Some(child), None) | (None, Some(child)) => Some(child),
+                (Some(left), Some(right)) => {
+                    let (rest, mut successor) = take_min(right);
+                    successor.left = Some(left);
+                    successor.right = rest;
+                    Some(rebalance(successor))
+                }
+            };
+        }
+    }
+    Some(rebalance(node))
+}
+
+/// Mapa ordenado sobre un árbol AVL: altura O(log n) garantizada.
+///
+/// ```
+/// use trees::AvlMap;
+///
+/// let mut map = AvlMap::new();
+/// for k in 1..=7 {
+///     map.insert(k, k * k);
+/// }
+/// assert_eq!(map.height(), 3);
+/// assert_eq!(map.remove(&4), Some(16));
+/// assert_eq!(map.get(&5), Some(&25));
+/// ```
+pub struct AvlMap<K: Ord, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K: Ord, V> AvlMap<K, V> {
+    /// Crea un mapa vacío.
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Retorna la cantidad de claves.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si el mapa no tiene claves.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna la altura del árbol (0 si está vacío).
+    ///
+    /// # Complejidad
+    /// **O(1)**: cada nodo guarda la suya.
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Inserta `value` bajo `key` y retorna el valor anterior, si había.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut old = None;
+        self.root = Some(insert(self.root.take(), key, value, &mut old));
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Retorna una referencia al valor de `key`.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    /// Retorna `true` si el mapa contiene `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Quita `key` del mapa y retorna su valor.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let mut removed = None;
+        self.root = remove(self.root.take(), key, &mut removed);
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Retorna un iterador in-order (claves ascendentes) sobre `(&K, &V)`.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.len,
+        };
+        iter.push_left_spine(self.root.as_deref());
+        iter
+    }
+
+    /// Recorre todo el árbol verificando que cada altura guardada coincida
+    /// con la recalculada, que cada factor de balance esté en `-1..=1` y que
+    /// las claves respeten el orden de un BST.
+    ///
+    /// Pensado para tests: es **O(n)**.
+    ///
+    /// # Panics
+    /// Si alguna de esas invariantes no se cumple.
+    pub fn assert_balanced(&self) {
+        fn check<K: Ord, V>(link: &Link<K, V>, lower: Option<&K>, upper: Option<&K>) -> usize {
+            let Some(node) = link else {
+                return 0;
+            };
+            assert!(lower.is_none_or(|lo| *lo < node.key), "clave fuera de orden");
+            assert!(upper.is_none_or(|hi| node.key < *hi), "clave fuera de orden");
+
+            let left = check(&node.left, lower, Some(&node.key));
+            let right = check(&node.right, Some(&node.key), upper);
+            assert!(left.abs_diff(right) <= 1, "factor de balance {}", left as isize - right as isize);
+            assert_eq!(node.height, 1 + left.max(right), "altura guardada incorrecta");
+            node.height
+        }
+
+        check(&self.root, None, None);
+    }
+}
+
+impl<K: Ord, V> Default for AvlMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for AvlMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (k, v) in iter {
+            map.insert(k, v);
+        }
+        map
+    }
+}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for AvlMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a AvlMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// Iterador in-order creado por [`AvlMap::iter`].
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left_spine(&mut self, mut node: Option<&'a Node<K, V>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right.as_deref());
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
@@ -0,0 +1,356 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+
+/*
+Árbol binario de búsqueda (BST) sin balancear
+
+Invariante: para cada nodo, todo lo que está a su izquierda es menor y todo lo
+que está a su derecha es mayor. Recorrerlo en in-order da las claves ordenadas.
+
+                50
+              /    \
+            30      70
+           /  \       \
+         20    40      80
+
+Sin balanceo, insertar claves ya ordenadas degenera en una lista:
+
+        1
+         \
+          2
+           \
+            3   ...   altura n → get/insert/remove O(n)
+
+Por eso TODO aquí es iterativo (insert, remove, iter, Drop): con recursión un
+árbol degenerado de 100_000 nodos desbordaría la pila.
+*/
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+/// Mapa ordenado sobre un árbol binario de búsqueda sin balancear.
+///
+/// ```
+/// use trees::MyBst;
+///
+/// let mut tree = MyBst::new();
+/// tree.insert(2, "dos");
+/// tree.insert(1, "uno");
+/// tree.insert(3, "tres");
+/// let keys: Vec<_> = tree.iter().map(|(k, _)| *k).collect();
+/// assert_eq!(keys, [1, 2, 3]);
+/// assert_eq!(tree.remove(&2), Some("dos"));
+/// ```
+pub struct MyBst<K: Ord, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+
+impl<K: Ord, V> MyBst<K, V> {
+    /// Crea un árbol vacío.
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+
+    /// Retorna la cantidad de claves.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si el árbol no tiene claves.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna el enlace donde está (o debería estar) `key`.
+    fn find_link(&mut self, key: &K) -> &mut Link<K, V> {
+        let mut link = &mut self.root;
+        loop {
+            match link.as_deref().map(|node| key.cmp(&node.key)) {
+                None | Some(Ordering::Equal) => return link,
+                Some(Ordering::Less) => link = &mut link.as_mut().unwrap().left,
+                Some(Ordering::Greater) => link = &mut link.as_mut().unwrap().right,
+            }
+        }
+    }
+
+    /// Inserta `value` bajo `key` y retorna el valor anterior, si había.
+    ///
+    /// # Complejidad
+    /// **O(h)**, donde `h` es la altura del árbol.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let link = self.find_link(&key);
+        match link {
+            Some(node) => Some(mem::replace(&mut node.value, value)),
+            None => {
+                *link = Some(Box::new(Node {
+                    key,
+                    value,
+                    left: None,
+                    right: None,
+                }));
+                self.len += 1;
+                None
+            }
+        }
+    }
+
+    /// Retorna una referencia al valor de `key`.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(&node.value),
+            };
+        }
+        None
+    }
+
+    /// Retorna una referencia mutable al valor de `key`.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.find_link(key).as_mut().map(|node| &mut node.value)
+    }
+
+    /// Retorna `true` si el árbol contiene `key`.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Quita `key` del árbol y retorna su valor.
+    ///
+    /// Hay tres casos según los hijos del nodo a borrar:
+    ///
+    /// ```text
+    ///  hoja:        lo quitamos
+    ///  un hijo:     el hijo ocupa su lugar
+    ///  dos hijos:   su sucesor in-order (el mínimo del subárbol derecho)
+    ///               ocupa su lugar; el sucesor tiene a lo sumo un hijo
+    ///               (derecho), así que sacarlo es uno de los casos anteriores
+    /// ```
+    ///
+    /// # Complejidad
+    /// **O(h)**.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let link = self.find_link(key);
+        let mut node = link.take()?;
+
+        *link = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let mut right = Some(right);
+                let mut successor = Self::take_min(&mut right);
+                successor.left = Some(left);
+                successor.right = right;
+                Some(successor)
+            }
+        };
+
+        self.len -= 1;
+        Some(node.value)
+    }
+
+    /// Desengancha y retorna el nodo mínimo del subárbol no vacío `link`.
+    fn take_min(mut link: &mut Link<K, V>) -> Box<Node<K, V>> {
+        while link.as_ref().unwrap().left.is_some() {
+            link = &mut link.as_mut().unwrap().left;
+        }
+        let mut min = link.take().unwrap();
+        *link = min.right.take();
+        min
+    }
+
+    /// Retorna el par con la clave más pequeña.
+    pub fn min(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    /// Retorna el par con la clave más grande.
+    pub fn max(&self) -> Option<(&K, &V)> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some((&node.key, &node.value))
+    }
+
+    /// Retorna la altura del árbol (0 si está vacío).
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut level: Vec<&Node<K, V>> = self.root.as_deref().into_iter().collect();
+        while !level.is_empty() {
+            height += 1;
+            level = level
+                .iter()
+                .flat_map(|n| [n.left.as_deref(), n.right.as_deref()])
+                .flatten()
+                .collect();
+        }
+        height
+    }
+
+    /// Retorna un iterador in-order (claves ascendentes) sobre `(&K, &V)`.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.len,
+        };
+        iter.push_left_spine(self.root.as_deref());
+        iter
+    }
+
+    /// Retorna un iterador in-order sobre las claves dentro de `range`.
+    ///
+    /// ```
+    /// use trees::MyBst;
+    ///
+    /// let tree: MyBst<i32, ()> = (1..=10).map(|k| (k, ())).collect();
+    /// let keys: Vec<_> = tree.range(3..6).map(|(k, _)| *k).collect();
+    /// assert_eq!(keys, [3, 4, 5]);
+    /// ```
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V, R> {
+        let mut stack = Vec::new();
+
+        // Bajar hasta el primer nodo >= inicio, apilando solo los que están
+        // dentro: los menores al inicio (y su subárbol izquierdo) se saltan
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            let before_start = match range.start_bound() {
+                Bound::Included(start) => node.key < *start,
+                Bound::Excluded(start) => node.key <= *start,
+                Bound::Unbounded => false,
+            };
+            if before_start {
+                current = node.right.as_deref();
+            } else {
+                stack.push(node);
+                current = node.left.as_deref();
+            }
+        }
+
+        Range { stack, range }
+    }
+}
+
+impl<K: Ord, V> Default for MyBst<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> Drop for MyBst<K, V> {
+    /// Libera los nodos con una pila explícita en vez de la recursión del
+    /// `Drop` por defecto de `Box`.
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<K, V>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for MyBst<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        for (k, v) in iter {
+            tree.insert(k, v);
+        }
+        tree
+    }
+}
+
+impl<K: Ord + fmt::Debug, V: fmt::Debug> fmt::Debug for MyBst<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K: Ord, V> IntoIterator for &'a MyBst<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// Iterador in-order creado por [`MyBst::iter`].
+///
+/// La pila guarda el camino de ancestros cuyo subárbol izquierdo ya se está
+/// recorriendo; nunca tiene más de `h` nodos.
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn push_left_spine(&mut self, mut node: Option<&'a Node<K, V>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right.as_deref());
+        self.remaining -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+/// Iterador in-order acotado, creado por [`MyBst::range`].
+pub struct Range<'a, K, V, R> {
+    stack: Vec<&'a Node<K, V>>,
+    range: R,
+}
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> Iterator for Range<'a, K, V, R> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        let node = self.stack.pop()?;
+
+        let past_end = match self.range.end_bound() {
+            Bound::Included(end) => node.key > *end,
+            Bound::Excluded(end) => node.key >= *end,
+            Bound::Unbounded => false,
+        };
+        if past_end {
+            self.stack.clear();
+            return None;
+        }
+
+        let mut current = node.right.as_deref();
+        while let Some(n) = current {
+            self.stack.push(n);
+            current = n.left.as_deref();
+        }
+        Some((&node.key, &node.value))
+    }
+}
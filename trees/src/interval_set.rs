@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::Range;
+
+/*
+Conjunto de intervalos
+
+Cada intervalo es SEMIABIERTO, [start, end): incluye `start` y excluye `end`.
+
+    [2, 5)   cubre 2, 3, 4        largo = end - start = 3
+    [5, 5)   vacío: insertarlo o quitarlo no hace nada
+
+Se guardan disjuntos, ordenados y SIN TOCARSE, en un BTreeMap start → end.
+Con intervalos semiabiertos "tocarse" es `a.end == b.start`: [2, 5) y [5, 8)
+no comparten ningún punto, pero juntos cubren exactamente [2, 8), así que se
+fusionan en uno solo. Entre dos intervalos guardados siempre queda al menos
+un punto libre.
+
+Insertar [4, 12) sobre {[1, 3), [4, 6), [8, 10), [12, 15)}:
+
+    0    3    6    9    12   15
+    ├─┤  ├─┤    ├─┤  ├──┤          antes
+       ├───────────┤               nuevo [4, 12)
+    ├─┤  ├──────────────┤          después: {[1, 3), [4, 15)}
+
+  1. si el intervalo anterior llega hasta `start` (end >= start), el nuevo
+     empieza donde empieza él;
+  2. se absorben los intervalos que empiezan en [start, end] (con `end`
+     incluido: el que empieza justo en `end` toca y también se fusiona).
+
+Quitar [5, 9) de {[1, 12)} parte el intervalo en dos: {[1, 5), [9, 12)}.
+*/
+
+/// Conjunto de puntos `u64` guardado como intervalos semiabiertos disjuntos.
+///
+/// ```
+/// use trees::IntervalSet;
+///
+/// let mut set = IntervalSet::new();
+/// set.insert(0..5);
+/// set.insert(5..10); // toca al anterior: se fusionan
+/// set.remove(3..4);  // abre un hueco
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [0..3, 4..10]);
+/// assert_eq!(set.covered_len(), 9);
+/// assert!(!set.contains(3));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    // start → end
+    intervals: BTreeMap<u64, u64>,
+}
+
+impl IntervalSet {
+    /// Crea un conjunto vacío.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retorna la cantidad de intervalos disjuntos.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Retorna `true` si no cubre ningún punto.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Retorna la cantidad total de puntos cubiertos.
+    pub fn covered_len(&self) -> u64 {
+        self.intervals.iter().map(|(start, end)| end - start).sum()
+    }
+
+    /// Agrega `range`, fusionándolo con los intervalos que se solapan o lo
+    /// tocan. Un rango vacío no hace nada.
+    ///
+    /// # Complejidad
+    /// **O((k + 1) log n)**, con k la cantidad de intervalos absorbidos.
+    pub fn insert(&mut self, range: Range<u64>) {
+        let Range { mut start, mut end } = range;
+        if start >= end {
+            return;
+        }
+
+        // 1. El anterior que empieza antes y llega al menos hasta `start`
+        if let Some((&s, &e)) = self.intervals.range(..start).next_back()
+            && e >= start
+        {
+            start = s;
+            end = end.max(e);
+            self.intervals.remove(&s);
+        }
+
+        // 2. Los que empiezan dentro de [start, end], incluido el que toca en `end`
+        while let Some((&s, &e)) = self.intervals.range(start..=end).next() {
+            end = end.max(e);
+            self.intervals.remove(&s);
+        }
+
+        self.intervals.insert(start, end);
+    }
+
+    /// Quita los puntos de `range`, recortando o partiendo en dos los
+    /// intervalos afectados. Un rango vacío no hace nada.
+    ///
+    /// # Complejidad
+    /// **O((k + 1) log n)**, con k la cantidad de intervalos tocados.
+    pub fn remove(&mut self, range: Range<u64>) {
+        let Range { start, end } = range;
+        if start >= end {
+            return;
+        }
+
+        // El anterior que empieza antes de `start` puede sobresalir por la
+        // izquierda y también por la derecha (el rango le abre un hueco)
+        if let Some((&s, &e)) = self.intervals.range(..start).next_back()
+            && e > start
+        {
+            self.intervals.insert(s, start);
+            if e > end {
+                self.intervals.insert(end, e);
+            }
+        }
+
+        // Los que empiezan dentro del rango se borran; si sobresalen por la
+        // derecha queda el sobrante [end, e)
+        while let Some((&s, &e)) = self.intervals.range(start..end).next() {
+            self.intervals.remove(&s);
+            if e > end {
+                self.intervals.insert(end, e);
+            }
+        }
+    }
+
+    /// Retorna `true` si `point` está cubierto.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn contains(&self, point: u64) -> bool {
+        self.intervals
+            .range(..=point)
+            .next_back()
+            .is_some_and(|(_, &end)| point < end)
+    }
+
+    /// Retorna `true` si algún punto de `range` está cubierto. Un rango vacío
+    /// no solapa con nada.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn overlaps(&self, range: Range<u64>) -> bool {
+        if range.start >= range.end {
+            return false;
+        }
+        // El último intervalo que empieza antes de range.end es el único candidato
+        self.intervals
+            .range(..range.end)
+            .next_back()
+            .is_some_and(|(_, &end)| end > range.start)
+    }
+
+    /// Retorna un iterador sobre los intervalos, disjuntos y en orden.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            inner: self.intervals.iter(),
+        }
+    }
+}
+
+impl fmt::Debug for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<Range<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<u64>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/// Iterador sobre los intervalos de un [`IntervalSet`], creado por
+/// [`IntervalSet::iter`].
+pub struct Iter<'a> {
+    inner: std::collections::btree_map::Iter<'a, u64, u64>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Range<u64>;
+
+    fn next(&mut self) -> Option<Range<u64>> {
+        self.inner.next().map(|(&start, &end)| start..end)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Range<u64>> {
+        self.inner.next_back().map(|(&start, &end)| start..end)
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = Range<u64>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
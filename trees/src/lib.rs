@@ -0,0 +1,11 @@
+mod avl;
+mod bst;
+mod interval_set;
+mod segment_tree;
+mod trie;
+
+pub use avl::{AvlMap, Iter as AvlIter};
+pub use bst::{Iter, MyBst, Range};
+pub use interval_set::{IntervalSet, Iter as IntervalIter};
+pub use segment_tree::{Bounded, SegmentTree};
+pub use trie::{IterPrefix, Trie};
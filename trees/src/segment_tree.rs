@@ -0,0 +1,193 @@
+use std::ops::{Add, Bound, RangeBounds};
+
+use vectors::MyVec;
+
+/*
+Árbol de segmentos (versión iterativa "de abajo hacia arriba")
+
+Se guarda en un arreglo de 2n posiciones: las hojas (los datos) van en
+[n, 2n) y cada nodo interno `i` combina a sus hijos `2i` y `2i + 1`. La
+posición 0 no se usa.
+
+    n = 4, datos [a, b, c, d], ⊕ = combine
+
+    índice:   1        2      3      4   5   6   7
+            a⊕b⊕c⊕d   a⊕b    c⊕d     a   b   c   d
+
+- update(i): cambia la hoja n+i y recalcula sus ancestros (i /= 2): O(log n).
+- query(l..r): dos cursores suben desde las hojas l y r; cada vez que un
+  cursor queda en un hijo "suelto" (que su padre no cubre entero) se toma ese
+  nodo. El izquierdo acumula por la izquierda y el derecho por la derecha, así
+  que el resultado respeta el orden aunque `combine` no sea conmutativa
+  (solo debe ser asociativa, con `identity` como neutro).
+*/
+
+/// Tipos con un valor mínimo y máximo: los neutros de `max` y `min`.
+pub trait Bounded: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty),*) => {
+        $(impl Bounded for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_bounded!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Árbol de segmentos con consultas de rango y actualizaciones puntuales.
+///
+/// `combine` debe ser asociativa e `identity` su elemento neutro.
+///
+/// ```
+/// use trees::SegmentTree;
+///
+/// let mut tree = SegmentTree::sum(&[5, 1, 4, 2]);
+/// assert_eq!(tree.query(1..3), 5);
+/// tree.update(2, 10);
+/// assert_eq!(tree.query(..), 18);
+/// ```
+pub struct SegmentTree<T, F: Fn(&T, &T) -> T> {
+    nodes: MyVec<T>,
+    len: usize,
+    identity: T,
+    combine: F,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> T> SegmentTree<T, F> {
+    /// Construye el árbol a partir de `data`.
+    ///
+    /// # Complejidad
+    /// **O(n)**.
+    pub fn new(data: &[T], identity: T, combine: F) -> Self {
+        let len = data.len();
+        let mut nodes = MyVec::from_fn(2 * len, |i| {
+            if i < len {
+                identity.clone()
+            } else {
+                data[i - len].clone()
+            }
+        });
+
+        for i in (1..len).rev() {
+            let combined = combine(nodes.get(2 * i).unwrap(), nodes.get(2 * i + 1).unwrap());
+            *nodes.get_mut(i).unwrap() = combined;
+        }
+
+        Self {
+            nodes,
+            len,
+            identity,
+            combine,
+        }
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si el árbol no tiene elementos.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna el elemento `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        self.nodes.get(self.len + index)
+    }
+
+    /// Reemplaza el elemento `index` por `value`.
+    ///
+    /// # Panics
+    /// Si `index >= len`.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn update(&mut self, index: usize, value: T) {
+        assert!(index < self.len, "índice {index} fuera de rango (largo {})", self.len);
+
+        let mut pos = self.len + index;
+        *self.nodes.get_mut(pos).unwrap() = value;
+        while pos > 1 {
+            pos /= 2;
+            let combined = (self.combine)(
+                self.nodes.get(2 * pos).unwrap(),
+                self.nodes.get(2 * pos + 1).unwrap(),
+            );
+            *self.nodes.get_mut(pos).unwrap() = combined;
+        }
+    }
+
+    /// Combina, en orden, los elementos dentro de `range`.
+    ///
+    /// Un rango vacío retorna `identity`.
+    ///
+    /// # Panics
+    /// Si el rango se sale de `0..len` o su inicio es mayor que su fin.
+    ///
+    /// # Complejidad
+    /// **O(log n)**.
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e + 1,
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.len,
+        };
+        assert!(
+            start <= end && end <= self.len,
+            "rango {start}..{end} inválido para largo {}",
+            self.len
+        );
+
+        let mut left_acc = self.identity.clone();
+        let mut right_acc = self.identity.clone();
+        let (mut l, mut r) = (start + self.len, end + self.len);
+
+        while l < r {
+            if l % 2 == 1 {
+                left_acc = (self.combine)(&left_acc, self.nodes.get(l).unwrap());
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                right_acc = (self.combine)(self.nodes.get(r).unwrap(), &right_acc);
+            }
+            l /= 2;
+            r /= 2;
+        }
+
+        (self.combine)(&left_acc, &right_acc)
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Default> SegmentTree<T, fn(&T, &T) -> T> {
+    /// Árbol de sumas; el neutro es `T::default()` (el cero).
+    pub fn sum(data: &[T]) -> Self {
+        Self::new(data, T::default(), |a, b| *a + *b)
+    }
+}
+
+impl<T: Bounded> SegmentTree<T, fn(&T, &T) -> T> {
+    /// Árbol de mínimos; el neutro es `T::MAX`.
+    pub fn min(data: &[T]) -> Self {
+        Self::new(data, T::MAX, |a, b| *a.min(b))
+    }
+
+    /// Árbol de máximos; el neutro es `T::MIN`.
+    pub fn max(data: &[T]) -> Self {
+        Self::new(data, T::MIN, |a, b| *a.max(b))
+    }
+}
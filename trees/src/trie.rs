@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+/*
+Trie (árbol de prefijos)
+
+Cada arista es un BYTE de la clave (no un char): las claves son `&str`, así que
+se recorren sus bytes UTF-8. Como el orden de los bytes UTF-8 coincide con el
+orden de los code points, recorrer los hijos en orden de byte da las claves en
+orden lexicográfico.
+
+Las palabras que comparten prefijo comparten camino; `*` marca los nodos que
+terminan una clave (tienen valor):
+
+    raíz ─c─ a ─r* ─p─ e ─t*        "car", "carpet"
+                  └─t*              "cart"
+         ─d─ o ─g*                  "dog"
+
+Borrar "carpet" tiene que podar p─e─t (quedaron sin valor y sin hijos) pero
+detenerse en "r", que todavía es el final de "car" y el camino de "cart".
+*/
+
+struct TrieNode<V> {
+    value: Option<V>,
+    children: BTreeMap<u8, TrieNode<V>>,
+}
+
+impl<V> TrieNode<V> {
+    fn new() -> Self {
+        Self {
+            value: None,
+            children: BTreeMap::new(),
+        }
+    }
+
+    /// Un nodo sin valor ni hijos no aporta nada y se puede podar.
+    fn is_prunable(&self) -> bool {
+        self.value.is_none() && self.children.is_empty()
+    }
+
+    /// Quita la clave `rest` (relativa a este nodo) podando a la vuelta las
+    /// ramas que quedaron vacías.
+    fn remove(&mut self, rest: &[u8]) -> Option<V> {
+        let Some((&byte, tail)) = rest.split_first() else {
+            return self.value.take();
+        };
+
+        let child = self.children.get_mut(&byte)?;
+        let removed = child.remove(tail);
+        if child.is_prunable() {
+            self.children.remove(&byte);
+        }
+        removed
+    }
+}
+
+/// Mapa de claves `&str` a valores `V`, organizado como árbol de prefijos
+/// por bytes.
+///
+/// ```
+/// use trees::Trie;
+///
+/// let mut trie = Trie::new();
+/// trie.insert("car", 1);
+/// trie.insert("cart", 2);
+/// assert!(trie.contains_prefix("ca"));
+/// let under_car: Vec<_> = trie.iter_prefix("car").map(|(k, _)| k).collect();
+/// assert_eq!(under_car, ["car", "cart"]);
+/// ```
+pub struct Trie<V> {
+    root: TrieNode<V>,
+    len: usize,
+}
+
+impl<V> Trie<V> {
+    /// Crea un trie vacío.
+    pub fn new() -> Self {
+        Self {
+            root: TrieNode::new(),
+            len: 0,
+        }
+    }
+
+    /// Retorna la cantidad de claves.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si el trie no tiene claves.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna el nodo al final del camino `prefix`, si existe.
+    fn node(&self, prefix: &str) -> Option<&TrieNode<V>> {
+        prefix
+            .bytes()
+            .try_fold(&self.root, |node, byte| node.children.get(&byte))
+    }
+
+    /// Inserta `value` bajo `key` y retorna el valor anterior, si había.
+    ///
+    /// # Complejidad
+    /// **O(largo de la clave)**, sin importar cuántas claves haya.
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let node = key.bytes().fold(&mut self.root, |node, byte| {
+            node.children.entry(byte).or_insert_with(TrieNode::new)
+        });
+
+        let old = node.value.replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Retorna una referencia al valor de `key`.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.node(key)?.value.as_ref()
+    }
+
+    /// Retorna `true` si el trie contiene exactamente `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Retorna `true` si alguna clave empieza con `prefix`.
+    ///
+    /// El prefijo vacío es prefijo de todo, así que es `true` si el trie no
+    /// está vacío.
+    pub fn contains_prefix(&self, prefix: &str) -> bool {
+        // Gracias a la poda, todo nodo alcanzable lleva a alguna clave; solo
+        // la raíz puede quedar vacía
+        self.node(prefix).is_some_and(|node| !node.is_prunable())
+    }
+
+    /// Quita `key` y retorna su valor, podando las ramas que quedan vacías.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        let removed = self.root.remove(key.as_bytes());
+        if removed.is_some() {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Retorna un iterador sobre las claves que empiezan con `prefix` y sus
+    /// valores, en orden lexicográfico.
+    pub fn iter_prefix(&self, prefix: &str) -> IterPrefix<'_, V> {
+        IterPrefix {
+            stack: self
+                .node(prefix)
+                .map(|node| (prefix.as_bytes().to_vec(), node))
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// Retorna un iterador sobre todas las claves y valores, en orden
+    /// lexicográfico.
+    pub fn iter(&self) -> IterPrefix<'_, V> {
+        self.iter_prefix("")
+    }
+}
+
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Trie<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Iterador en preorden creado por [`Trie::iter_prefix`].
+///
+/// Cada nodo se visita antes que sus hijos y los hijos en orden de byte, así
+/// que "car" sale antes que "cart" y "cart" antes que "cat".
+pub struct IterPrefix<'a, V> {
+    stack: Vec<(Vec<u8>, &'a TrieNode<V>)>,
+}
+
+impl<'a, V> Iterator for IterPrefix<'a, V> {
+    type Item = (String, &'a V);
+
+    fn next(&mut self) -> Option<(String, &'a V)> {
+        while let Some((path, node)) = self.stack.pop() {
+            // En orden inverso para que el byte menor quede arriba de la pila
+            for (&byte, child) in node.children.iter().rev() {
+                let mut child_path = path.clone();
+                child_path.push(byte);
+                self.stack.push((child_path, child));
+            }
+
+            if let Some(value) = &node.value {
+                let key = String::from_utf8(path).expect("las claves se insertaron como &str");
+                return Some((key, value));
+            }
+        }
+        None
+    }
+}
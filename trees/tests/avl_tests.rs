@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use trees::AvlMap;
+use vectors::SimpleRng;
+
+/// Cota de altura de un AVL: 1.44·log2(n) + 2.
+fn max_height(n: usize) -> usize {
+    (1.44 * (n as f64).log2() + 2.0) as usize
+}
+
+#[test]
+fn test_basic_map_operations() {
+    let mut map = AvlMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.height(), 0);
+    assert_eq!(map.remove(&"x"), None);
+
+    assert_eq!(map.insert("b", 2), None);
+    assert_eq!(map.insert("a", 1), None);
+    assert_eq!(map.insert("b", 20), Some(2));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&"b"), Some(&20));
+    assert!(!map.contains_key(&"z"));
+    assert_eq!(format!("{map:?}"), r#"{"a": 1, "b": 20}"#);
+    map.assert_balanced();
+}
+
+#[test]
+fn test_each_rotation_case() {
+    // LL, RR, LR y RL con tres claves: todas terminan con 2 en la raíz
+    for order in [[3, 2, 1], [1, 2, 3], [3, 1, 2], [1, 3, 2]] {
+        let map: AvlMap<i32, ()> = order.into_iter().map(|k| (k, ())).collect();
+        map.assert_balanced();
+        assert_eq!(map.height(), 2, "orden {order:?}");
+    }
+}
+
+#[test]
+fn test_ascending_keys_stay_logarithmic() {
+    let n = 10_000;
+    let mut map = AvlMap::new();
+    for k in 1..=n {
+        map.insert(k, k);
+    }
+    map.assert_balanced();
+    assert!(map.height() <= max_height(n), "altura {}", map.height());
+
+    for k in (2..=n).step_by(2) {
+        assert_eq!(map.remove(&k), Some(k));
+    }
+    map.assert_balanced();
+    assert_eq!(map.len(), n / 2);
+    assert!(map.height() <= max_height(n / 2), "altura {}", map.height());
+
+    let keys: Vec<usize> = map.iter().map(|(k, _)| *k).collect();
+    assert_eq!(keys, (1..=n).step_by(2).collect::<Vec<_>>());
+    assert_eq!(map.iter().len(), n / 2);
+}
+
+#[test]
+fn test_random_operations_against_btreemap() {
+    let mut rng = SimpleRng::from_seed(0xA71);
+    let mut mine = AvlMap::new();
+    let mut reference = BTreeMap::new();
+
+    for step in 0..5_000u64 {
+        let key = rng.below(300);
+        if rng.below(3) < 2 {
+            assert_eq!(mine.insert(key, step), reference.insert(key, step));
+        } else {
+            assert_eq!(mine.remove(&key), reference.remove(&key));
+        }
+        if step % 500 == 0 {
+            mine.assert_balanced();
+        }
+    }
+
+    mine.assert_balanced();
+    assert!(mine.iter().eq(reference.iter()));
+}
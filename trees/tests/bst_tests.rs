@@ -0,0 +1,113 @@
+use trees::MyBst;
+use vectors::{MyVec, SimpleRng};
+
+fn shuffled(range: std::ops::Range<u32>, seed: u64) -> MyVec<u32> {
+    let mut keys: MyVec<u32> = range.collect();
+    keys.shuffle(&mut SimpleRng::from_seed(seed));
+    keys
+}
+
+fn keys(tree: &MyBst<u32, u32>) -> Vec<u32> {
+    tree.iter().map(|(k, _)| *k).collect()
+}
+
+#[test]
+fn test_insert_get_replace() {
+    let mut tree = MyBst::new();
+    assert!(tree.is_empty());
+    assert_eq!(tree.get(&1), None);
+    assert_eq!(tree.min(), None);
+
+    assert_eq!(tree.insert(5, "cinco"), None);
+    assert_eq!(tree.insert(2, "dos"), None);
+    assert_eq!(tree.insert(8, "ocho"), None);
+    assert_eq!(tree.insert(5, "CINCO"), Some("cinco"));
+    assert_eq!(tree.len(), 3);
+
+    assert_eq!(tree.get(&5), Some(&"CINCO"));
+    *tree.get_mut(&2).unwrap() = "DOS";
+    assert_eq!(tree.get(&2), Some(&"DOS"));
+    assert_eq!(tree.get_mut(&99), None);
+    assert_eq!(tree.min(), Some((&2, &"DOS")));
+    assert_eq!(tree.max(), Some((&8, &"ocho")));
+    assert_eq!(format!("{tree:?}"), r#"{2: "DOS", 5: "CINCO", 8: "ocho"}"#);
+}
+
+#[test]
+fn test_shuffled_insert_iterates_sorted_then_delete_every_other() {
+    let mut tree = MyBst::new();
+    for k in shuffled(1..1001, 7) {
+        tree.insert(k, k * 2);
+    }
+    assert_eq!(tree.len(), 1000);
+    assert_eq!(keys(&tree), (1..1001).collect::<Vec<_>>());
+    assert_eq!(tree.iter().len(), 1000);
+
+    for k in shuffled(1..1001, 8) {
+        if k % 2 == 0 {
+            assert_eq!(tree.remove(&k), Some(k * 2));
+        }
+    }
+    assert_eq!(tree.remove(&2), None);
+    assert_eq!(tree.len(), 500);
+    assert_eq!(keys(&tree), (1..1001).step_by(2).collect::<Vec<_>>());
+    for k in 1..1001 {
+        assert_eq!(tree.contains_key(&k), k % 2 == 1);
+    }
+}
+
+#[test]
+fn test_remove_leaf_one_child_and_root_with_two_children() {
+    //        50
+    //      /    \
+    //    30      70
+    //   /  \       \
+    //  20   40      80
+    let mut tree: MyBst<u32, u32> = [50, 30, 70, 20, 40, 80].into_iter().map(|k| (k, k)).collect();
+
+    // Raíz con dos hijos: la reemplaza su sucesor, 70
+    assert_eq!(tree.remove(&50), Some(50));
+    assert_eq!(keys(&tree), [20, 30, 40, 70, 80]);
+    assert_eq!(tree.height(), 3);
+
+    // Hoja
+    assert_eq!(tree.remove(&20), Some(20));
+    // Un hijo (70 → 80)
+    assert_eq!(tree.remove(&70), Some(70));
+    assert_eq!(keys(&tree), [30, 40, 80]);
+
+    for k in [30, 40, 80] {
+        assert_eq!(tree.remove(&k), Some(k));
+    }
+    assert!(tree.is_empty());
+    assert_eq!(tree.height(), 0);
+}
+
+fn collect<'a>(it: impl Iterator<Item = (&'a u32, &'a u32)>) -> Vec<u32> {
+    it.map(|(k, _)| *k).collect()
+}
+
+#[test]
+fn test_range() {
+    let tree: MyBst<u32, u32> = shuffled(0..100, 3).into_iter().map(|k| (k, k)).collect();
+
+    assert_eq!(collect(tree.range(10..15)), [10, 11, 12, 13, 14]);
+    assert_eq!(collect(tree.range(95..)), [95, 96, 97, 98, 99]);
+    assert_eq!(collect(tree.range(..=2)), [0, 1, 2]);
+    assert_eq!(collect(tree.range(200..300)), Vec::<u32>::new());
+    assert_eq!(tree.range(..).count(), 100);
+}
+
+#[test]
+fn test_degenerate_tree_does_not_overflow_stack() {
+    // Claves ascendentes: el árbol es una lista de 30_000 nodos
+    let mut tree = MyBst::new();
+    for k in 0..30_000u32 {
+        tree.insert(k, ());
+    }
+    assert_eq!(tree.height(), 30_000);
+    assert_eq!(tree.iter().count(), 30_000);
+    assert_eq!(tree.remove(&0), Some(()));
+    assert_eq!(tree.max(), Some((&29_999, &())));
+    drop(tree);
+}
@@ -0,0 +1,127 @@
+// Los intervalos esperados se escriben como arreglos de rangos
+#![allow(clippy::single_range_in_vec_init)]
+
+use std::ops::Range;
+
+use trees::IntervalSet;
+use vectors::SimpleRng;
+
+fn intervals(set: &IntervalSet) -> Vec<Range<u64>> {
+    set.iter().collect()
+}
+
+#[test]
+fn test_insert_merges_overlapping_and_touching() {
+    let mut set = IntervalSet::new();
+    set.insert(10..20);
+    set.insert(15..25); // solapa
+    assert_eq!(intervals(&set), [10..25]);
+
+    set.insert(25..30); // toca por la derecha
+    set.insert(5..10); // toca por la izquierda
+    assert_eq!(intervals(&set), [5..30]);
+
+    set.insert(31..40); // deja un hueco en 30
+    assert_eq!(intervals(&set), [5..30, 31..40]);
+    assert_eq!(set.covered_len(), 34);
+}
+
+#[test]
+fn test_insert_bridging_two_intervals() {
+    let mut set: IntervalSet = [0..5, 10..15, 20..25].into_iter().collect();
+    set.insert(5..10); // toca a ambos lados exactamente
+    assert_eq!(intervals(&set), [0..15, 20..25]);
+
+    set.insert(12..22); // cubre el hueco y solapa a ambos
+    assert_eq!(intervals(&set), [0..25]);
+
+    set.insert(3..7); // contenido en uno existente
+    assert_eq!(intervals(&set), [0..25]);
+}
+
+#[test]
+fn test_remove_from_middle_creates_hole() {
+    let mut set: IntervalSet = [0..10].into_iter().collect();
+    set.remove(4..6);
+    assert_eq!(intervals(&set), [0..4, 6..10]);
+    assert!(set.contains(3));
+    assert!(!set.contains(4));
+    assert!(!set.contains(5));
+    assert!(set.contains(6));
+
+    set.remove(0..4); // exactamente un intervalo
+    assert_eq!(intervals(&set), [6..10]);
+    set.remove(9..12); // recorta el final
+    set.remove(2..7); // recorta el inicio
+    assert_eq!(intervals(&set), [7..9]);
+}
+
+#[test]
+fn test_remove_spanning_several_intervals() {
+    let mut set: IntervalSet = [0..5, 10..15, 20..25, 30..35].into_iter().collect();
+    set.remove(3..32);
+    assert_eq!(intervals(&set), [0..3, 32..35]);
+
+    set.remove(5..30); // solo toca los bordes: no quita nada
+    assert_eq!(intervals(&set), [0..3, 32..35]);
+}
+
+#[test]
+fn test_empty_ranges_are_noops() {
+    let mut set: IntervalSet = [0..5].into_iter().collect();
+    set.insert(7..7);
+    #[allow(clippy::reversed_empty_ranges)]
+    set.insert(9..8);
+    set.remove(2..2);
+    assert_eq!(intervals(&set), [0..5]);
+    assert!(!set.overlaps(3..3));
+
+    let empty = IntervalSet::new();
+    assert!(empty.is_empty());
+    assert!(!empty.contains(0));
+}
+
+#[test]
+fn test_contains_and_overlaps_at_endpoints() {
+    let set: IntervalSet = [10..20].into_iter().collect();
+    assert!(!set.contains(9));
+    assert!(set.contains(10));
+    assert!(set.contains(19));
+    assert!(!set.contains(20));
+
+    assert!(!set.overlaps(0..10)); // termina justo donde empieza
+    assert!(!set.overlaps(20..30)); // empieza justo donde termina
+    assert!(set.overlaps(0..11));
+    assert!(set.overlaps(19..30));
+    assert!(set.overlaps(12..13));
+    assert!(set.overlaps(0..100));
+    assert_eq!(format!("{set:?}"), "{10..20}");
+}
+
+#[test]
+fn test_random_operations_match_bitmap() {
+    const N: u64 = 200;
+    let mut rng = SimpleRng::from_seed(1136);
+    let mut set = IntervalSet::new();
+    let mut model = [false; N as usize];
+
+    for _ in 0..3_000 {
+        let a = rng.below(N);
+        let b = rng.below(N + 1);
+        if rng.below(2) == 0 {
+            set.insert(a..b);
+            (a..b).for_each(|i| model[i as usize] = true);
+        } else {
+            set.remove(a..b);
+            (a..b).for_each(|i| model[i as usize] = false);
+        }
+
+        for i in 0..N {
+            assert_eq!(set.contains(i), model[i as usize]);
+        }
+        assert_eq!(set.covered_len(), model.iter().filter(|&&b| b).count() as u64);
+        // Disjuntos, ordenados y sin tocarse
+        let all = intervals(&set);
+        assert!(all.windows(2).all(|w| w[0].end < w[1].start));
+    }
+}
@@ -0,0 +1,85 @@
+use trees::SegmentTree;
+use vectors::SimpleRng;
+
+fn random_data(seed: u64, n: usize) -> Vec<i64> {
+    let mut rng = SimpleRng::from_seed(seed);
+    (0..n).map(|_| rng.below(2_000) as i64 - 1_000).collect()
+}
+
+#[test]
+fn test_every_range_against_brute_force() {
+    let data = random_data(5, 200);
+    let sum = SegmentTree::sum(&data);
+    let min = SegmentTree::min(&data);
+    let max = SegmentTree::max(&data);
+
+    for l in 0..=data.len() {
+        for r in l..=data.len() {
+            let slice = &data[l..r];
+            assert_eq!(sum.query(l..r), slice.iter().sum::<i64>(), "suma {l}..{r}");
+            assert_eq!(min.query(l..r), slice.iter().copied().min().unwrap_or(i64::MAX));
+            assert_eq!(max.query(l..r), slice.iter().copied().max().unwrap_or(i64::MIN));
+        }
+    }
+}
+
+#[test]
+fn test_interleaved_updates_and_queries() {
+    let mut rng = SimpleRng::from_seed(9);
+    let mut data = random_data(6, 77);
+    let mut tree = SegmentTree::min(&data);
+
+    for _ in 0..2_000 {
+        if rng.below(2) == 0 {
+            let i = rng.below(data.len() as u64) as usize;
+            let value = rng.below(1_000) as i64;
+            data[i] = value;
+            tree.update(i, value);
+            assert_eq!(tree.get(i), Some(&value));
+        } else {
+            let a = rng.below(data.len() as u64 + 1) as usize;
+            let b = rng.below(data.len() as u64 + 1) as usize;
+            let (l, r) = (a.min(b), a.max(b));
+            let expected = data[l..r].iter().copied().min().unwrap_or(i64::MAX);
+            assert_eq!(tree.query(l..r), expected);
+        }
+    }
+}
+
+#[test]
+fn test_single_element_and_empty() {
+    let mut tree = SegmentTree::max(&[42u8]);
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree.query(..), 42);
+    assert_eq!(tree.query(0..=0), 42);
+    assert_eq!(tree.query(1..1), u8::MIN);
+    tree.update(0, 7);
+    assert_eq!(tree.query(..), 7);
+    assert_eq!(tree.get(1), None);
+
+    let empty = SegmentTree::sum(&[] as &[u32]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.query(..), 0);
+}
+
+#[test]
+fn test_non_commutative_combine_keeps_order() {
+    let words: Vec<String> = "abcdefghijklm".chars().map(String::from).collect();
+    let mut tree = SegmentTree::new(&words, String::new(), |a, b| format!("{a}{b}"));
+
+    for l in 0..=words.len() {
+        for r in l..=words.len() {
+            assert_eq!(tree.query(l..r), words[l..r].concat(), "rango {l}..{r}");
+        }
+    }
+
+    tree.update(3, String::from("XYZ"));
+    assert_eq!(tree.query(2..5), "cXYZe");
+    assert_eq!(tree.query(..), "abcXYZefghijklm");
+}
+
+#[test]
+#[should_panic(expected = "inválido")]
+fn test_query_out_of_bounds_panics() {
+    SegmentTree::sum(&[1, 2, 3]).query(1..4);
+}
@@ -0,0 +1,94 @@
+use trees::Trie;
+
+const WORDS: [&str; 10] = [
+    "car", "carpet", "cart", "cat", "dog", "do", "dot", "zebra", "año", "a",
+];
+
+fn build() -> Trie<usize> {
+    let mut trie = Trie::new();
+    for (i, word) in WORDS.iter().enumerate() {
+        assert_eq!(trie.insert(word, i), None);
+    }
+    trie
+}
+
+fn keys_under(trie: &Trie<usize>, prefix: &str) -> Vec<String> {
+    trie.iter_prefix(prefix).map(|(k, _)| k).collect()
+}
+
+#[test]
+fn test_insert_get_and_replace() {
+    let mut trie = build();
+    assert_eq!(trie.len(), WORDS.len());
+    assert_eq!(trie.get("cart"), Some(&2));
+    assert_eq!(trie.get("año"), Some(&8));
+    assert_eq!(trie.get("ca"), None);
+    assert!(!trie.contains_key("carp"));
+
+    assert_eq!(trie.insert("cart", 100), Some(2));
+    assert_eq!(trie.len(), WORDS.len());
+
+    // La clave vacía también es válida: vive en la raíz
+    assert_eq!(trie.insert("", 0), None);
+    assert_eq!(trie.get(""), Some(&0));
+    assert_eq!(trie.len(), WORDS.len() + 1);
+}
+
+#[test]
+fn test_prefix_queries() {
+    let trie = build();
+    assert!(trie.contains_prefix("ca"));
+    assert!(trie.contains_prefix("carpet"));
+    assert!(trie.contains_prefix(""));
+    assert!(!trie.contains_prefix("carpets"));
+    assert!(!trie.contains_prefix("x"));
+
+    assert_eq!(keys_under(&trie, "car"), ["car", "carpet", "cart"]);
+    assert_eq!(keys_under(&trie, "do"), ["do", "dog", "dot"]);
+    assert_eq!(keys_under(&trie, "q"), Vec::<String>::new());
+
+    let empty: Trie<()> = Trie::new();
+    assert!(!empty.contains_prefix(""));
+    assert_eq!(empty.iter().count(), 0);
+}
+
+#[test]
+fn test_iteration_is_lexicographic() {
+    let trie = build();
+    let all = keys_under(&trie, "");
+
+    let mut expected: Vec<String> = WORDS.iter().map(|w| w.to_string()).collect();
+    expected.sort();
+    assert_eq!(all, expected);
+    assert_eq!(all.last().unwrap(), "zebra");
+    // "año" va después de todo lo ASCII que empieza con "a"
+    assert_eq!(keys_under(&trie, "a"), ["a", "año"]);
+}
+
+#[test]
+fn test_remove_prunes_without_breaking_prefixes() {
+    let mut trie = build();
+
+    // Una clave que es extensión de otra: "car" sigue intacto
+    assert_eq!(trie.remove("carpet"), Some(1));
+    assert!(!trie.contains_prefix("carp"));
+    assert_eq!(trie.get("car"), Some(&0));
+    assert_eq!(keys_under(&trie, "car"), ["car", "cart"]);
+
+    // Una clave que es prefijo de otras: "dog" y "dot" siguen intactos
+    assert_eq!(trie.remove("do"), Some(5));
+    assert_eq!(trie.get("do"), None);
+    assert!(trie.contains_prefix("do"));
+    assert_eq!(keys_under(&trie, "do"), ["dog", "dot"]);
+
+    // Quitar algo que no es clave (aunque sea prefijo) no cambia nada
+    assert_eq!(trie.remove("ca"), None);
+    assert_eq!(trie.remove("cartwheel"), None);
+    assert_eq!(trie.len(), WORDS.len() - 2);
+
+    for word in ["car", "cart", "cat", "dog", "dot", "zebra", "año", "a"] {
+        assert!(trie.remove(word).is_some());
+    }
+    assert!(trie.is_empty());
+    assert!(!trie.contains_prefix(""));
+}
@@ -0,0 +1,159 @@
+/*
+Benchmark: MyVec<u64> contra Vec<u64>
+
+Arnés hecho a mano, sin dependencias: cada caso se corre varias veces y se
+reporta media y desviación estándar en milisegundos.
+
+    cargo bench
+
+`black_box` evita que el optimizador borre los bucles: le "esconde" al
+compilador el valor, así que no puede probar que el resultado no se usa ni
+precalcularlo.
+*/
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use vectors::{MyVec, SimpleRng};
+
+const N: usize = 1_000_000;
+const RUNS: usize = 7;
+
+/// Media y desviación estándar (poblacional) de una serie de mediciones.
+struct Stats {
+    mean: f64,
+    stddev: f64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[f64]) -> Self {
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        Self {
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Corre `f` una vez para calentar y luego `RUNS` veces midiendo cada una.
+fn measure<F: FnMut()>(mut f: F) -> Stats {
+    f();
+
+    let samples: Vec<f64> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed().as_secs_f64() * 1000.0
+        })
+        .collect();
+
+    Stats::from_samples(&samples)
+}
+
+fn row(name: &str, mine: Stats, std: Stats) {
+    println!(
+        "│ {name:<16} │ {:>8.3} ± {:>6.3} │ {:>8.3} ± {:>6.3} │ {:>6.2}x │",
+        mine.mean,
+        mine.stddev,
+        std.mean,
+        std.stddev,
+        mine.mean / std.mean
+    );
+}
+
+fn main() {
+    let full_mine: MyVec<u64> = (0..N as u64).collect();
+    let full_std: Vec<u64> = (0..N as u64).collect();
+
+    let mut rng = SimpleRng::from_seed(0xBE_4C);
+    let indices: Vec<usize> = (0..N).map(|_| rng.below(N as u64) as usize).collect();
+
+    println!("N = {N}, {RUNS} corridas por caso (ms, media ± desviación)");
+    println!("┌──────────────────┬───────────────────┬───────────────────┬─────────┐");
+    println!("│ caso             │ MyVec             │ Vec               │ ratio   │");
+    println!("├──────────────────┼───────────────────┼───────────────────┼─────────┤");
+
+    row(
+        "push_back",
+        measure(|| {
+            let mut v = MyVec::new();
+            for i in 0..N as u64 {
+                v.push_back(black_box(i));
+            }
+            black_box(&v);
+        }),
+        measure(|| {
+            let mut v = Vec::new();
+            for i in 0..N as u64 {
+                v.push(black_box(i));
+            }
+            black_box(&v);
+        }),
+    );
+
+    row(
+        "get aleatorio",
+        measure(|| {
+            let mut acc = 0u64;
+            for &i in &indices {
+                acc = acc.wrapping_add(*full_mine.get(black_box(i)).unwrap());
+            }
+            black_box(acc);
+        }),
+        measure(|| {
+            let mut acc = 0u64;
+            for &i in &indices {
+                acc = acc.wrapping_add(*full_std.get(black_box(i)).unwrap());
+            }
+            black_box(acc);
+        }),
+    );
+
+    row(
+        "suma con iter",
+        measure(|| {
+            black_box(black_box(&full_mine).iter().sum::<u64>());
+        }),
+        measure(|| {
+            black_box(black_box(&full_std).iter().sum::<u64>());
+        }),
+    );
+
+    row(
+        "reserve + push",
+        measure(|| {
+            let mut v = MyVec::with_capacity(N);
+            for i in 0..N as u64 {
+                v.push_back(black_box(i));
+            }
+            black_box(&v);
+        }),
+        measure(|| {
+            let mut v = Vec::with_capacity(N);
+            for i in 0..N as u64 {
+                v.push(black_box(i));
+            }
+            black_box(&v);
+        }),
+    );
+
+    println!("└──────────────────┴───────────────────┴───────────────────┴─────────┘");
+
+    // Crecimiento: cuántas realocaciones hace cada uno para llegar a N
+    let (mut mine, mut std) = (MyVec::new(), Vec::new());
+    let (mut mine_grows, mut std_grows) = (0, 0);
+    for i in 0..N as u64 {
+        let (cap_mine, cap_std) = (mine.capacity(), std.capacity());
+        mine.push_back(i);
+        std.push(i);
+        mine_grows += usize::from(mine.capacity() != cap_mine);
+        std_grows += usize::from(std.capacity() != cap_std);
+    }
+    println!(
+        "crecimiento hasta {N}: MyVec {mine_grows} realocaciones (cap {}), Vec {std_grows} (cap {})",
+        mine.capacity(),
+        std.capacity()
+    );
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::MyVec;
+
+/*
+Vector de bits
+
+Cada `u64` guarda 64 booleanos: el bit `i` vive en la palabra `i / 64`, en la
+posición `i % 64` (el bit menos significativo es el índice más bajo).
+
+    índices:   0 .. 63 │ 64 .. 127 │ 128 .. 129
+    palabras:  words[0]│ words[1]  │ words[2]  (solo 2 bits usados)
+                                          └── 62 bits "de relleno"
+
+Los bits de relleno de la última palabra SIEMPRE valen 0. Si no, `count_ones`
+los contaría y `!` los prendería: por eso toda operación que pueda tocarlos
+(`invert`, `!`) termina llamando a `clear_trailing_bits`.
+*/
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Cantidad de palabras para guardar `len` bits.
+fn words_for(len: usize) -> usize {
+    len.div_ceil(WORD_BITS)
+}
+
+/// Vector de booleanos empaquetados de a 64 por palabra sobre un `MyVec<u64>`.
+///
+/// ```
+/// use vectors::BitVec;
+///
+/// let mut bits = BitVec::with_len(100);
+/// bits.set(3, true);
+/// bits.set(64, true);
+/// assert_eq!(bits.count_ones(), 2);
+/// assert_eq!(bits.ones().collect::<Vec<_>>(), [3, 64]);
+/// assert_eq!((!&bits).count_ones(), 98);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct BitVec {
+    words: MyVec<u64>,
+    len: usize,
+}
+
+impl BitVec {
+    /// Crea un vector de bits vacío.
+    pub fn new() -> Self {
+        Self {
+            words: MyVec::new(),
+            len: 0,
+        }
+    }
+
+    /// Crea un vector de `len` bits, todos en `false`.
+    pub fn with_len(len: usize) -> Self {
+        Self {
+            words: MyVec::from_elem_copy(0, words_for(len)),
+            len,
+        }
+    }
+
+    /// Retorna la cantidad de bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna `true` si no hay bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna el bit `index`, o `None` si está fuera de rango.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.len {
+            return None;
+        }
+        let word = self.words.get(index / WORD_BITS)?;
+        Some(word >> (index % WORD_BITS) & 1 == 1)
+    }
+
+    /// Asigna `value` al bit `index`.
+    ///
+    /// # Panics
+    /// Si `index >= len`.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(
+            index < self.len,
+            "índice {index} fuera de rango para un BitVec de largo {}",
+            self.len
+        );
+        let word = self.words.get_mut(index / WORD_BITS).unwrap();
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// Agrega un bit al final.
+    ///
+    /// # Complejidad
+    /// **O(1)** amortizado.
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(WORD_BITS) {
+            self.words.push_back(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Cuenta los bits en `true`.
+    ///
+    /// # Complejidad
+    /// **O(n / 64)**: una instrucción `popcnt` por palabra.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Retorna un iterador sobre todos los bits como `bool`.
+    pub fn iter(&self) -> Bits<'_> {
+        Bits {
+            bits: self,
+            front: 0,
+        }
+    }
+
+    /// Retorna un iterador sobre los índices de los bits en `true`, en orden.
+    ///
+    /// Salta palabras enteras en cero, así que en conjuntos dispersos es
+    /// mucho más rápido que filtrar `iter()`.
+    pub fn ones(&self) -> Ones<'_> {
+        Ones {
+            words: self.words.as_slice(),
+            word_index: 0,
+            current: self.words.get(0).copied().unwrap_or(0),
+        }
+    }
+
+    /// Invierte todos los bits en el lugar (la versión in-place de `!`).
+    pub fn invert(&mut self) {
+        for word in self.words.iter_mut() {
+            *word = !*word;
+        }
+        self.clear_trailing_bits();
+    }
+
+    /// Apaga los bits de relleno de la última palabra.
+    fn clear_trailing_bits(&mut self) {
+        let used = self.len % WORD_BITS;
+        if used != 0 {
+            let last = self.words.len() - 1;
+            *self.words.get_mut(last).unwrap() &= (1 << used) - 1;
+        }
+    }
+
+    /// Combina palabra a palabra con `other` aplicando `op`.
+    ///
+    /// # Panics
+    /// Si los largos son distintos.
+    fn zip_words(&mut self, other: &BitVec, op: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            self.len, other.len,
+            "las operaciones bit a bit requieren BitVec del mismo largo"
+        );
+        for (a, &b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a = op(*a, b);
+        }
+    }
+}
+
+impl Default for BitVec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for BitVec {
+    /// Muestra los bits del índice 0 en adelante: `BitVec[0110]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BitVec[")?;
+        for bit in self.iter() {
+            write!(f, "{}", bit as u8)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl FromIterator<bool> for BitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = BitVec::new();
+        for bit in iter {
+            bits.push(bit);
+        }
+        bits
+    }
+}
+
+/// Implementa `op` y `op=` para `BitVec` (por valor y por referencia).
+macro_rules! bitwise_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $sym:tt) => {
+        impl $OpAssign<&BitVec> for BitVec {
+            /// # Panics
+            /// Si los largos son distintos.
+            fn $op_assign(&mut self, other: &BitVec) {
+                self.zip_words(other, |a, b| a $sym b);
+            }
+        }
+
+        impl $Op<&BitVec> for &BitVec {
+            type Output = BitVec;
+
+            fn $op(self, other: &BitVec) -> BitVec {
+                let mut result = self.clone();
+                result.$op_assign(other);
+                result
+            }
+        }
+
+        impl $Op for BitVec {
+            type Output = BitVec;
+
+            fn $op(mut self, other: BitVec) -> BitVec {
+                self.$op_assign(&other);
+                self
+            }
+        }
+    };
+}
+
+bitwise_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+bitwise_op!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+bitwise_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl Not for &BitVec {
+    type Output = BitVec;
+
+    fn not(self) -> BitVec {
+        let mut result = self.clone();
+        result.invert();
+        result
+    }
+}
+
+impl Not for BitVec {
+    type Output = BitVec;
+
+    fn not(mut self) -> BitVec {
+        self.invert();
+        self
+    }
+}
+
+/// Iterador sobre los bits como `bool`, creado por [`BitVec::iter`].
+pub struct Bits<'a> {
+    bits: &'a BitVec,
+    front: usize,
+}
+
+impl Iterator for Bits<'_> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        let bit = self.bits.get(self.front)?;
+        self.front += 1;
+        Some(bit)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bits.len - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bits<'_> {}
+
+/// Iterador sobre los índices en `true`, creado por [`BitVec::ones`].
+pub struct Ones<'a> {
+    words: &'a [u64],
+    word_index: usize,
+    /// Bits de la palabra actual que todavía no se han entregado.
+    current: u64,
+}
+
+impl Iterator for Ones<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.word_index += 1;
+            self.current = *self.words.get(self.word_index)?;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // Apaga el bit más bajo encendido
+        self.current &= self.current - 1;
+        Some(self.word_index * WORD_BITS + bit)
+    }
+}
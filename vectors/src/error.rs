@@ -0,0 +1,79 @@
+use std::alloc::Layout;
+use std::error::Error;
+use std::fmt;
+
+/// Error retornado por las operaciones de reserva falibles como
+/// [`MyVec::try_reserve`](crate::MyVec::try_reserve).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// La capacidad pedida no cabe en `usize` o supera `isize::MAX` bytes.
+    CapacityOverflow,
+    /// El allocador retornó un puntero nulo para este `Layout`.
+    AllocError { layout: Layout },
+    /// El vector es acotado a `cap` elementos y la reserva lo superaría.
+    CapacityExceeded { cap: usize },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryReserveError::CapacityOverflow => write!(f, "capacity overflow"),
+            TryReserveError::AllocError { layout } => {
+                write!(f, "memory allocation of {} bytes failed", layout.size())
+            }
+            TryReserveError::CapacityExceeded { cap } => {
+                write!(f, "capacity limit of {cap} elements exceeded")
+            }
+        }
+    }
+}
+
+impl Error for TryReserveError {}
+
+/// Error de las operaciones verificadas de [`MyVec`](crate::MyVec), como
+/// [`try_insert`](crate::MyVec::try_insert) y [`try_remove`](crate::MyVec::try_remove).
+///
+/// Cada variante guarda los números que causaron el fallo para que el
+/// mensaje de `Display` sea útil por sí solo.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MyVecError {
+    /// La capacidad pedida no cabe en `usize` o supera `isize::MAX` bytes.
+    CapacityOverflow,
+    /// El allocador no pudo entregar `bytes` bytes.
+    AllocFailed { bytes: usize },
+    /// Se accedió a `index` en un vector de longitud `len`.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// El vector tiene un límite de `cap` elementos y ya está lleno.
+    CapacityExceeded { cap: usize },
+}
+
+impl fmt::Display for MyVecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MyVecError::CapacityOverflow => write!(f, "capacity overflow"),
+            MyVecError::AllocFailed { bytes } => {
+                write!(f, "memory allocation of {bytes} bytes failed")
+            }
+            MyVecError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} out of bounds for length {len}")
+            }
+            MyVecError::CapacityExceeded { cap } => {
+                write!(f, "capacity limit of {cap} elements exceeded")
+            }
+        }
+    }
+}
+
+impl Error for MyVecError {}
+
+impl From<TryReserveError> for MyVecError {
+    fn from(err: TryReserveError) -> Self {
+        match err {
+            TryReserveError::CapacityOverflow => MyVecError::CapacityOverflow,
+            TryReserveError::AllocError { layout } => MyVecError::AllocFailed {
+                bytes: layout.size(),
+            },
+            TryReserveError::CapacityExceeded { cap } => MyVecError::CapacityExceeded { cap },
+        }
+    }
+}
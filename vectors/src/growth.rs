@@ -0,0 +1,64 @@
+/*
+Estrategias de crecimiento
+
+Cuando un vector se llena hay que pedir un bloque nuevo y mover todo. El factor
+de crecimiento decide el equilibrio entre memoria y tiempo:
+
+| Estrategia     | Memoria desperdiciada | Reubicaciones para n pushes |
+| -------------- | --------------------- | --------------------------- |
+| `Doubling`     | hasta 50%             | ~log2(n)                    |
+| `OnePointFive` | hasta 33%             | ~log1.5(n)                  |
+| `Exact`        | 0%                    | n  (push deja de ser O(1))  |
+
+Cualquier factor geométrico (> 1) mantiene `push_back` en O(1) amortizado;
+`Exact` existe solo para comparar.
+*/
+
+/// Decide la nueva capacidad de un `MyVec` cuando necesita crecer.
+///
+/// `MyVec` garantiza el resultado final: si la estrategia retorna menos que
+/// `required`, se usa `required`.
+pub trait GrowthStrategy {
+    /// Retorna la nueva capacidad dado el tamaño actual del bloque y el
+    /// mínimo de elementos que deben caber.
+    fn next_capacity(current: usize, required: usize) -> usize;
+}
+
+/// Duplica la capacidad (empezando en 4). Es la estrategia por defecto.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Doubling;
+
+impl GrowthStrategy for Doubling {
+    fn next_capacity(current: usize, required: usize) -> usize {
+        if current == 0 {
+            required.max(4)
+        } else {
+            // Si se desborda, Layout lo reporta como "capacity overflow"
+            current.saturating_mul(2).max(required)
+        }
+    }
+}
+
+/// Crece un 50% cada vez (empezando en 4).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OnePointFive;
+
+impl GrowthStrategy for OnePointFive {
+    fn next_capacity(current: usize, required: usize) -> usize {
+        if current == 0 {
+            required.max(4)
+        } else {
+            current.saturating_add(current / 2).max(required)
+        }
+    }
+}
+
+/// Reserva exactamente lo necesario, sin espacio extra.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Exact;
+
+impl GrowthStrategy for Exact {
+    fn next_capacity(_current: usize, required: usize) -> usize {
+        required
+    }
+}
@@ -0,0 +1,93 @@
+use std::io::{self, Read, Write};
+
+use crate::{GrowthStrategy, MyVec, TryReserveError};
+
+/// `MyVec<u8>` como destino en memoria, igual que `Vec<u8>`.
+///
+/// Sin límite, `write` nunca escribe a medias: añade todo el buffer al final.
+/// En un vector acotado `write` escribe solo lo que quepa y `write_all` no
+/// escribe nada si no cabe todo. Con el vector lleno `write` retorna `Ok(0)`,
+/// como todo `io::Write` que no acepta más bytes, y `write_all` falla con
+/// `WriteZero`. Sin memoria, el error es `OutOfMemory`.
+///
+/// ```
+/// use std::io::Write;
+/// use vectors::MyVec;
+///
+/// let mut out: MyVec<u8> = MyVec::new();
+/// write!(out, "{} + {} = {}", 1, 2, 1 + 2).unwrap();
+/// assert_eq!(out, *b"1 + 2 = 3");
+/// ```
+impl<G: GrowthStrategy> Write for MyVec<u8, G> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Acotado: escritura parcial con lo que quepa, o nada si está lleno
+        let n = match self.remaining_capacity() {
+            Some(room) => buf.len().min(room),
+            None => buf.len(),
+        };
+        self.try_reserve(n).map_err(reserve_error)?;
+        self.extend_from_slice_copy(&buf[..n]);
+        Ok(n)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.try_reserve(buf.len()).map_err(reserve_error)?;
+        self.extend_from_slice_copy(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Traduce un fallo de reserva al `io::Error` que espera quien escribe.
+fn reserve_error(err: TryReserveError) -> io::Error {
+    let kind = match err {
+        TryReserveError::CapacityExceeded { .. } => io::ErrorKind::WriteZero,
+        TryReserveError::CapacityOverflow | TryReserveError::AllocError { .. } => {
+            io::ErrorKind::OutOfMemory
+        }
+    };
+    io::Error::new(kind, err)
+}
+
+/// Cursor de lectura sobre los bytes de un `MyVec<u8>`.
+///
+/// Guarda la posición del siguiente byte a leer; cada `read` avanza lo que
+/// copió. Al llegar al final, `read` retorna `Ok(0)`.
+pub struct MyVecReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> MyVecReader<'a> {
+    /// Crea un lector que empieza en el primer byte de `v`.
+    pub fn new<G: GrowthStrategy>(v: &'a MyVec<u8, G>) -> Self {
+        Self {
+            bytes: v.as_slice(),
+            pos: 0,
+        }
+    }
+
+    /// Retorna la posición del siguiente byte a leer.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Retorna los bytes que faltan por leer.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.pos..]
+    }
+}
+
+impl Read for MyVecReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.remaining();
+        let n = remaining.len().min(buf.len());
+
+        buf[..n].copy_from_slice(&remaining[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
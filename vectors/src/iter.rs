@@ -0,0 +1,324 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr::{self, NonNull};
+use std::slice;
+
+use crate::MyVec;
+
+/// Iterador sobre ventanas superpuestas de un `MyVec`.
+///
+/// Creado por [`MyVec::windows`](crate::MyVec::windows).
+pub struct Windows<'a, T> {
+    slice: &'a [T],
+    size: usize,
+}
+
+impl<'a, T> Windows<'a, T> {
+    pub(crate) fn new(slice: &'a [T], size: usize) -> Self {
+        Self { slice, size }
+    }
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.size > self.slice.len() {
+            return None;
+        }
+
+        let window = &self.slice[..self.size];
+        self.slice = &self.slice[1..];
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.slice.len() + 1).saturating_sub(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Windows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.size > self.slice.len() {
+            return None;
+        }
+
+        let len = self.slice.len();
+        let window = &self.slice[len - self.size..];
+        self.slice = &self.slice[..len - 1];
+        Some(window)
+    }
+}
+
+impl<T> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T> FusedIterator for Windows<'_, T> {}
+
+/// Iterador sobre tramos consecutivos que cumplen un predicado entre vecinos.
+///
+/// Creado por [`MyVec::chunk_by`](crate::MyVec::chunk_by).
+pub struct ChunkBy<'a, T, F> {
+    slice: &'a [T],
+    pred: F,
+}
+
+impl<'a, T, F> ChunkBy<'a, T, F> {
+    pub(crate) fn new(slice: &'a [T], pred: F) -> Self {
+        Self { slice, pred }
+    }
+}
+
+impl<'a, T, F: FnMut(&T, &T) -> bool> Iterator for ChunkBy<'a, T, F> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.slice.is_empty() {
+            return None;
+        }
+
+        // El tramo crece mientras cada par adyacente cumpla el predicado
+        let mut end = 1;
+        while end < self.slice.len() && (self.pred)(&self.slice[end - 1], &self.slice[end]) {
+            end += 1;
+        }
+
+        let (chunk, rest) = self.slice.split_at(end);
+        self.slice = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Al menos un tramo si queda algo, a lo sumo uno por elemento
+        let len = self.slice.len();
+        (usize::from(len > 0), Some(len))
+    }
+}
+
+impl<T, F: FnMut(&T, &T) -> bool> FusedIterator for ChunkBy<'_, T, F> {}
+
+/*
+Iteradores de MyVec
+
+Los tres iteradores recorren el bloque con dos cursores, `front` y `back`
+(rango semiabierto `[front, back)`). `next()` avanza `front` y `next_back()`
+retrocede `back`; cuando se cruzan el iterador se agotó y sigue retornando
+`None` para siempre (FusedIterator). Como `back - front` es exactamente lo que
+queda, `size_hint` es exacto (ExactSizeIterator).
+
+    front               back
+      │                   │
+      ▼                   ▼
+    ┌───┬───┬───┬───┬───┬───┐
+    │ a │ b │ c │ d │ e │   │
+    └───┴───┴───┴───┴───┴───┘
+*/
+
+/// Iterador sobre `&T`, creado por [`MyVec::iter`](crate::MyVec::iter).
+pub struct Iter<'a, T> {
+    ptr: NonNull<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub(crate) fn new(slice: &'a [T]) -> Self {
+        Self {
+            ptr: NonNull::from(slice).cast(),
+            front: 0,
+            back: slice.len(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let elem = unsafe { &*self.ptr.as_ptr().add(self.front) };
+        self.front += 1;
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(unsafe { &*self.ptr.as_ptr().add(self.back) })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+/// Iterador sobre `&mut T`, creado por [`MyVec::iter_mut`](crate::MyVec::iter_mut).
+pub struct IterMut<'a, T> {
+    ptr: NonNull<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn new(slice: &'a mut [T]) -> Self {
+        let back = slice.len();
+        Self {
+            ptr: NonNull::from(slice).cast(),
+            front: 0,
+            back,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // Cada índice se entrega una sola vez, así que no hay dos &mut al mismo elemento
+        let elem = unsafe { &mut *self.ptr.as_ptr().add(self.front) };
+        self.front += 1;
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(unsafe { &mut *self.ptr.as_ptr().add(self.back) })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// Iterador que consume un `MyVec` y entrega sus elementos por valor.
+///
+/// Creado por `MyVec::into_iter`. Al destruirse, destruye los elementos que
+/// no se consumieron (por cualquiera de los dos extremos) y libera el bloque.
+pub struct IntoIter<T> {
+    ptr: NonNull<MaybeUninit<T>>,
+    capacity: usize,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> IntoIter<T> {
+    /// Toma posesión de un bloque con `len` elementos inicializados.
+    pub(crate) fn new(ptr: NonNull<MaybeUninit<T>>, capacity: usize, len: usize) -> Self {
+        Self {
+            ptr,
+            capacity,
+            front: 0,
+            back: len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Retorna como slice los elementos que aún no se consumieron.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(
+                self.ptr.as_ptr().add(self.front) as *const T,
+                self.back - self.front,
+            )
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        // Se lee (mueve) el valor; el cursor avanza para no leerlo otra vez
+        let elem = unsafe { ptr::read(self.ptr.as_ptr().add(self.front)).assume_init() };
+        self.front += 1;
+        Some(elem)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(unsafe { ptr::read(self.ptr.as_ptr().add(self.back)).assume_init() })
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        unsafe {
+            // Solo [front, back) sigue vivo: lo consumido por ambos extremos ya se movió
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.ptr.as_ptr().add(self.front) as *mut T,
+                self.back - self.front,
+            ));
+
+            if self.capacity > 0 {
+                MyVec::<T>::dealloc_block(self.ptr, self.capacity);
+            }
+        }
+    }
+}
+
+// SAFETY: mismas razones que `MyVec`: IntoIter es el único dueño del bloque
+unsafe impl<T: Send> Send for IntoIter<T> {}
+unsafe impl<T: Sync> Sync for IntoIter<T> {}
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
@@ -1,42 +1,361 @@
-use std::alloc::{alloc, dealloc, Layout};
-use std::mem::MaybeUninit;
+use std::alloc::{alloc, alloc_zeroed, dealloc, handle_alloc_error, Layout};
+use std::borrow::{Borrow, BorrowMut};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::{Bound, RangeBounds};
 use std::ptr::{self, NonNull};
+use std::slice;
 
-pub struct MyVec<T> {
+mod bit_vec;
+mod error;
+mod growth;
+mod io;
+mod iter;
+mod matrix;
+mod parallel;
+mod radix;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod rng;
+mod set_len_on_drop;
+mod small_vec;
+mod sorted_vec;
+mod stats;
+mod zeroable;
+
+pub use bit_vec::{BitVec, Bits, Ones};
+pub use error::{MyVecError, TryReserveError};
+pub use growth::{Doubling, Exact, GrowthStrategy, OnePointFive};
+pub use io::MyVecReader;
+pub use rng::SimpleRng;
+pub use iter::{ChunkBy, IntoIter, Iter, IterMut, Windows};
+pub use matrix::Matrix2D;
+pub use radix::RadixKey;
+pub use small_vec::MySmallVec;
+pub use sorted_vec::SortedVec;
+pub use stats::MemoryStats;
+pub use zeroable::Zeroable;
+
+use set_len_on_drop::SetLenOnDrop;
+
+/// Capacidad mínima que conserva la política de encogimiento automático
+/// (ver [`MyVec::set_auto_shrink`]), para no realojar una y otra vez con
+/// vectores casi vacíos.
+pub const MIN_AUTO_SHRINK_CAPACITY: usize = 4;
+
+/// Vector dinámico implementado a mano sobre `std::alloc`.
+///
+/// El parámetro `G` elige la [`GrowthStrategy`] usada al crecer; por defecto
+/// es [`Doubling`], así que `MyVec<T>` se comporta como siempre.
+///
+/// # Hilos
+/// `MyVec<T>` es `Send` y `Sync` exactamente cuando `T` lo es. Un vector de
+/// `Rc` no se puede mover a otro hilo:
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use vectors::MyVec;
+///
+/// let mut v = MyVec::new();
+/// v.push_back(Rc::new(1));
+/// std::thread::spawn(move || v.len());
+/// ```
+///
+/// # Drop check
+/// Como `MyVec` destruye sus elementos en `Drop`, los datos prestados que
+/// guarda deben vivir más que el vector:
+///
+/// ```compile_fail
+/// use vectors::MyVec;
+///
+/// let mut v = MyVec::new();
+/// let local = String::from("vive menos que v");
+/// v.push_back(&local);
+/// // `local` se destruye antes que `v`, pero el Drop de `v` podría leerlo
+/// ```
+pub struct MyVec<T, G: GrowthStrategy = Doubling> {
     ptr: NonNull<MaybeUninit<T>>,
     capacity: usize,
     len: usize,
+    /*
+    El puntero crudo no le dice al compilador que el vector es DUEÑO de
+    valores `T`. `PhantomData<T>` se comporta como si guardáramos un `T`:
+
+    - Drop check: el compilador sabe que al destruir `MyVec<T>` se destruyen
+      valores `T`, así que exige que lo que `T` presta siga vivo en ese momento.
+    - Varianza: `MyVec<T>` es covariante en `T` (igual que `Vec<T>`), por lo que
+      un `MyVec<&'static str>` se puede usar donde se espera `MyVec<&'a str>`.
+    - Auto traits: no cambia nada, Send/Sync se implementan a mano abajo.
+    */
+    _marker: PhantomData<T>,
+    // La estrategia es solo un tipo, nunca se guarda un valor `G`
+    growth: PhantomData<fn() -> G>,
+    // Política opcional de encogimiento, ver `set_auto_shrink`
+    auto_shrink: bool,
+    // Límite de capacidad del modo acotado, ver `with_max_capacity`
+    max_capacity: Option<usize>,
 }
 
+// SAFETY: `MyVec` es el único dueño de su bloque de memoria; nadie más guarda
+// el puntero. Mover el vector a otro hilo equivale a mover sus elementos `T`,
+// así que basta con que `T: Send`.
+unsafe impl<T: Send, G: GrowthStrategy> Send for MyVec<T, G> {}
+
+// SAFETY: a través de `&MyVec<T>` solo se obtienen `&T` (no hay mutabilidad
+// interior en el vector), así que compartirlo entre hilos es tan seguro como
+// compartir `&T`, que es justo lo que garantiza `T: Sync`.
+unsafe impl<T: Sync, G: GrowthStrategy> Sync for MyVec<T, G> {}
+
 impl<T> MyVec<T> {
     pub fn new() -> Self {
+        Self::with_strategy()
+    }
+
+    /// Crea un vector vacío con espacio para `cap` elementos.
+    ///
+    /// # Panics
+    /// Con el mensaje "capacity overflow" si `cap` elementos ocupan más de
+    /// `isize::MAX` bytes.
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_strategy(cap)
+    }
+
+    /// Crea un vector con `cap` elementos en cero usando `alloc_zeroed`.
+    ///
+    /// A diferencia de `from_elem(0, n)` no hay un bucle que escriba cada
+    /// elemento: el allocador entrega memoria ya en cero (a menudo páginas
+    /// nuevas del sistema operativo, que vienen en cero gratis).
+    /// Queda `len == capacity == cap`.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let buf = MyVec::<u8>::with_capacity_zeroed(1 << 20);
+    /// assert_eq!(buf.len(), 1 << 20);
+    /// assert!(buf.as_slice().iter().all(|&b| b == 0));
+    /// ```
+    ///
+    /// # Panics
+    /// Con el mensaje "capacity overflow" si `cap` elementos ocupan más de
+    /// `isize::MAX` bytes.
+    pub fn with_capacity_zeroed(cap: usize) -> Self
+    where
+        T: Zeroable,
+    {
+        let mut v = Self::new();
+        if cap == 0 {
+            return v;
+        }
+
+        let layout = Self::layout_for(cap).unwrap_or_else(|_| capacity_overflow());
+        let ptr = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            let raw_ptr = unsafe { alloc_zeroed(layout) } as *mut MaybeUninit<T>;
+            NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
+        };
+
+        v.ptr = ptr;
+        v.capacity = cap;
+        // SAFETY: `T: Zeroable` garantiza que todos los bytes en cero forman
+        // un `T` válido, así que los `cap` elementos ya están inicializados.
+        unsafe { v.set_len(cap) };
+        v
+    }
+
+    /// Crea un vector acotado que nunca tendrá más de `cap` elementos.
+    ///
+    /// El bloque de `cap` elementos se reserva de una vez, así que el vector
+    /// no necesita realojar para crecer. Si se encoge (`shrink_to_fit` o
+    /// [`set_auto_shrink`](Self::set_auto_shrink)) puede volver a crecer, pero
+    /// nunca más allá de `cap`. `push_back` sobre un vector lleno entra en
+    /// pánico; [`push_within_capacity`](Self::push_within_capacity) devuelve
+    /// el elemento en su lugar.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut log = MyVec::with_max_capacity(2);
+    /// log.push_back("a");
+    /// log.push_back("b");
+    /// assert!(log.is_full());
+    /// assert_eq!(log.push_within_capacity("c"), Err("c"));
+    /// ```
+    pub fn with_max_capacity(cap: usize) -> Self {
+        let mut v = Self::with_capacity(cap);
+        v.max_capacity = Some(cap);
+        v
+    }
+
+    /// Crea un vector con `n` copias de `value`.
+    ///
+    /// Asigna una sola vez exactamente `n` espacios y escribe `n - 1` clones;
+    /// el último espacio recibe `value` movido, ahorrando un clon.
+    /// Con `n == 0` no asigna memoria.
+    pub fn from_elem(value: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut v = Self::with_capacity(n);
+        if n > 0 {
+            v.resize(n, value);
+        }
+        v
+    }
+
+    /// Crea un vector de `n` elementos donde el elemento `i` es `f(i)`.
+    ///
+    /// Reserva exactamente `n` slots y llama a `f(0), f(1), ..., f(n - 1)` en
+    /// orden. Si `f` entra en pánico, los elementos ya escritos se destruyen y
+    /// el bloque se libera.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let squares = MyVec::from_fn(5, |i| i * i);
+    /// assert_eq!(squares, [0, 1, 4, 9, 16]);
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(n: usize, mut f: F) -> Self {
+        let mut v = Self::with_capacity(n);
+        let base = v.ptr.as_ptr();
+
+        // El guard escribe `len` al salir, también si `f` entra en pánico: así
+        // el Drop de `v` ve solo el prefijo inicializado.
+        let mut guard = SetLenOnDrop::new(&mut v.len);
+        for i in 0..n {
+            let elem = f(i);
+            unsafe { ptr::write(base.add(i), MaybeUninit::new(elem)) };
+            guard.increment_len(1);
+        }
+        drop(guard);
+
+        v
+    }
+
+    /// Como `from_elem`, pero para tipos `Copy`: escribe el valor una vez y
+    /// luego duplica el bloque ya escrito con memcpy (1, 2, 4, ... elementos).
+    pub fn from_elem_copy(value: T, n: usize) -> Self
+    where
+        T: Copy,
+    {
+        let mut v = Self::with_capacity(n);
+        if n == 0 {
+            return v;
+        }
+
+        v.push_back(value);
+        while v.len < n {
+            let chunk = v.len.min(n - v.len);
+            unsafe {
+                let base = v.ptr.as_ptr();
+                ptr::copy_nonoverlapping(base, base.add(v.len), chunk);
+            }
+            v.len += chunk;
+        }
+        v
+    }
+
+    /// Crea un vector copiando `other` con un solo memcpy.
+    pub fn from_slice_copy(other: &[T]) -> Self
+    where
+        T: Copy,
+    {
+        let mut v = Self::with_capacity(other.len());
+        v.extend_from_slice_copy(other);
+        v
+    }
+}
+
+impl<T, G: GrowthStrategy> MyVec<T, G> {
+    /// Crea un vector vacío que crece según la estrategia `G`.
+    ///
+    /// ```
+    /// use vectors::{MyVec, OnePointFive};
+    ///
+    /// let mut v: MyVec<u8, OnePointFive> = MyVec::with_strategy();
+    /// v.push_back(1);
+    /// ```
+    pub fn with_strategy() -> Self {
         Self {
             ptr: NonNull::dangling(),
             capacity: 0,
             len: 0,
+            _marker: PhantomData,
+            growth: PhantomData,
+            auto_shrink: false,
+            max_capacity: None,
+        }
+    }
+
+    /// Como `with_capacity`, pero con la estrategia de crecimiento `G`.
+    pub fn with_capacity_and_strategy(cap: usize) -> Self {
+        let mut v = Self::with_strategy();
+        if cap > 0 {
+            handle_reserve(v.try_relocate(cap));
+        }
+        v
+    }
+
+    /// Calcula el `Layout` para `cap` elementos verificando cada operación.
+    ///
+    /// Igual que std, limita el tamaño total a `isize::MAX` bytes: `ptr.add()`
+    /// recibe un desplazamiento con signo, así que un bloque más grande no se
+    /// podría recorrer de forma segura.
+    fn layout_for(cap: usize) -> Result<Layout, TryReserveError> {
+        let bytes = mem::size_of::<T>()
+            .checked_mul(cap)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if bytes > isize::MAX as usize {
+            return Err(TryReserveError::CapacityOverflow);
         }
+
+        Layout::from_size_align(bytes, mem::align_of::<T>())
+            .map_err(|_| TryReserveError::CapacityOverflow)
     }
 
     /// Asigna un nuevo bloque de memoria para `cap` elementos.
     ///
-    /// Retorna un `NonNull` apuntando al nuevo bloque de memoria sin inicializar.
-    /// Esta es una función auxiliar usada por `grow`.
-    fn allocate_raw(cap: usize) -> NonNull<MaybeUninit<T>> {
+    /// Retorna un `NonNull` apuntando al nuevo bloque de memoria sin inicializar,
+    /// o un error si el tamaño no es representable o el allocador falla.
+    /// Esta es una función auxiliar usada por `try_relocate`.
+    ///
+    /// Si `T` es de tamaño cero el bloque ocupa 0 bytes: pedirle 0 bytes al
+    /// allocador viola su contrato, así que se usa el puntero dangling.
+    fn try_allocate_raw(cap: usize) -> Result<NonNull<MaybeUninit<T>>, TryReserveError> {
         assert!(cap > 0);
-        let layout = Layout::array::<MaybeUninit<T>>(cap).unwrap();
+        let layout = Self::layout_for(cap)?;
+        if layout.size() == 0 {
+            return Ok(NonNull::dangling());
+        }
         let raw_ptr = unsafe { alloc(layout) } as *mut MaybeUninit<T>;
-        NonNull::new(raw_ptr).expect("allocation failed")
+        NonNull::new(raw_ptr).ok_or(TryReserveError::AllocError { layout })
     }
 
-    /// Aumenta la capacidad del vector cuando se queda sin espacio.
-    fn grow(&mut self) {
-        let new_cap = if self.capacity == 0 {
-            4
-        } else {
-            self.capacity * 2
-        };
+    /// Libera un bloque de `capacity` elementos obtenido con `try_allocate_raw`.
+    ///
+    /// No hace nada si el bloque no ocupa bytes (capacidad 0 o `T` de tamaño
+    /// cero): ese bloque nunca salió del allocador.
+    ///
+    /// # Safety
+    /// `ptr` debe ser ese bloque, con esa misma `capacity`, y no usarse después.
+    unsafe fn dealloc_block(ptr: NonNull<MaybeUninit<T>>, capacity: usize) {
+        // Este layout ya se validó cuando se asignó el bloque
+        let layout = unsafe { Self::layout_for(capacity).unwrap_unchecked() };
+        if layout.size() != 0 {
+            unsafe { dealloc(ptr.as_ptr() as *mut u8, layout) };
+        }
+    }
 
-        let new_ptr = Self::allocate_raw(new_cap);
+    /// Mueve los elementos a un nuevo bloque de `new_cap` elementos y libera el viejo.
+    ///
+    /// Sirve tanto para crecer como para encoger (`new_cap >= len`).
+    /// Si la asignación falla el vector queda intacto.
+    fn try_relocate(&mut self, new_cap: usize) -> Result<(), TryReserveError> {
+        let new_ptr = Self::try_allocate_raw(new_cap)?;
 
         if self.capacity > 0 {
             unsafe {
@@ -45,17 +364,196 @@ impl<T> MyVec<T> {
                     new_ptr.as_ptr(),
                     self.len,
                 );
-
-                let old_layout = Layout::array::<MaybeUninit<T>>(self.capacity).unwrap();
-                dealloc(self.ptr.as_ptr() as *mut u8, old_layout);
+                Self::dealloc_block(self.ptr, self.capacity);
             }
         }
 
         self.ptr = new_ptr;
         self.capacity = new_cap;
+        Ok(())
+    }
+
+    /// Aumenta la capacidad del vector cuando se queda sin espacio.
+    fn grow(&mut self) {
+        let required = match self.len.checked_add(1) {
+            Some(required) => required,
+            None => capacity_overflow(),
+        };
+
+        if let Some(max) = self.max_capacity
+            && required > max
+        {
+            panic!("bounded MyVec is full: max capacity is {max}");
+        }
+
+        handle_reserve(self.try_relocate(self.next_capacity(required)));
+    }
+
+    /// Pregunta a la estrategia `G` la nueva capacidad, garantizando que
+    /// nunca sea menor que `required` aunque la estrategia se equivoque.
+    ///
+    /// En modo acotado el resultado se recorta al límite; quien llama ya
+    /// verificó que `required` no lo supera.
+    fn next_capacity(&self, required: usize) -> usize {
+        let cap = G::next_capacity(self.capacity, required).max(required);
+        match self.max_capacity {
+            Some(max) => cap.min(max),
+            None => cap,
+        }
+    }
+
+    /// Intenta reservar espacio para al menos `additional` elementos más.
+    ///
+    /// A diferencia de `reserve`, no entra en pánico: si el tamaño pedido no es
+    /// representable o el allocador no tiene memoria, retorna el error y el
+    /// vector queda sin cambios. En un vector acotado, pedir más allá del
+    /// límite retorna `CapacityExceeded`.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        if let Some(max) = self.max_capacity
+            && required > max
+        {
+            return Err(TryReserveError::CapacityExceeded { cap: max });
+        }
+
+        self.try_relocate(self.next_capacity(required))
+    }
+
+    /// Reserva espacio para al menos `additional` elementos más.
+    ///
+    /// # Panics
+    /// Con "capacity overflow" si la nueva capacidad no es representable.
+    /// Si el allocador falla se llama a `handle_alloc_error`, que aborta.
+    pub fn reserve(&mut self, additional: usize) {
+        handle_reserve(self.try_reserve(additional));
+    }
+
+    /// Reduce la capacidad para que sea exactamente `len`.
+    ///
+    /// Si el vector está vacío libera el bloque y vuelve al puntero dangling.
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity == self.len {
+            return;
+        }
+
+        if self.len == 0 {
+            unsafe { Self::dealloc_block(self.ptr, self.capacity) };
+            self.ptr = NonNull::dangling();
+            self.capacity = 0;
+            return;
+        }
+
+        handle_reserve(self.try_relocate(self.len));
+    }
+
+    /// Activa o desactiva el encogimiento automático (desactivado por defecto).
+    ///
+    /// Con la política activa, `pop_back`, `truncate` y `remove` reducen la
+    /// capacidad a la mitad cada vez que `len` cae por debajo de `capacity / 4`,
+    /// sin bajar nunca de [`MIN_AUTO_SHRINK_CAPACITY`].
+    pub fn set_auto_shrink(&mut self, enabled: bool) {
+        self.auto_shrink = enabled;
+    }
+
+    /// Retorna `true` si el encogimiento automático está activo.
+    pub fn auto_shrink(&self) -> bool {
+        self.auto_shrink
+    }
+
+    /*
+    ¿Por qué encoger a la mitad cuando len < capacity/4 y no cuando len < capacity/2?
+
+    Si el umbral coincidiera con el factor de crecimiento, un vector lleno que
+    alterna push/pop justo en el borde realojaría en CADA operación:
+
+        cap 8, len 8 → push → cap 16 → pop → len 8 < 16/2 → cap 8 → push → ...
+
+    Con el umbral en 1/4, después de encoger queda len ≈ cap/2: hacen falta
+    muchas operaciones para volver a disparar cualquiera de los dos realojos,
+    así que el costo amortizado sigue siendo O(1).
+    */
+    /// Aplica la política de encogimiento automático si está activa.
+    fn maybe_auto_shrink(&mut self) {
+        if !self.auto_shrink {
+            return;
+        }
+
+        let mut new_cap = self.capacity;
+        while new_cap > MIN_AUTO_SHRINK_CAPACITY && self.len < new_cap / 4 {
+            new_cap = (new_cap / 2).max(MIN_AUTO_SHRINK_CAPACITY);
+        }
+
+        if new_cap < self.capacity {
+            // Encoger es una optimización: si el allocador falla, el vector
+            // sigue siendo válido con el bloque viejo.
+            let _ = self.try_relocate(new_cap);
+        }
+    }
+
+    /// Intenta añadir un elemento al final sin entrar en pánico.
+    ///
+    /// Si no se puede conseguir espacio, devuelve el elemento dentro de `Err`.
+    pub fn try_push(&mut self, new_elem: T) -> Result<(), T> {
+        if self.len >= self.capacity && self.try_reserve(1).is_err() {
+            return Err(new_elem);
+        }
+
+        unsafe {
+            let dst = self.ptr.as_ptr().add(self.len);
+            ptr::write(dst, MaybeUninit::new(new_elem));
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Añade un elemento al final solo si cabe en la capacidad actual.
+    ///
+    /// Nunca realoja: si `len == capacity` devuelve el elemento en `Err`.
+    /// En un vector acotado equivale a "añadir si no está lleno".
+    pub fn push_within_capacity(&mut self, elem: T) -> Result<(), T> {
+        if self.len == self.capacity {
+            return Err(elem);
+        }
+
+        unsafe {
+            ptr::write(self.ptr.as_ptr().add(self.len), MaybeUninit::new(elem));
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Retorna el límite de capacidad si el vector es acotado.
+    pub fn max_capacity(&self) -> Option<usize> {
+        self.max_capacity
+    }
+
+    /// Retorna `true` si el vector es acotado y alcanzó su límite.
+    ///
+    /// Un vector sin límite nunca está lleno: siempre puede crecer.
+    pub fn is_full(&self) -> bool {
+        self.max_capacity == Some(self.len)
+    }
+
+    /// Retorna cuántos elementos más admite un vector acotado, o `None` si
+    /// el vector no tiene límite.
+    pub fn remaining_capacity(&self) -> Option<usize> {
+        self.max_capacity.map(|max| max - self.len)
     }
 
     /// Añade un elemento al final del vector.
+    ///
+    /// # Panics
+    /// Si el vector es acotado y ya está lleno.
     pub fn push_back(&mut self, new_elem: T) {
         if self.len >= self.capacity {
             self.grow();
@@ -69,45 +567,1387 @@ impl<T> MyVec<T> {
         self.len += 1;
     }
 
-    /// Obtiene una referencia inmutable al elemento en la posición `index`.
+    /// Quita y retorna el último elemento, o `None` si el vector está vacío.
     ///
     /// # Complejidad
-    /// **O(1)** - Acceso en tiempo constante usando aritmética de punteros.
-    pub fn get(&self, index: usize) -> Option<&T> {
-        if index >= self.len {
+    /// **O(1)**
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
             return None;
         }
 
+        self.len -= 1;
+        // El slot queda fuera de `len`: se lee (mueve) una sola vez
+        let elem = unsafe { ptr::read(self.ptr.as_ptr().add(self.len)).assume_init() };
+        self.maybe_auto_shrink();
+        Some(elem)
+    }
+
+    /// Inserta un elemento en la posición `index`, desplazando hacia la derecha
+    /// todos los elementos posteriores.
+    ///
+    /// # Complejidad
+    /// **O(n)** - Hay que mover los `len - index` elementos siguientes.
+    ///
+    /// # Panics
+    /// Si `index > len`.
+    pub fn insert(&mut self, index: usize, elem: T) {
+        assert!(index <= self.len, "insertion index (is {index}) should be <= len (is {})", self.len);
+
+        if self.len >= self.capacity {
+            self.grow();
+        }
+
         unsafe {
-            let element_ptr = self.ptr.as_ptr().add(index);
-            Some((*element_ptr).assume_init_ref())
+            let src = self.ptr.as_ptr().add(index);
+            // Los bloques se superponen, por eso `copy` y no `copy_nonoverlapping`
+            ptr::copy(src, src.add(1), self.len - index);
+            ptr::write(src, MaybeUninit::new(elem));
         }
+
+        self.len += 1;
     }
 
-    /// Obtiene una referencia mutable al elemento en la posición `index`.
-    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+    /// Quita y retorna el elemento en la posición `index`, desplazando hacia la
+    /// izquierda todos los elementos posteriores.
+    ///
+    /// # Complejidad
+    /// **O(n)** - Hay que mover los `len - index - 1` elementos siguientes.
+    ///
+    /// # Panics
+    /// Si `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index (is {index}) should be < len (is {})", self.len);
+
+        let elem = unsafe {
+            let hole = self.ptr.as_ptr().add(index);
+            let elem = ptr::read(hole).assume_init();
+            ptr::copy(hole.add(1), hole, self.len - index - 1);
+            elem
+        };
+
+        self.len -= 1;
+        self.maybe_auto_shrink();
+        elem
+    }
+
+    /// Versión verificada de [`insert`](Self::insert): en lugar de entrar en
+    /// pánico retorna el error.
+    ///
+    /// # Errors
+    /// - `IndexOutOfBounds` si `index > len`.
+    /// - `CapacityExceeded` si el vector es acotado y ya está lleno.
+    /// - `CapacityOverflow` o `AllocFailed` si no se pudo conseguir espacio.
+    pub fn try_insert(&mut self, index: usize, elem: T) -> Result<(), MyVecError> {
+        if index > self.len {
+            return Err(MyVecError::IndexOutOfBounds { index, len: self.len });
+        }
+
+        self.try_reserve(1)?;
+        self.insert(index, elem);
+        Ok(())
+    }
+
+    /// Versión verificada de [`remove`](Self::remove): retorna
+    /// `IndexOutOfBounds` si `index >= len` en lugar de entrar en pánico.
+    pub fn try_remove(&mut self, index: usize) -> Result<T, MyVecError> {
         if index >= self.len {
-            return None;
+            return Err(MyVecError::IndexOutOfBounds { index, len: self.len });
         }
 
-        unsafe {
-            let element_ptr = self.ptr.as_ptr().add(index);
-            Some((*element_ptr).assume_init_mut())
+        Ok(self.remove(index))
+    }
+
+    /// Retorna `true` si los elementos están en orden no decreciente.
+    ///
+    /// Útil como `debug_assert!(v.is_sorted())` antes de `binary_search`.
+    /// Un vector vacío o de un solo elemento siempre está ordenado.
+    ///
+    /// # Complejidad
+    /// **O(n)** - Compara cada par adyacente una vez.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Retorna `true` si `compare(a, b)` se cumple para cada par adyacente `(a, b)`.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let v = MyVec::from_slice_copy(&[9, 5, 5, 1]);
+    /// assert!(v.is_sorted_by(|a, b| a >= b));
+    /// ```
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut compare: F) -> bool {
+        self.windows(2).all(|pair| compare(&pair[0], &pair[1]))
+    }
+
+    /// Retorna `true` si las claves `f(x)` están en orden no decreciente.
+    pub fn is_sorted_by_key<K: PartialOrd, F: FnMut(&T) -> K>(&self, mut f: F) -> bool {
+        self.is_sorted_by(|a, b| f(a) <= f(b))
+    }
+
+    /// Reordena el vector para que el elemento en `n` quede en su posición
+    /// ordenada (quickselect), sin ordenar el resto.
+    ///
+    /// Retorna `(menores, n-ésimo, mayores)`: todo lo de la izquierda es `<=`
+    /// y todo lo de la derecha es `>=` que el n-ésimo, en orden arbitrario.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut v = MyVec::from_slice_copy(&[7, 1, 9, 3, 5]);
+    /// let (_, median, _) = v.select_nth_unstable(2);
+    /// assert_eq!(*median, 5);
+    /// ```
+    ///
+    /// # Complejidad
+    /// **O(n)** en promedio. El pivote es la mediana de tres, así que entradas
+    /// ya ordenadas (o al revés) no caen en el peor caso O(n²).
+    ///
+    /// # Panics
+    /// Si `n >= len`.
+    pub fn select_nth_unstable(&mut self, n: usize) -> (&mut [T], &mut T, &mut [T])
+    where
+        T: Ord,
+    {
+        assert!(n < self.len, "partition index (is {n}) should be < len (is {})", self.len);
+
+        let s = self.as_mut_slice();
+        let (mut lo, mut hi) = (0, s.len());
+
+        /*
+        En cada vuelta se particiona la ventana [lo, hi) en tres zonas
+        alrededor del pivote `p` (partición de Dijkstra):
+
+            lo        lt          gt        hi
+            │  < p    │   == p    │   > p   │
+
+        Si `n` cae en la zona del medio, ya está en su lugar; si no, se sigue
+        solo con la zona que lo contiene. Agrupar los iguales hace que un
+        vector con todos los elementos repetidos termine en una sola pasada.
+        */
+        while hi - lo > 1 {
+            let mid = lo + (hi - lo) / 2;
+
+            // Mediana de tres: deja s[lo] <= s[mid] <= s[hi - 1]
+            if s[mid] < s[lo] {
+                s.swap(mid, lo);
+            }
+            if s[hi - 1] < s[mid] {
+                s.swap(hi - 1, mid);
+                if s[mid] < s[lo] {
+                    s.swap(mid, lo);
+                }
+            }
+
+            // El pivote va al inicio de la ventana; durante la partición
+            // siempre vive en s[lt]
+            s.swap(lo, mid);
+            let (mut lt, mut i, mut gt) = (lo, lo + 1, hi);
+            while i < gt {
+                match s[i].cmp(&s[lt]) {
+                    Ordering::Less => {
+                        s.swap(lt, i);
+                        lt += 1;
+                        i += 1;
+                    }
+                    Ordering::Greater => {
+                        gt -= 1;
+                        s.swap(i, gt);
+                    }
+                    Ordering::Equal => i += 1,
+                }
+            }
+
+            if n < lt {
+                hi = lt;
+            } else if n >= gt {
+                lo = gt;
+            } else {
+                break;
+            }
         }
+
+        let (left, rest) = s.split_at_mut(n);
+        let (nth, right) = rest.split_first_mut().expect("n < len");
+        (left, nth, right)
     }
 
-    /// Retorna la longitud actual del vector.
-    pub fn len(&self) -> usize {
-        self.len
+    /// Busca `x` en un vector ordenado usando búsqueda binaria.
+    ///
+    /// Retorna `Ok(index)` si lo encuentra, o `Err(index)` con la posición donde
+    /// debería insertarse para mantener el orden.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        let slice = self.as_slice();
+        let (mut low, mut high) = (0, self.len);
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match slice[mid].cmp(x) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+
+        Err(low)
     }
 
-    /// Retorna la capacidad del vector.
-    pub fn capacity(&self) -> usize {
-        self.capacity
+    /// Inserta `elem` manteniendo el vector ordenado y retorna el índice usado.
+    ///
+    /// Si ya existen elementos iguales, el nuevo se coloca después de ellos,
+    /// de modo que el orden de llegada entre duplicados se conserva (inserción estable).
+    ///
+    /// # Complejidad
+    /// **O(log n)** comparaciones + **O(n)** movimientos por el desplazamiento.
+    pub fn insert_sorted(&mut self, elem: T) -> usize
+    where
+        T: Ord,
+    {
+        // Búsqueda del primer elemento estrictamente mayor (upper bound)
+        let slice = self.as_slice();
+        let (mut low, mut high) = (0, self.len);
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if slice[mid] <= elem {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        self.insert(low, elem);
+        low
     }
 
-    /// Retorna `true` si el vector no contiene elementos.
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
+    /// Añade al final una copia de los elementos en el rango `src` del mismo vector.
+    ///
+    /// El rango se fija al momento de la llamada: aunque el vector crezca
+    /// mientras se copian los elementos, solo se clonan los de `src`.
+    ///
+    /// # Panics
+    /// Si el rango está fuera de los límites o `start > end`.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, src: R)
+    where
+        T: Clone,
+    {
+        let (start, end) = resolve_range(src, self.len);
+
+        // `reserve` puede mover el bloque, por eso se lee con self.ptr después
+        // de reservar; los índices no cambian al reubicar.
+        self.reserve(end - start);
+
+        let base = self.ptr.as_ptr();
+        let mut guard = SetLenOnDrop::new(&mut self.len);
+        for i in start..end {
+            unsafe {
+                let elem = (*base.add(i)).assume_init_ref().clone();
+                ptr::write(base.add(guard.current_len()), MaybeUninit::new(elem));
+            }
+            guard.increment_len(1);
+        }
+    }
+
+    /// Crea un vector nuevo con el contenido repetido `n` veces.
+    ///
+    /// Asigna `len * n` espacios una sola vez y luego duplica lo ya escrito
+    /// (1, 2, 4, ... copias) en lugar de recorrer el original `n` veces.
+    ///
+    /// # Panics
+    /// Con "capacity overflow" si `len * n` no cabe en `usize`.
+    pub fn repeat(&self, n: usize) -> MyVec<T, G>
+    where
+        T: Clone,
+    {
+        let total = match self.len.checked_mul(n) {
+            Some(total) => total,
+            None => capacity_overflow(),
+        };
+
+        let mut v = Self::with_capacity_and_strategy(total);
+        if total == 0 {
+            return v;
+        }
+
+        v.extend_from_slice(self.as_slice());
+        while v.len * 2 <= total {
+            v.extend_from_within(..);
+        }
+        v.extend_from_within(..total - v.len);
+        v
+    }
+
+    /// Añade al final un clon de cada elemento de `other`.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+
+        let base = self.ptr.as_ptr();
+        let mut guard = SetLenOnDrop::new(&mut self.len);
+        for elem in other {
+            unsafe {
+                ptr::write(base.add(guard.current_len()), MaybeUninit::new(elem.clone()));
+            }
+            guard.increment_len(1);
+        }
+    }
+
+    /*
+    Caminos rápidos para tipos `Copy`
+
+    Sin especialización (no existe en Rust estable) un método genérico sobre
+    `T: Clone` no puede saber si `T` además es `Copy`, así que
+    `extend_from_slice` llama `clone()` elemento por elemento. Para `Copy`
+    clonar es copiar bytes, y una sola `ptr::copy_nonoverlapping` (memcpy)
+    mueve todo el bloque de una vez. Estas variantes `_copy` piden `T: Copy`
+    de forma explícita; el que sabe que su tipo es `Copy` opta por ellas.
+
+    `clone()` tiene el mismo problema: `impl Clone` es uno solo para todo
+    `T: Clone`. Por eso la copia se hace con el helper privado `CloneSlice`,
+    que tiene dos implementaciones: `ByClone` (un `clone()` por elemento, la
+    que usa `clone()`) y `ByMemcpy` (solo para `T: Copy`, la que usa
+    `clone_copy()`). Ambas escriben en un bloque propio de exactamente `len`
+    elementos y conservan el límite y la política de encogimiento.
+    */
+
+    /// Como `extend_from_slice`, pero copiando todo el slice con un solo memcpy.
+    pub fn extend_from_slice_copy(&mut self, other: &[T])
+    where
+        T: Copy,
+    {
+        self.reserve(other.len());
+
+        unsafe {
+            ptr::copy_nonoverlapping(
+                other.as_ptr(),
+                self.ptr.as_ptr().add(self.len) as *mut T,
+                other.len(),
+            );
+        }
+        // `Copy` no puede entrar en pánico al copiarse: no hace falta guard
+        self.len += other.len();
+    }
+
+    /// Clona `self` en un bloque nuevo usando la estrategia de copia `C`.
+    fn clone_with<C: CloneSlice<T>>(&self) -> Self {
+        let mut v = Self::with_capacity_and_strategy(self.len);
+        v.auto_shrink = self.auto_shrink;
+        v.max_capacity = self.max_capacity;
+        C::append(&mut v, self.as_slice());
+        v
+    }
+
+    /// Como `clone()`, pero con un solo memcpy del bloque completo.
+    pub fn clone_copy(&self) -> Self
+    where
+        T: Copy,
+    {
+        self.clone_with::<ByMemcpy>()
+    }
+
+    /// Cambia la longitud a `new_len`.
+    ///
+    /// Si crece, rellena con clones de `value` (el último hueco recibe `value`
+    /// movido, ahorrando un clon). Si decrece, equivale a `truncate`.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+
+        let additional = new_len - self.len;
+        self.reserve(additional);
+
+        let base = self.ptr.as_ptr();
+        let mut guard = SetLenOnDrop::new(&mut self.len);
+        for _ in 1..additional {
+            unsafe {
+                ptr::write(base.add(guard.current_len()), MaybeUninit::new(value.clone()));
+            }
+            guard.increment_len(1);
+        }
+
+        unsafe {
+            ptr::write(base.add(guard.current_len()), MaybeUninit::new(value));
+        }
+        guard.increment_len(1);
+    }
+
+    /// Reordena el vector para que los elementos que cumplen `pred` queden
+    /// primero y retorna cuántos son (el índice donde empieza el segundo grupo).
+    ///
+    /// El orden dentro de cada grupo NO se conserva.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut v = MyVec::from_slice_copy(&[1, 2, 3, 4, 5, 6]);
+    /// let idx = v.partition_in_place(|x| x % 2 == 0);
+    /// assert_eq!(idx, 3);
+    /// assert!(v.as_slice()[..idx].iter().all(|x| x % 2 == 0));
+    /// ```
+    ///
+    /// # Complejidad
+    /// **O(n)** - Cada elemento se evalúa una vez y se hacen a lo sumo
+    /// `n / 2` intercambios, sin memoria extra.
+    pub fn partition_in_place<F: FnMut(&T) -> bool>(&mut self, mut pred: F) -> usize {
+        let s = self.as_mut_slice();
+        let (mut left, mut right) = (0, s.len());
+
+        /*
+        Dos cursores que avanzan uno hacia el otro (esquema de Hoare):
+
+            [ cumplen ... | left →   sin revisar   ← right | ... no cumplen ]
+
+        `left` salta los que ya están bien a la izquierda y `right` los que ya
+        están bien a la derecha. Cuando ambos se detienen, cada uno apunta a un
+        elemento en el lado equivocado: un solo swap arregla a los dos.
+        */
+        loop {
+            while left < right && pred(&s[left]) {
+                left += 1;
+            }
+            if left == right {
+                return left;
+            }
+
+            // s[left] ya se evaluó (no cumple): `right` se detiene antes de él
+            while right - 1 > left && !pred(&s[right - 1]) {
+                right -= 1;
+            }
+            if right - 1 == left {
+                return left;
+            }
+
+            s.swap(left, right - 1);
+            left += 1;
+            right -= 1;
+        }
+    }
+
+    /// Acorta el vector a `len` elementos, destruyendo el resto.
+    ///
+    /// No cambia la capacidad. Si `len >= self.len()` no hace nada.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let tail_len = self.len - len;
+        // Primero se acorta: si el Drop de un elemento entra en pánico, el
+        // vector ya no cuenta la cola y nunca la destruirá por segunda vez.
+        self.len = len;
+
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(
+                self.ptr.as_ptr().add(len) as *mut T,
+                tail_len,
+            );
+            // drop_in_place sobre un slice sigue destruyendo el resto aunque uno falle
+            ptr::drop_in_place(tail);
+        }
+
+        self.maybe_auto_shrink();
+    }
+
+    /// Conserva solo los elementos para los que `f` retorna `true`,
+    /// manteniendo su orden relativo.
+    ///
+    /// # Complejidad
+    /// **O(n)** - Cada elemento se evalúa y se mueve a lo sumo una vez.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let original_len = self.len;
+        // Mientras se procesa, el vector "no tiene" elementos: si algo falla y
+        // el guard no llegara a correr, se filtrarían elementos, nunca un doble drop.
+        self.len = 0;
+
+        struct Guard<'a, T, G: GrowthStrategy> {
+            v: &'a mut MyVec<T, G>,
+            processed: usize,
+            deleted: usize,
+            original_len: usize,
+        }
+
+        impl<T, G: GrowthStrategy> Drop for Guard<'_, T, G> {
+            fn drop(&mut self) {
+                // Si hubo pánico quedan elementos sin procesar: se corren hacia
+                // la izquierda para cerrar el hueco de los eliminados.
+                if self.deleted > 0 {
+                    unsafe {
+                        let base = self.v.ptr.as_ptr();
+                        ptr::copy(
+                            base.add(self.processed),
+                            base.add(self.processed - self.deleted),
+                            self.original_len - self.processed,
+                        );
+                    }
+                }
+                self.v.len = self.original_len - self.deleted;
+            }
+        }
+
+        let mut g = Guard {
+            v: self,
+            processed: 0,
+            deleted: 0,
+            original_len,
+        };
+
+        while g.processed < original_len {
+            unsafe {
+                let cur = g.v.ptr.as_ptr().add(g.processed);
+                if !f((*cur).assume_init_ref()) {
+                    // Se cuenta como procesado antes del drop, por si este entra en pánico
+                    g.processed += 1;
+                    g.deleted += 1;
+                    ptr::drop_in_place((*cur).as_mut_ptr());
+                    continue;
+                }
+
+                if g.deleted > 0 {
+                    let hole = g.v.ptr.as_ptr().add(g.processed - g.deleted);
+                    ptr::copy_nonoverlapping(cur, hole, 1);
+                }
+            }
+            g.processed += 1;
+        }
+    }
+
+    /// Obtiene una referencia inmutable al elemento en la posición `index`.
+    ///
+    /// # Complejidad
+    /// **O(1)** - Acceso en tiempo constante usando aritmética de punteros.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        unsafe {
+            let element_ptr = self.ptr.as_ptr().add(index);
+            Some((*element_ptr).assume_init_ref())
+        }
+    }
+
+    /// Obtiene una referencia mutable al elemento en la posición `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        unsafe {
+            let element_ptr = self.ptr.as_ptr().add(index);
+            Some((*element_ptr).assume_init_mut())
+        }
+    }
+
+    /// Obtiene `N` referencias mutables disjuntas a la vez.
+    ///
+    /// Retorna `None` si algún índice está fuera de rango o si dos índices se
+    /// repiten (habría dos `&mut` al mismo elemento).
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut dist: MyVec<u32> = MyVec::from_slice_copy(&[0, 7, 3]);
+    /// if let Some([a, b]) = dist.get_many_mut([1, 2]) {
+    ///     *a = (*a).min(*b + 1);
+    /// }
+    /// assert_eq!(dist, [0, 4, 3]);
+    /// ```
+    ///
+    /// # Complejidad
+    /// **O(N²)** - Se compara cada par de índices; N suele ser 2 o 3.
+    pub fn get_many_mut<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.len || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        /*
+        Dos `get_mut` seguidos no compilan porque cada uno toma prestado todo
+        el vector. Aquí se parte de un único puntero base y se crea una
+        referencia por índice: como ya verificamos que todos son distintos y
+        están en rango, ninguna referencia se superpone con otra.
+        */
+        let base = self.ptr.as_ptr() as *mut T;
+        Some(indices.map(|index| unsafe { &mut *base.add(index) }))
+    }
+
+    /// Intercambia los elementos en las posiciones `a` y `b`.
+    ///
+    /// # Panics
+    /// Si `a` o `b` están fuera de rango.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.as_mut_slice().swap(a, b);
+    }
+
+    /// Baraja el vector en su lugar con el algoritmo de Fisher–Yates.
+    ///
+    /// Recorre de atrás hacia adelante e intercambia cada posición `i` con una
+    /// posición uniforme en `[0, i]`: cada una de las `n!` permutaciones tiene
+    /// la misma probabilidad.
+    ///
+    /// # Complejidad
+    /// **O(n)**
+    pub fn shuffle(&mut self, rng: &mut SimpleRng) {
+        for i in (1..self.len).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            self.swap(i, j);
+        }
+    }
+
+    /// Retorna un elemento elegido al azar, o `None` si el vector está vacío.
+    pub fn choose(&self, rng: &mut SimpleRng) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.get(rng.below(self.len as u64) as usize)
+    }
+
+    /// Retorna la longitud actual del vector.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Retorna la capacidad del vector.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Retorna los bytes reservados en el heap: `capacity × size_of::<T>()`.
+    ///
+    /// Un vector recién creado con `new()` no ha reservado nada y retorna 0.
+    pub fn allocated_bytes(&self) -> usize {
+        self.capacity * mem::size_of::<T>()
+    }
+
+    /// Retorna el tamaño en bytes de cada elemento, padding incluido.
+    pub fn element_size(&self) -> usize {
+        mem::size_of::<T>()
+    }
+
+    /// Retorna la alineación en bytes que exige `T`.
+    pub fn element_align(&self) -> usize {
+        mem::align_of::<T>()
+    }
+
+    /// Retorna un resumen de la memoria que ocupa el vector.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut v: MyVec<u64> = MyVec::with_capacity(4);
+    /// v.push_back(1);
+    ///
+    /// let stats = v.memory_stats();
+    /// assert_eq!(stats.allocated_bytes, 32);
+    /// assert_eq!(stats.wasted_bytes, 24);
+    /// println!("{stats}");
+    /// ```
+    pub fn memory_stats(&self) -> MemoryStats {
+        let element_size = self.element_size();
+        let allocated_bytes = self.allocated_bytes();
+        let used_bytes = self.len * element_size;
+
+        MemoryStats {
+            len: self.len,
+            capacity: self.capacity,
+            element_size,
+            element_align: self.element_align(),
+            allocated_bytes,
+            used_bytes,
+            wasted_bytes: allocated_bytes - used_bytes,
+        }
+    }
+
+    /// Retorna `true` si el vector no contiene elementos.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Retorna un slice inmutable con los `len` elementos inicializados.
+    pub fn as_slice(&self) -> &[T] {
+        // Con len == 0 el puntero dangling es válido para un slice vacío.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr() as *const T, self.len) }
+    }
+
+    /// Retorna un slice mutable con los `len` elementos inicializados.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr() as *mut T, self.len) }
+    }
+
+    /// Retorna un iterador sobre referencias a los elementos.
+    ///
+    /// Es de doble extremo (`rev()` funciona) y conoce su longitud exacta.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.as_slice())
+    }
+
+    /// Retorna un iterador sobre referencias mutables a los elementos.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut::new(self.as_mut_slice())
+    }
+
+    /// Divide el vector en dos slices: `[0, mid)` y `[mid, len)`.
+    ///
+    /// # Panics
+    /// Si `mid > len`.
+    pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+        assert!(mid <= self.len, "mid (is {mid}) should be <= len (is {})", self.len);
+
+        unsafe {
+            let base = self.ptr.as_ptr() as *const T;
+            (
+                slice::from_raw_parts(base, mid),
+                slice::from_raw_parts(base.add(mid), self.len - mid),
+            )
+        }
+    }
+
+    /// Divide el vector en dos slices mutables: `[0, mid)` y `[mid, len)`.
+    ///
+    /// El borrow checker no puede probar que dos `&mut` sobre el mismo vector
+    /// no se solapan, así que se construyen a partir del puntero crudo: como los
+    /// rangos son disjuntos, las dos referencias nunca apuntan al mismo elemento.
+    ///
+    /// # Panics
+    /// Si `mid > len`.
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        assert!(mid <= self.len, "mid (is {mid}) should be <= len (is {})", self.len);
+
+        unsafe {
+            let base = self.ptr.as_ptr() as *mut T;
+            (
+                slice::from_raw_parts_mut(base, mid),
+                slice::from_raw_parts_mut(base.add(mid), self.len - mid),
+            )
+        }
+    }
+
+    /// Intercambia todos los elementos del vector con los de `other`.
+    ///
+    /// Usa un solo `ptr::swap_nonoverlapping` sobre toda la región, sin
+    /// temporales por elemento. Un `&mut [T]` nunca puede apuntar dentro de
+    /// este vector mientras tenemos `&mut self`, así que las regiones no se solapan.
+    ///
+    /// # Panics
+    /// Si `other.len() != self.len()`.
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        assert!(
+            self.len == other.len(),
+            "destination and source slices have different lengths ({} vs {})",
+            self.len,
+            other.len()
+        );
+
+        unsafe {
+            ptr::swap_nonoverlapping(self.ptr.as_ptr() as *mut T, other.as_mut_ptr(), self.len);
+        }
+    }
+
+    /// Retorna la capacidad libre (`len..capacity`) como slice de `MaybeUninit<T>`.
+    ///
+    /// Permite escribir elementos directamente en la memoria reservada y luego
+    /// confirmarlos con [`set_len`](Self::set_len).
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.ptr.as_ptr().add(self.len),
+                self.capacity - self.len,
+            )
+        }
+    }
+
+    /// Cambia la longitud del vector sin inicializar ni destruir nada.
+    ///
+    /// # Safety
+    /// - `new_len` debe ser menor o igual a `capacity()`.
+    /// - Los elementos en `old_len..new_len` deben estar inicializados.
+    /// - Si `new_len < len`, los elementos en `new_len..len` no se destruyen
+    ///   (el llamador se hace cargo de ellos o se pierden).
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.capacity);
+        self.len = new_len;
+    }
+
+    /// Retorna un iterador sobre los tramos consecutivos más largos en los que
+    /// `pred(a, b)` se cumple para cada par de vecinos `(a, b)`.
+    ///
+    /// Cuando `pred` retorna `false` empieza un tramo nuevo.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let v = MyVec::from_slice_copy(&[1, 1, 2, 2, 2, 3]);
+    /// let runs: Vec<_> = v.chunk_by(|a, b| a == b).map(|run| (run[0], run.len())).collect();
+    /// assert_eq!(runs, [(1, 2), (2, 3), (3, 1)]);
+    /// ```
+    pub fn chunk_by<F: FnMut(&T, &T) -> bool>(&self, pred: F) -> ChunkBy<'_, T, F> {
+        ChunkBy::new(self.as_slice(), pred)
+    }
+
+    /// Retorna `true` si `needle` es un prefijo del vector.
+    ///
+    /// Un `needle` vacío siempre es prefijo; uno más largo que el vector nunca.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let buf = MyVec::from_slice_copy(b"HTTP/1.1 200 OK");
+    /// assert!(buf.starts_with(b"HTTP/"));
+    /// ```
+    ///
+    /// # Complejidad
+    /// **O(m)** - Con `m = needle.len()`.
+    pub fn starts_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len && self.as_slice()[..needle.len()] == *needle
+    }
+
+    /// Retorna `true` si `needle` es un sufijo del vector.
+    ///
+    /// Mismas reglas que [`starts_with`](Self::starts_with) para `needle`
+    /// vacío o más largo que el vector.
+    pub fn ends_with(&self, needle: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        needle.len() <= self.len && self.as_slice()[self.len - needle.len()..] == *needle
+    }
+
+    /// Describe el estado interno del vector: dirección del bloque, longitud,
+    /// capacidad y el contenido de cada slot.
+    ///
+    /// Los slots fuera de `len` se muestran como `<uninit>` sin leerlos:
+    /// leer memoria sin inicializar es comportamiento indefinido.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut v = MyVec::with_capacity(4);
+    /// v.extend_from_slice(&[10, 20, 30]);
+    /// let dump = v.debug_dump();
+    /// assert!(dump.ends_with("len: 3, cap: 4 } [ 0: 10, 1: 20, 2: 30, 3: <uninit> ]"));
+    /// ```
+    pub fn debug_dump(&self) -> String
+    where
+        T: fmt::Debug,
+    {
+        let mut out = format!(
+            "MyVec {{ ptr: {:p}, len: {}, cap: {} }} [",
+            self.ptr, self.len, self.capacity
+        );
+
+        for (i, elem) in self.as_slice().iter().enumerate() {
+            let sep = if i == 0 { "" } else { "," };
+            out.push_str(&format!("{sep} {i}: {elem:?}"));
+        }
+        for i in self.len..self.capacity {
+            let sep = if i == 0 { "" } else { "," };
+            out.push_str(&format!("{sep} {i}: <uninit>"));
+        }
+
+        out.push_str(" ]");
+        out
+    }
+
+    /// Retorna los bytes crudos de cada slot en hexadecimal, un slot por línea.
+    ///
+    /// Solo está disponible para los primitivos [`Zeroable`], que no tienen
+    /// bytes de padding (leer padding también sería leer memoria sin
+    /// inicializar). Los slots fuera de `len` se muestran como `..`.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut v: MyVec<u16> = MyVec::with_capacity(2);
+    /// v.push_back(0x0102);
+    /// let dump = v.hex_dump();
+    /// assert_eq!(dump.lines().nth(1), Some("1: .. .."));
+    /// ```
+    pub fn hex_dump(&self) -> String
+    where
+        T: Zeroable,
+    {
+        let size = mem::size_of::<T>();
+        let mut lines = Vec::with_capacity(self.capacity);
+
+        // SAFETY: los `len` elementos están inicializados y un primitivo
+        // Zeroable no tiene padding, así que todos sus bytes son válidos.
+        let bytes =
+            unsafe { slice::from_raw_parts(self.ptr.as_ptr() as *const u8, self.len * size) };
+        for (i, elem) in bytes.chunks_exact(size.max(1)).enumerate() {
+            let hex: Vec<String> = elem.iter().map(|b| format!("{b:02x}")).collect();
+            lines.push(format!("{i}: {}", hex.join(" ")));
+        }
+        for i in self.len..self.capacity {
+            lines.push(format!("{i}: {}", vec![".."; size].join(" ")));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Retorna un iterador sobre ventanas superpuestas de tamaño `size`.
+    ///
+    /// Produce `len - size + 1` ventanas; si `size > len` no produce ninguna.
+    ///
+    /// # Panics
+    /// Si `size == 0`.
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        assert!(size != 0, "window size must be non-zero");
+        Windows::new(self.as_slice(), size)
+    }
+
+    /// Consume el vector y retorna un slice mutable que vive para siempre.
+    ///
+    /// La memoria nunca se libera (por eso el `'static`). Útil para datos
+    /// que se construyen una vez al arrancar y se usan hasta el final.
+    pub fn leak(self) -> &'static mut [T] {
+        // ManuallyDrop evita que Drop libere el bloque que estamos regalando
+        let me = ManuallyDrop::new(self);
+        unsafe { slice::from_raw_parts_mut(me.ptr.as_ptr() as *mut T, me.len) }
+    }
+
+    /// Convierte el vector en un `Box<[T]>` sin copiar los elementos.
+    ///
+    /// Primero encoge la capacidad a `len` (solo realoja si sobraba espacio),
+    /// porque `Box<[T]>` libera usando el layout de exactamente `len` elementos.
+    pub fn into_boxed_slice(mut self) -> Box<[T]> {
+        self.shrink_to_fit();
+
+        let me = ManuallyDrop::new(self);
+        unsafe {
+            let fat_ptr = ptr::slice_from_raw_parts_mut(me.ptr.as_ptr() as *mut T, me.len);
+            Box::from_raw(fat_ptr)
+        }
+    }
+
+    /// Retorna un puntero crudo al inicio del buffer.
+    ///
+    /// Con capacidad 0 es un puntero dangling: no nulo y bien alineado, pero
+    /// no se puede leer. El puntero deja de ser válido si el vector realoja.
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr.as_ptr() as *const T
+    }
+
+    /// Retorna un puntero crudo mutable al inicio del buffer.
+    ///
+    /// Sirve para que código externo (p. ej. una función de C) escriba en la
+    /// capacidad libre; después se ajusta la longitud con [`set_len`](Self::set_len).
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr.as_ptr() as *mut T
+    }
+
+    /// Descompone el vector en `(puntero, len, capacity)` sin liberar nada.
+    ///
+    /// Quien llama pasa a ser dueño de la memoria y de los elementos; la forma
+    /// de liberarlos es reconstruir el vector con [`from_raw_parts`](Self::from_raw_parts).
+    /// El límite de `with_max_capacity` y la política de `set_auto_shrink` se pierden.
+    pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
+        let mut me = ManuallyDrop::new(self);
+        (me.as_mut_ptr(), me.len, me.capacity)
+    }
+
+    /// Reconstruye un vector a partir de sus partes crudas.
+    ///
+    /// # Safety
+    /// - `ptr` debe venir de [`into_raw_parts`](Self::into_raw_parts) (o de
+    ///   `as_mut_ptr` de un vector que después se olvidó), o haber sido
+    ///   asignado con `std::alloc` usando el layout de `capacity` elementos `T`
+    ///   (`size_of::<T>() * capacity` bytes, alineación `align_of::<T>()`).
+    /// - Si `capacity == 0`, `ptr` debe ser no nulo y estar alineado (p. ej.
+    ///   `NonNull::dangling()`); nunca se liberará.
+    /// - `len <= capacity`.
+    /// - Los primeros `len` elementos deben estar inicializados.
+    /// - Nadie más puede usar ni liberar esa memoria después de la llamada:
+    ///   el vector la libera al destruirse.
+    pub unsafe fn from_raw_parts(ptr: *mut T, len: usize, capacity: usize) -> Self {
+        debug_assert!(len <= capacity);
+
+        let mut v = Self::with_strategy();
+        // SAFETY: el llamador garantiza que `ptr` no es nulo
+        v.ptr = unsafe { NonNull::new_unchecked(ptr as *mut MaybeUninit<T>) };
+        v.len = len;
+        v.capacity = capacity;
+        v
+    }
+}
+
+/// Crea un `MyVec` con una sintaxis parecida a `vec!`.
+///
+/// ```
+/// use vectors::{myvec, MyVec};
+///
+/// let empty: MyVec<i32> = myvec![];
+/// let list = myvec![1, 2, 3];
+/// let zeros = myvec![0u8; 4];
+///
+/// assert!(empty.is_empty());
+/// assert_eq!(list.get(2), Some(&3));
+/// assert_eq!(zeros.len(), 4);
+/// ```
+#[macro_export]
+macro_rules! myvec {
+    () => {
+        $crate::MyVec::new()
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::MyVec::from_elem($elem, $n)
+    };
+    ($($x:expr),+ $(,)?) => {{
+        let mut v = $crate::MyVec::new();
+        $(v.push_back($x);)+
+        v
+    }};
+}
+
+/// Convierte cualquier `RangeBounds` en un par `(start, end)` validado contra `len`.
+///
+/// # Panics
+/// Si el rango está fuera de `0..=len` o `start > end`.
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).expect("range start overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).expect("range end overflow"),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "range start (is {start}) should be <= range end (is {end})");
+    assert!(end <= len, "range end (is {end}) should be <= len (is {len})");
+    (start, end)
+}
+
+/// Convierte el resultado de una reserva falible en el comportamiento
+/// infalible estándar: pánico por overflow o `handle_alloc_error` sin memoria.
+fn handle_reserve(result: Result<(), TryReserveError>) {
+    match result {
+        Ok(()) => {}
+        Err(TryReserveError::CapacityOverflow) => capacity_overflow(),
+        Err(TryReserveError::AllocError { layout }) => handle_alloc_error(layout),
+        Err(TryReserveError::CapacityExceeded { cap }) => {
+            panic!("bounded MyVec is full: max capacity is {cap}")
+        }
+    }
+}
+
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow");
+}
+
+impl<T, G: GrowthStrategy> Drop for MyVec<T, G> {
+    /// Destruye los `len` elementos inicializados y libera el bloque de memoria.
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(self.as_mut_slice());
+
+            if self.capacity > 0 {
+                Self::dealloc_block(self.ptr, self.capacity);
+            }
+        }
+    }
+}
+
+/// Cómo añadir los clones de `src` al final de `dst`.
+trait CloneSlice<T> {
+    fn append<G: GrowthStrategy>(dst: &mut MyVec<T, G>, src: &[T]);
+}
+
+/// Un `clone()` por elemento: sirve para cualquier `T: Clone`.
+struct ByClone;
+
+/// Un solo memcpy: solo existe para `T: Copy`.
+struct ByMemcpy;
+
+impl<T: Clone> CloneSlice<T> for ByClone {
+    fn append<G: GrowthStrategy>(dst: &mut MyVec<T, G>, src: &[T]) {
+        dst.extend_from_slice(src);
+    }
+}
+
+impl<T: Copy> CloneSlice<T> for ByMemcpy {
+    fn append<G: GrowthStrategy>(dst: &mut MyVec<T, G>, src: &[T]) {
+        dst.extend_from_slice_copy(src);
+    }
+}
+
+impl<T: Clone, G: GrowthStrategy> Clone for MyVec<T, G> {
+    /// Clona los elementos en un bloque nuevo de exactamente `len` espacios.
+    ///
+    /// Para tipos `Copy`, [`clone_copy`](MyVec::clone_copy) hace lo mismo con
+    /// un solo memcpy.
+    fn clone(&self) -> Self {
+        self.clone_with::<ByClone>()
+    }
+
+    /// Copia `source` en `self` reutilizando el bloque de memoria existente.
+    ///
+    /// Solo realoja si `source` tiene más elementos que la capacidad actual,
+    /// o si el bloque actual supera el límite de `source`. Igual que `clone`,
+    /// el resultado toma el límite y la política de encogimiento de `source`.
+    fn clone_from(&mut self, source: &Self) {
+        // Antes de reservar: un destino acotado más chico no debe fallar
+        self.max_capacity = source.max_capacity;
+        self.auto_shrink = source.auto_shrink;
+
+        // Sobran elementos: se destruyen
+        self.truncate(source.len);
+
+        // Un bloque más grande que el límite nuevo dejaría pasar `push_back`
+        // más allá de él (los límites se revisan solo al crecer): se encoge
+        if let Some(max) = self.max_capacity
+            && self.capacity > max
+        {
+            if self.len == max {
+                self.shrink_to_fit();
+            } else {
+                handle_reserve(self.try_relocate(max));
+            }
+        }
+
+        // Prefijo común: clone_from de cada elemento (puede reutilizar sus
+        // propios buffers, p. ej. String)
+        let (common, tail) = source.as_slice().split_at(self.len);
+        for (dst, src) in self.as_mut_slice().iter_mut().zip(common) {
+            dst.clone_from(src);
+        }
+
+        // Faltan elementos: se clonan al final
+        self.extend_from_slice(tail);
+    }
+}
+
+impl<T: fmt::Debug, G: GrowthStrategy> fmt::Debug for MyVec<T, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+/*
+Igualdad entre tipos
+
+Igual que `Vec`, un `MyVec` se compara elemento a elemento con cualquier
+contenedor contiguo: otro `MyVec`, slices, arrays y `Vec`. Así funciona
+`assert_eq!(v, [1, 2, 3])` sin bucles manuales. Las direcciones inversas
+(`[1, 2, 3] == v`) se implementan donde las reglas de huérfanos lo permiten.
+*/
+impl<T, U, G1, G2> PartialEq<MyVec<U, G2>> for MyVec<T, G1>
+where
+    T: PartialEq<U>,
+    G1: GrowthStrategy,
+    G2: GrowthStrategy,
+{
+    fn eq(&self, other: &MyVec<U, G2>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, G: GrowthStrategy> Eq for MyVec<T, G> {}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy> PartialEq<[U]> for MyVec<T, G> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy> PartialEq<&[U]> for MyVec<T, G> {
+    fn eq(&self, other: &&[U]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy, const N: usize> PartialEq<[U; N]> for MyVec<T, G> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy, const N: usize> PartialEq<&[U; N]> for MyVec<T, G> {
+    fn eq(&self, other: &&[U; N]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy> PartialEq<Vec<U>> for MyVec<T, G> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy> PartialEq<MyVec<U, G>> for [T] {
+    fn eq(&self, other: &MyVec<U, G>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy> PartialEq<MyVec<U, G>> for &[T] {
+    fn eq(&self, other: &MyVec<U, G>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy, const N: usize> PartialEq<MyVec<U, G>> for [T; N] {
+    fn eq(&self, other: &MyVec<U, G>) -> bool {
+        self[..] == *other.as_slice()
+    }
+}
+
+impl<T: PartialEq<U>, U, G: GrowthStrategy> PartialEq<MyVec<U, G>> for Vec<T> {
+    fn eq(&self, other: &MyVec<U, G>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+/*
+Vistas como slice
+
+`AsRef`/`AsMut` permiten pasar un `MyVec` a funciones genéricas como
+`fn f(data: impl AsRef<[u8]>)`. `Borrow` es más estricto: promete que `Hash`,
+`Eq` y `Ord` dan lo mismo sobre el `MyVec` que sobre el slice prestado, y por
+eso todos delegan en `as_slice()`. Gracias a eso un `HashMap<MyVec<u8>, V>`
+se puede consultar con un `&[u8]`.
+*/
+impl<T, G: GrowthStrategy> AsRef<[T]> for MyVec<T, G> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, G: GrowthStrategy> AsMut<[T]> for MyVec<T, G> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, G: GrowthStrategy> Borrow<[T]> for MyVec<T, G> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, G: GrowthStrategy> BorrowMut<[T]> for MyVec<T, G> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Hash, G: GrowthStrategy> Hash for MyVec<T, G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: PartialOrd, G: GrowthStrategy> PartialOrd for MyVec<T, G> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, G: GrowthStrategy> Ord for MyVec<T, G> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T, G: GrowthStrategy> Extend<T> for MyVec<T, G> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        // Si `lower` pasa el límite de un vector acotado no se entra en
+        // pánico aquí: se llena lo que quepa y `push_back` falla solo si de
+        // verdad llega un elemento de más. Los demás errores (overflow o
+        // falta de memoria) se reportan como en cualquier reserva
+        match self.try_reserve(lower) {
+            Ok(()) | Err(TryReserveError::CapacityExceeded { .. }) => {}
+            Err(e) => handle_reserve(Err(e)),
+        }
+
+        // Fase rápida: escribir directo en la capacidad ya reservada
+        {
+            let base = self.ptr.as_ptr();
+            let capacity = self.capacity;
+            let mut guard = SetLenOnDrop::new(&mut self.len);
+
+            while guard.current_len() < capacity {
+                match iter.next() {
+                    Some(elem) => unsafe {
+                        ptr::write(base.add(guard.current_len()), MaybeUninit::new(elem));
+                        guard.increment_len(1);
+                    },
+                    None => return,
+                }
+            }
+        }
+
+        // El iterador dio más de lo que prometía: seguir creciendo normalmente
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T, G: GrowthStrategy> IntoIterator for MyVec<T, G> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consume el vector: el iterador se queda con el bloque de memoria.
+    fn into_iter(self) -> IntoIter<T> {
+        let me = ManuallyDrop::new(self);
+        IntoIter::new(me.ptr, me.capacity, me.len)
+    }
+}
+
+impl<'a, T, G: GrowthStrategy> IntoIterator for &'a MyVec<T, G> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, G: GrowthStrategy> IntoIterator for &'a mut MyVec<T, G> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, G: GrowthStrategy> FromIterator<T> for MyVec<T, G> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut v = Self::with_strategy();
+        v.extend(iter);
+        v
+    }
+}
+
+impl<T, G: GrowthStrategy> Default for MyVec<T, G> {
+    fn default() -> Self {
+        Self::with_strategy()
     }
 }
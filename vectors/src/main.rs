@@ -349,6 +349,12 @@ impl<T> MyVec<T> {
     }
 }
 
+impl<T> Default for MyVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 fn main() {
     println!("MyVec implementation - run 'cargo test' to see tests");
 }
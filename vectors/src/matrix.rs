@@ -0,0 +1,194 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use crate::MyVec;
+
+/*
+Matriz en orden por filas (row-major)
+
+Todas las celdas viven contiguas en un solo MyVec, una fila tras otra:
+
+    ┌ a b c ┐
+    └ d e f ┘   →   [a, b, c, d, e, f]     (r, c) está en  r * cols + c
+
+- Una fila es un slice contiguo: `row(r)` no copia nada.
+- Recorrer por filas es amigable con la caché; recorrer por columnas salta de
+  a `cols` elementos.
+*/
+
+/// Matriz de `rows × cols` elementos guardada por filas en un `MyVec`.
+///
+/// ```
+/// use vectors::Matrix2D;
+///
+/// let m = Matrix2D::from_fn(2, 3, |r, c| r * 10 + c);
+/// assert_eq!(m[(1, 2)], 12);
+/// assert_eq!(m.row(0), [0, 1, 2]);
+/// assert_eq!(m.transpose().row(2), [2, 12]);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Matrix2D<T> {
+    data: MyVec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Matrix2D<T> {
+    /// Crea una matriz de `rows × cols` con todas las celdas en `fill`.
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(rows, cols, |_, _| fill.clone())
+    }
+
+    /// Crea una matriz donde la celda `(r, c)` vale `f(r, c)`.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> Self {
+        let len = rows.checked_mul(cols).expect("rows * cols desborda usize");
+        let data = MyVec::from_fn(len, |i| f(i / cols, i % cols));
+        Self { data, rows, cols }
+    }
+
+    /// Crea una matriz a partir de sus celdas en orden por filas.
+    ///
+    /// # Panics
+    /// Si `cells.len() != rows * cols`.
+    pub fn from_slice(rows: usize, cols: usize, cells: &[T]) -> Self
+    where
+        T: Clone,
+    {
+        assert_eq!(
+            cells.len(),
+            rows * cols,
+            "se esperaban {rows}x{cols} = {} celdas",
+            rows * cols
+        );
+        Self::from_fn(rows, cols, |r, c| cells[r * cols + c].clone())
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Retorna `(filas, columnas)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn offset(&self, r: usize, c: usize) -> Option<usize> {
+        (r < self.rows && c < self.cols).then(|| r * self.cols + c)
+    }
+
+    fn offset_or_panic(&self, r: usize, c: usize) -> usize {
+        self.offset(r, c).unwrap_or_else(|| {
+            panic!(
+                "fila {r}, columna {c} fuera de rango para una matriz de {}x{}",
+                self.rows, self.cols
+            )
+        })
+    }
+
+    /// Retorna la celda `(r, c)`, o `None` si está fuera de rango.
+    pub fn get(&self, r: usize, c: usize) -> Option<&T> {
+        self.data.get(self.offset(r, c)?)
+    }
+
+    /// Retorna la celda `(r, c)` mutable, o `None` si está fuera de rango.
+    pub fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
+        let offset = self.offset(r, c)?;
+        self.data.get_mut(offset)
+    }
+
+    /// Retorna la fila `r` como slice.
+    ///
+    /// # Panics
+    /// Si `r >= rows`.
+    pub fn row(&self, r: usize) -> &[T] {
+        assert!(r < self.rows, "fila {r} fuera de rango para una matriz de {}x{}", self.rows, self.cols);
+        &self.data.as_slice()[r * self.cols..(r + 1) * self.cols]
+    }
+
+    /// Retorna un iterador sobre las filas, cada una como slice.
+    pub fn iter_rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        (0..self.rows).map(move |r| self.row(r))
+    }
+
+    /// Retorna la transpuesta: la celda `(r, c)` pasa a `(c, r)`.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_fn(self.cols, self.rows, |r, c| self[(c, r)].clone())
+    }
+
+    /// Aplica `f` a cada celda y retorna una matriz de la misma forma.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> Matrix2D<U> {
+        Matrix2D::from_fn(self.rows, self.cols, |r, c| f(&self[(r, c)]))
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Mul<Output = T> + Default> Matrix2D<T> {
+    /// Suma celda a celda.
+    ///
+    /// # Panics
+    /// Si las formas no coinciden.
+    pub fn add(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.shape(),
+            other.shape(),
+            "no se pueden sumar matrices de {}x{} y {}x{}",
+            self.rows,
+            self.cols,
+            other.rows,
+            other.cols
+        );
+        Self::from_fn(self.rows, self.cols, |r, c| self[(r, c)] + other[(r, c)])
+    }
+
+    /// Producto matricial: `(n × m) · (m × p) = (n × p)`.
+    ///
+    /// # Panics
+    /// Si `self.cols() != other.rows()`.
+    ///
+    /// # Complejidad
+    /// **O(n · m · p)**, el algoritmo ingenuo.
+    pub fn multiply(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.cols, other.rows,
+            "no se pueden multiplicar matrices de {}x{} y {}x{}",
+            self.rows, self.cols, other.rows, other.cols
+        );
+        Self::from_fn(self.rows, other.cols, |r, c| {
+            (0..self.cols).fold(T::default(), |acc, k| acc + self[(r, k)] * other[(k, c)])
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix2D<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Si `(r, c)` está fuera de rango; el mensaje incluye fila y columna.
+    fn index(&self, (r, c): (usize, usize)) -> &T {
+        let offset = self.offset_or_panic(r, c);
+        self.data.get(offset).unwrap()
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix2D<T> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut T {
+        let offset = self.offset_or_panic(r, c);
+        self.data.get_mut(offset).unwrap()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Matrix2D<T> {
+    /// Una fila por entrada: `[[1, 2], [3, 4]]`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter_rows()).finish()
+    }
+}
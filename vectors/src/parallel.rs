@@ -0,0 +1,90 @@
+use std::thread;
+
+use crate::{GrowthStrategy, MyVec};
+
+/*
+Paralelismo con hilos con alcance (scoped threads)
+
+`thread::scope` garantiza que todos los hilos creados dentro terminan antes de
+que la función retorne. Por eso los hilos pueden tomar prestado `&self` sin
+`Arc` ni `'static`: el compilador sabe que el préstamo dura más que ellos.
+
+El vector se parte en trozos contiguos, uno por hilo:
+
+    len = 10, hilos = 3  →  tamaño = ⌈10 / 3⌉ = 4
+
+    [ 0 1 2 3 | 4 5 6 7 | 8 9 ]
+      hilo 0    hilo 1    hilo 2
+
+Si un hilo entra en pánico, `scope` propaga el pánico al salir.
+*/
+
+impl<T: Sync, G: GrowthStrategy> MyVec<T, G> {
+    /// Tamaño de cada trozo para repartir el vector entre `num_threads` hilos.
+    ///
+    /// `num_threads == 0` se trata como 1. Nunca retorna 0, así que
+    /// `chunks` no entra en pánico con un vector vacío.
+    fn par_chunk_size(&self, num_threads: usize) -> usize {
+        self.len().div_ceil(num_threads.max(1)).max(1)
+    }
+
+    /// Llama a `f` sobre cada elemento repartiendo el trabajo entre
+    /// `num_threads` hilos. El orden de las llamadas no está definido.
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use vectors::MyVec;
+    ///
+    /// let v: MyVec<usize> = (1..=100).collect();
+    /// let total = AtomicUsize::new(0);
+    /// v.par_for_each(4, |x| {
+    ///     total.fetch_add(*x, Ordering::Relaxed);
+    /// });
+    /// assert_eq!(total.into_inner(), 5050);
+    /// ```
+    ///
+    /// # Panics
+    /// Si `f` entra en pánico en cualquier hilo.
+    pub fn par_for_each<F: Fn(&T) + Sync>(&self, num_threads: usize, f: F) {
+        let chunk_size = self.par_chunk_size(num_threads);
+        let f = &f;
+
+        thread::scope(|scope| {
+            for chunk in self.as_slice().chunks(chunk_size) {
+                scope.spawn(move || chunk.iter().for_each(f));
+            }
+        });
+    }
+
+    /// Aplica `f` a cada elemento en paralelo y retorna los resultados en el
+    /// mismo orden que los elementos originales.
+    ///
+    /// Cada hilo llena su propio `MyVec` y al final se concatenan en orden
+    /// de trozo, así que no hace falta sincronizar la escritura.
+    ///
+    /// # Panics
+    /// Si `f` entra en pánico en cualquier hilo.
+    pub fn par_map<U: Send, F: Fn(&T) -> U + Sync>(&self, num_threads: usize, f: F) -> MyVec<U> {
+        let chunk_size = self.par_chunk_size(num_threads);
+        let f = &f;
+
+        let parts: Vec<MyVec<U>> = thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .as_slice()
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<MyVec<U>>()))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        });
+
+        let mut out = MyVec::with_capacity(self.len());
+        for part in parts {
+            out.extend(part);
+        }
+        out
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{GrowthStrategy, MyVec};
+
+/// Enteros que se pueden ordenar por dígitos con [`MyVec::sort_radix`].
+///
+/// `to_radix_key` convierte el valor a un `u64` que se ordena igual que el
+/// original al compararlo sin signo. Está sellado: solo lo implementan los
+/// enteros primitivos.
+pub trait RadixKey: Copy + sealed::Sealed {
+    /// Cantidad de bytes (dígitos de 8 bits) que importan de la clave.
+    const BYTES: usize;
+
+    /// Retorna la clave sin signo que preserva el orden del valor.
+    fn to_radix_key(self) -> u64;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_radix_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                fn to_radix_key(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+}
+
+/*
+Negativos con sesgo (bias)
+
+En complemento a dos, -1 es 0xFF..FF: comparado sin signo queda por encima de
+todos los positivos. Sumar 2^(bits-1) (equivale a invertir el bit de signo)
+mueve el rango [MIN, MAX] a [0, 2^bits - 1] conservando el orden:
+
+    i8:  -128 → 0x00,  -1 → 0x7F,  0 → 0x80,  127 → 0xFF
+*/
+macro_rules! impl_radix_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                fn to_radix_key(self) -> u64 {
+                    ((self as $u) ^ (1 << (<$u>::BITS - 1))) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_unsigned!(u8, u16, u32, u64);
+impl_radix_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64);
+
+impl<T: RadixKey, G: GrowthStrategy> MyVec<T, G> {
+    /// Ordena el vector con radix sort LSD de dígitos de 8 bits.
+    ///
+    /// Es estable y da el mismo resultado que un sort por comparación, pero
+    /// sin comparar: hace una pasada de counting sort por cada byte de la
+    /// clave, del menos al más significativo, usando un único buffer auxiliar.
+    ///
+    /// ```
+    /// use vectors::MyVec;
+    ///
+    /// let mut v = MyVec::from_slice_copy(&[170i32, -45, 75, -90, 802, 24, 2, 66]);
+    /// v.sort_radix();
+    /// assert_eq!(v, [-90, -45, 2, 24, 66, 75, 170, 802]);
+    /// ```
+    ///
+    /// # Complejidad
+    /// **O(k · (n + 256))** con `k = size_of::<T>()`: lineal en `n`.
+    pub fn sort_radix(&mut self) {
+        if self.len() < 2 {
+            return;
+        }
+
+        let mut scratch: MyVec<T> = MyVec::from_slice_copy(self.as_slice());
+        let mut input: &mut [T] = self.as_mut_slice();
+        let mut output: &mut [T] = scratch.as_mut_slice();
+        let mut in_self = true;
+
+        for digit in 0..T::BYTES {
+            let shift = digit * 8;
+            let byte = |x: T| ((x.to_radix_key() >> shift) & 0xFF) as usize;
+
+            let mut counts = [0usize; 256];
+            for &x in input.iter() {
+                counts[byte(x)] += 1;
+            }
+
+            // Si todos comparten este byte la pasada no cambiaría nada
+            if counts.contains(&input.len()) {
+                continue;
+            }
+
+            // Suma de prefijos: `offsets[b]` es dónde va el primer elemento con byte b
+            let mut offsets = [0usize; 256];
+            for b in 1..256 {
+                offsets[b] = offsets[b - 1] + counts[b - 1];
+            }
+
+            // Recorrer en orden y escribir en el siguiente hueco de su cubeta es
+            // lo que hace la pasada estable
+            for &x in input.iter() {
+                let b = byte(x);
+                output[offsets[b]] = x;
+                offsets[b] += 1;
+            }
+
+            std::mem::swap(&mut input, &mut output);
+            in_self = !in_self;
+        }
+
+        if !in_self {
+            output.copy_from_slice(input);
+        }
+    }
+}
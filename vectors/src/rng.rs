@@ -0,0 +1,61 @@
+/*
+SplitMix64
+
+Un generador pseudoaleatorio mínimo: el estado es un contador que avanza de a
+una constante impar (la parte fraccionaria de φ · 2⁶⁴) y cada salida pasa por
+un "mezclador" de xor-shifts y multiplicaciones. No es criptográfico, pero es
+rápido, tiene período 2⁶⁴ y pasa las pruebas estadísticas comunes: suficiente
+para barajar en pruebas y demos sin depender del crate `rand`.
+
+Misma semilla ⇒ misma secuencia, así que los resultados son reproducibles.
+*/
+
+/// Generador pseudoaleatorio SplitMix64 con semilla fija.
+///
+/// ```
+/// use vectors::SimpleRng;
+///
+/// let mut a = SimpleRng::from_seed(42);
+/// let mut b = SimpleRng::from_seed(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SimpleRng {
+    state: u64,
+}
+
+impl SimpleRng {
+    /// Crea un generador a partir de `seed`. Cualquier valor es válido, cero incluido.
+    pub fn from_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Retorna el siguiente número de 64 bits de la secuencia.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Retorna un número uniforme en `[0, bound)`.
+    ///
+    /// Usa rechazo para evitar el sesgo de `next_u64() % bound`: se descartan
+    /// los valores de la última "vuelta" incompleta de tamaño `bound`.
+    ///
+    /// # Panics
+    /// Si `bound == 0`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound must be > 0");
+
+        // 2⁶⁴ mod bound: quitando esos valores quedan vueltas completas de `bound`
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let x = self.next_u64();
+            if x >= threshold {
+                return x % bound;
+            }
+        }
+    }
+}
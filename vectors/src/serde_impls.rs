@@ -0,0 +1,53 @@
+//! Soporte opcional de serde (feature `serde`).
+//!
+//! `MyVec<T>` se serializa como una secuencia, igual que `Vec<T>`, así que
+//! ambos formatos son intercambiables en JSON u otros formatos.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{GrowthStrategy, MyVec};
+
+/// Límite a la reserva inicial: el `size_hint` viene de datos externos y no
+/// conviene confiar ciegamente en él para asignar memoria.
+const MAX_PREALLOC: usize = 4096;
+
+impl<T: Serialize, G: GrowthStrategy> Serialize for MyVec<T, G> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+struct MyVecVisitor<T, G> {
+    marker: PhantomData<(T, G)>,
+}
+
+impl<'de, T: Deserialize<'de>, G: GrowthStrategy> Visitor<'de> for MyVecVisitor<T, G> {
+    type Value = MyVec<T, G>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut v = MyVec::with_strategy();
+        v.reserve(seq.size_hint().unwrap_or(0).min(MAX_PREALLOC));
+
+        while let Some(elem) = seq.next_element()? {
+            v.push_back(elem);
+        }
+
+        Ok(v)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, G: GrowthStrategy> Deserialize<'de> for MyVec<T, G> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(MyVecVisitor {
+            marker: PhantomData,
+        })
+    }
+}
@@ -0,0 +1,31 @@
+/// Guard que escribe la longitud de vuelta al vector cuando se destruye.
+///
+/// Las operaciones en bloque mezclan escrituras crudas con código del usuario
+/// (`Clone`, closures, iteradores) que puede entrar en pánico. Llevando la
+/// cuenta en `local_len` y publicándola en `Drop`, el vector siempre termina
+/// con `len` igual al prefijo realmente inicializado, haya pánico o no.
+pub(crate) struct SetLenOnDrop<'a> {
+    len: &'a mut usize,
+    local_len: usize,
+}
+
+impl<'a> SetLenOnDrop<'a> {
+    pub(crate) fn new(len: &'a mut usize) -> Self {
+        let local_len = *len;
+        Self { len, local_len }
+    }
+
+    pub(crate) fn increment_len(&mut self, increment: usize) {
+        self.local_len += increment;
+    }
+
+    pub(crate) fn current_len(&self) -> usize {
+        self.local_len
+    }
+}
+
+impl Drop for SetLenOnDrop<'_> {
+    fn drop(&mut self) {
+        *self.len = self.local_len;
+    }
+}
@@ -0,0 +1,183 @@
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+
+use crate::MyVec;
+
+/// Vector que guarda hasta `N` elementos dentro de la propia estructura
+/// (en el stack si la estructura vive en el stack) y solo pasa al heap cuando
+/// se supera esa cantidad.
+///
+/// Para cargas con muchos vectores pequeños (1-4 elementos) evita por completo
+/// las llamadas al allocador. Una vez que se "derrama" al heap se queda ahí,
+/// aunque luego vuelva a tener `N` elementos o menos.
+///
+/// ```text
+/// Inline (len <= N):            Heap (spilled):
+/// ┌──────────────────────┐      ┌──────────────┐      ┌───┬───┬───┬───┬───┐
+/// │ [a, b, _, _]  len: 2 │      │ MyVec { ptr }│ ───▶ │ a │ b │ c │ d │ e │
+/// └──────────────────────┘      └──────────────┘      └───┴───┴───┴───┴───┘
+/// ```
+pub struct MySmallVec<T, const N: usize> {
+    storage: Storage<T, N>,
+}
+
+enum Storage<T, const N: usize> {
+    Inline {
+        buf: [MaybeUninit<T>; N],
+        len: usize,
+    },
+    Heap(MyVec<T>),
+}
+
+impl<T, const N: usize> MySmallVec<T, N> {
+    pub fn new() -> Self {
+        Self {
+            storage: Storage::Inline {
+                buf: [const { MaybeUninit::uninit() }; N],
+                len: 0,
+            },
+        }
+    }
+
+    /// Retorna `true` si los elementos ya viven en el heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self.storage, Storage::Heap(_))
+    }
+
+    /// Añade un elemento al final, pasando al heap si ya no cabe inline.
+    pub fn push_back(&mut self, new_elem: T) {
+        match &mut self.storage {
+            Storage::Inline { buf, len } if *len < N => {
+                buf[*len].write(new_elem);
+                *len += 1;
+            }
+            Storage::Inline { .. } => {
+                self.spill();
+                self.push_back(new_elem);
+            }
+            Storage::Heap(v) => v.push_back(new_elem),
+        }
+    }
+
+    /// Mueve los `N` elementos inline a un `MyVec` en el heap.
+    fn spill(&mut self) {
+        let mut heap = MyVec::with_capacity(N * 2);
+
+        if let Storage::Inline { buf, len } = &mut self.storage {
+            unsafe {
+                // Copia bit a bit: a partir de aquí los dueños son los slots del MyVec
+                ptr::copy_nonoverlapping(
+                    buf.as_ptr(),
+                    heap.spare_capacity_mut().as_mut_ptr(),
+                    *len,
+                );
+                heap.set_len(*len);
+            }
+            // El buffer inline ya no es dueño de nada
+            *len = 0;
+        }
+
+        // Reemplazar el Inline no destruye nada: son MaybeUninit
+        let _ = mem::replace(&mut self.storage, Storage::Heap(heap));
+    }
+
+    /// Quita y retorna el último elemento, o `None` si está vacío.
+    pub fn pop_back(&mut self) -> Option<T> {
+        match &mut self.storage {
+            Storage::Inline { buf, len } => {
+                if *len == 0 {
+                    return None;
+                }
+                *len -= 1;
+                unsafe { Some(buf[*len].assume_init_read()) }
+            }
+            Storage::Heap(v) => v.pop_back(),
+        }
+    }
+
+    /// Obtiene una referencia inmutable al elemento en la posición `index`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Obtiene una referencia mutable al elemento en la posición `index`.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    /// Retorna la longitud actual (número de elementos).
+    pub fn len(&self) -> usize {
+        match &self.storage {
+            Storage::Inline { len, .. } => *len,
+            Storage::Heap(v) => v.len(),
+        }
+    }
+
+    /// Retorna `true` si no contiene elementos.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Retorna la capacidad actual: `N` mientras esté inline.
+    pub fn capacity(&self) -> usize {
+        match &self.storage {
+            Storage::Inline { .. } => N,
+            Storage::Heap(v) => v.capacity(),
+        }
+    }
+
+    /// Retorna un slice con los elementos, estén inline o en el heap.
+    pub fn as_slice(&self) -> &[T] {
+        match &self.storage {
+            Storage::Inline { buf, len } => unsafe {
+                slice::from_raw_parts(buf.as_ptr() as *const T, *len)
+            },
+            Storage::Heap(v) => v.as_slice(),
+        }
+    }
+
+    /// Retorna un slice mutable con los elementos.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.storage {
+            Storage::Inline { buf, len } => unsafe {
+                slice::from_raw_parts_mut(buf.as_mut_ptr() as *mut T, *len)
+            },
+            Storage::Heap(v) => v.as_mut_slice(),
+        }
+    }
+
+    /// Retorna un iterador sobre referencias a los elementos.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T, const N: usize> Drop for MySmallVec<T, N> {
+    fn drop(&mut self) {
+        // El MyVec del heap se destruye solo; inline hay que hacerlo a mano
+        if let Storage::Inline { buf, len } = &mut self.storage {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    buf.as_mut_ptr() as *mut T,
+                    *len,
+                ));
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Default for MySmallVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a MySmallVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+
+use crate::{Iter, MyVec};
+
+/*
+Vector ordenado
+
+Un MyVec cuyo invariante es estar siempre ordenado (de menor a mayor):
+
+    [1, 3, 3, 7, 9]
+
+- Buscar es una búsqueda binaria: O(log n) comparaciones.
+- Insertar busca la posición en O(log n) pero desplaza los mayores un lugar a
+  la derecha: O(n) movimientos. Conviene cuando se busca mucho más de lo que
+  se inserta; si no, un árbol (BST/AVL) es mejor.
+
+Duplicados: se permiten. `insert` coloca el nuevo DESPUÉS de los iguales (el
+orden de llegada se conserva). Para un conjunto sin repetidos está
+`insert_unique`, que rechaza el valor si ya existe.
+
+Límites usados por `find` y `range`:
+
+    valores:       [1, 3, 3, 7]
+    lower_bound(3)     ▲              primer índice con valor >= 3  → 1
+    upper_bound(3)           ▲        primer índice con valor >  3  → 3
+*/
+
+/// Vector que se mantiene ordenado en todo momento.
+///
+/// ```
+/// use vectors::SortedVec;
+///
+/// let mut v = SortedVec::from_vec(vec![5, 1, 4]);
+/// v.insert(3);
+/// assert_eq!(v.as_slice(), [1, 3, 4, 5]);
+/// assert!(v.contains(&4));
+/// assert_eq!(v.range(2..5), [3, 4]);
+/// assert_eq!(v.insert_unique(4), Err(4));
+/// ```
+#[derive(Clone)]
+pub struct SortedVec<T: Ord> {
+    data: MyVec<T>,
+}
+
+impl<T: Ord> SortedVec<T> {
+    /// Crea un vector ordenado vacío.
+    pub fn new() -> Self {
+        Self { data: MyVec::new() }
+    }
+
+    /// Crea un vector ordenado a partir de `vec`, ordenándolo una sola vez.
+    ///
+    /// # Complejidad
+    /// **O(n log n)**
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let mut data: MyVec<T> = vec.into_iter().collect();
+        data.as_mut_slice().sort();
+        Self { data }
+    }
+
+    /// Retorna la cantidad de elementos.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Retorna `true` si no hay elementos.
+    pub fn is_empty(&self) -> bool {
+        self.data.len() == 0
+    }
+
+    /// Retorna los elementos como slice ordenado.
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Retorna un iterador en orden ascendente.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Retorna el `MyVec` interno, ya ordenado.
+    pub fn into_inner(self) -> MyVec<T> {
+        self.data
+    }
+
+    /// Primer índice con un valor `>= value`.
+    fn lower_bound(&self, value: &T) -> usize {
+        self.as_slice().partition_point(|x| x < value)
+    }
+
+    /// Primer índice con un valor `> value`.
+    fn upper_bound(&self, value: &T) -> usize {
+        self.as_slice().partition_point(|x| x <= value)
+    }
+
+    /// Inserta `value` después de los iguales que ya existan y retorna su
+    /// índice.
+    ///
+    /// # Complejidad
+    /// **O(log n)** comparaciones + **O(n)** movimientos.
+    pub fn insert(&mut self, value: T) -> usize {
+        self.data.insert_sorted(value)
+    }
+
+    /// Inserta `value` solo si no existe uno igual; si existe, lo devuelve en
+    /// el `Err`.
+    pub fn insert_unique(&mut self, value: T) -> Result<usize, T> {
+        match self.data.binary_search(&value) {
+            Ok(_) => Err(value),
+            Err(index) => {
+                self.data.insert(index, value);
+                Ok(index)
+            }
+        }
+    }
+
+    /// Retorna el índice de la primera aparición de `value`.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn find(&self, value: &T) -> Option<usize> {
+        let index = self.lower_bound(value);
+        (self.as_slice().get(index) == Some(value)).then_some(index)
+    }
+
+    /// Retorna `true` si `value` está en el vector.
+    ///
+    /// # Complejidad
+    /// **O(log n)**
+    pub fn contains(&self, value: &T) -> bool {
+        self.data.binary_search(value).is_ok()
+    }
+
+    /// Quita y retorna la primera aparición de `value`.
+    ///
+    /// # Complejidad
+    /// **O(log n)** comparaciones + **O(n)** movimientos.
+    pub fn remove_item(&mut self, value: &T) -> Option<T> {
+        let index = self.find(value)?;
+        Some(self.data.remove(index))
+    }
+
+    /// Retorna el slice de los elementos que caen en `range`.
+    ///
+    /// Un rango invertido (`5..2`) produce un slice vacío.
+    ///
+    /// # Complejidad
+    /// **O(log n)**, sin copiar elementos.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        let start = match range.start_bound() {
+            Bound::Included(value) => self.lower_bound(value),
+            Bound::Excluded(value) => self.upper_bound(value),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(value) => self.upper_bound(value),
+            Bound::Excluded(value) => self.lower_bound(value),
+            Bound::Unbounded => self.len(),
+        };
+        &self.as_slice()[start..end.max(start)]
+    }
+
+    /// Mueve todos los elementos de `other` a `self` con un solo recorrido de
+    /// mezcla, dejando `other` vacío. Entre iguales, los de `self` quedan
+    /// primero.
+    ///
+    /// # Complejidad
+    /// **O(n + m)**
+    pub fn merge(&mut self, other: &mut SortedVec<T>) {
+        let left = mem::take(&mut self.data);
+        let right = mem::take(&mut other.data);
+        let mut merged = MyVec::with_capacity(left.len() + right.len());
+
+        let mut left = left.into_iter().peekable();
+        let mut right = right.into_iter().peekable();
+        loop {
+            let take_left = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) => a <= b,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => break,
+            };
+            let next = if take_left { left.next() } else { right.next() };
+            merged.push_back(next.expect("peek vio un elemento"));
+        }
+
+        self.data = merged;
+    }
+}
+
+impl<T: Ord> Default for SortedVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for SortedVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<'a, T: Ord> IntoIterator for &'a SortedVec<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
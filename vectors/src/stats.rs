@@ -0,0 +1,65 @@
+use std::fmt;
+
+/// Resumen del uso de memoria de un [`MyVec`](crate::MyVec), retornado por
+/// [`MyVec::memory_stats`](crate::MyVec::memory_stats).
+///
+/// Todos los tamaños están en bytes, salvo `len` y `capacity` que cuentan
+/// elementos.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Elementos inicializados.
+    pub len: usize,
+    /// Elementos que caben en el bloque reservado.
+    pub capacity: usize,
+    /// Tamaño de cada elemento (`size_of::<T>()`, incluye el padding).
+    pub element_size: usize,
+    /// Alineación de cada elemento (`align_of::<T>()`).
+    pub element_align: usize,
+    /// Bytes reservados en el heap: `capacity × element_size`.
+    pub allocated_bytes: usize,
+    /// Bytes ocupados por elementos vivos: `len × element_size`.
+    pub used_bytes: usize,
+    /// Bytes reservados pero sin usar: `allocated_bytes - used_bytes`.
+    pub wasted_bytes: usize,
+}
+
+/*
+Ejemplo de salida del Display para un MyVec<u64> con 3 elementos y capacidad 4:
+
+    ┌───────────────┬───────┐
+    │ len           │     3 │
+    │ capacity      │     4 │
+    │ element size  │     8 │
+    │ element align │     8 │
+    │ allocated     │    32 │
+    │ used          │    24 │
+    │ wasted        │     8 │
+    └───────────────┴───────┘
+*/
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = [
+            ("len", self.len),
+            ("capacity", self.capacity),
+            ("element size", self.element_size),
+            ("element align", self.element_align),
+            ("allocated", self.allocated_bytes),
+            ("used", self.used_bytes),
+            ("wasted", self.wasted_bytes),
+        ];
+
+        // La columna de valores se ensancha si algún número no cabe en 5 dígitos
+        let width = rows
+            .iter()
+            .map(|(_, value)| value.to_string().len())
+            .max()
+            .unwrap_or(0)
+            .max(5);
+
+        writeln!(f, "┌───────────────┬─{}─┐", "─".repeat(width))?;
+        for (label, value) in rows {
+            writeln!(f, "│ {label:<13} │ {value:>width$} │")?;
+        }
+        write!(f, "└───────────────┴─{}─┘", "─".repeat(width))
+    }
+}
@@ -0,0 +1,29 @@
+/// Tipos para los que un bloque de bytes en cero es un valor válido.
+///
+/// Está sellado: solo lo implementan los enteros y flotantes primitivos, así
+/// que [`MyVec::with_capacity_zeroed`](crate::MyVec::with_capacity_zeroed)
+/// puede ser segura. Un `bool` o una referencia en cero no serían válidos
+/// (o no lo serían siempre), por eso no se puede implementar desde fuera.
+///
+/// ```compile_fail
+/// use vectors::MyVec;
+///
+/// // bool no es Zeroable
+/// let flags = MyVec::<bool>::with_capacity_zeroed(8);
+/// ```
+pub trait Zeroable: sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_zeroable {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl Zeroable for $t {}
+        )*
+    };
+}
+
+impl_zeroable!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
@@ -0,0 +1,108 @@
+use vectors::BitVec;
+
+#[test]
+fn test_boundary_indices_63_64_65() {
+    let mut bits = BitVec::with_len(130);
+    for i in [0, 63, 64, 65, 127, 128, 129] {
+        bits.set(i, true);
+    }
+    assert_eq!(bits.get(62), Some(false));
+    assert_eq!(bits.get(63), Some(true));
+    assert_eq!(bits.get(64), Some(true));
+    assert_eq!(bits.get(65), Some(true));
+    assert_eq!(bits.get(130), None);
+    assert_eq!(bits.ones().collect::<Vec<_>>(), [0, 63, 64, 65, 127, 128, 129]);
+
+    bits.set(64, false);
+    assert_eq!(bits.get(64), Some(false));
+    assert_eq!(bits.count_ones(), 6);
+}
+
+#[test]
+#[should_panic(expected = "fuera de rango")]
+fn test_set_out_of_range_panics() {
+    let mut bits = BitVec::with_len(64);
+    bits.set(64, true);
+}
+
+#[test]
+fn test_push_and_iter() {
+    let mut bits = BitVec::new();
+    assert!(bits.is_empty());
+    for i in 0..70 {
+        bits.push(i % 3 == 0);
+    }
+    assert_eq!(bits.len(), 70);
+    assert_eq!(bits.iter().len(), 70);
+    assert!(bits.iter().enumerate().all(|(i, b)| b == (i % 3 == 0)));
+    assert_eq!(bits.count_ones(), 24);
+
+    let small: BitVec = [false, true, true, false].into_iter().collect();
+    assert_eq!(format!("{small:?}"), "BitVec[0110]");
+}
+
+#[test]
+fn test_not_masks_trailing_bits() {
+    for len in [1, 63, 64, 65, 100] {
+        let bits = BitVec::with_len(len);
+        let inverted = !&bits;
+        assert_eq!(inverted.count_ones(), len, "largo {len}");
+        assert_eq!(inverted.ones().last(), Some(len - 1));
+
+        // Doble negación vuelve al original, relleno incluido
+        assert_eq!(!inverted, bits);
+    }
+
+    let mut bits = BitVec::with_len(65);
+    bits.set(64, true);
+    bits.invert();
+    assert_eq!(bits.count_ones(), 64);
+    assert_eq!(bits.get(64), Some(false));
+}
+
+#[test]
+fn test_bitwise_ops() {
+    let a: BitVec = [true, true, false, false].into_iter().collect();
+    let b: BitVec = [true, false, true, false].into_iter().collect();
+
+    assert_eq!(format!("{:?}", &a & &b), "BitVec[1000]");
+    assert_eq!(format!("{:?}", &a | &b), "BitVec[1110]");
+    assert_eq!(format!("{:?}", &a ^ &b), "BitVec[0110]");
+    assert_eq!(format!("{:?}", a.clone() & b.clone()), "BitVec[1000]");
+
+    let mut c = a.clone();
+    c |= &b;
+    c ^= &a;
+    assert_eq!(c, b.clone() & !a.clone());
+    c &= &BitVec::with_len(4);
+    assert_eq!(c.count_ones(), 0);
+}
+
+#[test]
+#[should_panic(expected = "mismo largo")]
+fn test_ops_with_different_lengths_panic() {
+    let _ = &BitVec::with_len(3) & &BitVec::with_len(4);
+}
+
+#[test]
+fn test_sieve_of_eratosthenes() {
+    let n = 1_000;
+    let mut composite = BitVec::with_len(n + 1);
+    composite.set(0, true);
+    composite.set(1, true);
+
+    let mut p = 2;
+    while p * p <= n {
+        if composite.get(p) == Some(false) {
+            for multiple in (p * p..=n).step_by(p) {
+                composite.set(multiple, true);
+            }
+        }
+        p += 1;
+    }
+
+    let primes: Vec<usize> = (!composite).ones().collect();
+    assert_eq!(primes.len(), 168);
+    assert_eq!(&primes[..10], [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert_eq!(primes.last(), Some(&997));
+}
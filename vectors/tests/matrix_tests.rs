@@ -0,0 +1,66 @@
+use vectors::Matrix2D;
+
+fn identity(n: usize) -> Matrix2D<i64> {
+    Matrix2D::from_fn(n, n, |r, c| i64::from(r == c))
+}
+
+#[test]
+fn test_new_get_and_index() {
+    let mut m = Matrix2D::new(2, 3, 0);
+    assert_eq!(m.shape(), (2, 3));
+    m[(1, 2)] = 7;
+    *m.get_mut(0, 1).unwrap() = 4;
+
+    assert_eq!(m.get(1, 2), Some(&7));
+    assert_eq!(m.get(2, 0), None);
+    assert_eq!(m.get(0, 3), None);
+    assert_eq!(m.row(1), [0, 0, 7]);
+    assert_eq!(m.iter_rows().len(), 2);
+    assert_eq!(format!("{m:?}"), "[[0, 4, 0], [0, 0, 7]]");
+}
+
+#[test]
+#[should_panic(expected = "fila 2, columna 0 fuera de rango para una matriz de 2x3")]
+fn test_index_out_of_range_panics() {
+    let m = Matrix2D::new(2, 3, 0u8);
+    let _ = m[(2, 0)];
+}
+
+#[test]
+fn test_identity_multiplication() {
+    let a = Matrix2D::from_fn(4, 4, |r, c| (r * 4 + c) as i64 - 5);
+    assert_eq!(a.multiply(&identity(4)), a);
+    assert_eq!(identity(4).multiply(&a), a);
+}
+
+#[test]
+fn test_transpose_non_square() {
+    let m = Matrix2D::from_slice(2, 3, &["a", "b", "c", "d", "e", "f"]);
+    let t = m.transpose();
+    assert_eq!(t.shape(), (3, 2));
+    assert_eq!(t.iter_rows().collect::<Vec<_>>(), [["a", "d"], ["b", "e"], ["c", "f"]]);
+    assert_eq!(t.transpose(), m);
+}
+
+#[test]
+fn test_known_product_and_sum() {
+    let a = Matrix2D::from_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    let b = Matrix2D::from_slice(3, 2, &[7, 8, 9, 10, 11, 12]);
+    assert_eq!(a.multiply(&b), Matrix2D::from_slice(2, 2, &[58, 64, 139, 154]));
+
+    let doubled = a.add(&a);
+    assert_eq!(doubled, a.map(|x| x * 2));
+}
+
+#[test]
+#[should_panic(expected = "no se pueden multiplicar matrices de 2x3 y 2x3")]
+fn test_multiply_dimension_mismatch_panics() {
+    let a = Matrix2D::new(2, 3, 1);
+    a.multiply(&a);
+}
+
+#[test]
+#[should_panic(expected = "no se pueden sumar")]
+fn test_add_dimension_mismatch_panics() {
+    Matrix2D::new(2, 3, 1).add(&Matrix2D::new(3, 2, 1));
+}
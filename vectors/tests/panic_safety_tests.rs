@@ -0,0 +1,243 @@
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+use vectors::MyVec;
+
+/// Contadores compartidos entre todos los elementos de una prueba.
+#[derive(Default)]
+struct Counters {
+    clones: Cell<usize>,
+    drops: Cell<usize>,
+}
+
+/// Elemento cuyo clon número `n` (contando desde 1) entra en pánico.
+struct PanicOnNthClone {
+    value: i32,
+    panic_at: usize,
+    counters: Rc<Counters>,
+}
+
+impl PanicOnNthClone {
+    fn new(value: i32, panic_at: usize, counters: &Rc<Counters>) -> Self {
+        Self {
+            value,
+            panic_at,
+            counters: Rc::clone(counters),
+        }
+    }
+}
+
+impl Clone for PanicOnNthClone {
+    fn clone(&self) -> Self {
+        let clones = self.counters.clones.get() + 1;
+        self.counters.clones.set(clones);
+        if clones == self.panic_at {
+            panic!("clone número {clones}");
+        }
+        Self::new(self.value, self.panic_at, &self.counters)
+    }
+}
+
+impl Drop for PanicOnNthClone {
+    fn drop(&mut self) {
+        self.counters.drops.set(self.counters.drops.get() + 1);
+    }
+}
+
+/// Elemento cuyo drop número `n` (contando desde 1) entra en pánico.
+struct PanicOnNthDrop {
+    value: i32,
+    panic_at: usize,
+    counters: Rc<Counters>,
+}
+
+impl PanicOnNthDrop {
+    fn new(value: i32, panic_at: usize, counters: &Rc<Counters>) -> Self {
+        Self {
+            value,
+            panic_at,
+            counters: Rc::clone(counters),
+        }
+    }
+}
+
+impl Drop for PanicOnNthDrop {
+    fn drop(&mut self) {
+        let drops = self.counters.drops.get() + 1;
+        self.counters.drops.set(drops);
+        if drops == self.panic_at {
+            panic!("drop número {drops}");
+        }
+    }
+}
+
+fn values<T>(v: &MyVec<T>, value: impl Fn(&T) -> i32) -> Vec<i32> {
+    v.as_slice().iter().map(value).collect()
+}
+
+#[test]
+fn test_clone_panic() {
+    let counters = Rc::new(Counters::default());
+    let mut v = MyVec::new();
+    for i in 0..5 {
+        v.push_back(PanicOnNthClone::new(i, 3, &counters));
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| v.clone()));
+    assert!(result.is_err());
+    // Los dos clones que sí se crearon se destruyeron con el vector a medias
+    assert_eq!(counters.drops.get(), 2);
+
+    // El original sigue intacto
+    assert_eq!(values(&v, |e| e.value), vec![0, 1, 2, 3, 4]);
+    drop(v);
+    assert_eq!(counters.drops.get(), 7);
+}
+
+#[test]
+fn test_extend_from_slice_panic() {
+    let counters = Rc::new(Counters::default());
+    let source: Vec<_> = (10..15).map(|i| PanicOnNthClone::new(i, 3, &counters)).collect();
+
+    let mut v = MyVec::new();
+    v.push_back(PanicOnNthClone::new(0, 0, &counters));
+
+    let result = catch_unwind(AssertUnwindSafe(|| v.extend_from_slice(&source)));
+    assert!(result.is_err());
+    // len cuenta solo el prefijo inicializado: el original + 2 clones
+    assert_eq!(values(&v, |e| e.value), vec![0, 10, 11]);
+
+    drop(v);
+    assert_eq!(counters.drops.get(), 3);
+}
+
+#[test]
+fn test_extend_from_within_panic() {
+    let counters = Rc::new(Counters::default());
+    let mut v = MyVec::new();
+    for i in 0..4 {
+        v.push_back(PanicOnNthClone::new(i, 2, &counters));
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| v.extend_from_within(..)));
+    assert!(result.is_err());
+    assert_eq!(values(&v, |e| e.value), vec![0, 1, 2, 3, 0]);
+
+    drop(v);
+    assert_eq!(counters.drops.get(), 5);
+}
+
+#[test]
+fn test_resize_panic() {
+    let counters = Rc::new(Counters::default());
+    let mut v = MyVec::new();
+    v.push_back(PanicOnNthClone::new(1, 0, &counters));
+
+    let fill = PanicOnNthClone::new(7, 3, &counters);
+    let result = catch_unwind(AssertUnwindSafe(|| v.resize(6, fill)));
+    assert!(result.is_err());
+    assert_eq!(values(&v, |e| e.value), vec![1, 7, 7]);
+
+    // `fill` se destruyó durante el unwinding: 1 drop
+    assert_eq!(counters.drops.get(), 1);
+    drop(v);
+    assert_eq!(counters.drops.get(), 4);
+}
+
+#[test]
+fn test_extend_iterator_panic() {
+    let mut v = MyVec::new();
+    v.push_back(String::from("antes"));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        v.extend((0..5).map(|i| {
+            if i == 3 {
+                panic!("el iterador falla");
+            }
+            i.to_string()
+        }))
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(v.as_slice(), &["antes", "0", "1", "2"]);
+}
+
+#[test]
+fn test_retain_predicate_panic() {
+    let counters = Rc::new(Counters::default());
+    let mut v = MyVec::new();
+    for i in 0..10 {
+        v.push_back(PanicOnNthDrop::new(i, 0, &counters));
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        v.retain(|e| {
+            if e.value == 5 {
+                panic!("el predicado falla");
+            }
+            e.value % 2 == 1
+        })
+    }));
+
+    assert!(result.is_err());
+    // Se eliminaron 0, 2 y 4; lo no procesado se conserva en orden
+    assert_eq!(values(&v, |e| e.value), vec![1, 3, 5, 6, 7, 8, 9]);
+    assert_eq!(counters.drops.get(), 3);
+
+    drop(v);
+    assert_eq!(counters.drops.get(), 10);
+}
+
+#[test]
+fn test_retain_drop_panic() {
+    let counters = Rc::new(Counters::default());
+    let mut v = MyVec::new();
+    for i in 0..6 {
+        v.push_back(PanicOnNthDrop::new(i, 2, &counters));
+    }
+
+    // El segundo elemento eliminado (el 2) entra en pánico al destruirse
+    let result = catch_unwind(AssertUnwindSafe(|| v.retain(|e| e.value % 2 == 1)));
+    assert!(result.is_err());
+    assert_eq!(values(&v, |e| e.value), vec![1, 3, 4, 5]);
+
+    drop(v);
+    assert_eq!(counters.drops.get(), 6);
+}
+
+#[test]
+fn test_truncate_drop_panic() {
+    let counters = Rc::new(Counters::default());
+    let mut v = MyVec::new();
+    for i in 0..6 {
+        v.push_back(PanicOnNthDrop::new(i, 2, &counters));
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| v.truncate(2)));
+    assert!(result.is_err());
+    // La cola completa se destruyó igual, y el vector ya no la cuenta
+    assert_eq!(v.len(), 2);
+    assert_eq!(counters.drops.get(), 4);
+
+    drop(v);
+    assert_eq!(counters.drops.get(), 6);
+}
+
+#[test]
+fn test_from_fn_panic() {
+    let counters = Rc::new(Counters::default());
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        MyVec::from_fn(5, |i| {
+            if i == 3 {
+                panic!("f({i})");
+            }
+            PanicOnNthDrop::new(i as i32, 0, &counters)
+        })
+    }));
+
+    assert!(result.is_err());
+    // f(0), f(1), f(2) se escribieron y se destruyeron una sola vez
+    assert_eq!(counters.drops.get(), 3);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use vectors::MyVec;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    name: String,
+}
+
+#[test]
+fn test_serde_empty() {
+    let v: MyVec<i32> = MyVec::new();
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "[]");
+
+    let back: MyVec<i32> = serde_json::from_str(&json).unwrap();
+    assert!(back.is_empty());
+}
+
+#[test]
+fn test_serde_structs() {
+    let mut v = MyVec::new();
+    v.push_back(Record { id: 1, name: String::from("uno") });
+    v.push_back(Record { id: 2, name: String::from("dos") });
+
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, r#"[{"id":1,"name":"uno"},{"id":2,"name":"dos"}]"#);
+
+    // Mismo formato que un Vec
+    let as_vec: Vec<Record> = serde_json::from_str(&json).unwrap();
+    assert_eq!(as_vec.len(), 2);
+
+    let back: MyVec<Record> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.as_slice(), v.as_slice());
+}
+
+#[test]
+fn test_serde_nested() {
+    let mut outer: MyVec<MyVec<u8>> = MyVec::new();
+    for n in 0..3u8 {
+        let mut inner = MyVec::new();
+        for i in 0..n {
+            inner.push_back(i);
+        }
+        outer.push_back(inner);
+    }
+
+    let json = serde_json::to_string(&outer).unwrap();
+    assert_eq!(json, "[[],[0],[0,1]]");
+
+    let back: MyVec<MyVec<u8>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.len(), 3);
+    assert_eq!(back.get(2).unwrap().as_slice(), &[0, 1]);
+}
@@ -0,0 +1,108 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::rc::Rc;
+
+use vectors::MySmallVec;
+
+/// Allocador que cuenta las asignaciones del hilo actual.
+///
+/// Los tests corren en paralelo, por eso el contador es thread-local.
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(|n| n.get())
+}
+
+struct Counted(Rc<Cell<usize>>);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn test_spill_keeps_contents() {
+    let mut v: MySmallVec<String, 4> = MySmallVec::new();
+    for i in 0..4 {
+        v.push_back(i.to_string());
+    }
+    assert!(!v.spilled());
+    assert_eq!(v.capacity(), 4);
+
+    v.push_back(String::from("4"));
+    assert!(v.spilled());
+    assert_eq!(v.len(), 5);
+    assert_eq!(v.as_slice(), &["0", "1", "2", "3", "4"]);
+
+    assert_eq!(v.pop_back().as_deref(), Some("4"));
+    assert_eq!(v.get(0).map(String::as_str), Some("0"));
+    assert_eq!(v.iter().count(), 4);
+}
+
+#[test]
+fn test_no_allocation_while_inline() {
+    let before = allocations();
+
+    let mut v: MySmallVec<u64, 4> = MySmallVec::new();
+    for i in 0..4 {
+        v.push_back(i);
+    }
+    *v.get_mut(0).unwrap() = 10;
+    assert_eq!(v.pop_back(), Some(3));
+    assert_eq!(v.iter().sum::<u64>(), 13);
+
+    assert_eq!(allocations(), before);
+
+    v.push_back(3);
+    v.push_back(4);
+    assert!(v.spilled());
+    assert_eq!(allocations(), before + 1);
+}
+
+#[test]
+fn test_drop_inline() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut v: MySmallVec<Counted, 4> = MySmallVec::new();
+        for _ in 0..3 {
+            v.push_back(Counted(Rc::clone(&drops)));
+        }
+        drop(v.pop_back());
+        assert_eq!(drops.get(), 1);
+    }
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn test_drop_spilled() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut v: MySmallVec<Counted, 2> = MySmallVec::new();
+        for _ in 0..7 {
+            v.push_back(Counted(Rc::clone(&drops)));
+        }
+        assert!(v.spilled());
+        // Pasar al heap no destruye nada
+        assert_eq!(drops.get(), 0);
+    }
+    assert_eq!(drops.get(), 7);
+}
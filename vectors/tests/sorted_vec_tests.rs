@@ -0,0 +1,106 @@
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+use vectors::{SimpleRng, SortedVec};
+
+#[test]
+fn test_random_insertions_stay_sorted() {
+    let mut rng = SimpleRng::from_seed(1135);
+    let mut v = SortedVec::new();
+    let mut model = Vec::new();
+
+    for _ in 0..2_000 {
+        let x = rng.below(500);
+        v.insert(x);
+        model.push(x);
+        assert!(v.as_slice().is_sorted());
+    }
+
+    model.sort();
+    assert_eq!(v.as_slice(), model);
+}
+
+#[test]
+fn test_duplicates_and_insert_unique() {
+    let mut v = SortedVec::from_vec(vec![3, 1, 3]);
+    assert_eq!(v.insert(3), 3);
+    assert_eq!(v.find(&3), Some(1));
+    assert_eq!(v.insert_unique(3), Err(3));
+    assert_eq!(v.insert_unique(2), Ok(1));
+    assert_eq!(v.as_slice(), [1, 2, 3, 3, 3]);
+
+    assert_eq!(v.remove_item(&3), Some(3));
+    assert_eq!(v.remove_item(&9), None);
+    assert_eq!(v.find(&9), None);
+    assert_eq!(format!("{v:?}"), "[1, 2, 3, 3]");
+}
+
+#[test]
+fn test_merge_equals_sorting_concatenation() {
+    let mut rng = SimpleRng::from_seed(7);
+    let a: Vec<u64> = (0..300).map(|_| rng.below(100)).collect();
+    let b: Vec<u64> = (0..200).map(|_| rng.below(100)).collect();
+
+    let mut left = SortedVec::from_vec(a.clone());
+    let mut right: SortedVec<u64> = b.iter().copied().collect();
+    left.merge(&mut right);
+
+    let mut expected = [a, b].concat();
+    expected.sort();
+    assert_eq!(left.as_slice(), expected);
+    assert!(right.is_empty());
+
+    let mut empty = SortedVec::new();
+    left.merge(&mut empty);
+    assert_eq!(left.len(), 500);
+}
+
+#[test]
+fn test_range_boundaries() {
+    let v: SortedVec<i32> = [1, 3, 3, 5, 7, 9].into_iter().collect();
+
+    assert_eq!(v.range(3..7), [3, 3, 5]);
+    assert_eq!(v.range(3..=7), [3, 3, 5, 7]);
+    assert_eq!(v.range(4..5), [] as [i32; 0]);
+    assert_eq!(v.range(..3), [1]);
+    assert_eq!(v.range(8..), [9]);
+    assert_eq!(v.range(..), v.as_slice());
+    assert_eq!(v.range(0..100), v.as_slice());
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = v.range(7..3);
+    assert!(reversed.is_empty());
+}
+
+thread_local! {
+    static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Llave que cuenta cuántas veces se compara.
+#[derive(PartialEq, Eq)]
+struct Counted(u32);
+
+impl PartialOrd for Counted {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Counted {
+    fn cmp(&self, other: &Self) -> Ordering {
+        COMPARISONS.with(|c| c.set(c.get() + 1));
+        self.0.cmp(&other.0)
+    }
+}
+
+#[test]
+fn test_contains_uses_logarithmic_comparisons() {
+    let v = SortedVec::from_vec((0..100_000).map(Counted).collect());
+
+    for probe in [0, 1, 50_000, 99_999, 100_000, 123_456] {
+        COMPARISONS.with(|c| c.set(0));
+        let expected = probe < 100_000;
+        assert_eq!(v.contains(&Counted(probe)), expected);
+        // log2(100_000) ≈ 17
+        assert!(COMPARISONS.with(Cell::get) <= 20);
+    }
+}
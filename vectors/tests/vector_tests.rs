@@ -1,4 +1,17 @@
-use vectors::MyVec;
+use std::cell::Cell;
+use std::rc::Rc;
+
+use vectors::{MemoryStats, MyVec, MyVecError, SimpleRng, TryReserveError};
+
+/// Valor que suma 1 al contador compartido cada vez que se destruye.
+#[derive(Clone)]
+struct Counted<T = ()>(T, Rc<Cell<usize>>);
+
+impl<T> Drop for Counted<T> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() + 1);
+    }
+}
 
 #[test]
 fn test_vector_access_o1() {
@@ -56,3 +69,1687 @@ fn test_push_and_grow() {
         assert_eq!(v.get(i), Some(&((i + 1) as i32)));
     }
 }
+
+#[test]
+fn test_windows() {
+    let mut v = MyVec::new();
+    for i in 1..=4 {
+        v.push_back(i);
+    }
+
+    let pairs: Vec<&[i32]> = v.windows(2).collect();
+    assert_eq!(pairs, vec![&[1, 2][..], &[2, 3][..], &[3, 4][..]]);
+    assert_eq!(v.windows(2).len(), 3);
+
+    // Recorrido desde el final
+    let back: Vec<&[i32]> = v.windows(2).rev().collect();
+    assert_eq!(back, vec![&[3, 4][..], &[2, 3][..], &[1, 2][..]]);
+
+    // Ventana del mismo tamaño que el vector: una sola ventana
+    let whole: Vec<&[i32]> = v.windows(4).collect();
+    assert_eq!(whole, vec![&[1, 2, 3, 4][..]]);
+
+    // Ventana más grande que el vector: ninguna
+    assert_eq!(v.windows(5).count(), 0);
+    assert_eq!(v.windows(5).size_hint(), (0, Some(0)));
+}
+
+#[test]
+#[should_panic]
+fn test_windows_zero_size() {
+    let v: MyVec<i32> = MyVec::new();
+    v.windows(0);
+}
+
+#[test]
+fn test_insert_sorted() {
+    let input = [7, 3, 9, 1, 4, 8, 2, 6, 5, 0];
+    let mut v = MyVec::new();
+    for x in input {
+        let index = v.insert_sorted(x);
+        assert_eq!(v.get(index), Some(&x));
+    }
+
+    let mut expected = input.to_vec();
+    expected.sort();
+    assert_eq!(v.as_slice(), &expected[..]);
+    assert_eq!(v.binary_search(&4), Ok(4));
+    assert_eq!(v.binary_search(&10), Err(10));
+}
+
+#[test]
+fn test_insert_sorted_stable_duplicates() {
+    // (clave, orden de llegada) comparado solo por la clave
+    #[derive(Debug, PartialEq, Eq)]
+    struct Keyed(i32, usize);
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Keyed {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let mut v = MyVec::new();
+    for (seq, key) in [2, 1, 2, 1, 2].into_iter().enumerate() {
+        v.insert_sorted(Keyed(key, seq));
+    }
+
+    let order: Vec<(i32, usize)> = v.as_slice().iter().map(|k| (k.0, k.1)).collect();
+    assert_eq!(order, vec![(1, 1), (1, 3), (2, 0), (2, 2), (2, 4)]);
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut v: MyVec<u64> = MyVec::new();
+    assert_eq!(v.try_reserve(10), Ok(()));
+    assert!(v.capacity() >= 10);
+    assert_eq!(v.len(), 0);
+
+    // len + additional no cabe en usize
+    v.push_back(1);
+    assert_eq!(v.try_reserve(usize::MAX), Err(TryReserveError::CapacityOverflow));
+
+    // Más de isize::MAX bytes: el Layout no es representable
+    let too_big = isize::MAX as usize / std::mem::size_of::<u64>() + 1;
+    assert_eq!(v.try_reserve(too_big), Err(TryReserveError::CapacityOverflow));
+
+    // El vector no cambia después de un error
+    assert_eq!(v.get(0), Some(&1));
+    assert_eq!(v.len(), 1);
+}
+
+#[test]
+fn test_try_push() {
+    let mut v = MyVec::new();
+    for i in 0..100 {
+        assert_eq!(v.try_push(i), Ok(()));
+    }
+
+    assert_eq!(v.len(), 100);
+    assert_eq!(v.get(99), Some(&99));
+}
+
+#[test]
+fn test_with_capacity() {
+    let mut v = MyVec::with_capacity(10);
+    assert_eq!(v.capacity(), 10);
+    assert_eq!(v.len(), 0);
+
+    for i in 0..10 {
+        v.push_back(i);
+    }
+    assert_eq!(v.capacity(), 10);
+
+    let empty: MyVec<u8> = MyVec::with_capacity(0);
+    assert_eq!(empty.capacity(), 0);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_with_capacity_overflow() {
+    let _v: MyVec<u64> = MyVec::with_capacity(usize::MAX / std::mem::size_of::<u64>() + 1);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_with_capacity_over_isize_max() {
+    let _v: MyVec<u8> = MyVec::with_capacity(isize::MAX as usize + 1);
+}
+
+#[test]
+fn test_send_to_thread() {
+    let mut v = MyVec::new();
+    v.push_back(String::from("hola"));
+    v.push_back(String::from("mundo"));
+
+    let handle = std::thread::spawn(move || {
+        assert_eq!(v.get(1).map(String::as_str), Some("mundo"));
+        v.len()
+    });
+
+    assert_eq!(handle.join().unwrap(), 2);
+}
+
+#[test]
+fn test_share_between_scoped_threads() {
+    let mut v = MyVec::new();
+    for i in 0..100 {
+        v.push_back(i);
+    }
+
+    let total: i32 = std::thread::scope(|s| {
+        let left = s.spawn(|| v.as_slice()[..50].iter().sum::<i32>());
+        let right = s.spawn(|| v.as_slice()[50..].iter().sum::<i32>());
+        left.join().unwrap() + right.join().unwrap()
+    });
+
+    assert_eq!(total, (0..100).sum());
+}
+
+#[test]
+fn test_covariance() {
+    // Solo compila si MyVec es covariante en T
+    fn shorten<'a>(v: MyVec<&'static str>) -> MyVec<&'a str> {
+        v
+    }
+
+    let local = String::from("local");
+    let mut v = shorten(MyVec::new());
+    v.push_back("estático");
+    v.push_back(&local);
+
+    assert_eq!(v.get(0), Some(&"estático"));
+    assert_eq!(v.get(1), Some(&"local"));
+}
+
+#[test]
+fn test_drop_elements() {
+    let drops = Rc::new(Cell::new(0));
+    {
+        let mut v = MyVec::new();
+        for _ in 0..10 {
+            v.push_back(Counted((), Rc::clone(&drops)));
+        }
+        assert_eq!(drops.get(), 0);
+    }
+
+    // Cada elemento se destruye exactamente una vez
+    assert_eq!(drops.get(), 10);
+}
+
+#[test]
+fn test_shrink_to_fit() {
+    let mut v = MyVec::with_capacity(100);
+    v.push_back(1);
+    v.push_back(2);
+    v.shrink_to_fit();
+    assert_eq!(v.capacity(), 2);
+    assert_eq!(v.as_slice(), &[1, 2]);
+
+    let mut empty: MyVec<i32> = MyVec::with_capacity(8);
+    empty.shrink_to_fit();
+    assert_eq!(empty.capacity(), 0);
+}
+
+#[test]
+fn test_leak() {
+    let mut v = MyVec::new();
+    v.push_back(String::from("config"));
+    v.push_back(String::from("inicial"));
+
+    let leaked: &'static mut [String] = v.leak();
+    assert_eq!(leaked[1], "inicial");
+
+    leaked[0].push_str("_modificada");
+    assert_eq!(leaked[0], "config_modificada");
+}
+
+#[test]
+fn test_into_boxed_slice() {
+    let mut v = MyVec::with_capacity(3);
+    for i in 0..3 {
+        v.push_back(i.to_string());
+    }
+    // capacidad == len: el Box reutiliza el mismo bloque
+    let ptr = v.as_slice().as_ptr();
+    let boxed = v.into_boxed_slice();
+    assert_eq!(boxed.as_ptr(), ptr);
+    assert_eq!(&*boxed, &["0", "1", "2"]);
+
+    // Con capacidad sobrante se encoge primero
+    let mut v = MyVec::with_capacity(10);
+    v.push_back(7u64);
+    let boxed = v.into_boxed_slice();
+    assert_eq!(&*boxed, &[7]);
+
+    let empty: Box<[u8]> = MyVec::new().into_boxed_slice();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_spare_capacity_and_set_len() {
+    let mut v: MyVec<u8> = MyVec::new();
+    v.reserve(16);
+
+    let spare = v.spare_capacity_mut();
+    assert!(spare.len() >= 16);
+    for (i, slot) in spare[..16].iter_mut().enumerate() {
+        slot.write(i as u8 * 2);
+    }
+
+    unsafe { v.set_len(16) };
+    assert_eq!(v.len(), 16);
+    assert_eq!(v.get(0), Some(&0));
+    assert_eq!(v.get(15), Some(&30));
+}
+
+#[test]
+fn test_read_into_spare_capacity() {
+    use std::io::Read;
+
+    let mut source: &[u8] = b"hola desde read()";
+    let mut v: MyVec<u8> = MyVec::new();
+    v.push_back(b'>');
+    v.reserve(32);
+
+    let spare = v.spare_capacity_mut();
+    // Read exige un &mut [u8] inicializado: primero se llena con ceros
+    for slot in spare.iter_mut() {
+        slot.write(0);
+    }
+    let buf = unsafe { std::slice::from_raw_parts_mut(spare.as_mut_ptr() as *mut u8, spare.len()) };
+    let n = source.read(buf).unwrap();
+
+    unsafe { v.set_len(v.len() + n) };
+    assert_eq!(v.as_slice(), b">hola desde read()");
+}
+
+#[test]
+fn test_split_at_mut() {
+    let mut v = MyVec::new();
+    for i in 0..6 {
+        v.push_back(i);
+    }
+
+    let (left, right) = v.split_at_mut(3);
+    left[0] = 100;
+    right[2] = 500;
+    left.reverse();
+
+    assert_eq!(v.get(0), Some(&2));
+    assert_eq!(v.get(2), Some(&100));
+    assert_eq!(v.get(5), Some(&500));
+
+    let (left, right) = v.split_at(3);
+    assert_eq!(left, &[2, 1, 100]);
+    assert_eq!(right, &[3, 4, 500]);
+}
+
+#[test]
+fn test_split_at_edges() {
+    let mut v = MyVec::new();
+    v.push_back('a');
+    v.push_back('b');
+
+    let (left, right) = v.split_at(0);
+    assert!(left.is_empty());
+    assert_eq!(right, &['a', 'b']);
+
+    let (left, right) = v.split_at_mut(2);
+    assert_eq!(left, &['a', 'b']);
+    assert!(right.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_split_at_out_of_bounds() {
+    let mut v = MyVec::new();
+    v.push_back(1);
+    v.split_at(2);
+}
+
+#[test]
+fn test_extend_from_within() {
+    let mut v = MyVec::new();
+    for c in "abcdef".chars() {
+        v.push_back(c.to_string());
+    }
+
+    v.extend_from_within(1..3);
+    assert_eq!(v.len(), 8);
+    assert_eq!(v.as_slice()[6..], ["b", "c"]);
+
+    // Rango que termina en len: la copia no se vuelve a leer a sí misma
+    v.extend_from_within(6..);
+    assert_eq!(v.len(), 10);
+    assert_eq!(v.as_slice()[8..], ["b", "c"]);
+
+    // Rango vacío
+    v.extend_from_within(3..3);
+    assert_eq!(v.len(), 10);
+}
+
+#[test]
+fn test_extend_from_within_grows() {
+    // Estilo LZ77: copiar todo el contenido obliga a reubicar el buffer
+    let mut v = MyVec::with_capacity(4);
+    for i in 0..4 {
+        v.push_back(i);
+    }
+
+    v.extend_from_within(..);
+    v.extend_from_within(2..=5);
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3, 0, 1, 2, 3, 2, 3, 0, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_extend_from_within_out_of_bounds() {
+    let mut v = MyVec::new();
+    v.push_back(1);
+    v.extend_from_within(0..2);
+}
+
+#[test]
+fn test_bulk_operations() {
+    let mut v = MyVec::new();
+    v.extend(0..5);
+    v.extend_from_slice(&[5, 6]);
+    assert_eq!(v.as_slice(), &[0, 1, 2, 3, 4, 5, 6]);
+
+    let copy = v.clone();
+    v.retain(|x| x % 3 != 0);
+    assert_eq!(v.as_slice(), &[1, 2, 4, 5]);
+    assert_eq!(copy.len(), 7);
+
+    v.resize(6, 9);
+    assert_eq!(v.as_slice(), &[1, 2, 4, 5, 9, 9]);
+    v.resize(2, 0);
+    assert_eq!(v.as_slice(), &[1, 2]);
+
+    v.truncate(0);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_from_elem() {
+    let v = MyVec::from_elem(String::from("x"), 3);
+    assert_eq!(v.len(), 3);
+    assert_eq!(v.capacity(), 3);
+
+    // Cada String es independiente
+    let mut v = v;
+    v.get_mut(0).unwrap().push('!');
+    assert_eq!(v.as_slice(), &["x!", "x", "x"]);
+
+    let empty: MyVec<String> = MyVec::from_elem(String::new(), 0);
+    assert_eq!(empty.capacity(), 0);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_myvec_macro() {
+    let v = vectors::myvec![7u8; 5];
+    assert_eq!(v.as_slice(), &[7; 5]);
+    assert_eq!(v.capacity(), 5);
+
+    let v = vectors::myvec!["a", "b"];
+    assert_eq!(v.as_slice(), &["a", "b"]);
+}
+
+#[test]
+fn test_repeat() {
+    let v = vectors::myvec![1, 2, 3];
+
+    let tiled = v.repeat(3);
+    assert_eq!(tiled.as_slice(), &[1, 2, 3, 1, 2, 3, 1, 2, 3]);
+    assert_eq!(tiled.capacity(), 9);
+
+    assert!(v.repeat(0).is_empty());
+    assert_eq!(v.repeat(1).as_slice(), v.as_slice());
+
+    // Contra la concatenación manual, con n que no es potencia de 2
+    let mut manual = Vec::new();
+    for _ in 0..13 {
+        manual.extend_from_slice(v.as_slice());
+    }
+    assert_eq!(v.repeat(13).as_slice(), &manual[..]);
+
+    let empty: MyVec<i32> = MyVec::new();
+    assert!(empty.repeat(100).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_repeat_overflow() {
+    let v = vectors::myvec![1u8, 2];
+    v.repeat(usize::MAX);
+}
+
+#[test]
+fn test_partial_eq_across_types() {
+    let v = vectors::myvec![1, 2, 3];
+    let prefix = vectors::myvec![1, 2];
+
+    // MyVec con MyVec
+    assert_eq!(v, vectors::myvec![1, 2, 3]);
+    assert_ne!(v, prefix);
+
+    // Arrays y referencias a arrays
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v, &[1, 2, 3]);
+    assert_ne!(v, [1, 2, 4]);
+    assert_ne!(prefix, [1, 2, 3]);
+    assert_eq!([1, 2, 3], v);
+
+    // Slices
+    let slice: &[i32] = &[1, 2, 3];
+    assert_eq!(v, slice);
+    assert_eq!(v, *slice);
+    assert_eq!(slice, v);
+    assert_eq!(*slice, v);
+    assert_ne!(prefix, slice);
+    assert_ne!(slice, prefix);
+
+    // Vec de std
+    assert_eq!(v, vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], v);
+    assert_ne!(v, vec![1, 2]);
+    assert_ne!(vec![1, 2], v);
+    assert_ne!(vec![1, 2, 3, 4], v);
+
+    // Tipos de elemento distintos pero comparables
+    let owned = vectors::myvec![String::from("a")];
+    assert_eq!(owned, ["a"]);
+}
+
+#[test]
+fn test_borrow_hashmap_lookup() {
+    use std::collections::HashMap;
+
+    let mut map: HashMap<MyVec<u8>, &str> = HashMap::new();
+    map.insert(vectors::myvec![b'k', b'1'], "uno");
+    map.insert(vectors::myvec![b'k', b'2'], "dos");
+
+    // Búsqueda con &[u8] gracias a Borrow<[u8]>
+    let key: &[u8] = b"k2";
+    assert_eq!(map.get(key), Some(&"dos"));
+    assert_eq!(map.get(&b"k3"[..]), None);
+}
+
+#[test]
+fn test_as_ref_and_as_mut() {
+    fn checksum(data: impl AsRef<[u8]>) -> u32 {
+        data.as_ref().iter().map(|&b| b as u32).sum()
+    }
+
+    fn zero_first(mut data: impl AsMut<[u8]>) {
+        data.as_mut()[0] = 0;
+    }
+
+    let mut v = vectors::myvec![1u8, 2, 3];
+    assert_eq!(checksum(&v), 6);
+
+    zero_first(&mut v);
+    assert_eq!(checksum(v), 5);
+}
+
+#[test]
+fn test_ordering_matches_slices() {
+    let a = vectors::myvec![1, 2, 3];
+    let b = vectors::myvec![1, 3];
+    assert!(a < b);
+    assert_eq!(a.cmp(&b), [1, 2, 3][..].cmp(&[1, 3][..]));
+}
+
+#[test]
+fn test_pop_back() {
+    let mut v = vectors::myvec![String::from("a"), String::from("b")];
+    assert_eq!(v.pop_back().as_deref(), Some("b"));
+    assert_eq!(v.pop_back().as_deref(), Some("a"));
+    assert_eq!(v.pop_back(), None);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_growth_strategies() {
+    fn capacities<G: vectors::GrowthStrategy>(mut v: MyVec<u32, G>, pushes: u32) -> Vec<usize> {
+        let mut seen = Vec::new();
+        for i in 0..pushes {
+            v.push_back(i);
+            if seen.last() != Some(&v.capacity()) {
+                seen.push(v.capacity());
+            }
+        }
+        seen
+    }
+
+    // Por defecto se conserva el comportamiento de siempre: duplicar
+    assert_eq!(capacities(MyVec::new(), 40), vec![4, 8, 16, 32, 64]);
+
+    let doubling: MyVec<u32, vectors::Doubling> = MyVec::with_strategy();
+    assert_eq!(capacities(doubling, 40), vec![4, 8, 16, 32, 64]);
+
+    let one_point_five: MyVec<u32, vectors::OnePointFive> = MyVec::with_strategy();
+    assert_eq!(capacities(one_point_five, 40), vec![4, 6, 9, 13, 19, 28, 42]);
+
+    let exact: MyVec<u32, vectors::Exact> = MyVec::with_strategy();
+    assert_eq!(capacities(exact, 5), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_growth_strategy_reserve() {
+    let mut v: MyVec<u8, vectors::OnePointFive> = MyVec::with_capacity_and_strategy(10);
+    v.extend(0..10);
+
+    // reserve pasa por la estrategia, pero nunca da menos de lo pedido
+    v.reserve(1);
+    assert_eq!(v.capacity(), 15);
+    v.reserve(100);
+    assert_eq!(v.capacity(), 110);
+
+    let mut exact: MyVec<u8, vectors::Exact> = MyVec::with_strategy();
+    exact.reserve(7);
+    assert_eq!(exact.capacity(), 7);
+}
+
+#[test]
+fn test_copy_fast_paths() {
+    let mut v = MyVec::from_slice_copy(&[1u16, 2, 3]);
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(v.capacity(), 3);
+
+    v.extend_from_slice_copy(&[4, 5]);
+    v.extend_from_slice_copy(&[]);
+    assert_eq!(v, [1, 2, 3, 4, 5]);
+    let copied = v.clone_copy();
+    assert_eq!(copied, v.clone());
+    assert_eq!(copied.capacity(), 5);
+    assert_ne!(copied.as_slice().as_ptr(), v.as_slice().as_ptr());
+
+    // clone_copy conserva el límite, igual que clone
+    let mut bounded = MyVec::with_max_capacity(3);
+    bounded.extend_from_slice_copy(&[7u8, 8]);
+    let copied = bounded.clone_copy();
+    assert_eq!(copied, [7, 8]);
+    assert_eq!(copied.max_capacity(), Some(3));
+    assert_eq!(copied.capacity(), 2);
+
+    for n in [0, 1, 2, 7, 64, 100] {
+        let fast = MyVec::from_elem_copy(0xABu8, n);
+        assert_eq!(fast, MyVec::from_elem(0xABu8, n));
+        assert_eq!(fast.capacity(), n);
+    }
+}
+
+#[test]
+fn test_copy_vs_clone_10mb() {
+    use std::time::Instant;
+
+    const SIZE: usize = 10 * 1024 * 1024;
+    let mut source: MyVec<u8> = MyVec::with_capacity(SIZE);
+    source.extend((0..SIZE).map(|i| (i % 251) as u8));
+
+    let start = Instant::now();
+    let cloned = source.clone();
+    let clone_time = start.elapsed();
+
+    let start = Instant::now();
+    let copied = source.clone_copy();
+    let copy_time = start.elapsed();
+
+    println!("clone elemento a elemento: {clone_time:?}");
+    println!("clone_copy (memcpy):       {copy_time:?}");
+
+    // Igualdad byte a byte
+    assert_eq!(cloned.as_slice(), source.as_slice());
+    assert_eq!(copied.as_slice(), source.as_slice());
+}
+
+#[test]
+fn test_clone_non_copy_clones_each_element() {
+    thread_local! {
+        static CLONES: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct CountedClone(String);
+
+    impl Clone for CountedClone {
+        fn clone(&self) -> Self {
+            CLONES.with(|n| n.set(n.get() + 1));
+            CountedClone(self.0.clone())
+        }
+    }
+
+    let mut source = MyVec::with_capacity(10);
+    for word in ["a", "b", "c"] {
+        source.push_back(CountedClone(String::from(word)));
+    }
+
+    let cloned = source.clone();
+    assert_eq!(CLONES.with(Cell::get), 3);
+    assert_eq!(cloned, source);
+    assert_eq!(cloned.capacity(), 3);
+
+    let empty: MyVec<CountedClone> = MyVec::new();
+    assert_eq!(empty.clone().capacity(), 0);
+}
+
+#[test]
+fn test_clone_from_reuses_buffer() {
+    let source = vectors::myvec![1, 2, 3];
+
+    let mut dst: MyVec<i32> = MyVec::with_capacity(16);
+    dst.extend(0..10);
+    let ptr = dst.as_slice().as_ptr();
+
+    dst.clone_from(&source);
+    assert_eq!(dst, [1, 2, 3]);
+    assert_eq!(dst.capacity(), 16);
+    assert_eq!(dst.as_slice().as_ptr(), ptr);
+
+    // Más corto que la fuente pero con capacidad suficiente
+    let mut short: MyVec<i32> = MyVec::with_capacity(8);
+    short.push_back(9);
+    let ptr = short.as_slice().as_ptr();
+    short.clone_from(&source);
+    assert_eq!(short, [1, 2, 3]);
+    assert_eq!(short.as_slice().as_ptr(), ptr);
+
+    // Sin capacidad suficiente sí realoja
+    let mut tiny: MyVec<i32> = MyVec::new();
+    tiny.clone_from(&source);
+    assert_eq!(tiny, source);
+}
+
+#[test]
+fn test_clone_from_into_bounded_destination() {
+    let source = vectors::myvec![1, 2, 3, 4, 5];
+
+    // El destino acotado es más chico que la fuente: toma los límites de `source`
+    let mut dst: MyVec<i32> = MyVec::with_max_capacity(2);
+    dst.push_back(9);
+    dst.clone_from(&source);
+    assert_eq!(dst, [1, 2, 3, 4, 5]);
+    assert_eq!(dst.max_capacity(), None);
+
+    let mut bounded = MyVec::with_max_capacity(4);
+    bounded.extend(0..3);
+    bounded.set_auto_shrink(true);
+    let mut unbounded: MyVec<i32> = MyVec::new();
+    unbounded.clone_from(&bounded);
+    assert_eq!(unbounded, [0, 1, 2]);
+    assert_eq!(unbounded.max_capacity(), Some(4));
+    assert!(unbounded.auto_shrink());
+    assert_eq!(unbounded.max_capacity(), bounded.clone().max_capacity());
+
+    // Destino con datos y un bloque más grande que el límite de la fuente
+    let mut small = MyVec::with_max_capacity(4);
+    small.extend([1, 2]);
+    let mut big: MyVec<i32> = MyVec::with_capacity(16);
+    big.extend(0..8);
+    big.clone_from(&small);
+    assert_eq!(big, [1, 2]);
+    assert_eq!(big.capacity(), 4);
+    assert_eq!(big.remaining_capacity(), Some(2));
+    big.push_back(3);
+    big.push_back(4);
+    assert!(big.is_full());
+    assert_eq!(big.push_within_capacity(5), Err(5));
+    assert_eq!(big.try_reserve(1), Err(TryReserveError::CapacityExceeded { cap: 4 }));
+
+    // Fuente llena: el bloque queda justo del tamaño del límite
+    let mut full: MyVec<i32> = MyVec::with_capacity(16);
+    full.extend(0..10);
+    full.clone_from(&big);
+    assert_eq!(full, [1, 2, 3, 4]);
+    assert_eq!(full.capacity(), 4);
+    assert!(full.is_full());
+}
+
+#[test]
+fn test_clone_from_drops_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut source = MyVec::new();
+    for i in 0..3 {
+        source.push_back(Counted(i, Rc::clone(&drops)));
+    }
+
+    let mut dst = MyVec::new();
+    for i in 10..15 {
+        dst.push_back(Counted(i, Rc::clone(&drops)));
+    }
+
+    dst.clone_from(&source);
+    // 2 sobrantes + 3 sobrescritos, cada uno exactamente una vez
+    assert_eq!(drops.get(), 5);
+    assert_eq!(dst.as_slice().iter().map(|c| c.0).collect::<Vec<_>>(), vec![0, 1, 2]);
+
+    drop(dst);
+    drop(source);
+    assert_eq!(drops.get(), 11);
+}
+
+#[test]
+fn test_swap_with_slice() {
+    let mut v = vectors::myvec![String::from("a"), String::from("b")];
+    let mut buffer = [String::from("x"), String::from("y")];
+
+    v.swap_with_slice(&mut buffer);
+    assert_eq!(v, ["x", "y"]);
+    assert_eq!(buffer, ["a", "b"]);
+
+    let mut empty: MyVec<String> = MyVec::new();
+    empty.swap_with_slice(&mut []);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_swap_with_slice_length_mismatch() {
+    let mut v = vectors::myvec![1, 2, 3];
+    v.swap_with_slice(&mut [0, 0]);
+}
+
+#[test]
+fn test_iter_rev_matches_forward() {
+    let v: MyVec<i32> = (1..=5).collect();
+
+    let forward: Vec<_> = v.iter().copied().collect();
+    let mut backward: Vec<_> = v.iter().rev().copied().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    let owned: Vec<_> = v.clone().into_iter().rev().collect();
+    assert_eq!(owned, vec![5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_iter_exact_len_and_fused() {
+    let mut v: MyVec<i32> = (0..4).collect();
+
+    let mut it = v.iter();
+    for remaining in (0..4).rev() {
+        it.next();
+        assert_eq!(it.len(), remaining);
+    }
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    for x in &mut v {
+        *x *= 10;
+    }
+    assert_eq!(v, [0, 10, 20, 30]);
+
+    let mut into = v.into_iter();
+    assert_eq!(into.len(), 4);
+    assert_eq!(into.next(), Some(0));
+    assert_eq!(into.next_back(), Some(30));
+    assert_eq!(into.as_slice(), &[10, 20]);
+    into.by_ref().for_each(drop);
+    assert_eq!(into.next(), None);
+    assert_eq!(into.next_back(), None);
+}
+
+#[test]
+fn test_into_iter_drops_unconsumed_exactly_once() {
+    let drops = Rc::new(Cell::new(0));
+    let mut v = MyVec::new();
+    for _ in 0..6 {
+        v.push_back(Counted((), Rc::clone(&drops)));
+    }
+
+    let mut it = v.into_iter();
+    drop(it.next());
+    drop(it.next_back());
+    drop(it.next_back());
+    assert_eq!(drops.get(), 3);
+
+    // Los 3 del medio se destruyen al soltar el iterador
+    drop(it);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn test_memory_stats_primitive_types() {
+    let mut bytes: MyVec<u8> = MyVec::with_capacity(16);
+    bytes.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(bytes.allocated_bytes(), 16);
+    assert_eq!(bytes.element_size(), 1);
+    assert_eq!(bytes.element_align(), 1);
+
+    let mut words: MyVec<u64> = MyVec::with_capacity(8);
+    words.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(
+        words.memory_stats(),
+        MemoryStats {
+            len: 3,
+            capacity: 8,
+            element_size: 8,
+            element_align: 8,
+            allocated_bytes: 64,
+            used_bytes: 24,
+            wasted_bytes: 40,
+        }
+    );
+}
+
+#[test]
+fn test_memory_stats_counts_padding() {
+    // u8 + u32: 5 bytes de datos, pero la alineación de u32 lo lleva a 8
+    #[allow(dead_code)]
+    struct Padded {
+        tag: u8,
+        value: u32,
+    }
+
+    let mut v: MyVec<Padded> = MyVec::with_capacity(2);
+    v.push_back(Padded { tag: 1, value: 2 });
+
+    let stats = v.memory_stats();
+    assert_eq!(stats.element_size, 8);
+    assert_eq!(stats.element_align, 4);
+    assert_eq!(stats.allocated_bytes, 16);
+    assert_eq!(stats.used_bytes, 8);
+    assert_eq!(stats.wasted_bytes, 8);
+}
+
+#[test]
+fn test_memory_stats_empty_vector() {
+    let v: MyVec<u64> = MyVec::new();
+    let stats = v.memory_stats();
+
+    assert_eq!(v.allocated_bytes(), 0);
+    assert_eq!(stats.allocated_bytes, 0);
+    assert_eq!(stats.wasted_bytes, 0);
+
+    let table = stats.to_string();
+    assert!(table.contains("allocated"));
+    assert_eq!(table.lines().count(), 9);
+}
+
+#[test]
+fn test_auto_shrink_releases_memory_after_burst() {
+    let mut with_policy: MyVec<u32> = MyVec::new();
+    let mut without_policy: MyVec<u32> = MyVec::new();
+    with_policy.set_auto_shrink(true);
+    assert!(!without_policy.auto_shrink());
+
+    for i in 0..1024 {
+        with_policy.push_back(i);
+        without_policy.push_back(i);
+    }
+    let peak = without_policy.capacity();
+    assert_eq!(with_policy.capacity(), peak);
+
+    while with_policy.len() > 10 {
+        with_policy.pop_back();
+        without_policy.pop_back();
+    }
+
+    assert_eq!(without_policy.capacity(), peak);
+    assert!(with_policy.capacity() < peak);
+    // Invariante de la política: tras cada pop, len >= capacity / 4
+    assert!(with_policy.capacity() <= 4 * with_policy.len() + 4);
+    assert_eq!(with_policy.as_slice(), without_policy.as_slice());
+}
+
+#[test]
+fn test_auto_shrink_truncate_and_remove_respect_minimum() {
+    let mut v: MyVec<u32> = (0..256).collect();
+    v.set_auto_shrink(true);
+
+    // 3 no es menor que 8/4: se detiene en 8
+    v.truncate(3);
+    assert_eq!(v, [0, 1, 2]);
+    assert_eq!(v.capacity(), 8);
+
+    assert_eq!(v.remove(1), 1);
+    assert_eq!(v.remove(0), 0);
+    assert_eq!(v.capacity(), vectors::MIN_AUTO_SHRINK_CAPACITY);
+    assert_eq!(v.pop_back(), Some(2));
+    assert_eq!(v.capacity(), vectors::MIN_AUTO_SHRINK_CAPACITY);
+}
+
+#[test]
+fn test_remove_shifts_left() {
+    let mut v = vectors::myvec![String::from("a"), String::from("b"), String::from("c")];
+
+    assert_eq!(v.remove(1), "b");
+    assert_eq!(v, ["a", "c"]);
+    assert_eq!(v.remove(1), "c");
+    assert_eq!(v, ["a"]);
+}
+
+#[test]
+#[should_panic]
+fn test_remove_out_of_bounds() {
+    let mut v = vectors::myvec![1, 2, 3];
+    v.remove(3);
+}
+
+#[test]
+fn test_get_many_mut_disjoint() {
+    let mut v = vectors::myvec![1, 2, 3, 4];
+
+    let [a, b] = v.get_many_mut([0, 3]).unwrap();
+    std::mem::swap(a, b);
+    *a += 10;
+    assert_eq!(v, [14, 2, 3, 1]);
+
+    let [only] = v.get_many_mut([1]).unwrap();
+    *only = 0;
+    assert_eq!(v, [14, 0, 3, 1]);
+
+    let [x, y, z] = v.get_many_mut([2, 0, 1]).unwrap();
+    *x += 1;
+    *y += 1;
+    *z += 1;
+    assert_eq!(v, [15, 1, 4, 1]);
+}
+
+#[test]
+fn test_get_many_mut_rejects_invalid_indices() {
+    let mut v = vectors::myvec![1, 2, 3];
+
+    assert!(v.get_many_mut([1, 1]).is_none());
+    assert!(v.get_many_mut([0, 2, 0]).is_none());
+    assert!(v.get_many_mut([0, 3]).is_none());
+    assert!(v.get_many_mut([usize::MAX]).is_none());
+    assert_eq!(v, [1, 2, 3]);
+}
+
+#[test]
+fn test_try_insert_and_try_remove() {
+    let mut v = vectors::myvec![1, 3];
+
+    assert_eq!(v.try_insert(1, 2), Ok(()));
+    assert_eq!(v.try_insert(3, 4), Ok(()));
+    assert_eq!(v, [1, 2, 3, 4]);
+
+    let err = v.try_insert(9, 0).unwrap_err();
+    assert_eq!(err, MyVecError::IndexOutOfBounds { index: 9, len: 4 });
+    assert_eq!(err.to_string(), "index 9 out of bounds for length 4");
+
+    assert_eq!(v.try_remove(0), Ok(1));
+    match v.try_remove(3) {
+        Err(MyVecError::IndexOutOfBounds { index, len }) => assert_eq!((index, len), (3, 3)),
+        other => panic!("se esperaba IndexOutOfBounds, llegó {other:?}"),
+    }
+    assert_eq!(v, [2, 3, 4]);
+}
+
+#[test]
+fn test_my_vec_error_variants_display() {
+    assert_eq!(MyVecError::CapacityOverflow.to_string(), "capacity overflow");
+    assert_eq!(
+        MyVecError::AllocFailed { bytes: 4096 }.to_string(),
+        "memory allocation of 4096 bytes failed"
+    );
+    assert_eq!(
+        MyVecError::CapacityExceeded { cap: 8 }.to_string(),
+        "capacity limit of 8 elements exceeded"
+    );
+
+    // try_reserve se traduce al error estructurado con `?`
+    let mut v: MyVec<u64> = MyVec::new();
+    let err: MyVecError = v.try_reserve(usize::MAX).unwrap_err().into();
+    assert!(matches!(err, MyVecError::CapacityOverflow));
+
+    let layout = std::alloc::Layout::array::<u64>(16).unwrap();
+    let err = MyVecError::from(TryReserveError::AllocError { layout });
+    assert!(matches!(err, MyVecError::AllocFailed { bytes: 128 }));
+}
+
+#[test]
+fn test_bounded_vector_rejects_past_cap() {
+    let mut v = MyVec::with_max_capacity(3);
+    for i in 0..3 {
+        assert_eq!(v.remaining_capacity(), Some(3 - i));
+        v.push_back(format!("log {i}"));
+    }
+
+    assert!(v.is_full());
+    assert_eq!(v.capacity(), 3);
+    assert_eq!(
+        v.push_within_capacity(String::from("rechazado")),
+        Err(String::from("rechazado"))
+    );
+    assert_eq!(v.try_reserve(1), Err(TryReserveError::CapacityExceeded { cap: 3 }));
+    assert_eq!(v.try_push(String::from("x")), Err(String::from("x")));
+
+    assert_eq!(v.pop_back().as_deref(), Some("log 2"));
+    assert!(!v.is_full());
+    assert_eq!(v.push_within_capacity(String::from("log 3")), Ok(()));
+    assert_eq!(v, ["log 0", "log 1", "log 3"]);
+    assert_eq!(v.capacity(), 3);
+}
+
+#[test]
+fn test_bounded_vector_reports_capacity_exceeded() {
+    let mut v = MyVec::with_max_capacity(4);
+    for i in 0..4 {
+        assert_eq!(v.try_insert(0, i), Ok(()));
+    }
+
+    assert_eq!(v.try_insert(2, 99), Err(MyVecError::CapacityExceeded { cap: 4 }));
+    assert_eq!(v.try_reserve(1), Err(TryReserveError::CapacityExceeded { cap: 4 }));
+    assert_eq!(
+        TryReserveError::CapacityExceeded { cap: 4 }.to_string(),
+        "capacity limit of 4 elements exceeded"
+    );
+    // Un índice inválido se reporta antes que la falta de espacio
+    assert_eq!(v.try_insert(9, 99), Err(MyVecError::IndexOutOfBounds { index: 9, len: 4 }));
+    assert_eq!(v, [3, 2, 1, 0]);
+
+    // Pedir más de lo que queda también falla aunque haya espacio libre
+    v.pop_back();
+    assert_eq!(v.try_reserve(2), Err(TryReserveError::CapacityExceeded { cap: 4 }));
+    assert_eq!(v.try_reserve(1), Ok(()));
+}
+
+/// Iterador que promete más elementos de los que entrega.
+struct Overpromise(std::ops::Range<i32>);
+
+impl Iterator for Overpromise {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (100, None)
+    }
+}
+
+#[test]
+fn test_bounded_vector_extend_fills_remaining_space() {
+    let mut v = MyVec::with_max_capacity(5);
+    v.push_back(0);
+    v.extend(Overpromise(1..5));
+    assert_eq!(v, [0, 1, 2, 3, 4]);
+    assert_eq!(v.capacity(), 5);
+
+    // Tras encoger vuelve a crecer, pero sin pasar el límite
+    v.truncate(2);
+    v.shrink_to_fit();
+    assert_eq!(v.capacity(), 2);
+    v.extend(5..8);
+    assert_eq!(v, [0, 1, 5, 6, 7]);
+    assert_eq!(v.capacity(), 5);
+}
+
+#[test]
+#[should_panic(expected = "bounded MyVec is full")]
+fn test_bounded_vector_extend_panics_past_cap() {
+    let mut v = MyVec::with_max_capacity(3);
+    v.extend(Overpromise(0..4));
+}
+
+/// Iterador que promete `usize::MAX` elementos sin entregar ninguno.
+struct Bottomless;
+
+impl Iterator for Bottomless {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_extend_reports_capacity_overflow() {
+    // Solo el límite de un vector acotado se tolera; el overflow no
+    let mut v: MyVec<u64> = MyVec::new();
+    v.extend(Bottomless);
+}
+
+#[test]
+#[should_panic(expected = "bounded MyVec is full")]
+fn test_bounded_vector_reserve_panics_past_cap() {
+    let mut v: MyVec<u8> = MyVec::with_max_capacity(2);
+    v.reserve(3);
+}
+
+#[test]
+#[should_panic(expected = "bounded MyVec is full")]
+fn test_bounded_vector_push_back_panics_when_full() {
+    let mut v = MyVec::with_max_capacity(2);
+    v.push_back(1);
+    v.push_back(2);
+    v.push_back(3);
+}
+
+#[test]
+fn test_unbounded_vector_unaffected_by_bounded_mode() {
+    let mut v: MyVec<i32> = MyVec::new();
+    assert_eq!(v.max_capacity(), None);
+    assert_eq!(v.remaining_capacity(), None);
+
+    for i in 0..100 {
+        v.push_back(i);
+    }
+    assert!(!v.is_full());
+
+    // Sin espacio libre, push_within_capacity no realoja
+    v.shrink_to_fit();
+    assert_eq!(v.push_within_capacity(100), Err(100));
+    v.push_back(100);
+    assert_eq!(v.len(), 101);
+}
+
+#[test]
+fn test_with_capacity_zeroed_matches_from_elem() {
+    let n = 1 << 16;
+
+    let bytes = MyVec::<u8>::with_capacity_zeroed(n);
+    assert_eq!(bytes.len(), n);
+    assert_eq!(bytes.capacity(), n);
+    assert!(bytes.as_slice().iter().all(|&b| b == 0));
+    assert_eq!(bytes, MyVec::from_elem(0u8, n));
+
+    let floats = MyVec::<f64>::with_capacity_zeroed(1000);
+    assert_eq!(floats.len(), 1000);
+    assert_eq!(floats.capacity(), 1000);
+    assert!(floats.as_slice().iter().all(|&x| x == 0.0 && x.is_sign_positive()));
+
+    let empty = MyVec::<i32>::with_capacity_zeroed(0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.capacity(), 0);
+}
+
+#[test]
+fn test_with_capacity_zeroed_is_usable_afterwards() {
+    let mut v = MyVec::<u32>::with_capacity_zeroed(4);
+    v.as_mut_slice()[1] = 7;
+    v.push_back(9);
+
+    assert_eq!(v, [0, 7, 0, 0, 9]);
+}
+
+#[test]
+fn test_starts_with_and_ends_with() {
+    let buf = MyVec::from_slice_copy(b"HTTP/1.1");
+
+    assert!(buf.starts_with(b"HTTP/1.1"));
+    assert!(buf.ends_with(b"HTTP/1.1"));
+    assert!(buf.starts_with(b"HTTP/"));
+    assert!(buf.ends_with(b"1.1"));
+
+    // Solo difiere el último byte comparado
+    assert!(!buf.starts_with(b"HTTP?"));
+    assert!(!buf.ends_with(b"1.2"));
+    assert!(!buf.starts_with(b"HTTP/1.1 extra"));
+
+    let empty: MyVec<u8> = MyVec::new();
+    assert!(!empty.starts_with(b"H"));
+    assert!(!empty.ends_with(b"H"));
+    assert!(empty.starts_with(b""));
+    assert!(empty.ends_with(b""));
+    assert!(buf.starts_with(&[]));
+}
+
+#[test]
+fn test_is_sorted_variants() {
+    let empty: MyVec<i32> = MyVec::new();
+    assert!(empty.is_sorted());
+    assert!(vectors::myvec![42].is_sorted());
+
+    assert!(vectors::myvec![1, 2, 2, 5].is_sorted());
+    assert!(!vectors::myvec![2, 1, 3, 4].is_sorted());
+    assert!(!vectors::myvec![1, 2, 3, 0].is_sorted());
+
+    let desc = vectors::myvec![9, 7, 7, 1];
+    assert!(!desc.is_sorted());
+    assert!(desc.is_sorted_by(|a, b| a >= b));
+
+    let words = vectors::myvec!["a", "bb", "ccc", "dd"];
+    assert!(!words.is_sorted_by_key(|w| w.len()));
+    assert!(words.is_sorted());
+}
+
+#[test]
+fn test_select_nth_unstable_matches_sorted_copy() {
+    // LCG simple para tener datos "aleatorios" reproducibles
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut data = Vec::new();
+    for _ in 0..500 {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        data.push((state >> 33) as i64 % 100);
+    }
+    let mut sorted = data.clone();
+    sorted.sort();
+
+    for n in [0, 1, 137, 250, 498, 499] {
+        let mut v = MyVec::from_slice_copy(&data);
+        let (left, nth, right) = v.select_nth_unstable(n);
+
+        assert_eq!(*nth, sorted[n]);
+        let pivot = *nth;
+        assert!(left.iter().all(|&x| x <= pivot));
+        assert!(right.iter().all(|&x| x >= pivot));
+        assert_eq!(left.len(), n);
+    }
+}
+
+#[test]
+fn test_select_nth_unstable_linear_on_easy_inputs() {
+    use std::cmp::Ordering;
+
+    thread_local! {
+        static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(PartialEq, Eq)]
+    struct CountedCmp(u32);
+
+    impl PartialOrd for CountedCmp {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for CountedCmp {
+        fn cmp(&self, other: &Self) -> Ordering {
+            COMPARISONS.with(|c| c.set(c.get() + 1));
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let n = 10_000;
+    let inputs: [Box<dyn Fn(u32) -> u32>; 3] = [
+        Box::new(|i| i),
+        Box::new(move |i| n - i),
+        Box::new(|_| 7),
+    ];
+
+    for make in inputs {
+        let mut v: MyVec<CountedCmp> = (0..n).map(|i| CountedCmp(make(i))).collect();
+        COMPARISONS.with(|c| c.set(0));
+
+        let median = v.select_nth_unstable(n as usize / 2).1.0;
+
+        // Un pivote malo sobre entradas ordenadas haría ~n²/2 comparaciones
+        let comparisons = COMPARISONS.with(|c| c.get());
+        assert!(comparisons < 10 * n as usize, "{comparisons} comparaciones");
+        let mut expected: Vec<u32> = (0..n).map(&make).collect();
+        expected.sort();
+        assert_eq!(median, expected[n as usize / 2]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_select_nth_unstable_out_of_bounds() {
+    let mut v = vectors::myvec![3, 1, 2];
+    v.select_nth_unstable(3);
+}
+
+#[test]
+fn test_chunk_by_runs() {
+    let v = vectors::myvec![1, 1, 2, 2, 2, 3];
+    let runs: Vec<&[i32]> = v.chunk_by(|a, b| a == b).collect();
+    assert_eq!(runs, [&[1, 1][..], &[2, 2, 2], &[3]]);
+
+    let empty: MyVec<i32> = MyVec::new();
+    assert_eq!(empty.chunk_by(|a, b| a == b).count(), 0);
+
+    let distinct = vectors::myvec![1, 2, 3, 4];
+    assert!(distinct.chunk_by(|a, b| a == b).all(|c| c.len() == 1));
+    assert_eq!(distinct.chunk_by(|a, b| a == b).count(), distinct.len());
+}
+
+#[test]
+fn test_chunk_by_parity_concatenates_back() {
+    let v = vectors::myvec![2, 4, 1, 3, 5, 6, 8, 7];
+    let chunks: Vec<&[i32]> = v.chunk_by(|a, b| a % 2 == b % 2).collect();
+
+    assert_eq!(chunks.len(), 4);
+    assert_eq!(chunks.concat(), v.as_slice());
+}
+
+#[test]
+fn test_shuffle_golden_permutation() {
+    let mut rng = SimpleRng::from_seed(0);
+    // Primer valor conocido de SplitMix64 con semilla 0
+    assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+
+    let mut v: MyVec<u32> = (0..10).collect();
+    v.shuffle(&mut SimpleRng::from_seed(2024));
+    assert_eq!(v, [9, 0, 6, 3, 4, 2, 5, 7, 8, 1]);
+}
+
+#[test]
+fn test_shuffle_preserves_elements() {
+    let mut rng = SimpleRng::from_seed(7);
+    let mut v: MyVec<u32> = (0..100).map(|i| i % 13).collect();
+    let original = v.clone();
+
+    v.shuffle(&mut rng);
+    assert_ne!(v, original);
+
+    let mut a = v.as_slice().to_vec();
+    let mut b = original.as_slice().to_vec();
+    a.sort();
+    b.sort();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_shuffle_and_choose_edge_cases() {
+    let mut rng = SimpleRng::from_seed(1);
+
+    let mut empty: MyVec<i32> = MyVec::new();
+    empty.shuffle(&mut rng);
+    assert!(empty.is_empty());
+    assert_eq!(empty.choose(&mut rng), None);
+
+    let mut one = vectors::myvec![5];
+    one.shuffle(&mut rng);
+    assert_eq!(one, [5]);
+    assert_eq!(one.choose(&mut rng), Some(&5));
+
+    let v = vectors::myvec![10, 20, 30];
+    for _ in 0..50 {
+        assert!(v.as_slice().contains(v.choose(&mut rng).unwrap()));
+    }
+}
+
+#[test]
+fn test_raw_parts_round_trip_without_leak_or_double_free() {
+    let drops = Rc::new(Cell::new(0));
+    let mut v = MyVec::with_capacity(8);
+    for word in ["uno", "dos", "tres"] {
+        v.push_back(Counted(word.to_string(), Rc::clone(&drops)));
+    }
+    let data_ptr = v.as_ptr();
+
+    let (ptr, len, cap) = v.into_raw_parts();
+    assert_eq!((ptr as *const Counted<String>, len, cap), (data_ptr, 3, 8));
+    assert_eq!(drops.get(), 0);
+
+    let rebuilt: MyVec<Counted<String>> = unsafe { MyVec::from_raw_parts(ptr, len, cap) };
+    assert_eq!(rebuilt.get(2).map(|c| c.0.as_str()), Some("tres"));
+    assert_eq!(rebuilt.capacity(), 8);
+
+    drop(rebuilt);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn test_ffi_style_fill_through_mut_ptr() {
+    // Simula una función de C que escribe `n` bytes en el buffer recibido
+    unsafe fn fill_bytes(dst: *mut u8, n: usize) -> usize {
+        for i in 0..n {
+            unsafe { std::ptr::write(dst.add(i), b'a' + i as u8) };
+        }
+        n
+    }
+
+    let mut buf: MyVec<u8> = MyVec::with_capacity(16);
+    unsafe {
+        let written = fill_bytes(buf.as_mut_ptr(), 5);
+        buf.set_len(written);
+    }
+
+    assert_eq!(buf, *b"abcde");
+}
+
+#[test]
+fn test_debug_dump_shows_layout() {
+    let mut v = MyVec::with_capacity(4);
+    v.extend_from_slice(&[10, 20, 30]);
+
+    let dump = v.debug_dump();
+    assert!(dump.starts_with("MyVec { ptr: 0x"));
+    assert!(dump.contains("len: 3, cap: 4"));
+    assert!(dump.contains("0: 10, 1: 20, 2: 30, 3: <uninit>"));
+
+    let words = vectors::myvec![String::from("hola")];
+    assert!(words.debug_dump().contains("0: \"hola\""));
+}
+
+#[test]
+fn test_debug_dump_empty_vector() {
+    // Con capacidad 0 el puntero es dangling: solo se imprime, nunca se lee
+    let v: MyVec<String> = MyVec::new();
+    let dump = v.debug_dump();
+
+    assert!(dump.contains("len: 0, cap: 0"));
+    assert!(dump.ends_with("[ ]"));
+    assert_eq!(MyVec::<u32>::new().hex_dump(), "");
+}
+
+#[test]
+fn test_hex_dump_bytes_per_slot() {
+    let mut v: MyVec<u32> = MyVec::with_capacity(3);
+    v.push_back(u32::from_ne_bytes([0xde, 0xad, 0xbe, 0xef]));
+    v.push_back(1);
+
+    let dump = v.hex_dump();
+    let lines: Vec<&str> = dump.lines().collect();
+    assert_eq!(lines[0], "0: de ad be ef");
+    assert_eq!(lines[2], "2: .. .. .. ..");
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn test_io_write_then_read_in_chunks() {
+    use std::io::{Read, Write};
+    use vectors::MyVecReader;
+
+    let mut out: MyVec<u8> = MyVec::new();
+    let name = "ana";
+    write!(out, "id={} nombre={name}", 42).unwrap();
+    writeln!(out, ";").unwrap();
+    out.flush().unwrap();
+
+    let mut reader = MyVecReader::new(&out);
+    let mut chunk = [0u8; 3];
+    let mut collected = Vec::new();
+    loop {
+        let n = reader.read(&mut chunk).unwrap();
+        if n == 0 {
+            break;
+        }
+        collected.extend_from_slice(&chunk[..n]);
+    }
+
+    assert_eq!(collected, b"id=42 nombre=ana;\n");
+    assert_eq!(reader.position(), out.len());
+    assert!(reader.remaining().is_empty());
+}
+
+#[test]
+fn test_io_zero_length_and_capacity_boundary() {
+    use std::io::{Read, Write};
+    use vectors::MyVecReader;
+
+    let mut out: MyVec<u8> = MyVec::with_capacity(4);
+    assert_eq!(out.write(&[]).unwrap(), 0);
+    assert!(out.is_empty());
+
+    // Cruza el límite de capacidad: 3 + 3 > 4
+    assert_eq!(out.write(b"abc").unwrap(), 3);
+    assert_eq!(out.write(b"def").unwrap(), 3);
+    assert!(out.capacity() >= 6);
+    assert_eq!(out, *b"abcdef");
+
+    let mut reader = MyVecReader::new(&out);
+    assert_eq!(reader.read(&mut []).unwrap(), 0);
+    assert_eq!(reader.position(), 0);
+
+    let mut all = String::new();
+    reader.read_to_string(&mut all).unwrap();
+    assert_eq!(all, "abcdef");
+}
+
+#[test]
+fn test_io_write_to_bounded_vector_fails_instead_of_panicking() {
+    use std::io::{self, ErrorKind, Write};
+
+    let mut out: MyVec<u8> = MyVec::with_max_capacity(8);
+    out.write_all(b"GET /").unwrap();
+
+    // write_all es todo o nada: no cabe, no escribe
+    let err = out.write_all(b"index.html").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+    assert_eq!(err.to_string(), "capacity limit of 8 elements exceeded");
+    assert_eq!(out, *b"GET /");
+
+    // write escribe lo que quepa; lleno, retorna Ok(0) sin error
+    assert_eq!(out.write(b"index.html").unwrap(), 3);
+    assert_eq!(out, *b"GET /ind");
+    assert_eq!(out.write(b"ex").unwrap(), 0);
+    assert_eq!(out.write(&[]).unwrap(), 0);
+    assert_eq!(out, *b"GET /ind");
+
+    // El write_all por defecto, que se apoya solo en write, reporta WriteZero
+    struct OnlyWrite<'a>(&'a mut MyVec<u8>);
+
+    impl Write for OnlyWrite<'_> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = OnlyWrite(&mut out).write_all(b"ex").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::WriteZero);
+
+    assert_eq!(write!(out, "!").unwrap_err().kind(), ErrorKind::WriteZero);
+    assert_eq!(out.len(), 8);
+}
+
+#[test]
+fn test_from_fn_builds_table() {
+    let squares = MyVec::from_fn(10, |i| (i * i) as u64);
+    assert_eq!(squares.len(), 10);
+    assert_eq!(squares.capacity(), 10);
+    for i in 0..10 {
+        assert_eq!(squares.get(i), Some(&((i * i) as u64)));
+    }
+
+    let mut calls = 0;
+    let empty: MyVec<String> = MyVec::from_fn(0, |_| {
+        calls += 1;
+        String::new()
+    });
+    assert_eq!(calls, 0);
+    assert_eq!(empty.capacity(), 0);
+}
+
+#[test]
+fn test_partition_in_place_splits_by_predicate() {
+    let mut v: MyVec<u32> = (0..50).map(|i| (i * 37) % 50).collect();
+    let is_small = |x: &u32| *x < 20;
+    let expected = v.as_slice().iter().filter(|x| is_small(x)).count();
+
+    let mut calls = 0;
+    let idx = v.partition_in_place(|x| {
+        calls += 1;
+        is_small(x)
+    });
+
+    assert_eq!(idx, expected);
+    assert_eq!(calls, v.len());
+    assert!(v.as_slice()[..idx].iter().all(is_small));
+    assert!(v.as_slice()[idx..].iter().all(|x| !is_small(x)));
+}
+
+#[test]
+fn test_partition_in_place_edge_cases() {
+    let mut empty: MyVec<i32> = MyVec::new();
+    assert_eq!(empty.partition_in_place(|_| true), 0);
+
+    let mut all_true = vectors::myvec![2, 4, 6];
+    assert_eq!(all_true.partition_in_place(|x| x % 2 == 0), 3);
+    assert_eq!(all_true, [2, 4, 6]);
+
+    let mut all_false = vectors::myvec![1, 3, 5];
+    assert_eq!(all_false.partition_in_place(|x| x % 2 == 0), 0);
+    assert_eq!(all_false, [1, 3, 5]);
+}
+
+#[test]
+fn test_sort_radix_matches_std_sort() {
+    let mut rng = SimpleRng::from_seed(99);
+    let data: Vec<u32> = (0..5000).map(|_| rng.next_u64() as u32).collect();
+
+    let mut v = MyVec::from_slice_copy(&data);
+    v.sort_radix();
+    let mut expected = data.clone();
+    expected.sort();
+    assert_eq!(v, expected);
+
+    let mut sorted = MyVec::from_slice_copy(&expected);
+    sorted.sort_radix();
+    assert_eq!(sorted, expected);
+
+    let mut reversed: MyVec<u64> = (0..1000).rev().collect();
+    reversed.sort_radix();
+    assert!(reversed.is_sorted());
+
+    let mut equal = MyVec::from_elem_copy(7u16, 100);
+    equal.sort_radix();
+    assert_eq!(equal, [7u16; 100]);
+}
+
+#[test]
+fn test_sort_radix_signed_bias() {
+    let mut rng = SimpleRng::from_seed(5);
+    let mut data: Vec<i32> = (0..2000).map(|_| rng.next_u64() as i32).collect();
+    data.extend([i32::MIN, -1, 0, 1, i32::MAX]);
+
+    let mut v = MyVec::from_slice_copy(&data);
+    v.sort_radix();
+    data.sort();
+    assert_eq!(v, data);
+
+    let mut small = vectors::myvec![3i8, -128, 127, -1, 0];
+    small.sort_radix();
+    assert_eq!(small, [-128, -1, 0, 3, 127]);
+}
+
+#[test]
+fn test_par_map_matches_sequential() {
+    let v: MyVec<u64> = (0..1_000_000).collect();
+
+    let parallel = v.par_map(8, |x| x * x);
+    let sequential: MyVec<u64> = v.iter().map(|x| x * x).collect();
+    assert_eq!(parallel, sequential);
+
+    // 0 hilos se trata como 1; más hilos que elementos también funciona
+    let small = vectors::myvec![1, 2, 3];
+    assert_eq!(small.par_map(0, |x| x + 1), [2, 3, 4]);
+    assert_eq!(small.par_map(16, |x| x + 1), [2, 3, 4]);
+
+    let empty: MyVec<i32> = MyVec::new();
+    assert!(empty.par_map(4, |x| *x).is_empty());
+}
+
+#[test]
+fn test_par_for_each_accumulates() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let v: MyVec<usize> = (0..100_000).collect();
+    let total = AtomicUsize::new(0);
+    let calls = AtomicUsize::new(0);
+
+    v.par_for_each(6, |x| {
+        total.fetch_add(*x, Ordering::Relaxed);
+        calls.fetch_add(1, Ordering::Relaxed);
+    });
+
+    assert_eq!(total.into_inner(), v.iter().sum::<usize>());
+    assert_eq!(calls.into_inner(), v.len());
+}
+
+#[test]
+#[should_panic(expected = "elemento prohibido")]
+fn test_par_map_propagates_worker_panic() {
+    let v: MyVec<u32> = (0..1000).collect();
+    v.par_map(4, |&x| {
+        if x == 777 {
+            panic!("elemento prohibido");
+        }
+        x
+    });
+}
@@ -0,0 +1,74 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use vectors::MyVec;
+
+/// Allocator que cuenta, por hilo, cuántas veces se le pide o devuelve
+/// memoria, para que los tests que corren en paralelo no se mezclen.
+struct CountingAlloc;
+
+thread_local! {
+    static CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        assert!(layout.size() > 0, "allocation of zero bytes");
+        CALLS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        assert!(layout.size() > 0, "deallocation of zero bytes");
+        CALLS.with(|n| n.set(n.get() + 1));
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn calls() -> usize {
+    CALLS.with(Cell::get)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Unit;
+
+#[test]
+fn test_zero_sized_elements_never_touch_the_allocator() {
+    let before = calls();
+
+    let mut v: MyVec<Unit> = MyVec::with_capacity(4);
+    for _ in 0..100 {
+        v.push_back(Unit);
+    }
+    assert_eq!(v.len(), 100);
+
+    let cloned = v.clone();
+    let copied = v.clone_copy();
+    assert_eq!((cloned.len(), copied.len()), (100, 100));
+
+    v.truncate(10);
+    v.shrink_to_fit();
+    v.insert(3, Unit);
+    assert_eq!(v.pop_back(), Some(Unit));
+    v.truncate(0);
+    v.shrink_to_fit();
+
+    assert_eq!(copied.into_iter().count(), 100);
+    drop(cloned);
+    drop(v);
+
+    assert_eq!(calls(), before);
+}
+
+#[test]
+fn test_sized_elements_still_allocate() {
+    let before = calls();
+    let v = MyVec::from_slice_copy(&[1u32, 2, 3]);
+    let copied = v.clone_copy();
+    assert_eq!(calls(), before + 2);
+    drop((v, copied));
+    assert_eq!(calls(), before + 4);
+}